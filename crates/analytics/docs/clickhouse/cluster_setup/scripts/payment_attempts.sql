@@ -26,6 +26,7 @@ CREATE TABLE hyperswitch.payment_attempt_queue on cluster '{cluster}' (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `authentication_escalation_reason` Nullable(String),
     `created_at` DateTime CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `modified_at` DateTime CODEC(T64, LZ4),
@@ -65,6 +66,7 @@ CREATE TABLE hyperswitch.payment_attempt_dist on cluster '{cluster}' (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `authentication_escalation_reason` Nullable(String),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
@@ -101,6 +103,7 @@ CREATE MATERIALIZED VIEW hyperswitch.payment_attempt_mv on cluster '{cluster}' T
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `authentication_escalation_reason` Nullable(String),
     `created_at` DateTime64(3),
     `capture_on` Nullable(DateTime64(3)),
     `last_synced` Nullable(DateTime64(3)),
@@ -135,6 +138,7 @@ SELECT
     error_code,
     connector_metadata,
     payment_experience,
+    authentication_escalation_reason,
     created_at,
     capture_on,
     last_synced,
@@ -174,6 +178,7 @@ CREATE TABLE hyperswitch.payment_attempt_clustered on cluster '{cluster}' (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `authentication_escalation_reason` Nullable(String),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
@@ -26,6 +26,7 @@ CREATE TABLE payment_attempts_queue (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `authentication_escalation_reason` Nullable(String),
     `created_at` DateTime CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `modified_at` DateTime CODEC(T64, LZ4),
@@ -64,6 +65,7 @@ CREATE TABLE payment_attempt_dist (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `authentication_escalation_reason` Nullable(String),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
@@ -111,6 +113,7 @@ CREATE MATERIALIZED VIEW kafka_parse_pa TO payment_attempt_dist (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `authentication_escalation_reason` Nullable(String),
     `created_at` DateTime64(3),
     `capture_on` Nullable(DateTime64(3)),
     `last_synced` Nullable(DateTime64(3)),
@@ -145,6 +148,7 @@ SELECT
     error_code,
     connector_metadata,
     payment_experience,
+    authentication_escalation_reason,
     created_at,
     capture_on,
     last_synced,
@@ -103,9 +103,32 @@ pub struct MerchantAccountCreate {
 pub struct MerchantAccountMetadata {
     pub compatible_connector: Option<api_enums::Connector>,
 
+    /// Restrictions on the return_url of payments, by default only http and https urls are allowed
+    pub return_url_allowlist: Option<ReturnUrlAllowlist>,
+
     #[serde(flatten)]
     pub data: Option<pii::SecretSerdeValue>,
 }
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct ReturnUrlAllowlist {
+    /// Disables the return_url validation, allowing any return_url
+    #[serde(default)]
+    pub disabled: bool,
+
+    /// Allows only https return_urls when the allowed schemes are not set
+    #[serde(default)]
+    pub https_only: bool,
+
+    /// The schemes allowed in the return_url, http and https are allowed if not set
+    #[schema(example = json!(["https"]))]
+    pub allowed_schemes: Option<Vec<String>>,
+
+    /// The hosts allowed in the return_url, any host is allowed if not set. The hosts are matched
+    /// case-insensitively, a host starting with `*.` allows all the subdomains of the host
+    #[schema(example = json!(["example.com", "*.example.com"]))]
+    pub allowed_hosts: Option<Vec<String>>,
+}
+
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct MerchantAccountUpdate {
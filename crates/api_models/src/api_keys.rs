@@ -23,6 +23,11 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The role whose permissions the API Key is restricted to. The API Key is granted the
+    /// access of the merchant when no role is provided.
+    #[schema(max_length = 64, example = "merchant_developer")]
+    pub role_id: Option<String>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +65,10 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The role whose permissions the API Key is restricted to, if any.
+    #[schema(max_length = 64, example = "merchant_developer")]
+    pub role_id: Option<String>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +111,10 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The role whose permissions the API Key is restricted to, if any.
+    #[schema(max_length = 64, example = "merchant_developer")]
+    pub role_id: Option<String>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
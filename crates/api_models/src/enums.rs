@@ -435,6 +435,54 @@ pub enum FrmPreferredFlowTypes {
     Pre,
     Post,
 }
+
+/// Indicates how the connector used to process the payment was selected
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorSelectionSource {
+    /// The connector was chosen by the routing algorithm passed in the request or stored on the payment
+    ExplicitRouting,
+    /// The connector was reused from a previous attempt or from the mandate of the payment
+    PriorAttempt,
+    /// The connector was chosen by the merchant's configured routing algorithm
+    DefaultAlgorithm,
+}
+
+/// Version of the 3DS protocol through which the customer is authenticated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ThreeDsVersion {
+    /// 3DS 1.0, in which the customer is always challenged
+    V1,
+    /// 3DS 2.x, in which the issuer can authenticate the customer without a challenge
+    V2,
+}
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct UnresolvedResponseReason {
     pub code: String,
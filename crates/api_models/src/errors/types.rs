@@ -94,6 +94,7 @@ pub enum ApiErrorResponse {
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
+    TooManyRequests(ApiError),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -122,6 +123,7 @@ impl ApiErrorResponse {
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
+            | Self::TooManyRequests(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -139,6 +141,7 @@ impl ApiErrorResponse {
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
+            | Self::TooManyRequests(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -154,7 +157,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::ConnectorError(_, _) => "connector",
         }
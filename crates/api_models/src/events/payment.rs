@@ -7,10 +7,11 @@ use crate::{
     },
     payments::{
         PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints, PaymentListFilters,
-        PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest, PaymentsCancelRequest,
-        PaymentsCaptureRequest, PaymentsIncrementalAuthorizationRequest, PaymentsRejectRequest,
-        PaymentsRequest, PaymentsResponse, PaymentsRetrieveRequest, PaymentsStartRequest,
-        RedirectionResponse,
+        PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest,
+        PaymentsBatchConfirmRequest, PaymentsBatchConfirmResponse, PaymentsCancelRequest,
+        PaymentsCaptureRequest, PaymentsConfirmPreflightRequest, PaymentsConfirmPreflightResponse,
+        PaymentsIncrementalAuthorizationRequest, PaymentsRejectRequest, PaymentsRequest,
+        PaymentsResponse, PaymentsRetrieveRequest, PaymentsStartRequest, RedirectionResponse,
     },
 };
 impl ApiEventMetric for PaymentsRetrieveRequest {
@@ -151,6 +152,10 @@ impl ApiEventMetric for PaymentListResponseV2 {
     }
 }
 
+impl ApiEventMetric for PaymentsBatchConfirmRequest {}
+
+impl ApiEventMetric for PaymentsBatchConfirmResponse {}
+
 impl ApiEventMetric for RedirectionResponse {}
 
 impl ApiEventMetric for PaymentsIncrementalAuthorizationRequest {
@@ -160,3 +165,19 @@ impl ApiEventMetric for PaymentsIncrementalAuthorizationRequest {
         })
     }
 }
+
+impl ApiEventMetric for PaymentsConfirmPreflightRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentsConfirmPreflightResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
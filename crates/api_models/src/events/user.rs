@@ -12,9 +12,9 @@ use crate::user::{
     },
     AuthorizeResponse, ChangePasswordRequest, ConnectAccountRequest, CreateInternalUserRequest,
     DashboardEntryResponse, ForgotPasswordRequest, GetUsersResponse, InviteUserRequest,
-    InviteUserResponse, ResetPasswordRequest, SendVerifyEmailRequest, SignUpRequest,
-    SignUpWithMerchantIdRequest, SwitchMerchantIdRequest, UpdateUserAccountDetailsRequest,
-    UserMerchantCreate, VerifyEmailRequest,
+    InviteUserResponse, ListMerchantsForUserResponse, ListUsersResponse, ResetPasswordRequest,
+    SendVerifyEmailRequest, SignUpRequest, SignUpWithMerchantIdRequest, SwitchMerchantIdRequest,
+    UpdateUserAccountDetailsRequest, UserMerchantCreate, UserRoleListQuery, VerifyEmailRequest,
 };
 
 impl ApiEventMetric for DashboardEntryResponse {
@@ -48,6 +48,9 @@ common_utils::impl_misc_api_event_type!(
     CreateInternalUserRequest,
     UserMerchantCreate,
     GetUsersResponse,
+    ListUsersResponse,
+    ListMerchantsForUserResponse,
+    UserRoleListQuery,
     AuthorizeResponse,
     ConnectAccountRequest,
     ForgotPasswordRequest,
@@ -1,8 +1,16 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::user_role::{
-    AcceptInvitationRequest, AuthorizationInfoResponse, GetRoleRequest, ListRolesResponse,
-    RoleInfoResponse, UpdateUserRoleRequest,
+    AcceptInvitationRequest, ApproveUserRoleChangeRequest, AssignOrUpdateUserRoleRequest,
+    AssignOrUpdateUserRoleResponse, AuthorizationInfoResponse, CloneRoleRequest,
+    DeleteRolePermissionOverridesRequest, DeleteRolePreferencesRequest, DeleteUserRoleRequest,
+    GetRoleRequest, GetUserAccessMapRequest, ImportRolesRequest, ImportRolesResponse,
+    ListRolesResponse, ListUserRoleChangeRequestsRequest, ListUserRoleChangeRequestsResponse,
+    ListUsersWithPermissionRequest, ResetUserRoleRequest, ResumeUserRoleRequest, RoleDefinitions,
+    RoleInfoResponse, RolePreferencesResponse, SuspendUserRoleRequest,
+    UpdateRolePermissionOverridesRequest, UpdateRolePreferencesRequest,
+    UpdateUserPreferencesRequest, UpdateUserRoleRequest, UpdateUserRoleResponse,
+    UserAccessMapResponse,
 };
 
 common_utils::impl_misc_api_event_type!(
@@ -11,5 +19,28 @@ common_utils::impl_misc_api_event_type!(
     GetRoleRequest,
     AuthorizationInfoResponse,
     UpdateUserRoleRequest,
-    AcceptInvitationRequest
+    UpdateUserRoleResponse,
+    AssignOrUpdateUserRoleRequest,
+    AssignOrUpdateUserRoleResponse,
+    ResetUserRoleRequest,
+    DeleteUserRoleRequest,
+    SuspendUserRoleRequest,
+    ResumeUserRoleRequest,
+    ApproveUserRoleChangeRequest,
+    ListUserRoleChangeRequestsRequest,
+    ListUserRoleChangeRequestsResponse,
+    ListUsersWithPermissionRequest,
+    AcceptInvitationRequest,
+    RolePreferencesResponse,
+    UpdateRolePreferencesRequest,
+    DeleteRolePreferencesRequest,
+    UpdateUserPreferencesRequest,
+    UpdateRolePermissionOverridesRequest,
+    DeleteRolePermissionOverridesRequest,
+    CloneRoleRequest,
+    RoleDefinitions,
+    ImportRolesRequest,
+    ImportRolesResponse,
+    GetUserAccessMapRequest,
+    UserAccessMapResponse
 );
@@ -1,6 +1,142 @@
+use std::collections::BTreeMap;
+
+/// Overall status of the deep health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckStatus {
+    Healthy,
+    /// Every dependency passed the health check, but some reported a soft issue listed in the
+    /// degraded reasons
+    Degraded,
+    Unhealthy,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RouterHealthCheckResponse {
+    pub status: HealthCheckStatus,
+    /// Version of the build the node is running, including the git commit when available
+    pub version: String,
+    /// Number of seconds elapsed since the node started
+    pub uptime_seconds: u64,
+    /// Soft issues detected by the probes which passed the health check
+    #[serde(default)]
+    pub degraded_reasons: Vec<String>,
     pub database: String,
     pub redis: String,
-    pub locker: String,
+    /// Status of the Redis pub/sub probe, not present when the probe is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redis_pubsub: Option<String>,
+    /// Status of the locker probe, not present when the probe is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locker: Option<String>,
+    pub schema_version: Option<String>,
+    /// Difference (in milliseconds) between the clocks of the database and of the node, positive
+    /// when the database is ahead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clock_skew_ms: Option<i64>,
+    pub authz: Option<String>,
+    /// Status of the stuck payments probe, not present when the probe is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stuck_payments: Option<String>,
+    /// Number of payments processing for longer than the configured threshold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stuck_payments_count: Option<i64>,
+    /// Status of the end to end probe, not present when the probe is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e2e: Option<String>,
+    /// Duration (in milliseconds) of the last run of the end to end probe
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e2e_latency_in_millis: Option<u128>,
+    /// Status of the dead letter probe, not present when the probe is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_letter: Option<String>,
+    /// Number of entries the drainer kept failing to drain and copied to the dead letter stream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_letter_count: Option<u64>,
+    /// Status of the Redis replication probe, not present when the probe is disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redis_replication: Option<String>,
+    /// Number of bytes the most lagging Redis replica is behind the master
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redis_replication_lag_bytes: Option<u64>,
+    /// Status of each Redis instance keyed by its role, the primary Redis being reported as
+    /// `primary`, not present when no additional Redis instance is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redis_instances: Option<BTreeMap<String, String>>,
+}
+
+/// Result of the self-check of the permission map
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AuthzSelfCheckResponse {
+    pub status: HealthCheckStatus,
+    /// Inconsistencies found between the predefined roles and the permission modules
+    pub drift: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+pub struct DeepHealthCheckQuery {
+    /// Bypass the cached result and probe every dependency. A live probe runs queries against
+    /// the database, redis and the locker, so it should be reserved for manual checks and not
+    /// be used by automated scrapers, which should rely on the cached result instead.
+    #[serde(default)]
+    pub fresh: bool,
+}
+
+/// Component reported by the deep health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckComponent {
+    Database,
+    Redis,
+    RedisPubsub,
+    Locker,
+    Authz,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SimulateHealthCheckFailureRequest {
+    pub component: HealthCheckComponent,
+    /// Number of upcoming deep health checks which report the component as unhealthy
+    pub count: u32,
+}
+
+impl common_utils::events::ApiEventMetric for SimulateHealthCheckFailureRequest {}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DrainerDeadLetterListQuery {
+    /// Maximum number of entries to be returned, the most recent entries are returned first
+    pub limit: Option<u32>,
+}
+
+impl common_utils::events::ApiEventMetric for DrainerDeadLetterListQuery {}
+
+/// Entry which the drainer failed to drain after exhausting the retries
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DrainerDeadLetterEntry {
+    /// Identifier of the entry in the drainer stream
+    pub entry_id: String,
+    /// Drainer stream the entry was read from
+    pub stream_name: String,
+    pub request_id: String,
+    pub global_id: String,
+    /// Database operation of the entry, such as `insert` or `update`
+    pub operation: String,
+    /// Table the operation was run against
+    pub table: String,
+    /// Number of times draining the entry was attempted
+    pub retry_count: Option<i64>,
+    /// Error returned by the last attempt to drain the entry
+    pub last_error: String,
+    /// Unix timestamp (in seconds) at which the entry was dead-lettered
+    pub dead_lettered_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DrainerDeadLetterListResponse {
+    /// Number of entries in the dead letter stream
+    pub dead_letter_count: u64,
+    /// Most recent entries of the dead letter stream, most recent first
+    pub entries: Vec<DrainerDeadLetterEntry>,
 }
+
+impl common_utils::events::ApiEventMetric for DrainerDeadLetterListResponse {}
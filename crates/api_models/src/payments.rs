@@ -176,6 +176,13 @@ pub struct PaymentsRequest {
     #[schema(example = true)]
     pub off_session: Option<bool>,
 
+    /// The network transaction id of the customer initiated transaction, to be forwarded to the connector when this payment is a merchant initiated transaction made off session. This parameter can only be used with `off_session: true`.
+    #[schema(max_length = 255, example = "016153570198200")]
+    pub network_transaction_id: Option<String>,
+
+    /// The installment plan selected by the customer, to be forwarded to the connector. This parameter can only be used for the payment method types for which the connector offers installments.
+    pub installment_plan: Option<InstallmentPlan>,
+
     /// A description of the payment
     #[schema(example = "It's my first payment request")]
     pub description: Option<String>,
@@ -203,6 +210,11 @@ pub struct PaymentsRequest {
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
 
+    /// The identifier of a card saved for the customer, used to confirm the payment with the
+    /// saved card instead of a payment token. Only supported on confirm.
+    #[schema(example = "pm_01926c58bc6e77c09e809964e72af8c8")]
+    pub payment_method_id: Option<String>,
+
     /// This is used when payment is to be confirmed and the card is not saved.
     /// This field will be deprecated soon, use the CardToken object instead
     #[schema(value_type = Option<String>, deprecated)]
@@ -305,6 +317,11 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<PaymentCreatePaymentLinkConfig>)]
     pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
 
+    /// The payment link through which the payment is being confirmed, if the payment method was
+    /// selected on the hosted payment link page
+    #[schema(example = "plink_5xTAklqvJmY2XzrLYPK3")]
+    pub payment_link_id: Option<String>,
+
     /// The business profile to use for this payment, if not passed the default business profile
     /// associated with the merchant account will be used.
     pub profile_id: Option<String>,
@@ -327,6 +344,19 @@ pub struct PaymentsRequest {
 
     /// additional data related to some frm connectors
     pub frm_metadata: Option<serde_json::Value>,
+
+    /// The version of 3DS through which the customer has to be authenticated when the payment is
+    /// confirmed with 3DS. Whether the payment fails or falls back to a lower version, when the
+    /// connector does not support the version, depends on the 3DS version policy of the merchant
+    #[schema(value_type = Option<ThreeDsVersion>, example = "v2")]
+    pub preferred_3ds_version: Option<api_enums::ThreeDsVersion>,
+
+    /// An identifier for the order in the merchant's system. It must be unique across the payments of the merchant
+    #[schema(max_length = 255, example = "order_1a2b3c")]
+    pub merchant_order_reference_id: Option<String>,
+
+    /// The consent of the customer for setting up a mandate. Required on confirm when `setup_future_usage` is `off_session`, unless it is passed in `mandate_data`
+    pub customer_acceptance: Option<CustomerAcceptance>,
 }
 
 impl PaymentsRequest {
@@ -356,10 +386,12 @@ impl RequestSurchargeDetails {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct HeaderPayload {
     pub payment_confirm_source: Option<api_enums::PaymentSource>,
     pub x_hs_latency: Option<bool>,
+    /// Id correlating the logs of the request with the logs of the connector
+    pub x_correlation_id: Option<String>,
 }
 
 #[derive(
@@ -748,6 +780,47 @@ impl Card {
     }
 }
 
+/// Network token issued by the card network in place of the card, along with the cryptogram
+/// authenticating the payment
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct NetworkTokenData {
+    /// The network token
+    #[schema(value_type = String, example = "4242424242424242")]
+    pub network_token: CardNumber,
+
+    /// The expiry month of the network token
+    #[schema(value_type = String, example = "24")]
+    pub token_exp_month: Secret<String>,
+
+    /// The expiry year of the network token
+    #[schema(value_type = String, example = "2026")]
+    pub token_exp_year: Secret<String>,
+
+    /// The cryptogram generated by the card network for the payment
+    #[schema(value_type = String, example = "AgAAAAAAAIR8CQrXcIhbQAAAAAA=")]
+    pub token_cryptogram: Option<Secret<String>>,
+
+    /// The electronic commerce indicator returned along with the cryptogram
+    #[schema(example = "05")]
+    pub eci: Option<String>,
+
+    /// The card network of the network token
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<api_enums::CardNetwork>,
+}
+
+/// Installment plan offered by the connector and selected by the customer
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct InstallmentPlan {
+    /// The id of the installment plan, as offered by the connector
+    #[schema(max_length = 64, example = "INS54434")]
+    pub plan_id: String,
+
+    /// The number of installments the payment is split into
+    #[schema(example = 3)]
+    pub number_of_installments: u8,
+}
+
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct CardToken {
@@ -890,6 +963,7 @@ pub enum PaymentMethodData {
     Voucher(VoucherData),
     GiftCard(Box<GiftCardData>),
     CardToken(CardToken),
+    NetworkToken(NetworkTokenData),
 }
 
 impl PaymentMethodData {
@@ -918,7 +992,8 @@ impl PaymentMethodData {
             | Self::Upi(_)
             | Self::Voucher(_)
             | Self::GiftCard(_)
-            | Self::CardToken(_) => None,
+            | Self::CardToken(_)
+            | Self::NetworkToken(_) => None,
         }
     }
     pub fn apply_additional_payment_data(
@@ -1154,6 +1229,11 @@ pub enum AdditionalPaymentData {
     Voucher {},
     CardRedirect {},
     CardToken {},
+    /// Reference to the network token the payment was made with, the cryptogram is not stored
+    NetworkToken {
+        last4: Option<String>,
+        card_network: Option<api_enums::CardNetwork>,
+    },
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -1724,6 +1804,7 @@ pub enum PaymentMethodDataResponse {
     GiftCard,
     CardRedirect,
     CardToken,
+    NetworkToken,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -2197,6 +2278,10 @@ pub struct PaymentsResponse {
     /// error message unified across the connectors is received here if there was an error while calling connector
     pub unified_message: Option<String>,
 
+    /// Whether confirming the payment again could succeed, when the connector failed the payment
+    #[schema(value_type = Option<ConnectorErrorClassification>, example = "retryable")]
+    pub error_classification: Option<api_enums::ConnectorErrorClassification>,
+
     /// Payment Experience for the current payment
     #[schema(value_type = Option<PaymentExperience>, example = "redirect_to_url")]
     pub payment_experience: Option<api_enums::PaymentExperience>,
@@ -2262,12 +2347,26 @@ pub struct PaymentsResponse {
     /// total number of attempts associated with this payment
     pub attempt_count: i16,
 
+    /// If true, confirming the payment created a new attempt. Present only in the confirm response
+    pub is_new_attempt: Option<bool>,
+
     /// Denotes the action(approve or reject) taken by merchant in case of manual review. Manual review can occur when the transaction is marked as risky by the frm_processor, payment processor or when there is underpayment/over payment incase of crypto payment
     pub merchant_decision: Option<String>,
 
     /// Identifier of the connector ( merchant connector account ) which was chosen to make the payment
     pub merchant_connector_id: Option<String>,
 
+    /// Indicates how the connector used for the payment was selected
+    #[schema(value_type = Option<ConnectorSelectionSource>, example = "default_algorithm")]
+    pub connector_selection_source: Option<api_enums::ConnectorSelectionSource>,
+
+    /// The amount of the payment before it was changed at confirm. Present only when confirm changed the amount
+    #[schema(example = 6540)]
+    pub original_amount: Option<i64>,
+
+    /// If true, the amount was changed at confirm on an attempt which was sent to the connector before, and a new authorization is required for the changed amount
+    pub requires_reauthorization: Option<bool>,
+
     /// If true incremental authorization can be performed on this payment
     pub incremental_authorization_allowed: Option<bool>,
 
@@ -2284,6 +2383,81 @@ pub struct PaymentsResponse {
 
     /// Payment Fingerprint
     pub fingerprint: Option<String>,
+
+    /// The identifier for the order in the merchant's system, as passed in the request
+    pub merchant_order_reference_id: Option<String>,
+
+    /// Non-fatal issues detected while confirming the payment, which did not prevent the payment from being confirmed. Present only in the confirm response
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<PaymentWarning>,
+
+    /// Outcome of the call to the connector, with the same set of fields for every connector. Present only in the confirm response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_outcome: Option<ConnectorOutcome>,
+
+    /// Error details as returned by the connector, these differ between connectors. Present only in the confirm response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_raw: Option<ConnectorRawResponse>,
+}
+
+/// Outcome of the call to the connector, normalized so that it does not depend on the connector
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorOutcome {
+    /// Status of the payment at the connector
+    #[schema(value_type = IntentStatus, example = "failed")]
+    pub status: api_enums::IntentStatus,
+    /// Whether the connector failed the payment
+    pub is_failure: bool,
+    /// Unified error code, the same for equivalent errors of different connectors
+    #[schema(example = "UE_000")]
+    pub error_code: Option<String>,
+    /// Unified error message, the same for equivalent errors of different connectors
+    #[schema(example = "Issue with payment method details")]
+    pub error_message: Option<String>,
+    /// Whether confirming the payment again could succeed
+    #[schema(value_type = Option<ConnectorErrorClassification>, example = "non_retryable")]
+    pub error_classification: Option<api_enums::ConnectorErrorClassification>,
+}
+
+/// Error details as returned by the connector
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorRawResponse {
+    /// Error code of the connector
+    #[schema(example = "card_declined")]
+    pub code: Option<String>,
+    /// Error message of the connector
+    #[schema(example = "Your card was declined")]
+    pub message: Option<String>,
+    /// Detailed reason for the error given by the connector
+    pub reason: Option<String>,
+    /// HTTP status code of the response of the connector
+    #[schema(example = 402)]
+    pub http_status_code: Option<u16>,
+}
+
+/// Non-fatal issue detected while processing a payment
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentWarning {
+    /// Code identifying the issue
+    pub code: PaymentWarningCode,
+    /// Description of the issue
+    #[schema(example = "The payment was routed through the fallback connector adyen")]
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentWarningCode {
+    /// A surcharge was applied from the surcharge configuration of the merchant
+    SurchargeApplied,
+    /// The payment was routed through a fallback connector, as the primary connector is unhealthy
+    FallbackConnectorUsed,
+    /// The payment was routed through an unhealthy connector, as no healthy connector was available
+    UnhealthyConnectorUsed,
+    /// The amount was changed on an attempt which was authorized before, a new authorization is required
+    ReauthorizationRequired,
+    /// The connector does not support the preferred 3DS version, the customer is authenticated through a lower version
+    ThreeDsVersionFallback,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -2380,6 +2554,32 @@ pub struct PaymentListResponseV2 {
     pub data: Vec<PaymentsResponse>,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentsBatchConfirmRequest {
+    /// The list of payments to be confirmed, each identified by its payment_id
+    pub payments: Vec<PaymentsRequest>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentsBatchConfirmResponse {
+    /// The number of payments included in the batch
+    pub size: usize,
+    /// The confirm result of each payment, in the same order as the request
+    pub data: Vec<PaymentsBatchConfirmResult>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentsBatchConfirmResult {
+    /// The identifier of the payment that was confirmed
+    pub payment_id: Option<String>,
+    /// The payments response object, present if the confirm call succeeded
+    pub response: Option<PaymentsResponse>,
+    /// The error code, present if the confirm call failed
+    pub error_code: Option<String>,
+    /// The error message, present if the confirm call failed
+    pub error_message: Option<String>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct PaymentListFilterConstraints {
     /// The identifier for payment
@@ -2563,6 +2763,7 @@ impl From<AdditionalPaymentData> for PaymentMethodDataResponse {
             AdditionalPaymentData::GiftCard {} => Self::GiftCard,
             AdditionalPaymentData::CardRedirect {} => Self::CardRedirect,
             AdditionalPaymentData::CardToken {} => Self::CardToken,
+            AdditionalPaymentData::NetworkToken { .. } => Self::NetworkToken,
         }
     }
 }
@@ -2626,6 +2827,11 @@ pub struct OrderDetailsWithAmount {
     pub quantity: u16,
     /// the amount per quantity of product
     pub amount: i64,
+    /// The tax for the whole quantity of the product. When the tax is provided for any of the
+    /// products, the amounts and taxes of all the products must add up to the amount of the
+    /// payment on confirm
+    #[schema(example = 100)]
+    pub tax_amount: Option<i64>,
     // Does the order includes shipping
     pub requires_shipping: Option<bool>,
     /// The image URL of the product
@@ -3123,6 +3329,40 @@ pub struct PaymentsIncrementalAuthorizationRequest {
     pub reason: Option<String>,
 }
 
+/// Request to resolve the fields required to confirm a payment with a payment method, before
+/// the payment method data is collected
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsConfirmPreflightRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The client secret of the payment, required when authenticating with the publishable key
+    pub client_secret: Option<String>,
+    /// The payment method with which the payment would be confirmed
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+    /// The payment method type with which the payment would be confirmed
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsConfirmPreflightResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The connector the payment would be routed through, not present when no connector is
+    /// eligible for the payment method
+    pub connector: Option<String>,
+    /// Fields required by the connector to confirm the payment with the payment method
+    pub required_fields: Vec<crate::payment_methods::RequiredFieldInfo>,
+    /// Fields which are only required when the payment method is saved for future usage
+    pub optional_fields: Vec<crate::payment_methods::RequiredFieldInfo>,
+    /// Fields of `browser_info` required by the connector to authenticate the payment with 3DS
+    pub required_browser_info_fields: Vec<String>,
+    /// Whether `mandate_data` must be provided on confirm, for payments set up for future usage
+    pub mandate_data_required: bool,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct PaymentsApproveRequest {
     /// The identifier for the payment
@@ -3165,6 +3405,7 @@ pub struct FrmMessage {
     pub frm_score: Option<i32>,
     pub frm_reason: Option<serde_json::Value>,
     pub frm_error: Option<String>,
+    pub reviewed_by: Option<String>,
 }
 
 mod payment_id_type {
@@ -81,6 +81,8 @@ pub struct InviteUserRequest {
     pub email: pii::Email,
     pub name: Secret<String>,
     pub role_id: String,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -111,6 +113,23 @@ pub struct UserMerchantCreate {
 #[derive(Debug, serde::Serialize)]
 pub struct GetUsersResponse(pub Vec<UserDetails>);
 
+/// Page of the users of the merchant
+#[derive(Debug, serde::Serialize)]
+pub struct ListUsersResponse {
+    pub users: Vec<UserDetails>,
+    /// Cursor to pass to fetch the next page, absent on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Pagination of the listings of user roles
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct UserRoleListQuery {
+    /// Number of entries in the page
+    pub limit: Option<u32>,
+    /// Cursor returned with the previous page, the first page is listed when absent
+    pub cursor: Option<String>,
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct UserDetails {
     pub user_id: String,
@@ -119,6 +138,8 @@ pub struct UserDetails {
     pub role_id: String,
     pub role_name: String,
     pub status: UserStatus,
+    pub title: Option<String>,
+    pub department: Option<String>,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub last_modified_at: time::PrimitiveDateTime,
 }
@@ -139,6 +160,16 @@ pub struct SendVerifyEmailRequest {
 pub struct UserMerchantAccount {
     pub merchant_id: String,
     pub merchant_name: OptionalEncryptableName,
+    /// If true, the access of the user to the merchant is suspended
+    pub is_suspended: bool,
+}
+
+/// Page of the merchants the user is part of
+#[derive(Debug, serde::Serialize)]
+pub struct ListMerchantsForUserResponse {
+    pub merchant_accounts: Vec<UserMerchantAccount>,
+    /// Cursor to pass to fetch the next page, absent on the last page
+    pub next_cursor: Option<String>,
 }
 
 #[cfg(feature = "recon")]
@@ -5,9 +5,125 @@ pub struct ListRolesResponse(pub Vec<RoleInfoResponse>);
 
 #[derive(Debug, serde::Serialize)]
 pub struct RoleInfoResponse {
-    pub role_id: &'static str,
+    pub role_id: String,
+    pub permissions: Vec<Permission>,
+    pub permission_info: Vec<PermissionInfo>,
+    pub role_name: String,
+    /// UI preferences of the role resolved for the user, the preferences set by the user take
+    /// precedence over the defaults of the role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<RolePreferencesResponse>,
+}
+
+/// UI preferences of a role, which do not affect its permissions
+#[derive(Debug, Default, serde::Serialize)]
+pub struct RolePreferencesResponse {
+    pub default_landing_page: Option<String>,
+    pub visible_widgets: Option<Vec<String>>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdateRolePreferencesRequest {
+    pub role_id: String,
+    /// Page opened after login, left unchanged when not set
+    pub default_landing_page: Option<String>,
+    /// Widgets shown on the home page, left unchanged when not set
+    pub visible_widgets: Option<Vec<String>>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DeleteRolePreferencesRequest {
+    pub role_id: String,
+}
+
+/// Preferences of the user which override the defaults of their role
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdateUserPreferencesRequest {
+    /// Page opened after login, left unchanged when not set
+    pub default_landing_page: Option<String>,
+    /// Widgets shown on the home page, left unchanged when not set
+    pub visible_widgets: Option<Vec<String>>,
+}
+
+/// Permissions layered on top of the predefined permissions of a role for the merchant
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdateRolePermissionOverridesRequest {
+    pub role_id: String,
+    /// Permissions granted to the role in addition to its predefined permissions
+    #[serde(default)]
+    pub granted: Vec<Permission>,
+    /// Predefined permissions of the role which are revoked
+    #[serde(default)]
+    pub revoked: Vec<Permission>,
+}
+
+/// Creates a custom role of the merchant with the permissions of an existing role
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CloneRoleRequest {
+    /// Id of the predefined or custom role whose permissions are copied
+    pub role_id: String,
+    /// Name of the new role, which must be unique among the roles of the merchant
+    pub role_name: String,
+}
+
+/// Role definitions of a merchant, in a portable document used to back up and restore its
+/// authorization configuration
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct RoleDefinitions {
+    /// Predefined roles which can be assigned in the merchant, along with their permission overrides
+    #[serde(default)]
+    pub predefined_roles: Vec<PredefinedRoleDefinition>,
+    /// Roles created by the merchant
+    #[serde(default)]
+    pub custom_roles: Vec<CustomRoleDefinition>,
+}
+
+/// Reference to a predefined role, whose permissions are inherited from the predefined permissions
+/// of the role with the overrides of the merchant applied
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PredefinedRoleDefinition {
+    pub role_id: String,
+    /// Permissions granted to the role in addition to its predefined permissions
+    #[serde(default)]
+    pub granted: Vec<Permission>,
+    /// Predefined permissions of the role which are revoked
+    #[serde(default)]
+    pub revoked: Vec<Permission>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct CustomRoleDefinition {
+    pub role_id: String,
+    pub role_name: String,
     pub permissions: Vec<Permission>,
-    pub role_name: &'static str,
+}
+
+/// Restores the role definitions of the merchant from an export
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ImportRolesRequest {
+    pub roles: RoleDefinitions,
+    /// Whether the role definitions of the merchant are replaced when they differ from the import
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ImportRolesResponse {
+    pub status: RoleImportStatus,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleImportStatus {
+    /// The role definitions of the merchant were replaced by the import
+    Imported,
+    /// The role definitions of the merchant already matched the import
+    Unchanged,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DeleteRolePermissionOverridesRequest {
+    pub role_id: String,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -15,7 +131,7 @@ pub struct GetRoleRequest {
     pub role_id: String,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Permission {
     PaymentRead,
     PaymentWrite,
@@ -79,19 +195,159 @@ pub struct ModuleInfo {
 #[derive(Debug, serde::Serialize)]
 pub struct PermissionInfo {
     pub enum_name: Permission,
+    pub label: &'static str,
     pub description: &'static str,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct UpdateUserRoleRequest {
+    pub user_id: String,
+    /// New role of the user, the role is left unchanged when not set
+    pub role_id: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    /// Job title of the user in the merchant, left unchanged when not set
+    pub title: Option<String>,
+    /// Department of the user in the merchant, left unchanged when not set
+    pub department: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct UpdateUserRoleResponse {
+    pub status: RoleUpdateStatus,
+    /// Id of the role change request awaiting approval, present when the status is `pending_approval`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleUpdateStatus {
+    Updated,
+    PendingApproval,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ResetUserRoleRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DeleteUserRoleRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SuspendUserRoleRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ResumeUserRoleRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AssignOrUpdateUserRoleRequest {
+    pub user_id: String,
+    pub role_id: String,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AssignOrUpdateUserRoleResponse {
+    pub status: RoleAssignmentStatus,
+    /// Id of the role change request awaiting approval, present when the status is `pending_approval`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleAssignmentStatus {
+    Created,
+    Updated,
+    PendingApproval,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ApproveUserRoleChangeRequest {
+    pub request_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ListUserRoleChangeRequestsRequest {
+    /// Only list the changes of the role of this user
+    pub user_id: Option<String>,
+    /// Only list the changes which the user can approve, leaving out the ones they requested or
+    /// which change their own role
+    #[serde(default)]
+    pub approvable_only: bool,
+}
+
+/// Lists the users of the merchant whose role grants the permission, for access reviews
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ListUsersWithPermissionRequest {
+    pub permission: Permission,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ListUserRoleChangeRequestsResponse(pub Vec<UserRoleChangeRequestResponse>);
+
+/// Change of the role of a user awaiting the approval of a second admin
+#[derive(Debug, serde::Serialize)]
+pub struct UserRoleChangeRequestResponse {
+    pub request_id: String,
     pub user_id: String,
     pub role_id: String,
+    /// Expiry of the role once the change is approved
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub role_expires_at: Option<time::PrimitiveDateTime>,
+    pub requested_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// Time after which the request can no longer be approved
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct GetUserAccessMapRequest {
+    /// User whose access is returned, the user making the request when not passed. Only internal
+    /// users can look up the access of another user.
+    pub user_id: Option<String>,
+}
+
+/// Access of a user across all the merchants they are part of
+#[derive(Debug, serde::Serialize)]
+pub struct UserAccessMapResponse {
+    pub user_id: String,
+    pub merchants: Vec<MerchantAccessResponse>,
+}
+
+/// Role of a user in a merchant, along with the permissions the role grants in the merchant
+#[derive(Debug, serde::Serialize)]
+pub struct MerchantAccessResponse {
+    pub merchant_id: String,
+    pub role_id: String,
+    /// Name of the role, not present for the internal roles and the roles which no longer exist
+    pub role_name: Option<String>,
+    pub permissions: Vec<Permission>,
+    pub status: UserStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub is_expired: bool,
+    /// Whether the role currently grants access to the merchant, which it does not while the
+    /// invitation is pending, while the access is suspended or once the role has expired
+    pub is_effective: bool,
 }
 
 #[derive(Debug, serde::Serialize)]
 pub enum UserStatus {
     Active,
     InvitationSent,
+    Suspended,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -101,3 +357,16 @@ pub struct AcceptInvitationRequest {
 }
 
 pub type AcceptInvitationResponse = DashboardEntryResponse;
+
+/// Content of the outgoing webhook sent when the role of a user in a merchant changes
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+pub struct UserRoleWebhookContent {
+    pub merchant_id: String,
+    pub user_id: String,
+    /// Role of the user before the change, if the user had one
+    pub old_role: Option<String>,
+    /// Role of the user after the change, not present when the role was deleted
+    pub new_role: Option<String>,
+    /// User who made the change, not present when the change was made by the system
+    pub actor: Option<String>,
+}
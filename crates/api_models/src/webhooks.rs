@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{disputes, enums as api_enums, mandates, payments, refunds, user_role};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -177,6 +177,8 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse)]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[schema(value_type = UserRoleWebhookContent)]
+    UserRoleDetails(Box<user_role::UserRoleWebhookContent>),
 }
 
 #[derive(Debug, Clone, Serialize)]
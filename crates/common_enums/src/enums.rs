@@ -276,6 +276,33 @@ pub enum AuthorizationStatus {
     Unresolved,
 }
 
+/// Indicates whether confirming the payment again could succeed after the connector failed it
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorErrorClassification {
+    /// The failure is transient, such as a network failure or an error of the connector
+    Retryable,
+    /// The payment was declined, retrying it with the same details fails again
+    NonRetryable,
+    /// The outcome of the payment is unknown, such as for a duplicate request, and has to be
+    /// checked before retrying it
+    NeedsReview,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -963,6 +990,10 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    UserRoleUpdated,
+    UserRoleDeleted,
+    UserRoleSuspended,
+    UserRoleResumed,
 }
 
 // TODO: This decision about using KV mode or not,
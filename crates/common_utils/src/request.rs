@@ -42,6 +42,8 @@ pub struct Request {
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
     pub body: Option<RequestContent>,
+    /// Timeout of the request, the default timeout of the client is used when not set
+    pub timeout_in_secs: Option<u64>,
 }
 
 impl std::fmt::Debug for RequestContent {
@@ -71,6 +73,7 @@ impl Request {
             certificate: None,
             certificate_key: None,
             body: None,
+            timeout_in_secs: None,
         }
     }
 
@@ -93,6 +96,10 @@ impl Request {
     pub fn add_certificate_key(&mut self, certificate_key: Option<String>) {
         self.certificate = certificate_key;
     }
+
+    pub fn set_timeout_in_secs(&mut self, timeout_in_secs: Option<u64>) {
+        self.timeout_in_secs = timeout_in_secs;
+    }
 }
 
 #[derive(Debug)]
@@ -165,6 +172,7 @@ impl RequestBuilder {
             certificate: self.certificate,
             certificate_key: self.certificate_key,
             body: self.body,
+            timeout_in_secs: None,
         }
     }
 }
@@ -54,5 +54,6 @@ pub struct PaymentIntent {
     pub incremental_authorization_allowed: Option<bool>,
     pub authorization_count: Option<i32>,
     pub fingerprint_id: Option<String>,
+    pub merchant_order_reference_id: Option<String>,
     pub session_expiry: Option<PrimitiveDateTime>,
 }
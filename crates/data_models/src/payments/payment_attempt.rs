@@ -155,6 +155,27 @@ pub struct PaymentAttempt {
     pub merchant_connector_id: Option<String>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    // network transaction id of the customer initiated transaction, forwarded on subsequent merchant initiated transactions
+    pub network_transaction_id: Option<String>,
+    // index of the connector in the routing-provided chain which was selected, when the primary connector was skipped for being unhealthy
+    pub connector_fallback_index: Option<i16>,
+    // set when every connector of the routing-provided chain was unhealthy and the payment was routed through the primary connector regardless
+    pub routed_to_unhealthy_connector: Option<bool>,
+    // installment plan selected on confirm, forwarded to the connector with the authorization
+    pub installment_plan: Option<serde_json::Value>,
+    // idempotency key derived from the attempt id and the retry counter, forwarded to connectors supporting idempotent requests
+    pub connector_idempotency_key: Option<String>,
+    // fraud score of the payment and the score threshold of the merchant which the score reached, if any
+    pub frm_score: Option<i32>,
+    pub frm_score_threshold: Option<i32>,
+    // acceptance of the customer for setting up a mandate, as passed on confirm
+    pub customer_acceptance: Option<serde_json::Value>,
+    // id correlating the logs of the confirm of this attempt with the logs of the connector
+    pub correlation_id: Option<String>,
+    // whether confirming the payment again could succeed after the connector failed this attempt
+    pub error_classification: Option<storage_enums::ConnectorErrorClassification>,
+    // reason the attempt was escalated to three ds authentication after a soft decline, if any
+    pub authentication_escalation_reason: Option<String>,
 }
 
 impl PaymentAttempt {
@@ -232,6 +253,27 @@ pub struct PaymentAttemptNew {
     pub merchant_connector_id: Option<String>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    // network transaction id of the customer initiated transaction, forwarded on subsequent merchant initiated transactions
+    pub network_transaction_id: Option<String>,
+    // index of the connector in the routing-provided chain which was selected, when the primary connector was skipped for being unhealthy
+    pub connector_fallback_index: Option<i16>,
+    // set when every connector of the routing-provided chain was unhealthy and the payment was routed through the primary connector regardless
+    pub routed_to_unhealthy_connector: Option<bool>,
+    // installment plan selected on confirm, forwarded to the connector with the authorization
+    pub installment_plan: Option<serde_json::Value>,
+    // idempotency key derived from the attempt id and the retry counter, forwarded to connectors supporting idempotent requests
+    pub connector_idempotency_key: Option<String>,
+    // fraud score of the payment and the score threshold of the merchant which the score reached, if any
+    pub frm_score: Option<i32>,
+    pub frm_score_threshold: Option<i32>,
+    // acceptance of the customer for setting up a mandate, as passed on confirm
+    pub customer_acceptance: Option<serde_json::Value>,
+    // id correlating the logs of the confirm of this attempt with the logs of the connector
+    pub correlation_id: Option<String>,
+    // whether confirming the payment again could succeed after the connector failed this attempt
+    pub error_classification: Option<storage_enums::ConnectorErrorClassification>,
+    // reason the attempt was escalated to three ds authentication after a soft decline, if any
+    pub authentication_escalation_reason: Option<String>,
 }
 
 impl PaymentAttemptNew {
@@ -301,6 +343,15 @@ pub enum PaymentAttemptUpdate {
         surcharge_amount: Option<i64>,
         tax_amount: Option<i64>,
         merchant_connector_id: Option<String>,
+        network_transaction_id: Option<String>,
+        connector_fallback_index: Option<i16>,
+        routed_to_unhealthy_connector: Option<bool>,
+        installment_plan: Option<serde_json::Value>,
+        connector_idempotency_key: Option<String>,
+        frm_score: Option<i32>,
+        frm_score_threshold: Option<i32>,
+        customer_acceptance: Option<serde_json::Value>,
+        correlation_id: Option<String>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -359,6 +410,7 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         connector_transaction_id: Option<String>,
+        error_classification: Option<storage_enums::ConnectorErrorClassification>,
     },
     CaptureUpdate {
         amount_to_capture: Option<i64>,
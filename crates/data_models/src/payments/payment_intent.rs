@@ -30,6 +30,13 @@ pub trait PaymentIntentInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, errors::StorageError>;
 
+    async fn find_optional_payment_intent_by_merchant_order_reference_id_merchant_id(
+        &self,
+        merchant_order_reference_id: &str,
+        merchant_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Option<PaymentIntent>, errors::StorageError>;
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
@@ -111,6 +118,7 @@ pub struct PaymentIntentNew {
     pub incremental_authorization_allowed: Option<bool>,
     pub authorization_count: Option<i32>,
     pub fingerprint_id: Option<String>,
+    pub merchant_order_reference_id: Option<String>,
     pub session_expiry: Option<PrimitiveDateTime>,
 }
 
@@ -165,6 +173,7 @@ pub enum PaymentIntentUpdate {
         payment_confirm_source: Option<storage_enums::PaymentSource>,
         updated_by: String,
         fingerprint_id: Option<String>,
+        merchant_order_reference_id: Option<String>,
         session_expiry: Option<PrimitiveDateTime>,
     },
     PaymentAttemptAndAttemptCountUpdate {
@@ -179,6 +188,7 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
     },
     ApproveUpdate {
+        status: storage_enums::IntentStatus,
         merchant_decision: Option<String>,
         updated_by: String,
     },
@@ -231,6 +241,7 @@ pub struct PaymentIntentUpdateInternal {
     pub incremental_authorization_allowed: Option<bool>,
     pub authorization_count: Option<i32>,
     pub fingerprint_id: Option<String>,
+    pub merchant_order_reference_id: Option<String>,
     pub session_expiry: Option<PrimitiveDateTime>,
 }
 
@@ -256,6 +267,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 payment_confirm_source,
                 updated_by,
                 fingerprint_id,
+                merchant_order_reference_id,
                 session_expiry,
             } => Self {
                 amount: Some(amount),
@@ -277,6 +289,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 payment_confirm_source,
                 updated_by,
                 fingerprint_id,
+                merchant_order_reference_id,
                 session_expiry,
                 ..Default::default()
             },
@@ -374,9 +387,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 ..Default::default()
             },
             PaymentIntentUpdate::ApproveUpdate {
+                status,
                 merchant_decision,
                 updated_by,
             } => Self {
+                status: Some(status),
                 merchant_decision,
                 updated_by,
                 ..Default::default()
@@ -16,6 +16,9 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    /// Role whose permissions the API key is restricted to, the API key is not restricted when
+    /// it is not set
+    pub role_id: Option<String>,
 }
 
 #[derive(Debug, Insertable)]
@@ -30,6 +33,9 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    /// Role whose permissions the API key is restricted to, the API key is not restricted when
+    /// it is not set
+    pub role_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -66,6 +66,7 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    UserRoles,
 }
 
 #[derive(
@@ -87,6 +88,7 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    UserRoleDetails,
 }
 
 #[derive(
@@ -426,6 +428,7 @@ pub enum UserStatus {
     Active,
     #[default]
     InvitationSent,
+    Suspended,
 }
 
 #[derive(
@@ -25,6 +25,7 @@ pub struct FraudCheck {
     pub metadata: Option<serde_json::Value>,
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
+    pub reviewed_by: Option<String>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -64,6 +65,10 @@ pub enum FraudCheckUpdate {
         status: FraudCheckStatus,
         error_message: Option<Option<String>>,
     },
+    ManualReviewUpdate {
+        reviewed_by: String,
+        last_step: FraudCheckLastStep,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -76,6 +81,7 @@ pub struct FraudCheckUpdateInternal {
     frm_error: Option<Option<String>>,
     metadata: Option<serde_json::Value>,
     last_step: FraudCheckLastStep,
+    reviewed_by: Option<String>,
 }
 
 impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
@@ -106,6 +112,14 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
                 frm_error: error_message,
                 ..Default::default()
             },
+            FraudCheckUpdate::ManualReviewUpdate {
+                reviewed_by,
+                last_step,
+            } => Self {
+                reviewed_by: Some(reviewed_by),
+                last_step,
+                ..Default::default()
+            },
         }
     }
 }
@@ -64,6 +64,17 @@ pub struct PaymentAttempt {
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
     pub net_amount: Option<i64>,
+    pub network_transaction_id: Option<String>,
+    pub connector_fallback_index: Option<i16>,
+    pub routed_to_unhealthy_connector: Option<bool>,
+    pub installment_plan: Option<serde_json::Value>,
+    pub connector_idempotency_key: Option<String>,
+    pub frm_score: Option<i32>,
+    pub frm_score_threshold: Option<i32>,
+    pub customer_acceptance: Option<serde_json::Value>,
+    pub correlation_id: Option<String>,
+    pub error_classification: Option<storage_enums::ConnectorErrorClassification>,
+    pub authentication_escalation_reason: Option<String>,
 }
 
 impl PaymentAttempt {
@@ -138,6 +149,17 @@ pub struct PaymentAttemptNew {
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
     pub net_amount: Option<i64>,
+    pub network_transaction_id: Option<String>,
+    pub connector_fallback_index: Option<i16>,
+    pub routed_to_unhealthy_connector: Option<bool>,
+    pub installment_plan: Option<serde_json::Value>,
+    pub connector_idempotency_key: Option<String>,
+    pub frm_score: Option<i32>,
+    pub frm_score_threshold: Option<i32>,
+    pub customer_acceptance: Option<serde_json::Value>,
+    pub correlation_id: Option<String>,
+    pub error_classification: Option<storage_enums::ConnectorErrorClassification>,
+    pub authentication_escalation_reason: Option<String>,
 }
 
 impl PaymentAttemptNew {
@@ -212,6 +234,15 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        network_transaction_id: Option<String>,
+        connector_fallback_index: Option<i16>,
+        routed_to_unhealthy_connector: Option<bool>,
+        installment_plan: Option<serde_json::Value>,
+        connector_idempotency_key: Option<String>,
+        frm_score: Option<i32>,
+        frm_score_threshold: Option<i32>,
+        customer_acceptance: Option<serde_json::Value>,
+        correlation_id: Option<String>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -270,6 +301,7 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         connector_transaction_id: Option<String>,
+        error_classification: Option<storage_enums::ConnectorErrorClassification>,
     },
     CaptureUpdate {
         amount_to_capture: Option<i64>,
@@ -343,6 +375,16 @@ pub struct PaymentAttemptUpdateInternal {
     encoded_data: Option<String>,
     unified_code: Option<Option<String>>,
     unified_message: Option<Option<String>>,
+    network_transaction_id: Option<String>,
+    connector_fallback_index: Option<i16>,
+    routed_to_unhealthy_connector: Option<bool>,
+    installment_plan: Option<serde_json::Value>,
+    connector_idempotency_key: Option<String>,
+    frm_score: Option<i32>,
+    frm_score_threshold: Option<i32>,
+    customer_acceptance: Option<serde_json::Value>,
+    correlation_id: Option<String>,
+    error_classification: Option<storage_enums::ConnectorErrorClassification>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -403,6 +445,16 @@ impl PaymentAttemptUpdate {
             encoded_data,
             unified_code,
             unified_message,
+            network_transaction_id,
+            connector_fallback_index,
+            routed_to_unhealthy_connector,
+            installment_plan,
+            connector_idempotency_key,
+            frm_score,
+            frm_score_threshold,
+            customer_acceptance,
+            correlation_id,
+            error_classification,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -444,6 +496,18 @@ impl PaymentAttemptUpdate {
             encoded_data: encoded_data.or(source.encoded_data),
             unified_code: unified_code.unwrap_or(source.unified_code),
             unified_message: unified_message.unwrap_or(source.unified_message),
+            network_transaction_id: network_transaction_id.or(source.network_transaction_id),
+            connector_fallback_index: connector_fallback_index.or(source.connector_fallback_index),
+            routed_to_unhealthy_connector: routed_to_unhealthy_connector
+                .or(source.routed_to_unhealthy_connector),
+            installment_plan: installment_plan.or(source.installment_plan),
+            connector_idempotency_key: connector_idempotency_key
+                .or(source.connector_idempotency_key),
+            frm_score: frm_score.or(source.frm_score),
+            frm_score_threshold: frm_score_threshold.or(source.frm_score_threshold),
+            customer_acceptance: customer_acceptance.or(source.customer_acceptance),
+            correlation_id: correlation_id.or(source.correlation_id),
+            error_classification: error_classification.or(source.error_classification),
             ..source
         }
     }
@@ -519,6 +583,15 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 merchant_connector_id,
                 surcharge_amount,
                 tax_amount,
+                network_transaction_id,
+                connector_fallback_index,
+                routed_to_unhealthy_connector,
+                installment_plan,
+                connector_idempotency_key,
+                frm_score,
+                frm_score_threshold,
+                customer_acceptance,
+                correlation_id,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -541,6 +614,15 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 merchant_connector_id,
                 surcharge_amount,
                 tax_amount,
+                network_transaction_id,
+                connector_fallback_index,
+                routed_to_unhealthy_connector,
+                installment_plan,
+                connector_idempotency_key,
+                frm_score,
+                frm_score_threshold,
+                customer_acceptance,
+                correlation_id,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -617,6 +699,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 connector_transaction_id,
+                error_classification,
             } => Self {
                 connector,
                 status: Some(status),
@@ -629,6 +712,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 connector_transaction_id,
+                error_classification,
                 ..Default::default()
             },
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -57,6 +57,7 @@ pub struct PaymentIntent {
     pub authorization_count: Option<i32>,
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
+    pub merchant_order_reference_id: Option<String>,
 }
 
 #[derive(
@@ -109,6 +110,7 @@ pub struct PaymentIntentNew {
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
+    pub merchant_order_reference_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -163,6 +165,7 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         session_expiry: Option<PrimitiveDateTime>,
         fingerprint_id: Option<String>,
+        merchant_order_reference_id: Option<String>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -176,6 +179,7 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
     },
     ApproveUpdate {
+        status: storage_enums::IntentStatus,
         merchant_decision: Option<String>,
         updated_by: String,
     },
@@ -230,6 +234,7 @@ pub struct PaymentIntentUpdateInternal {
     pub authorization_count: Option<i32>,
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
+    pub merchant_order_reference_id: Option<String>,
 }
 
 impl PaymentIntentUpdate {
@@ -264,6 +269,7 @@ impl PaymentIntentUpdate {
             authorization_count,
             session_expiry,
             fingerprint_id,
+            merchant_order_reference_id,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -298,6 +304,8 @@ impl PaymentIntentUpdate {
                 .or(source.incremental_authorization_allowed),
             authorization_count: authorization_count.or(source.authorization_count),
             fingerprint_id: fingerprint_id.or(source.fingerprint_id),
+            merchant_order_reference_id: merchant_order_reference_id
+                .or(source.merchant_order_reference_id),
             session_expiry: session_expiry.or(source.session_expiry),
             ..source
         }
@@ -327,6 +335,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 session_expiry,
                 fingerprint_id,
+                merchant_order_reference_id,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -348,6 +357,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 session_expiry,
                 fingerprint_id,
+                merchant_order_reference_id,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
@@ -444,9 +454,11 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 ..Default::default()
             },
             PaymentIntentUpdate::ApproveUpdate {
+                status,
                 merchant_decision,
                 updated_by,
             } => Self {
+                status: Some(status),
                 merchant_decision,
                 updated_by,
                 ..Default::default()
@@ -1,4 +1,6 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::generics;
@@ -14,6 +16,22 @@ impl ConfigNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Config> {
         generics::generic_insert(conn, self).await
     }
+
+    /// Inserts the config, or replaces the value of the config when one exists with the same key
+    #[instrument(skip(conn))]
+    pub async fn upsert(self, conn: &PgPooledConn) -> StorageResult<Config> {
+        let config = self.config.clone();
+        diesel::insert_into(<Config as HasTable>::table())
+            .values(self)
+            .on_conflict(dsl::key)
+            .do_update()
+            .set(dsl::config.eq(config))
+            .get_result_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while upserting config")
+    }
 }
 
 impl Config {
@@ -49,6 +67,30 @@ impl Config {
         }
     }
 
+    /// Updates the config only when its value is still `current_config`, the update fails with a
+    /// not found error when the config was changed or deleted in the meantime
+    #[instrument(skip(conn))]
+    pub async fn update_by_key_if_unchanged(
+        conn: &PgPooledConn,
+        key: &str,
+        current_config: String,
+        config_update: ConfigUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::key
+                .eq(key.to_owned())
+                .and(dsl::config.eq(current_config)),
+            ConfigUpdateInternal::from(config_update),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn delete_by_key(conn: &PgPooledConn, key: &str) -> StorageResult<bool> {
         generics::generic_delete::<<Self as HasTable>::Table, _>(conn, dsl::key.eq(key.to_owned()))
@@ -1,9 +1,11 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
-use super::generics;
+use super::generics::{self, db_metrics};
 use crate::{
-    errors,
+    enums, errors,
     payment_intent::{
         PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
     },
@@ -73,4 +75,42 @@ impl PaymentIntent {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_merchant_order_reference_id_merchant_id(
+        conn: &PgPooledConn,
+        merchant_order_reference_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_order_reference_id.eq(merchant_order_reference_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Counts the payment intents in the given status which were last modified before the
+    /// given time, relying on the index on `status` and `modified_at`
+    #[instrument(skip(conn))]
+    pub async fn get_count_by_status_modified_before(
+        conn: &PgPooledConn,
+        status: enums::IntentStatus,
+        modified_before: time::PrimitiveDateTime,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table()
+            .count()
+            .filter(dsl::status.eq(status))
+            .filter(dsl::modified_at.lt(modified_before));
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting payment intents by status")
+    }
 }
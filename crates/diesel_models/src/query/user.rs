@@ -1,7 +1,7 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
-    associations::HasTable, debug_query, result::Error as DieselError, ExpressionMethods,
-    JoinOnDsl, QueryDsl,
+    associations::HasTable, debug_query, result::Error as DieselError, BoolExpressionMethods,
+    ExpressionMethods, JoinOnDsl, QueryDsl,
 };
 use error_stack::{report, IntoReport};
 use router_env::{
@@ -18,7 +18,7 @@ use crate::{
         users::dsl as users_dsl,
     },
     user::*,
-    user_role::UserRole,
+    user_role::{UserRole, UserRoleListCursor},
     PgPooledConn, StorageResult,
 };
 
@@ -91,4 +91,41 @@ impl User {
                 _ => err.change_context(errors::DatabaseError::Others),
             })
     }
+
+    /// Lists a page of the users of the merchant along with their roles, ordered by the creation of
+    /// their roles and starting after the cursor when one is given
+    pub async fn find_joined_users_and_roles_by_merchant_id_after_cursor(
+        conn: &PgPooledConn,
+        mid: &str,
+        cursor: Option<UserRoleListCursor>,
+        limit: i64,
+    ) -> StorageResult<Vec<(Self, UserRole)>> {
+        let mut query = Self::table()
+            .inner_join(user_roles::table.on(user_roles_dsl::user_id.eq(users_dsl::user_id)))
+            .filter(user_roles_dsl::merchant_id.eq(mid.to_owned()))
+            .order((user_roles_dsl::created_at.asc(), user_roles_dsl::id.asc()))
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(cursor) = cursor {
+            query = query.filter(
+                user_roles_dsl::created_at
+                    .gt(cursor.created_at)
+                    .or(user_roles_dsl::created_at
+                        .eq(cursor.created_at)
+                        .and(user_roles_dsl::id.gt(cursor.id))),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        query
+            .get_results_async::<(Self, UserRole)>(conn)
+            .await
+            .into_report()
+            .map_err(|err| match err.current_context() {
+                DieselError::NotFound => err.change_context(errors::DatabaseError::NotFound),
+                _ => err.change_context(errors::DatabaseError::Others),
+            })
+    }
 }
@@ -1,7 +1,23 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
-use router_env::tracing::{self, instrument};
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{
+    logger,
+    tracing::{self, instrument},
+};
 
-use crate::{query::generics, schema::user_roles::dsl, user_role::*, PgPooledConn, StorageResult};
+use crate::{
+    enums, errors,
+    query::generics,
+    schema::{
+        role_preferences::dsl as preferences_dsl,
+        user_role_change_requests::dsl as change_request_dsl, user_roles::dsl,
+    },
+    user_role::*,
+    PgPooledConn, StorageResult,
+};
 
 impl UserRoleNew {
     #[instrument(skip(conn))]
@@ -14,7 +30,11 @@ impl UserRole {
     pub async fn find_by_user_id(conn: &PgPooledConn, user_id: String) -> StorageResult<Self> {
         generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
             conn,
-            dsl::user_id.eq(user_id),
+            dsl::user_id.eq(user_id).and(
+                dsl::expires_at
+                    .is_null()
+                    .or(dsl::expires_at.gt(common_utils::date_time::now())),
+            ),
         )
         .await
     }
@@ -23,6 +43,27 @@ impl UserRole {
         conn: &PgPooledConn,
         user_id: String,
         merchant_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::user_id
+                .eq(user_id)
+                .and(dsl::merchant_id.eq(merchant_id))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
+        )
+        .await
+    }
+
+    /// Finds the role of the user in the merchant even if it expired, for the cleanup of the
+    /// expired roles which were not removed yet
+    pub async fn find_by_user_id_merchant_id_including_expired(
+        conn: &PgPooledConn,
+        user_id: String,
+        merchant_id: String,
     ) -> StorageResult<Self> {
         generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
             conn,
@@ -59,10 +100,216 @@ impl UserRole {
             .await
     }
 
+    pub async fn delete_by_user_id_merchant_id(
+        conn: &PgPooledConn,
+        user_id: String,
+        merchant_id: String,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::user_id
+                .eq(user_id)
+                .and(dsl::merchant_id.eq(merchant_id)),
+        )
+        .await
+    }
+
+    pub async fn delete_expired_by_user_id_merchant_id(
+        conn: &PgPooledConn,
+        user_id: String,
+        merchant_id: String,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::user_id
+                .eq(user_id)
+                .and(dsl::merchant_id.eq(merchant_id))
+                .and(dsl::expires_at.le(common_utils::date_time::now())),
+        )
+        .await
+    }
+
+    /// Deletes the role of the user in the merchant unless it is the last active admin role of the
+    /// merchant, in which case `false` is returned and the role is kept
+    pub async fn delete_by_user_id_merchant_id_keeping_admin(
+        conn: &PgPooledConn,
+        user_id: String,
+        merchant_id: String,
+        admin_role_ids: Vec<String>,
+    ) -> StorageResult<bool> {
+        conn.transaction_async(|conn| {
+            Box::pin(async move {
+                if !Self::lock_other_active_admin_exists(
+                    &conn,
+                    &user_id,
+                    &merchant_id,
+                    admin_role_ids,
+                )
+                .await?
+                {
+                    return Ok(false);
+                }
+
+                let deleted = diesel::delete(
+                    <Self as HasTable>::table().filter(
+                        dsl::user_id
+                            .eq(user_id)
+                            .and(dsl::merchant_id.eq(merchant_id)),
+                    ),
+                )
+                .execute_async(&conn)
+                .await?;
+
+                match deleted {
+                    0 => Err(errors::DatabaseError::NotFound),
+                    _ => Ok(true),
+                }
+            })
+        })
+        .await
+        .into_report()
+        .attach_printable("Error while deleting user role")
+    }
+
+    /// Updates the role of the user in the merchant unless it is the last active admin role of the
+    /// merchant, in which case `None` is returned and the role is kept as is
+    pub async fn update_by_user_id_merchant_id_keeping_admin(
+        conn: &PgPooledConn,
+        user_id: String,
+        merchant_id: String,
+        admin_role_ids: Vec<String>,
+        update: UserRoleUpdate,
+    ) -> StorageResult<Option<Self>> {
+        conn.transaction_async(|conn| {
+            Box::pin(async move {
+                if !Self::lock_other_active_admin_exists(
+                    &conn,
+                    &user_id,
+                    &merchant_id,
+                    admin_role_ids,
+                )
+                .await?
+                {
+                    return Ok(None);
+                }
+
+                diesel::update(
+                    <Self as HasTable>::table().filter(
+                        dsl::user_id
+                            .eq(user_id)
+                            .and(dsl::merchant_id.eq(merchant_id)),
+                    ),
+                )
+                .set(UserRoleUpdateInternal::from(update))
+                .get_result_async(&conn)
+                .await
+                .map(Some)
+                .map_err(errors::DatabaseError::from)
+            })
+        })
+        .await
+        .into_report()
+        .attach_printable("Error while updating user role")
+    }
+
+    /// Locks the active admin roles of the merchant until the end of the transaction and tells
+    /// whether one of them belongs to a user other than the given one. Removals of admins of the
+    /// same merchant are serialized by the lock, so that two of them cannot each count on the
+    /// other admin remaining.
+    async fn lock_other_active_admin_exists(
+        conn: &PgPooledConn,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: Vec<String>,
+    ) -> Result<bool, errors::DatabaseError> {
+        let admin_user_ids: Vec<String> = <Self as HasTable>::table()
+            .select(dsl::user_id)
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::role_id.eq_any(admin_role_ids))
+                    .and(dsl::status.eq(enums::UserStatus::Active))
+                    .and(
+                        dsl::expires_at
+                            .is_null()
+                            .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                    ),
+            )
+            .for_update()
+            .get_results_async(conn)
+            .await?;
+
+        Ok(admin_user_ids
+            .iter()
+            .any(|admin_user_id| admin_user_id != user_id))
+    }
+
     pub async fn list_by_user_id(conn: &PgPooledConn, user_id: String) -> StorageResult<Vec<Self>> {
         generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
             conn,
-            dsl::user_id.eq(user_id),
+            dsl::user_id.eq(user_id).and(
+                dsl::expires_at
+                    .is_null()
+                    .or(dsl::expires_at.gt(common_utils::date_time::now())),
+            ),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// Lists a page of the unexpired roles of the user, starting after the cursor when one is given
+    pub async fn list_by_user_id_after_cursor(
+        conn: &PgPooledConn,
+        user_id: String,
+        cursor: Option<UserRoleListCursor>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table()
+            .into_boxed()
+            .filter(
+                dsl::user_id.eq(user_id).and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
+            )
+            .order((dsl::created_at.asc(), dsl::id.asc()))
+            .limit(limit);
+
+        if let Some(cursor) = cursor {
+            query = query.filter(
+                dsl::created_at.gt(cursor.created_at).or(dsl::created_at
+                    .eq(cursor.created_at)
+                    .and(dsl::id.gt(cursor.id))),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while listing the roles of the user")
+    }
+
+    pub async fn list_active_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: String,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id)
+                .and(dsl::status.eq(enums::UserStatus::Active))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
             None,
             None,
             Some(dsl::created_at.asc()),
@@ -70,3 +317,161 @@ impl UserRole {
         .await
     }
 }
+
+impl UserRoleChangeRequestNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<UserRoleChangeRequest> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl UserRoleChangeRequest {
+    pub async fn find_by_request_id_merchant_id(
+        conn: &PgPooledConn,
+        request_id: String,
+        merchant_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            change_request_dsl::request_id
+                .eq(request_id)
+                .and(change_request_dsl::merchant_id.eq(merchant_id))
+                .and(change_request_dsl::expires_at.gt(common_utils::date_time::now())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: String,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            change_request_dsl::merchant_id
+                .eq(merchant_id)
+                .and(change_request_dsl::expires_at.gt(common_utils::date_time::now())),
+            None,
+            None,
+            Some(change_request_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_request_id(
+        conn: &PgPooledConn,
+        request_id: String,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            change_request_dsl::request_id.eq(request_id),
+        )
+        .await
+    }
+
+    pub async fn delete_expired_by_user_id_merchant_id(
+        conn: &PgPooledConn,
+        user_id: String,
+        merchant_id: String,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            change_request_dsl::user_id
+                .eq(user_id)
+                .and(change_request_dsl::merchant_id.eq(merchant_id))
+                .and(change_request_dsl::expires_at.le(common_utils::date_time::now())),
+        )
+        .await
+    }
+}
+
+impl RolePreferencesNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RolePreferences> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RolePreferences {
+    pub async fn update_by_merchant_id_role_id_user_id(
+        conn: &PgPooledConn,
+        merchant_id: String,
+        role_id: String,
+        user_id: Option<String>,
+        update: RolePreferencesUpdate,
+    ) -> StorageResult<Self> {
+        let predicate = preferences_dsl::merchant_id
+            .eq(merchant_id)
+            .and(preferences_dsl::role_id.eq(role_id));
+
+        if let Some(uid) = user_id {
+            generics::generic_update_with_unique_predicate_get_result::<
+                <Self as HasTable>::Table,
+                _,
+                _,
+                _,
+            >(
+                conn,
+                predicate.and(preferences_dsl::user_id.eq(uid)),
+                RolePreferencesUpdateInternal::from(update),
+            )
+            .await
+        } else {
+            generics::generic_update_with_unique_predicate_get_result::<
+                <Self as HasTable>::Table,
+                _,
+                _,
+                _,
+            >(
+                conn,
+                predicate.and(preferences_dsl::user_id.is_null()),
+                RolePreferencesUpdateInternal::from(update),
+            )
+            .await
+        }
+    }
+
+    /// Lists the defaults of all the roles of the merchant along with the overrides of the user
+    pub async fn list_by_merchant_id_user_id(
+        conn: &PgPooledConn,
+        merchant_id: String,
+        user_id: String,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            preferences_dsl::merchant_id.eq(merchant_id).and(
+                preferences_dsl::user_id
+                    .is_null()
+                    .or(preferences_dsl::user_id.eq(user_id)),
+            ),
+            None,
+            None,
+            Some(preferences_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_role_id_user_id(
+        conn: &PgPooledConn,
+        merchant_id: String,
+        role_id: String,
+        user_id: Option<String>,
+    ) -> StorageResult<bool> {
+        let predicate = preferences_dsl::merchant_id
+            .eq(merchant_id)
+            .and(preferences_dsl::role_id.eq(role_id));
+
+        if let Some(uid) = user_id {
+            generics::generic_delete::<<Self as HasTable>::Table, _>(
+                conn,
+                predicate.and(preferences_dsl::user_id.eq(uid)),
+            )
+            .await
+        } else {
+            generics::generic_delete::<<Self as HasTable>::Table, _>(
+                conn,
+                predicate.and(preferences_dsl::user_id.is_null()),
+            )
+            .await
+        }
+    }
+}
@@ -54,6 +54,8 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        #[max_length = 64]
+        role_id -> Nullable<Varchar>,
     }
 }
 
@@ -373,6 +375,8 @@ diesel::table! {
         modified_at -> Timestamp,
         #[max_length = 64]
         last_step -> Varchar,
+        #[max_length = 255]
+        reviewed_by -> Nullable<Varchar>,
     }
 }
 
@@ -688,6 +692,22 @@ diesel::table! {
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
         net_amount -> Nullable<Int8>,
+        #[max_length = 255]
+        network_transaction_id -> Nullable<Varchar>,
+        connector_fallback_index -> Nullable<Int2>,
+        routed_to_unhealthy_connector -> Nullable<Bool>,
+        installment_plan -> Nullable<Jsonb>,
+        #[max_length = 128]
+        connector_idempotency_key -> Nullable<Varchar>,
+        frm_score -> Nullable<Int4>,
+        frm_score_threshold -> Nullable<Int4>,
+        customer_acceptance -> Nullable<Jsonb>,
+        #[max_length = 64]
+        correlation_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        error_classification -> Nullable<Varchar>,
+        #[max_length = 255]
+        authentication_escalation_reason -> Nullable<Varchar>,
     }
 }
 
@@ -755,6 +775,8 @@ diesel::table! {
         session_expiry -> Nullable<Timestamp>,
         #[max_length = 64]
         fingerprint_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        merchant_order_reference_id -> Nullable<Varchar>,
     }
 }
 
@@ -993,6 +1015,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    role_preferences (id) {
+        id -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        role_id -> Varchar,
+        #[max_length = 64]
+        user_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        default_landing_page -> Nullable<Varchar>,
+        visible_widgets -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+        #[max_length = 64]
+        last_modified_by -> Varchar,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1015,6 +1061,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    user_role_change_requests (id) {
+        id -> Int4,
+        #[max_length = 64]
+        request_id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        role_id -> Varchar,
+        role_expires_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        requested_by -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1036,6 +1104,11 @@ diesel::table! {
         last_modified_by -> Varchar,
         created_at -> Timestamp,
         last_modified -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        title -> Nullable<Varchar>,
+        #[max_length = 64]
+        department -> Nullable<Varchar>,
     }
 }
 
@@ -1094,7 +1167,9 @@ diesel::allow_tables_to_appear_in_same_query!(
     process_tracker,
     refund,
     reverse_lookup,
+    role_preferences,
     routing_algorithm,
+    user_role_change_requests,
     user_roles,
     users,
 );
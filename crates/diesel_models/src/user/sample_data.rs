@@ -116,6 +116,17 @@ impl PaymentAttemptBatchNew {
             unified_code: self.unified_code,
             unified_message: self.unified_message,
             net_amount: self.net_amount,
+            network_transaction_id: None,
+            connector_fallback_index: None,
+            routed_to_unhealthy_connector: None,
+            installment_plan: None,
+            connector_idempotency_key: None,
+            frm_score: None,
+            frm_score_threshold: None,
+            customer_acceptance: None,
+            correlation_id: None,
+            error_classification: None,
+            authentication_escalation_reason: None,
         }
     }
 }
@@ -1,7 +1,10 @@
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
 
-use crate::{enums, schema::user_roles};
+use crate::{
+    enums,
+    schema::{role_preferences, user_role_change_requests, user_roles},
+};
 
 #[derive(Clone, Debug, Identifiable, Queryable)]
 #[diesel(table_name = user_roles)]
@@ -16,6 +19,41 @@ pub struct UserRole {
     pub last_modified_by: String,
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub title: Option<String>,
+    pub department: Option<String>,
+}
+
+impl UserRole {
+    /// Returns true if the role had an expiry set and the expiry has passed
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.map_or(false, |expires_at| {
+            expires_at <= common_utils::date_time::now()
+        })
+    }
+
+    /// Position of the role in the paginated listings of user roles
+    pub fn get_list_cursor(&self) -> UserRoleListCursor {
+        UserRoleListCursor {
+            created_at: self.created_at,
+            id: self.id,
+        }
+    }
+}
+
+/// Position in the paginated listings of user roles, which are ordered by creation time and id.
+/// A page starts right after the user role the cursor points to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UserRoleListCursor {
+    pub created_at: PrimitiveDateTime,
+    pub id: i32,
+}
+
+impl UserRoleListCursor {
+    /// Returns true if the user role comes after the cursor in the listing order
+    pub fn precedes(&self, user_role: &UserRole) -> bool {
+        (self.created_at, self.id) < (user_role.created_at, user_role.id)
+    }
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -30,6 +68,9 @@ pub struct UserRoleNew {
     pub last_modified_by: String,
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub title: Option<String>,
+    pub department: Option<String>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -39,6 +80,9 @@ pub struct UserRoleUpdateInternal {
     status: Option<enums::UserStatus>,
     last_modified_by: Option<String>,
     last_modified: PrimitiveDateTime,
+    expires_at: Option<Option<PrimitiveDateTime>>,
+    title: Option<String>,
+    department: Option<String>,
 }
 
 pub enum UserRoleUpdate {
@@ -49,6 +93,14 @@ pub enum UserRoleUpdate {
     UpdateRole {
         role_id: String,
         modified_by: String,
+        expires_at: Option<PrimitiveDateTime>,
+    },
+    /// Updates the attributes of the user in the merchant which do not affect their permissions,
+    /// the attributes which are not set are left unchanged
+    UpdateAttributes {
+        title: Option<String>,
+        department: Option<String>,
+        modified_by: String,
     },
 }
 
@@ -59,11 +111,15 @@ impl From<UserRoleUpdate> for UserRoleUpdateInternal {
             UserRoleUpdate::UpdateRole {
                 role_id,
                 modified_by,
+                expires_at,
             } => Self {
                 role_id: Some(role_id),
                 last_modified_by: Some(modified_by),
                 status: None,
                 last_modified,
+                expires_at: Some(expires_at),
+                title: None,
+                department: None,
             },
             UserRoleUpdate::UpdateStatus {
                 status,
@@ -73,6 +129,131 @@ impl From<UserRoleUpdate> for UserRoleUpdateInternal {
                 last_modified,
                 last_modified_by: Some(modified_by),
                 role_id: None,
+                expires_at: None,
+                title: None,
+                department: None,
+            },
+            UserRoleUpdate::UpdateAttributes {
+                title,
+                department,
+                modified_by,
+            } => Self {
+                title,
+                department,
+                last_modified,
+                last_modified_by: Some(modified_by),
+                role_id: None,
+                status: None,
+                expires_at: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct UserRoleExpiryTrackingData {
+    pub user_id: String,
+    pub merchant_id: String,
+}
+
+/// Change of the role of a user which is awaiting the approval of a second admin
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = user_role_change_requests)]
+pub struct UserRoleChangeRequest {
+    pub id: i32,
+    pub request_id: String,
+    pub user_id: String,
+    pub merchant_id: String,
+    pub role_id: String,
+    pub role_expires_at: Option<PrimitiveDateTime>,
+    pub requested_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
+
+impl UserRoleChangeRequest {
+    /// Returns true if the request was not approved before its expiry
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= common_utils::date_time::now()
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = user_role_change_requests)]
+pub struct UserRoleChangeRequestNew {
+    pub request_id: String,
+    pub user_id: String,
+    pub merchant_id: String,
+    pub role_id: String,
+    pub role_expires_at: Option<PrimitiveDateTime>,
+    pub requested_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
+
+/// UI preferences of a role in a merchant, which do not affect the permissions of the role. The
+/// preferences without a user are the defaults of the role, while the preferences with a user
+/// override the defaults for that user
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = role_preferences)]
+pub struct RolePreferences {
+    pub id: i32,
+    pub merchant_id: String,
+    pub role_id: String,
+    pub user_id: Option<String>,
+    pub default_landing_page: Option<String>,
+    pub visible_widgets: Option<Vec<Option<String>>>,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_by: String,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = role_preferences)]
+pub struct RolePreferencesNew {
+    pub merchant_id: String,
+    pub role_id: String,
+    pub user_id: Option<String>,
+    pub default_landing_page: Option<String>,
+    pub visible_widgets: Option<Vec<Option<String>>>,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_by: String,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = role_preferences)]
+pub struct RolePreferencesUpdateInternal {
+    default_landing_page: Option<String>,
+    visible_widgets: Option<Vec<Option<String>>>,
+    last_modified_by: String,
+    last_modified_at: PrimitiveDateTime,
+}
+
+pub enum RolePreferencesUpdate {
+    /// Updates the preferences which are set, the preferences which are not set are left unchanged
+    UpdatePreferences {
+        default_landing_page: Option<String>,
+        visible_widgets: Option<Vec<Option<String>>>,
+        modified_by: String,
+    },
+}
+
+impl From<RolePreferencesUpdate> for RolePreferencesUpdateInternal {
+    fn from(value: RolePreferencesUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match value {
+            RolePreferencesUpdate::UpdatePreferences {
+                default_landing_page,
+                visible_widgets,
+                modified_by,
+            } => Self {
+                default_landing_page,
+                visible_widgets,
+                last_modified_by: modified_by,
+                last_modified_at,
             },
         }
     }
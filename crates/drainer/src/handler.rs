@@ -6,8 +6,8 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, types::DeadLetterEntry,
+    utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -72,6 +72,7 @@ impl Handler {
                     self.store.clone(),
                     stream_index,
                     self.conf.max_read_count,
+                    self.conf.max_retry_count,
                     self.active_tasks.clone(),
                     jobs_picked.clone(),
                 ));
@@ -144,6 +145,7 @@ async fn drainer_handler(
     store: Arc<Store>,
     stream_index: u8,
     max_read_count: u64,
+    max_retry_count: u32,
     active_tasks: Arc<atomic::AtomicU64>,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -154,6 +156,7 @@ async fn drainer_handler(
     let drainer_result = Box::pin(drainer(
         store.clone(),
         max_read_count,
+        max_retry_count,
         stream_name.as_str(),
         jobs_picked,
     ))
@@ -177,6 +180,7 @@ async fn drainer_handler(
 async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
+    max_retry_count: u32,
     stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -219,6 +223,9 @@ async fn drainer(
     let mut last_processed_id = String::new();
 
     for (entry_id, entry) in entries.clone() {
+        // The serialized query is retained, so that it can be recorded if the entry is
+        // dead-lettered
+        let typed_sql = entry.get("typed_sql").cloned().unwrap_or_default();
         let data = match StreamData::from_hashmap(entry) {
             Ok(data) => data,
             Err(err) => {
@@ -237,24 +244,80 @@ async fn drainer(
             }
         };
 
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
+        tracing::Span::current().record("request_id", data.request_id.as_str());
+        tracing::Span::current().record("global_id", data.global_id.as_str());
         tracing::Span::current().record("session_id", &session_id);
 
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
+        let operation = data.typed_sql.operation();
+        let table = data.typed_sql.table();
+
+        let err = match data.typed_sql.execute_query(&store, data.pushed_at).await {
             Ok(_) => {
                 last_processed_id = entry_id;
+                continue;
             }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
-                }
-                // break from the loop in case of an error in query
-                _ => break,
-            },
+            Err(err) => err,
+        };
+
+        // In case of Uniqueviolation we can't really do anything to fix it so just clear it
+        // from the stream. The entry is still copied to the dead letter stream, so that a write
+        // lost on a unique violation, such as a payment intent whose merchant order reference is
+        // used by another payment, does not go unnoticed
+        if is_failed_entry_trimmable(err.current_context()) {
+            let dead_letter_entry = DeadLetterEntry {
+                entry_id: entry_id.clone(),
+                stream_name: stream_name.to_string(),
+                request_id: data.request_id,
+                global_id: data.global_id,
+                operation: operation.to_string(),
+                table: table.to_string(),
+                typed_sql,
+                retry_count: 0,
+                last_error: format!("{err:#}"),
+                dead_lettered_at: common_utils::date_time::now_unix_timestamp(),
+            };
+            push_to_dead_letter_stream(
+                &store,
+                dead_letter_entry,
+                "Entry failed on a unique violation and was trimmed from the stream",
+            )
+            .await;
+            last_processed_id = entry_id;
+            continue;
         }
+
+        // break from the loop in case of an error in query, the entry is retried in the next
+        // session however many times it fails, so that no later entry of the stream is written
+        // before it
+        let retry_count = match store.increment_retry_count(stream_name, &entry_id).await {
+            Ok(retry_count) => retry_count,
+            Err(error) => {
+                logger::error!(operation = "increment_retry_count", err=?error);
+                break;
+            }
+        };
+
+        if is_dead_letter_due(retry_count, max_retry_count) {
+            let dead_letter_entry = DeadLetterEntry {
+                entry_id: entry_id.clone(),
+                stream_name: stream_name.to_string(),
+                request_id: data.request_id,
+                global_id: data.global_id,
+                operation: operation.to_string(),
+                table: table.to_string(),
+                typed_sql,
+                retry_count,
+                last_error: format!("{err:#}"),
+                dead_lettered_at: common_utils::date_time::now_unix_timestamp(),
+            };
+            push_to_dead_letter_stream(
+                &store,
+                dead_letter_entry,
+                "Entry exhausted the retries and was copied to the dead letter stream",
+            )
+            .await;
+        }
+        break;
     }
 
     if !last_processed_id.is_empty() {
@@ -275,3 +338,69 @@ async fn drainer(
 
     Ok(())
 }
+
+async fn push_to_dead_letter_stream(
+    store: &Store,
+    dead_letter_entry: DeadLetterEntry,
+    message: &'static str,
+) {
+    match store.push_to_dead_letter_stream(&dead_letter_entry).await {
+        Ok(()) => {
+            logger::error!(?dead_letter_entry, "{message}");
+            metrics::ENTRIES_DEAD_LETTERED.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue {
+                    key: "table".into(),
+                    value: dead_letter_entry.table.into(),
+                }],
+            );
+        }
+        Err(error) => {
+            logger::error!(operation = "dead_letter", err=?error);
+        }
+    }
+}
+
+/// Whether an entry whose query failed can be trimmed from the stream. Only the entries which can
+/// never be written because of a unique violation are trimmed. An entry failing for any other
+/// reason is kept however many times it was retried, so that the drainer never moves past a write
+/// which did not reach the database.
+fn is_failed_entry_trimmable(error: &diesel_models::errors::DatabaseError) -> bool {
+    matches!(error, diesel_models::errors::DatabaseError::UniqueViolation)
+}
+
+/// Whether a failing entry is copied to the dead letter stream, for alerting. An entry is copied
+/// once, when it reaches the maximum number of retries, rather than on every later retry.
+fn is_dead_letter_due(retry_count: i64, max_retry_count: u32) -> bool {
+    retry_count == i64::from(max_retry_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel_models::errors::DatabaseError;
+
+    use super::*;
+
+    #[test]
+    fn test_failing_entry_is_not_acked() {
+        assert!(is_failed_entry_trimmable(&DatabaseError::UniqueViolation));
+
+        // An entry failing on an outage of the database is kept in the stream, including once it
+        // exhausted its retries and was copied to the dead letter stream
+        assert!(!is_failed_entry_trimmable(
+            &DatabaseError::DatabaseConnectionError
+        ));
+        assert!(!is_failed_entry_trimmable(&DatabaseError::Others));
+    }
+
+    #[test]
+    fn test_entry_is_dead_lettered_once() {
+        let max_retry_count = 5;
+
+        assert!(!is_dead_letter_due(1, max_retry_count));
+        assert!(!is_dead_letter_due(4, max_retry_count));
+        assert!(is_dead_letter_due(5, max_retry_count));
+        assert!(!is_dead_letter_due(6, max_retry_count));
+    }
+}
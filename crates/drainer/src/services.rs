@@ -14,6 +14,8 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub drainer_dead_letter_stream_name: String,
+    pub drainer_dead_letter_max_length: u64,
 }
 
 impl Store {
@@ -40,6 +42,8 @@ impl Store {
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                drainer_dead_letter_stream_name: config.drainer.dead_letter_stream_name.clone(),
+                drainer_dead_letter_max_length: config.drainer.dead_letter_max_length,
             },
             request_id: None,
         }
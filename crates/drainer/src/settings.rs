@@ -60,6 +60,13 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    /// Number of failed attempts after which an entry is copied to the dead letter stream for
+    /// alerting, the entry is still retried until it is drained
+    pub max_retry_count: u32,
+    /// Stream which records the entries which could not be drained
+    pub dead_letter_stream_name: String,
+    /// Number of most recent entries retained in the dead letter stream
+    pub dead_letter_max_length: u64,
 }
 
 impl Default for Database {
@@ -84,6 +91,9 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            max_retry_count: 5,
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
+            dead_letter_max_length: 1000,
         }
     }
 }
@@ -124,6 +134,12 @@ impl DrainerSettings {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        common_utils::fp_utils::when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
         })
     }
 }
@@ -4,11 +4,14 @@ use error_stack::IntoReport;
 use redis_interface as redis;
 use router_env::{logger, tracing};
 
-use crate::{errors, metrics, Store};
+use crate::{errors, metrics, types::DeadLetterEntry, Store};
 
 pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
 
+/// Duration (in seconds) for which the retry count of a failing entry is retained
+const RETRY_COUNT_TTL: i64 = 24 * 60 * 60;
+
 impl Store {
     #[inline(always)]
     pub fn drainer_stream(&self, shard_key: &str) -> String {
@@ -78,6 +81,70 @@ impl Store {
 
         output
     }
+
+    #[inline(always)]
+    fn get_retry_count_key(stream_name: &str, entry_id: &str) -> String {
+        format!("{stream_name}_retry_count_{entry_id}")
+    }
+
+    /// Increments the number of times the entry failed to be drained and returns it
+    pub async fn increment_retry_count(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<i64> {
+        let retry_count_key = Self::get_retry_count_key(stream_name, entry_id);
+
+        let retry_count = self
+            .redis_conn
+            .increment_key(&retry_count_key)
+            .await
+            .map_err(errors::DrainerError::from)
+            .into_report()?;
+
+        // The key is never deleted once the entry is drained, it expires instead
+        self.redis_conn
+            .set_expiry(&retry_count_key, RETRY_COUNT_TTL)
+            .await
+            .map_err(errors::DrainerError::from)
+            .into_report()?;
+
+        Ok(retry_count)
+    }
+
+    /// Records the entry in the dead letter stream, which retains only the most recent entries
+    pub async fn push_to_dead_letter_stream(
+        &self,
+        entry: &DeadLetterEntry,
+    ) -> errors::DrainerResult<()> {
+        let dead_letter_stream_name = self.config.drainer_dead_letter_stream_name.as_str();
+
+        self.redis_conn
+            .stream_append_entry(
+                dead_letter_stream_name,
+                &redis::RedisEntryId::AutoGeneratedID,
+                entry.to_field_value_pairs(),
+            )
+            .await
+            .map_err(errors::DrainerError::from)
+            .into_report()?;
+
+        self.redis_conn
+            .stream_trim_entries(
+                dead_letter_stream_name,
+                (
+                    redis::StreamCapKind::MaxLen,
+                    redis::StreamCapTrim::AlmostExact,
+                    i64::try_from(self.config.drainer_dead_letter_max_length).unwrap_or(i64::MAX),
+                ),
+            )
+            .await
+            .map_err(errors::DrainerError::from)
+            .into_report()?;
+
+        Ok(())
+    }
+
     pub async fn trim_from_stream(
         &self,
         stream_name: &str,
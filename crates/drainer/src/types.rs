@@ -34,3 +34,38 @@ impl StreamData {
             .change_context(errors::ParsingError::StructParseFailure("StreamData"))
     }
 }
+
+/// Copy of an entry which kept failing to be drained for the configured number of retries, or
+/// which was trimmed from the stream on a unique violation, recorded in the dead letter stream
+/// along with the last error
+#[derive(Debug)]
+pub struct DeadLetterEntry {
+    pub entry_id: String,
+    pub stream_name: String,
+    pub request_id: String,
+    pub global_id: String,
+    pub operation: String,
+    pub table: String,
+    /// Serialized query of the entry, so that the cause of the failure can be investigated
+    pub typed_sql: String,
+    pub retry_count: i64,
+    pub last_error: String,
+    pub dead_lettered_at: i64,
+}
+
+impl DeadLetterEntry {
+    pub fn to_field_value_pairs(&self) -> Vec<(&str, String)> {
+        vec![
+            ("entry_id", self.entry_id.clone()),
+            ("stream_name", self.stream_name.clone()),
+            ("request_id", self.request_id.clone()),
+            ("global_id", self.global_id.clone()),
+            ("operation", self.operation.clone()),
+            ("table", self.table.clone()),
+            ("typed_sql", self.typed_sql.clone()),
+            ("retry_count", self.retry_count.to_string()),
+            ("last_error", self.last_error.clone()),
+            ("dead_lettered_at", self.dead_lettered_at.to_string()),
+        ]
+    }
+}
@@ -15,11 +15,11 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{ClientLike, HashesInterface, KeysInterface, StreamsInterface},
     prelude::RedisErrorKind,
     types::{
-        Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse,
+        Expiration, FromRedis, InfoKind, MultipleIDs, MultipleKeys, MultipleOrderedPairs,
+        MultipleStrings, RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse,
     },
 };
 use futures::StreamExt;
@@ -27,7 +27,7 @@ use router_env::{instrument, logger, tracing};
 
 use crate::{
     errors,
-    types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, SetnxReply},
+    types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, ReplicationInfo, SetnxReply},
 };
 
 impl super::RedisConnectionPool {
@@ -173,6 +173,24 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::DeleteFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_ttl(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .ttl(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key_with_expiry<V>(
         &self,
@@ -542,6 +560,16 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetLengthFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_replication_info(&self) -> CustomResult<ReplicationInfo, errors::RedisError> {
+        self.pool
+            .info::<String>(Some(InfoKind::Replication))
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetReplicationInfoFailed)
+            .map(|info| ReplicationInfo::from_info(&info))
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_read_entries<K, Ids>(
         &self,
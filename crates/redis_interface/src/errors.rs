@@ -18,6 +18,8 @@ pub enum RedisError {
     GetFailed,
     #[error("Failed to delete key value in Redis")]
     DeleteFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to append entry to Redis stream")]
     StreamAppendFailed,
     #[error("Failed to read entries from Redis stream")]
@@ -66,4 +68,6 @@ pub enum RedisError {
     OnMessageError,
     #[error("Got an unknown result from redis")]
     UnknownResult,
+    #[error("Failed to get the replication info from Redis")]
+    GetReplicationInfoFailed,
 }
@@ -255,3 +255,58 @@ impl fred::types::FromRedis for DelReply {
         }
     }
 }
+
+/// Replication section of the `INFO` reply of the node the pool is connected to
+#[derive(Debug, Clone, Default)]
+pub struct ReplicationInfo {
+    /// Role of the node, either `master` or `slave`
+    pub role: String,
+    /// Replication offset of the node
+    pub master_repl_offset: Option<u64>,
+    /// Replication offsets of the connected replicas, only reported by a master
+    pub replica_offsets: Vec<u64>,
+}
+
+impl ReplicationInfo {
+    /// Parses the reply of `INFO replication`, which has one `field:value` pair per line. Each
+    /// connected replica is reported as `slave<n>:ip=<ip>,port=<port>,state=<state>,offset=<offset>,lag=<lag>`
+    pub fn from_info(info: &str) -> Self {
+        info.lines()
+            .filter_map(|line| line.trim().split_once(':'))
+            .fold(Self::default(), |mut replication_info, (field, value)| {
+                let is_replica_field = field.strip_prefix("slave").map_or(false, |index| {
+                    !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit())
+                });
+
+                match field {
+                    "role" => replication_info.role = value.to_string(),
+                    "master_repl_offset" => {
+                        replication_info.master_repl_offset = value.parse().ok()
+                    }
+                    _ if is_replica_field => {
+                        if let Some(offset) = value
+                            .split(',')
+                            .find_map(|pair| pair.strip_prefix("offset="))
+                            .and_then(|offset| offset.parse().ok())
+                        {
+                            replication_info.replica_offsets.push(offset);
+                        }
+                    }
+                    _ => {}
+                }
+
+                replication_info
+            })
+    }
+
+    /// Number of bytes the most lagging replica is behind the master, `None` when the node is not
+    /// a master or no replica is connected to it
+    pub fn max_lag_bytes(&self) -> Option<u64> {
+        let master_repl_offset = self.master_repl_offset.filter(|_| self.role == "master")?;
+
+        self.replica_offsets
+            .iter()
+            .map(|replica_offset| master_repl_offset.saturating_sub(*replica_offset))
+            .max()
+    }
+}
@@ -4,6 +4,22 @@ fn main() {
     #[cfg(debug_assertions)]
     println!("cargo:rustc-env=RUST_MIN_STACK=6291456"); // 6 * 1024 * 1024 = 6 MiB
 
+    // Expose the latest migration version, used by the deep health check to detect schema drift
+    println!("cargo:rerun-if-changed=../../migrations");
+    if let Some(schema_version) = std::fs::read_dir("../../migrations")
+        .ok()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| {
+            name.split_once('_')
+                .map(|(version, _)| version.replace('-', ""))
+        })
+        .max()
+    {
+        println!("cargo:rustc-env=ROUTER_EXPECTED_SCHEMA_VERSION={schema_version}");
+    }
+
     #[cfg(feature = "vergen")]
     router_env::vergen::generate_cargo_instructions();
 }
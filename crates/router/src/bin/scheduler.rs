@@ -83,6 +83,8 @@ pub enum PTRunner {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
+    UserRoleExpiryWorkflow,
+    UserRoleWebhookRetryWorkflow,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -107,6 +109,12 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
             Some(PTRunner::DeleteTokenizeDataWorkflow) => {
                 Box::new(workflows::tokenized_data::DeleteTokenizeDataWorkflow)
             }
+            Some(PTRunner::UserRoleExpiryWorkflow) => {
+                Box::new(workflows::user_role_expiry::UserRoleExpiryWorkflow)
+            }
+            Some(PTRunner::UserRoleWebhookRetryWorkflow) => {
+                Box::new(workflows::user_role_webhook::UserRoleWebhookRetryWorkflow)
+            }
             _ => Err(ProcessTrackerError::UnexpectedFlow)?,
         };
         let app_state = &state.clone();
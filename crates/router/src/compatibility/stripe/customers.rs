@@ -7,7 +7,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::{api_locking, customers, payment_methods::cards},
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::{customers as customer_types, payment_methods},
 };
 
@@ -47,7 +47,7 @@ pub async fn customer_create(
         |state, auth, req| {
             customers::create_customer(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -82,7 +82,7 @@ pub async fn customer_retrieve(
         |state, auth, req| {
             customers::retrieve_customer(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -126,7 +126,7 @@ pub async fn customer_update(
         |state, auth, req| {
             customers::update_customer(state, auth.merchant_account, req, auth.key_store)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -161,7 +161,7 @@ pub async fn customer_delete(
         |state, auth, req| {
             customers::delete_customer(state, auth.merchant_account, req, auth.key_store)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -201,7 +201,7 @@ pub async fn list_customer_payment_method_api(
                 Some(customer_id.as_str()),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
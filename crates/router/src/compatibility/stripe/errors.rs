@@ -199,6 +199,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id already exists in our records.")]
     DuplicatePayment { payment_id: String },
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "A payment with the specified merchant_order_reference_id already exists in our records.")]
+    DuplicateOrderReference { payment_id: String },
+
     #[error(error_type = StripeErrorType::ConnectorError, code = "", message = "{code}: {message}")]
     ExternalConnectorError {
         code: String,
@@ -481,6 +484,15 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 connector,
                 status_code,
             },
+            errors::ApiErrorResponse::ConnectorTimeout {
+                connector,
+                timeout_in_secs,
+            } => Self::ExternalConnectorError {
+                code: crate::consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
+                message: format!("{connector} did not respond within {timeout_in_secs} seconds"),
+                connector,
+                status_code: reqwest::StatusCode::GATEWAY_TIMEOUT.as_u16(),
+            },
             errors::ApiErrorResponse::IncorrectConnectorNameGiven => {
                 Self::IncorrectConnectorNameGiven
             }
@@ -555,6 +567,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::DuplicatePayment { payment_id } => {
                 Self::DuplicatePayment { payment_id }
             }
+            errors::ApiErrorResponse::DuplicateOrderReference { payment_id } => {
+                Self::DuplicateOrderReference { payment_id }
+            }
             errors::ApiErrorResponse::DisputeNotFound { dispute_id } => Self::ResourceMissing {
                 object: "dispute".to_owned(),
                 id: dispute_id,
@@ -595,9 +610,24 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::PaymentMethodUnactivated
             }
             errors::ApiErrorResponse::ResourceBusy => Self::PaymentMethodUnactivated,
+            errors::ApiErrorResponse::PaymentBeingProcessed { .. } => Self::LockTimeout,
             errors::ApiErrorResponse::InvalidConnectorConfiguration { config } => {
                 Self::InvalidConnectorConfiguration { config }
             }
+            errors::ApiErrorResponse::MaximumRetriesExceeded { max_attempts } => {
+                Self::PreconditionFailed {
+                    message: format!(
+                        "The payment has reached the maximum of {max_attempts} attempts"
+                    ),
+                }
+            }
+            errors::ApiErrorResponse::PaymentMethodTypeNotAllowed {
+                payment_method_type,
+            } => Self::InvalidRequestData {
+                message: format!(
+                    "Payment method type {payment_method_type} is not allowed for this payment"
+                ),
+            },
             errors::ApiErrorResponse::CurrencyConversionFailed => Self::CurrencyConversionFailed,
         }
     }
@@ -652,6 +682,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentIntentMandateInvalid { .. }
             | Self::PaymentIntentUnexpectedState { .. }
             | Self::DuplicatePayment { .. }
+            | Self::DuplicateOrderReference { .. }
             | Self::GenericDuplicateError { .. }
             | Self::IncorrectConnectorNameGiven
             | Self::ResourceMissing { .. }
@@ -8,7 +8,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::{api_locking::GetLockingInput, payment_methods::Oss, payments},
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::api as api_types,
 };
 
@@ -63,7 +63,7 @@ pub async fn payment_intents_create(
                 api_types::HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -87,11 +87,14 @@ pub async fn payment_intents_retrieve(
         expand_captures: None,
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let flow = Flow::PaymentsRetrieve;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -152,10 +155,11 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
         merchant_connector_details: json_payload.merchant_connector_details.clone(),
         ..Default::default()
     };
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentRead) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let flow = Flow::PaymentsRetrieve;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -217,10 +221,11 @@ pub async fn payment_intents_update(
 
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentWrite) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let flow = Flow::PaymentsUpdate;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -284,11 +289,14 @@ pub async fn payment_intents_confirm(
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     let flow = Flow::PaymentsConfirm;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -378,7 +386,7 @@ pub async fn payment_intents_capture(
                 api_types::HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -404,10 +412,11 @@ pub async fn payment_intents_cancel(
     let mut payload: payment_types::PaymentsCancelRequest = stripe_payload.into();
     payload.payment_id = payment_id;
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentWrite) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let flow = Flow::PaymentsCancel;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -473,7 +482,7 @@ pub async fn payment_intent_list(
         &req,
         payload,
         |state, auth, req| payments::list_payments(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -11,7 +11,7 @@ use crate::{
     },
     core::{api_locking, payment_methods::Oss, payments},
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::api as api_types,
 };
 
@@ -73,7 +73,7 @@ pub async fn setup_intents_create(
                 api_types::HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -97,11 +97,14 @@ pub async fn setup_intents_retrieve(
         expand_captures: None,
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let flow = Flow::PaymentsRetrieve;
 
@@ -163,11 +166,14 @@ pub async fn setup_intents_update(
         };
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(setup_id));
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     let flow = Flow::PaymentsUpdate;
 
@@ -237,11 +243,14 @@ pub async fn setup_intents_confirm(
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(setup_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     let flow = Flow::PaymentsConfirm;
 
@@ -74,6 +74,7 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    UserRole(api_models::user_role::UserRoleWebhookContent),
 }
 
 #[derive(Serialize, Debug)]
@@ -183,6 +184,10 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::UserRoleUpdated => "user_role.updated",
+        api_models::enums::EventType::UserRoleDeleted => "user_role.deleted",
+        api_models::enums::EventType::UserRoleSuspended => "user_role.suspended",
+        api_models::enums::EventType::UserRoleResumed => "user_role.resumed",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -230,6 +235,7 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::UserRoleDetails(user_role) => Self::UserRole(*user_role),
         }
     }
 }
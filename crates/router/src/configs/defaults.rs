@@ -89,6 +89,107 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::PaymentBatchConfirm {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 10,
+            max_concurrency: 5,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentPayloadLimits {
+    fn default() -> Self {
+        Self {
+            max_field_size_in_bytes: 65536,
+            max_field_key_count: 1024,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentAttempts {
+    fn default() -> Self {
+        Self {
+            max_attempts_per_payment: 50,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorTimeouts {
+    fn default() -> Self {
+        Self {
+            default_timeout_in_secs: crate::consts::REQUEST_TIME_OUT,
+            connectors: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+impl Default for super::settings::RoleChangeRateLimit {
+    fn default() -> Self {
+        Self {
+            max_requests_per_window: 50,
+            window_in_secs: 60,
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+impl Default for super::settings::RoleChangeApproval {
+    fn default() -> Self {
+        Self {
+            sensitive_roles: Vec::new(),
+            request_expiry_in_secs: 86400,
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+impl Default for super::settings::RoleInfoAccess {
+    fn default() -> Self {
+        Self {
+            view_permission: crate::services::authorization::permissions::Permission::UsersRead,
+        }
+    }
+}
+
+impl Default for super::settings::HealthCheck {
+    fn default() -> Self {
+        Self {
+            cache_ttl_in_millis: 2000,
+            probe_timeout_in_millis: 5000,
+            metrics_enabled: false,
+            metrics_bearer_token: None,
+            read_only_db: false,
+            probe_order: super::settings::HealthCheckDependency::DEFAULT_ORDER.to_vec(),
+            stop_on_first_failure: false,
+            liveness_max_scheduling_delay_in_millis: 100,
+            redis_pubsub_enabled: false,
+            redis_pubsub_timeout_in_millis: 1000,
+            redis_connection_retries: 2,
+            redis_connection_retry_base_delay_in_millis: 150,
+            max_clock_skew_in_millis: 1000,
+            failure_simulation_enabled: false,
+            stuck_payments_probe_enabled: false,
+            stuck_payments_threshold_in_secs: 900,
+            stuck_payments_max_count: 100,
+            end_to_end_enabled: false,
+            end_to_end_merchant_id: None,
+            end_to_end_connector: None,
+            end_to_end_card_number: "4242424242424242".to_string(),
+            end_to_end_min_interval_in_secs: 300,
+            locker_probe_enabled: false,
+            locker_timeout_in_millis: 2000,
+            dead_letter_probe_enabled: false,
+            dead_letter_max_count: 0,
+            redis_replication_probe_enabled: false,
+            redis_replication_max_lag_bytes: 1_048_576,
+            redis_instances: HashMap::new(),
+            redis_instances_connection_timeout_in_millis: 1000,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -104,6 +205,9 @@ impl Default for super::settings::DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000,
             loop_interval: 100,
+            max_retry_count: 5,
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
+            dead_letter_max_length: 1000,
         }
     }
 }
@@ -79,6 +79,11 @@ pub struct Settings {
     pub connectors: Connectors,
     pub forex_api: ForexApi,
     pub refund: Refund,
+    pub payment_batch_confirm: PaymentBatchConfirm,
+    pub payment_payload_limits: PaymentPayloadLimits,
+    pub payment_attempts: PaymentAttempts,
+    pub confirm_replay: ConfirmReplay,
+    pub connector_timeouts: ConnectorTimeouts,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -103,6 +108,8 @@ pub struct Settings {
     pub mandates: Mandates,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
+    pub session_token_prefetch: SessionTokenPrefetchConfig,
+    pub three_ds_version: ThreeDsVersionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
     pub payment_method_auth: PaymentMethodAuth,
     pub connector_request_reference_id_config: ConnectorRequestReferenceIdConfig,
@@ -110,8 +117,16 @@ pub struct Settings {
     pub payouts: Payouts,
     pub applepay_decrypt_keys: ApplePayDecryptConifg,
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
+    pub network_transaction_id_required_connectors: NetworkTransactionIdRequiredConnectors,
+    pub network_token_supported_connectors: NetworkTokenSupportedConnectors,
+    pub statement_descriptor_constraints: StatementDescriptorConstraints,
+    pub three_ds_browser_info_requirements: ThreeDsBrowserInfoRequirements,
+    pub connector_error_classification: ConnectorErrorClassificationConfig,
+    pub connector_installment_plans: ConnectorInstallmentPlans,
+    pub connector_supported_countries: ConnectorSupportedCountries,
     pub applepay_merchant_configs: ApplepayMerchantConfigs,
     pub lock_settings: LockSettings,
+    pub health_check: HealthCheck,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub payment_link: PaymentLink,
     #[cfg(feature = "olap")]
@@ -125,6 +140,12 @@ pub struct Settings {
     pub events: EventsConfig,
     #[cfg(feature = "olap")]
     pub connector_onboarding: ConnectorOnboarding,
+    #[cfg(feature = "olap")]
+    pub role_change_rate_limit: RoleChangeRateLimit,
+    #[cfg(feature = "olap")]
+    pub role_change_approval: RoleChangeApproval,
+    #[cfg(feature = "olap")]
+    pub role_info_access: RoleInfoAccess,
 }
 
 #[cfg(feature = "frm")]
@@ -203,6 +224,101 @@ pub struct TokenizationConfig(pub HashMap<String, PaymentMethodTokenFilter>);
 #[serde(transparent)]
 pub struct TempLockerEnableConfig(pub HashMap<String, TempLockerEnablePaymentMethodFilter>);
 
+/// Connectors which require the network transaction id of the customer initiated transaction
+/// for off session merchant initiated transactions
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NetworkTransactionIdRequiredConnectors {
+    #[serde(deserialize_with = "connector_deser")]
+    pub connector_list: HashSet<api_models::enums::Connector>,
+}
+
+/// Connectors to which a network token and its cryptogram can be forwarded in place of a card
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NetworkTokenSupportedConnectors {
+    #[serde(deserialize_with = "connector_deser")]
+    pub connector_list: HashSet<api_models::enums::Connector>,
+}
+
+/// Limits enforced by the connectors on the statement descriptor, keyed by the connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct StatementDescriptorConstraints(pub HashMap<String, StatementDescriptorConstraint>);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct StatementDescriptorConstraint {
+    /// Maximum number of characters allowed in the statement descriptor
+    pub max_length: usize,
+    /// Whether the statement descriptor may only contain alphanumeric characters and spaces
+    #[serde(default)]
+    pub alphanumeric_only: bool,
+}
+
+/// Browser information fields required by the connectors to run a 3DS authentication, keyed by
+/// the connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ThreeDsBrowserInfoRequirements(pub HashMap<String, Vec<BrowserInfoField>>);
+
+/// Classification of the errors of the connectors, keyed by the connector name and then by the
+/// error code returned by the connector
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorErrorClassificationConfig(
+    pub HashMap<String, HashMap<String, api_models::enums::ConnectorErrorClassification>>,
+);
+
+/// Installment plans offered by the connectors, keyed by the connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorInstallmentPlans(pub HashMap<String, InstallmentPlansConfig>);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct InstallmentPlansConfig {
+    /// Payment method types for which the connector offers installments
+    #[serde(deserialize_with = "pm_type_deser")]
+    pub payment_method_types: HashSet<diesel_models::enums::PaymentMethodType>,
+    pub plans: Vec<InstallmentPlanConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct InstallmentPlanConfig {
+    pub plan_id: String,
+    /// Numbers of installments the payment can be split into with this plan
+    pub installment_options: Vec<u8>,
+    /// Unix timestamp after which the plan is no longer offered
+    pub expires_at: Option<i64>,
+}
+
+/// Countries the geographically restricted connectors operate in, keyed by the connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorSupportedCountries(pub HashMap<String, SupportedCountriesConfig>);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SupportedCountriesConfig {
+    /// Countries the billing address of the payment must be in
+    #[serde(deserialize_with = "string_set_deser")]
+    pub countries: Option<HashSet<api_models::enums::CountryAlpha2>>,
+    /// Whether the shipping address of the payment must also be in the supported countries
+    #[serde(default)]
+    pub validate_shipping: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrowserInfoField {
+    AcceptHeader,
+    UserAgent,
+    Language,
+    ColorDepth,
+    ScreenHeight,
+    ScreenWidth,
+    TimeZone,
+    JavaEnabled,
+    JavaScriptEnabled,
+    IpAddress,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorCustomer {
     #[serde(deserialize_with = "connector_deser")]
@@ -504,6 +620,184 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentBatchConfirm {
+    pub max_batch_size: usize,
+    pub max_concurrency: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentPayloadLimits {
+    /// Maximum size (in bytes) of each of the metadata, order_details and connector_metadata fields
+    pub max_field_size_in_bytes: usize,
+    /// Maximum number of keys, including nested keys, in each of these fields
+    pub max_field_key_count: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentAttempts {
+    /// Maximum number of attempts a single payment can accrue, unless overridden for the merchant
+    pub max_attempts_per_payment: i16,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConfirmReplay {
+    /// Duration (in seconds) after a payment succeeded during which confirming it again with the
+    /// same client secret returns the payment instead of an error, unless overridden for the
+    /// merchant. Disabled when 0.
+    pub grace_window_in_secs: u64,
+}
+
+/// Timeouts of the authorization requests sent to the connectors
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorTimeouts {
+    /// Timeout used for the connectors which have no timeout of their own
+    pub default_timeout_in_secs: u64,
+    /// Timeout of each connector, keyed by the connector name
+    pub connectors: HashMap<String, u64>,
+}
+
+impl ConnectorTimeouts {
+    pub fn get_timeout_in_secs(&self, connector_name: &str) -> u64 {
+        self.connectors
+            .get(connector_name)
+            .copied()
+            .unwrap_or(self.default_timeout_in_secs)
+    }
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RoleChangeRateLimit {
+    /// Maximum number of user role changes a single user can perform in a window
+    pub max_requests_per_window: i64,
+    /// Duration (in seconds) of the rate limiting window
+    pub window_in_secs: i64,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RoleChangeApproval {
+    /// Roles whose assignment has to be approved by a second admin before it is applied
+    pub sensitive_roles: Vec<String>,
+    /// Duration (in seconds) after which a role change request which was not approved expires
+    pub request_expiry_in_secs: i64,
+}
+
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RoleInfoAccess {
+    /// Permission a user requires to view the authorization info and to list the roles
+    pub view_permission: crate::services::authorization::permissions::Permission,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct HealthCheck {
+    /// Duration (in milliseconds) for which the result of the deep health check is cached, the
+    /// result is refreshed in the background once every such duration
+    pub cache_ttl_in_millis: u64,
+    /// Duration (in milliseconds) after which a probe of a dependency is reported as failed
+    pub probe_timeout_in_millis: u64,
+    /// Whether the health check measurements are exposed in the prometheus format
+    pub metrics_enabled: bool,
+    /// Bearer token required to access the health check metrics, if set
+    pub metrics_bearer_token: Option<masking::Secret<String>>,
+    /// Whether the database health check runs only the read probe, for nodes connected to a read replica
+    pub read_only_db: bool,
+    /// Order in which the dependencies are probed, dependencies which are not listed are probed
+    /// after the listed ones
+    pub probe_order: Vec<HealthCheckDependency>,
+    /// Whether the remaining dependencies are skipped once a dependency is found to be unhealthy
+    pub stop_on_first_failure: bool,
+    /// Maximum duration (in milliseconds) the runtime may take to schedule a task before the
+    /// liveness probe reports the process as unresponsive
+    pub liveness_max_scheduling_delay_in_millis: u64,
+    /// Whether the deep health check also probes Redis pub/sub, which the cache invalidation
+    /// relies on
+    pub redis_pubsub_enabled: bool,
+    /// Duration (in milliseconds) within which the message published by the Redis pub/sub probe
+    /// must be received
+    pub redis_pubsub_timeout_in_millis: u64,
+    /// Number of times establishing the connection to Redis is retried by the deep health check,
+    /// so that Redis reconnecting after a restart is not reported as unhealthy
+    pub redis_connection_retries: u32,
+    /// Delay (in milliseconds) before the first retry of the Redis connection, doubled after each
+    /// retry
+    pub redis_connection_retry_base_delay_in_millis: u64,
+    /// Difference (in milliseconds) between the clocks of the database and of this node above
+    /// which the database is reported as degraded
+    pub max_clock_skew_in_millis: u64,
+    /// Whether failures of the components can be simulated in the deep health check, to test
+    /// the alerting and the orchestrator. Must stay disabled in production.
+    pub failure_simulation_enabled: bool,
+    /// Whether the deep health check counts the payments stuck in the `processing` status, which
+    /// indicates that the asynchronous updates of the payments are not being processed
+    pub stuck_payments_probe_enabled: bool,
+    /// Duration (in seconds) after which a payment in the `processing` status is considered stuck
+    pub stuck_payments_threshold_in_secs: u64,
+    /// Number of stuck payments above which the deep health check reports them as degraded
+    pub stuck_payments_max_count: u64,
+    /// Whether the deep health check authorizes and voids a payment of a test merchant, reported
+    /// as `e2e`. Must only be enabled with a merchant whose connectors are in sandbox.
+    pub end_to_end_enabled: bool,
+    /// Test merchant whose payment is authorized and voided by the end to end probe
+    pub end_to_end_merchant_id: Option<String>,
+    /// Sandbox connector the end to end probe payment is processed through, the routing of the
+    /// test merchant decides when unset
+    pub end_to_end_connector: Option<enums::Connector>,
+    /// Test card authorized by the end to end probe
+    pub end_to_end_card_number: String,
+    /// Minimum duration (in seconds) between two runs of the end to end probe, the result of the
+    /// last run is reported in between
+    pub end_to_end_min_interval_in_secs: u64,
+    /// Whether the deep health check probes the health endpoint of the configured locker,
+    /// reported as `locker`
+    pub locker_probe_enabled: bool,
+    /// Duration (in milliseconds) within which the locker must respond to the locker probe
+    pub locker_timeout_in_millis: u64,
+    /// Whether the deep health check counts the entries which the drainer failed to drain and
+    /// copied to the dead letter stream, reported as `dead_letter`
+    pub dead_letter_probe_enabled: bool,
+    /// Number of dead-lettered entries above which the deep health check reports them as degraded
+    pub dead_letter_max_count: u64,
+    /// Whether the deep health check measures how far the Redis replicas are behind the master,
+    /// reported as `redis_replication`
+    pub redis_replication_probe_enabled: bool,
+    /// Replication lag (in bytes) above which the deep health check reports Redis replication as
+    /// degraded
+    pub redis_replication_max_lag_bytes: u64,
+    /// Redis instances probed by the deep health check along with the primary Redis, keyed by
+    /// their role, for deployments which use separate instances for the cache, the streams or the
+    /// locks. Each instance is reported under `redis_instances`.
+    pub redis_instances: HashMap<String, RedisSettings>,
+    /// Duration (in milliseconds) within which the connection to each of the additional Redis
+    /// instances must be established
+    pub redis_instances_connection_timeout_in_millis: u64,
+}
+
+/// Dependency probed by the deep health check
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckDependency {
+    Database,
+    Redis,
+    Locker,
+}
+
+impl HealthCheckDependency {
+    /// Order in which the dependencies are probed when no order is configured
+    pub const DEFAULT_ORDER: [Self; 3] = [Self::Database, Self::Redis, Self::Locker];
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -687,6 +981,9 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub max_retry_count: u32,
+    pub dead_letter_stream_name: String,
+    pub dead_letter_max_length: u64,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -737,6 +1034,38 @@ pub struct DelayedSessionConfig {
     pub connectors_with_delayed_session_response: HashSet<api_models::enums::Connector>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SessionTokenPrefetchConfig {
+    /// Connectors for which the wallet session token is fetched along with the confirm call
+    #[serde(deserialize_with = "connector_deser")]
+    pub connectors_with_session_token_prefetch: HashSet<api_models::enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ThreeDsVersionConfig {
+    /// Connectors which authenticate the customer through 3DS 2.x, the other connectors are
+    /// considered to support 3DS 1.0 only
+    #[serde(deserialize_with = "connector_deser")]
+    pub connectors_with_3ds2: HashSet<api_models::enums::Connector>,
+    /// How the preferred 3DS version of a payment is enforced for the merchants who did not
+    /// configure their own policy
+    pub default_enforcement: ThreeDsVersionEnforcement,
+}
+
+/// How the preferred 3DS version of a payment is enforced when the connector does not support it
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreeDsVersionEnforcement {
+    /// The payment fails
+    #[default]
+    Hard,
+    /// The customer is authenticated through the version supported by the connector, and a
+    /// warning is returned
+    Soft,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct WebhookSourceVerificationCall {
     #[serde(deserialize_with = "connector_deser")]
@@ -804,6 +1133,8 @@ impl Settings {
                     .with_list_parse_key("redis.cluster_urls")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
+                    .with_list_parse_key("health_check.probe_order")
+                    .with_list_parse_key("role_change_approval.sensitive_roles")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
 
             )
@@ -856,6 +1187,15 @@ impl Settings {
         #[cfg(feature = "aws_s3")]
         self.file_upload_config.validate()?;
         self.lock_settings.validate()?;
+        self.payment_batch_confirm.validate()?;
+        self.payment_payload_limits.validate()?;
+        self.payment_attempts.validate()?;
+        self.connector_timeouts.validate()?;
+        self.health_check.validate()?;
+        #[cfg(feature = "olap")]
+        self.role_change_rate_limit.validate()?;
+        #[cfg(feature = "olap")]
+        self.role_change_approval.validate()?;
         self.events.validate()?;
         Ok(())
     }
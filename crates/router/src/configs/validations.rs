@@ -123,6 +123,12 @@ impl super::settings::DrainerSettings {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        common_utils::fp_utils::when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
         })
     }
 }
@@ -193,3 +199,209 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+impl super::settings::PaymentBatchConfirm {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment batch confirm max_batch_size must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.max_concurrency == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment batch confirm max_concurrency must not be empty or 0".into(),
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "olap")]
+impl super::settings::RoleChangeRateLimit {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_requests_per_window <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "role change rate limit max_requests_per_window must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.window_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "role change rate limit window_in_secs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "olap")]
+impl super::settings::RoleChangeApproval {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.request_expiry_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "role change approval request_expiry_in_secs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::HealthCheck {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        let has_duplicates = self
+            .probe_order
+            .iter()
+            .enumerate()
+            .any(|(index, dependency)| self.probe_order[..index].contains(dependency));
+
+        when(has_duplicates, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "health check probe_order must not list a dependency more than once".into(),
+            ))
+        })?;
+
+        when(self.liveness_max_scheduling_delay_in_millis == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "health check liveness_max_scheduling_delay_in_millis must be greater than 0"
+                    .into(),
+            ))
+        })?;
+
+        when(
+            self.redis_pubsub_enabled && self.redis_pubsub_timeout_in_millis == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "health check redis_pubsub_timeout_in_millis must be greater than 0".into(),
+                ))
+            },
+        )?;
+
+        when(self.redis_connection_retries > 5, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "health check redis_connection_retries must not be greater than 5".into(),
+            ))
+        })?;
+
+        when(
+            self.redis_connection_retries > 0
+                && self.redis_connection_retry_base_delay_in_millis == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "health check redis_connection_retry_base_delay_in_millis must be greater than 0"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.stuck_payments_probe_enabled && self.stuck_payments_threshold_in_secs == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "health check stuck_payments_threshold_in_secs must be greater than 0".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.end_to_end_enabled
+                && self
+                    .end_to_end_merchant_id
+                    .as_ref()
+                    .map_or(true, |merchant_id| merchant_id.is_default_or_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "health check end_to_end_merchant_id must not be empty".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.end_to_end_enabled && self.end_to_end_min_interval_in_secs == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "health check end_to_end_min_interval_in_secs must be greater than 0".into(),
+                ))
+            },
+        )?;
+
+        self.redis_instances
+            .iter()
+            .try_for_each(|(role, redis_instance)| {
+                redis_instance.validate().map_err(|error| {
+                    println!("{error}");
+                    ApplicationError::InvalidConfigurationValueError(format!(
+                        "health check redis instance `{role}` configuration"
+                    ))
+                })
+            })?;
+
+        when(
+            !self.redis_instances.is_empty()
+                && self.redis_instances_connection_timeout_in_millis == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "health check redis_instances_connection_timeout_in_millis must be greater than 0"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::PaymentPayloadLimits {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_field_size_in_bytes == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment payload limits max_field_size_in_bytes must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.max_field_key_count == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment payload limits max_field_key_count must not be empty or 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::PaymentAttempts {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_attempts_per_payment <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment attempts max_attempts_per_payment must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorTimeouts {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.default_timeout_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector timeouts default_timeout_in_secs must not be empty or 0".into(),
+            ))
+        })?;
+
+        self.connectors
+            .iter()
+            .try_for_each(|(connector_name, timeout_in_secs)| {
+                when(*timeout_in_secs == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "connector timeout of {connector_name} must not be 0"
+                    )))
+                })
+            })
+    }
+}
@@ -1498,7 +1498,8 @@ impl<'a> TryFrom<&AdyenRouterData<&types::PaymentsAuthorizeRouterData>>
                 | payments::PaymentMethodData::MandatePayment
                 | payments::PaymentMethodData::Reward
                 | payments::PaymentMethodData::Upi(_)
-                | payments::PaymentMethodData::CardToken(_) => {
+                | payments::PaymentMethodData::CardToken(_)
+                | payments::PaymentMethodData::NetworkToken(_) => {
                     Err(errors::ConnectorError::NotSupported {
                         message: utils::SELECTED_PAYMENT_METHOD.to_string(),
                         connector: "Adyen",
@@ -2394,7 +2395,8 @@ impl<'a>
                     | payments::PaymentMethodData::Upi(_)
                     | payments::PaymentMethodData::Voucher(_)
                     | payments::PaymentMethodData::GiftCard(_)
-                    | api::PaymentMethodData::CardToken(_) => {
+                    | api::PaymentMethodData::CardToken(_)
+                    | api::PaymentMethodData::NetworkToken(_) => {
                         Err(errors::ConnectorError::NotSupported {
                             message: "Network tokenization for payment method".to_string(),
                             connector: "Adyen",
@@ -134,19 +134,27 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                         expiration_month: ccard.card_exp_month.clone(),
                         expiration_year: ccard.card_exp_year.clone(),
                         capture: should_capture.to_string(),
-                        installments_id: item
-                            .router_data
-                            .request
-                            .mandate_id
-                            .as_ref()
-                            .map(|ids| ids.mandate_id.clone()),
+                        installments_id: match item.router_data.request.installment_plan {
+                            Some(ref installment_plan) => Some(installment_plan.plan_id.clone()),
+                            None => item
+                                .router_data
+                                .request
+                                .mandate_id
+                                .as_ref()
+                                .map(|ids| ids.mandate_id.clone()),
+                        },
                         // [#595[FEATURE] Pass Mandate history information in payment flows/request]
-                        installments: item
-                            .router_data
-                            .request
-                            .mandate_id
-                            .clone()
-                            .map(|_| "1".to_string()),
+                        installments: match item.router_data.request.installment_plan {
+                            Some(ref installment_plan) => {
+                                Some(installment_plan.number_of_installments.to_string())
+                            }
+                            None => item
+                                .router_data
+                                .request
+                                .mandate_id
+                                .clone()
+                                .map(|_| "1".to_string()),
+                        },
                     }),
                     order_id: item.router_data.connector_request_reference_id.clone(),
                     three_dsecure: match item.router_data.auth_type {
@@ -172,9 +180,14 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
             | api::PaymentMethodData::Upi(_)
             | api::PaymentMethodData::Voucher(_)
             | api::PaymentMethodData::GiftCard(_)
-            | api::PaymentMethodData::CardToken(_) => Err(errors::ConnectorError::NotImplemented(
-                crate::connector::utils::get_unimplemented_payment_method_error_message("Dlocal"),
-            ))?,
+            | api::PaymentMethodData::CardToken(_)
+            | api::PaymentMethodData::NetworkToken(_) => {
+                Err(errors::ConnectorError::NotImplemented(
+                    crate::connector::utils::get_unimplemented_payment_method_error_message(
+                        "Dlocal",
+                    ),
+                ))?
+            }
         }
     }
 }
@@ -417,7 +417,8 @@ impl
             | api_payments::PaymentMethodData::Upi(_)
             | api_payments::PaymentMethodData::Voucher(_)
             | api_payments::PaymentMethodData::GiftCard(_)
-            | api_payments::PaymentMethodData::CardToken(_) => Err(error_stack::report!(
+            | api_payments::PaymentMethodData::CardToken(_)
+            | api_payments::PaymentMethodData::NetworkToken(_) => Err(error_stack::report!(
                 errors::ConnectorError::MismatchedPaymentData
             )),
         }
@@ -627,7 +627,8 @@ fn get_payment_details_and_product(
         | PaymentMethodData::Upi(_)
         | PaymentMethodData::Voucher(_)
         | PaymentMethodData::GiftCard(_)
-        | PaymentMethodData::CardToken(_) => Err(errors::ConnectorError::NotImplemented(
+        | PaymentMethodData::CardToken(_)
+        | PaymentMethodData::NetworkToken(_) => Err(errors::ConnectorError::NotImplemented(
             utils::get_unimplemented_payment_method_error_message("nexinets"),
         ))?,
     }
@@ -858,7 +858,8 @@ impl<F>
             | payments::PaymentMethodData::Voucher(_)
             | payments::PaymentMethodData::CardRedirect(_)
             | payments::PaymentMethodData::GiftCard(_)
-            | payments::PaymentMethodData::CardToken(_) => {
+            | payments::PaymentMethodData::CardToken(_)
+            | payments::PaymentMethodData::NetworkToken(_) => {
                 Err(errors::ConnectorError::NotImplemented(
                     utils::get_unimplemented_payment_method_error_message("nuvei"),
                 )
@@ -1039,6 +1040,7 @@ impl TryFrom<(&types::PaymentsCompleteAuthorizeRouterData, String)> for NuveiPay
             | Some(api::PaymentMethodData::Reward)
             | Some(api::PaymentMethodData::Upi(..))
             | Some(api::PaymentMethodData::CardToken(..))
+            | Some(api::PaymentMethodData::NetworkToken(..))
             | None => Err(errors::ConnectorError::NotImplemented(
                 utils::get_unimplemented_payment_method_error_message("nuvei"),
             )),
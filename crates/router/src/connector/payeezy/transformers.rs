@@ -264,7 +264,8 @@ fn get_payment_method_data(
         | api::PaymentMethodData::Upi(_)
         | api::PaymentMethodData::Voucher(_)
         | api::PaymentMethodData::GiftCard(_)
-        | api::PaymentMethodData::CardToken(_) => Err(errors::ConnectorError::NotImplemented(
+        | api::PaymentMethodData::CardToken(_)
+        | api::PaymentMethodData::NetworkToken(_) => Err(errors::ConnectorError::NotImplemented(
             utils::get_unimplemented_payment_method_error_message("Payeezy"),
         ))?,
     }
@@ -768,6 +768,12 @@ impl
         )];
         let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
         header.append(&mut api_key);
+        if let Some(idempotency_key) = req.request.connector_idempotency_key.clone() {
+            header.push((
+                headers::IDEMPOTENCY_KEY.to_string(),
+                idempotency_key.into_masked(),
+            ));
+        }
         Ok(header)
     }
 
@@ -1479,11 +1479,14 @@ fn create_stripe_payment_method(
 
         payments::PaymentMethodData::Upi(_)
         | payments::PaymentMethodData::MandatePayment
-        | payments::PaymentMethodData::CardToken(_) => Err(errors::ConnectorError::NotSupported {
-            message: connector_util::SELECTED_PAYMENT_METHOD.to_string(),
-            connector: "stripe",
+        | payments::PaymentMethodData::CardToken(_)
+        | payments::PaymentMethodData::NetworkToken(_) => {
+            Err(errors::ConnectorError::NotSupported {
+                message: connector_util::SELECTED_PAYMENT_METHOD.to_string(),
+                connector: "stripe",
+            }
+            .into())
         }
-        .into()),
     }
 }
 
@@ -1804,6 +1807,19 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                         payment_method_type.as_ref(),
                     )?;
 
+                    // Merchant initiated transaction referring to the customer initiated transaction through its network transaction id
+                    if let Some(network_transaction_id) =
+                        item.request.network_transaction_id.clone()
+                    {
+                        payment_method_options = Some(StripePaymentMethodOptions::Card {
+                            mandate_options: None,
+                            network_transaction_id: None,
+                            mit_exemption: Some(MitExemption {
+                                network_transaction_id: Secret::new(network_transaction_id),
+                            }),
+                        });
+                    }
+
                     (
                         Some(payment_method_data),
                         None,
@@ -1842,43 +1858,38 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             .request
             .setup_mandate_details
             .as_ref()
-            .and_then(|mandate_details| {
-                mandate_details
-                    .customer_acceptance
-                    .as_ref()
-                    .map(|customer_acceptance| {
-                        Ok::<_, error_stack::Report<errors::ConnectorError>>(
-                            match customer_acceptance.acceptance_type {
-                                AcceptanceType::Online => {
-                                    let online_mandate = customer_acceptance
-                                        .online
-                                        .clone()
-                                        .get_required_value("online")
+            .and_then(|mandate_details| mandate_details.customer_acceptance.as_ref())
+            .or(item.request.customer_acceptance.as_ref())
+            .map(|customer_acceptance| {
+                Ok::<_, error_stack::Report<errors::ConnectorError>>(
+                    match customer_acceptance.acceptance_type {
+                        AcceptanceType::Online => {
+                            let online_mandate = customer_acceptance
+                                .online
+                                .clone()
+                                .get_required_value("online")
+                                .change_context(errors::ConnectorError::MissingRequiredField {
+                                    field_name: "online",
+                                })?;
+                            StripeMandateRequest {
+                                mandate_type: StripeMandateType::Online {
+                                    ip_address: online_mandate
+                                        .ip_address
+                                        .get_required_value("ip_address")
                                         .change_context(
                                             errors::ConnectorError::MissingRequiredField {
-                                                field_name: "online",
+                                                field_name: "ip_address",
                                             },
-                                        )?;
-                                    StripeMandateRequest {
-                                        mandate_type: StripeMandateType::Online {
-                                            ip_address: online_mandate
-                                                .ip_address
-                                                .get_required_value("ip_address")
-                                                .change_context(
-                                                    errors::ConnectorError::MissingRequiredField {
-                                                        field_name: "ip_address",
-                                                    },
-                                                )?,
-                                            user_agent: online_mandate.user_agent,
-                                        },
-                                    }
-                                }
-                                AcceptanceType::Offline => StripeMandateRequest {
-                                    mandate_type: StripeMandateType::Offline,
+                                        )?,
+                                    user_agent: online_mandate.user_agent,
                                 },
-                            },
-                        )
-                    })
+                            }
+                        }
+                        AcceptanceType::Offline => StripeMandateRequest {
+                            mandate_type: StripeMandateType::Offline,
+                        },
+                    },
+                )
             })
             .transpose()?
             .or_else(|| {
@@ -3033,6 +3044,7 @@ impl TryFrom<&types::PaymentsPreProcessingRouterData> for StripeCreditTransferSo
             | Some(payments::PaymentMethodData::CardRedirect(..))
             | Some(payments::PaymentMethodData::Voucher(..))
             | Some(payments::PaymentMethodData::CardToken(..))
+            | Some(payments::PaymentMethodData::NetworkToken(..))
             | None => Err(errors::ConnectorError::NotImplemented(
                 connector_util::get_unimplemented_payment_method_error_message("stripe"),
             )
@@ -3456,10 +3468,13 @@ impl
             | api::PaymentMethodData::Upi(_)
             | api::PaymentMethodData::CardRedirect(_)
             | api::PaymentMethodData::Voucher(_)
-            | api::PaymentMethodData::CardToken(_) => Err(errors::ConnectorError::NotSupported {
-                message: format!("{pm_type:?}"),
-                connector: "Stripe",
-            })?,
+            | api::PaymentMethodData::CardToken(_)
+            | api::PaymentMethodData::NetworkToken(_) => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: format!("{pm_type:?}"),
+                    connector: "Stripe",
+                })?
+            }
         }
     }
 }
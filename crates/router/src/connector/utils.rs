@@ -390,6 +390,7 @@ pub trait PaymentsAuthorizeRequestData {
     fn get_surcharge_amount(&self) -> Option<i64>;
     fn get_tax_on_surcharge_amount(&self) -> Option<i64>;
     fn get_total_surcharge_amount(&self) -> Option<i64>;
+    fn get_order_tax_amount(&self) -> Option<i64>;
 }
 
 pub trait PaymentMethodTokenizationRequestData {
@@ -518,6 +519,17 @@ impl PaymentsAuthorizeRequestData for types::PaymentsAuthorizeData {
             .map(|surcharge_details| surcharge_details.get_total_surcharge_amount())
     }
 
+    /// Total tax of the products of the order, when the tax breakdown of the products was
+    /// provided. The breakdown is validated to add up to the amount of the payment on confirm.
+    fn get_order_tax_amount(&self) -> Option<i64> {
+        self.order_details.as_ref().and_then(|order_details| {
+            order_details
+                .iter()
+                .filter_map(|order| order.tax_amount)
+                .reduce(|total_tax_amount, tax_amount| total_tax_amount + tax_amount)
+        })
+    }
+
     fn is_customer_initiated_mandate_payment(&self) -> bool {
         self.setup_mandate_details.is_some()
     }
@@ -65,6 +65,28 @@ fn fetch_payment_instrument(
             card_number: card.card_number,
             ..CardPayment::default()
         })),
+        api::PaymentMethodData::NetworkToken(network_token) => {
+            Ok(PaymentInstrument::Card(CardPayment {
+                card_expiry_date: CardExpiryDate {
+                    month: network_token
+                        .token_exp_month
+                        .peek()
+                        .clone()
+                        .parse::<i8>()
+                        .into_report()
+                        .change_context(errors::ConnectorError::RequestEncodingFailed)?,
+                    year: network_token
+                        .token_exp_year
+                        .peek()
+                        .clone()
+                        .parse::<i32>()
+                        .into_report()
+                        .change_context(errors::ConnectorError::RequestEncodingFailed)?,
+                },
+                card_number: network_token.network_token,
+                ..CardPayment::default()
+            }))
+        }
         api::PaymentMethodData::Wallet(wallet) => match wallet {
             api_models::payments::WalletData::GooglePay(data) => {
                 Ok(PaymentInstrument::Googlepay(WalletPayment {
@@ -130,6 +152,24 @@ fn fetch_payment_instrument(
     }
 }
 
+/// The cryptogram of a network token payment is sent as the authentication of the customer
+fn fetch_customer(payment_method: &api::PaymentMethodData) -> Option<Customer> {
+    match payment_method {
+        api::PaymentMethodData::NetworkToken(network_token) => network_token
+            .token_cryptogram
+            .as_ref()
+            .map(|cryptogram| Customer {
+                authentication: Some(CustomerAuthentication::Token(NetworkToken {
+                    auth_type: CustomerAuthType::NetworkToken,
+                    authentication_value: cryptogram.peek().clone(),
+                    eci: network_token.eci.clone(),
+                })),
+                ..Customer::default()
+            }),
+        _ => None,
+    }
+}
+
 impl
     TryFrom<
         &WorldpayRouterData<
@@ -177,7 +217,7 @@ impl
             },
             transaction_reference: item.router_data.connector_request_reference_id.clone(),
             channel: None,
-            customer: None,
+            customer: fetch_customer(&item.router_data.request.payment_method_data),
         })
     }
 }
@@ -5,6 +5,8 @@ pub mod user_role;
 // ID generation
 pub(crate) const ID_LENGTH: usize = 20;
 pub(crate) const MAX_ID_LENGTH: usize = 64;
+/// Maximum length of the order reference passed by the merchant, bounded by the column width
+pub(crate) const MERCHANT_ORDER_REFERENCE_ID_MAX_LENGTH: usize = 255;
 #[rustfmt::skip]
 pub(crate) const ALPHABETS: [char; 62] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
@@ -87,3 +89,59 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
+
+/// Latest database migration version known to this binary, if the migrations were available at build time
+pub const EXPECTED_SCHEMA_VERSION: Option<&str> = option_env!("ROUTER_EXPECTED_SCHEMA_VERSION");
+
+/// Key of the config row written by the database health check, and of the key written by the
+/// Redis health check
+pub const HEALTH_CHECK_TEST_KEY: &str = "test_key";
+
+/// Stream to which the Redis health check appends its probe entry
+pub const HEALTH_CHECK_TEST_STREAM: &str = "test_stream";
+
+/// Channel on which the Redis pub/sub health check publishes its probe message
+pub const HEALTH_CHECK_PUBSUB_CHANNEL: &str = "hyperswitch_health_check";
+
+/// Amount (in the lowest denomination of USD) authorized by the end to end health check probe
+pub const HEALTH_CHECK_END_TO_END_AMOUNT: i64 = 100;
+
+/// Header listing the dependencies which failed the deep health check
+pub const X_UNHEALTHY_DEPENDENCIES: &str = "X-Unhealthy-Dependencies";
+
+/// Number of drainer dead letter entries listed when no limit is given
+pub const DEAD_LETTER_LIST_DEFAULT_LIMIT: u32 = 20;
+
+/// Maximum number of drainer dead letter entries which can be listed at once
+pub const DEAD_LETTER_LIST_MAX_LIMIT: u32 = 100;
+
+/// Media type of the responses encoded as MessagePack
+pub const APPLICATION_MSGPACK: &str = "application/msgpack";
+
+/// Header carrying the id correlating the logs of a payment confirm with the logs of the connector
+pub const X_CORRELATION_ID: &str = "X-Correlation-Id";
+
+/// Key of the config listing the connectors which are currently unhealthy
+pub const UNHEALTHY_CONNECTORS_CONFIG_KEY: &str = "unhealthy_connectors";
+
+/// Prefix of the config enabling the sanitization of statement descriptors for a merchant
+pub const SANITIZE_STATEMENT_DESCRIPTOR_CONFIG_PREFIX: &str = "sanitize_statement_descriptor";
+
+/// Prefix of the config forbidding confirm from reusing the payment token stored on the attempt for a merchant
+pub const EXPLICIT_PAYMENT_METHOD_REQUIRED_CONFIG_PREFIX: &str = "explicit_payment_method_required";
+
+/// Prefix of the config holding the fraud score thresholds of a merchant
+pub const FRM_SCORE_THRESHOLDS_CONFIG_PREFIX: &str = "frm_score_thresholds";
+
+/// Prefix of the config holding the 3DS version policy of a merchant, the JSON string `"hard"` or `"soft"`
+pub const THREE_DS_VERSION_ENFORCEMENT_CONFIG_PREFIX: &str = "three_ds_version_enforcement";
+
+/// Prefix of the config holding the validity (in seconds) of the client secrets of a merchant
+pub const CLIENT_SECRET_EXPIRY_CONFIG_PREFIX: &str = "client_secret_expiry";
+
+/// Prefix of the config overriding the maximum number of attempts per payment of a merchant
+pub const MAX_ATTEMPTS_PER_PAYMENT_CONFIG_PREFIX: &str = "max_attempts_per_payment";
+
+/// Prefix of the config overriding the grace window (in seconds) of the confirm replay of a
+/// merchant
+pub const CONFIRM_REPLAY_GRACE_WINDOW_CONFIG_PREFIX: &str = "confirm_replay_grace_window";
@@ -9,3 +9,46 @@ pub const ROLE_ID_MERCHANT_DEVELOPER: &str = "merchant_developer";
 pub const ROLE_ID_MERCHANT_OPERATOR: &str = "merchant_operator";
 pub const ROLE_ID_MERCHANT_CUSTOMER_SUPPORT: &str = "merchant_customer_support";
 pub const INTERNAL_USER_MERCHANT_ID: &str = "juspay000";
+/// Roles of the users who administer a merchant, of which a merchant always keeps one
+pub const ADMIN_ROLE_IDS: [&str; 2] = [ROLE_ID_MERCHANT_ADMIN, ROLE_ID_ORGANIZATION_ADMIN];
+
+/// Role assigned when resetting a user whose merchant has no default role configured
+pub const ROLE_ID_SYSTEM_DEFAULT: &str = ROLE_ID_MERCHANT_VIEW_ONLY;
+pub const DEFAULT_ROLE_CONFIG_PREFIX: &str = "default_user_role";
+pub const PERMISSION_OVERRIDES_CONFIG_PREFIX: &str = "role_permission_overrides";
+pub const CUSTOM_ROLES_CONFIG_PREFIX: &str = "custom_roles";
+pub const CUSTOM_ROLE_ID_PREFIX: &str = "custom_role";
+pub const PREDEFINED_ROLE_PERMISSIONS_CONFIG_KEY: &str = "predefined_role_permissions";
+/// Number of times a role config of a merchant is read and written again when it was changed
+/// concurrently, before giving up
+pub const ROLE_CONFIG_UPDATE_ATTEMPTS: usize = 3;
+
+pub const USER_ROLE_EXPIRY_RUNNER: &str = "USER_ROLE_EXPIRY_WORKFLOW";
+pub const USER_ROLE_EXPIRY_TAG: &str = "USER_ROLE";
+
+pub const USER_ROLE_WEBHOOK_RETRY_RUNNER: &str = "USER_ROLE_WEBHOOK_RETRY_WORKFLOW";
+pub const USER_ROLE_WEBHOOK_RETRY_TAG: &str = "USER_ROLE";
+
+/// Number of entries in a page of the user role listings when no limit is given
+pub const USER_ROLE_LIST_DEFAULT_LIMIT: u32 = 20;
+/// Maximum number of entries in a page of the user role listings
+pub const USER_ROLE_LIST_MAX_LIMIT: u32 = 100;
+
+pub const ROLE_CHANGE_RATE_LIMIT_PREFIX: &str = "role_change_rate_limit";
+pub const TOKEN_EPOCH_PREFIX: &str = "user_token_epoch";
+pub const USER_ROLE_ACCESS_CACHE_PREFIX: &str = "user_role_access";
+/// Time for which the access of a user to a merchant is cached for the authentication of requests
+pub const USER_ROLE_ACCESS_CACHE_TTL_IN_SECS: i64 = 60 * 5; // 5 minutes
+
+/// Maximum length of the title and department of a user in a merchant
+pub const MAX_USER_ROLE_ATTRIBUTE_LENGTH: usize = 64;
+
+/// Maximum length of the name of a custom role
+pub const MAX_ROLE_NAME_LENGTH: usize = 64;
+
+/// Maximum length of the default landing page in the preferences of a role
+pub const MAX_LANDING_PAGE_LENGTH: usize = 255;
+/// Maximum number of visible widgets in the preferences of a role
+pub const MAX_VISIBLE_WIDGETS: usize = 50;
+/// Maximum length of the name of a widget in the preferences of a role
+pub const MAX_WIDGET_NAME_LENGTH: usize = 64;
@@ -16,9 +16,13 @@ use crate::types::storage::enums;
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
     routes::{metrics, AppState},
-    services::ApplicationResponse,
+    services::{
+        authorization::{self, predefined_permissions},
+        ApplicationResponse,
+    },
     types::{api, storage, transformers::ForeignInto},
     utils,
 };
@@ -173,6 +177,10 @@ pub async fn create_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    if let Some(role_id) = api_key.role_id.as_deref() {
+        validate_api_key_role(store, role_id, &merchant_id).await?;
+    }
+
     let hash_key = get_hash_key(
         api_key_config,
         #[cfg(feature = "kms")]
@@ -192,6 +200,7 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        role_id: api_key.role_id,
     };
 
     let api_key = store
@@ -226,6 +235,25 @@ pub async fn create_api_key(
     ))
 }
 
+/// Validates that the API key can be restricted to the role, which must either be an invitable
+/// predefined role or a custom role of the merchant
+async fn validate_api_key_role(
+    store: &dyn StorageInterface,
+    role_id: &str,
+    merchant_id: &str,
+) -> RouterResult<()> {
+    if predefined_permissions::is_role_invitable(role_id) {
+        return Ok(());
+    }
+
+    let custom_roles = authorization::get_custom_roles(store, merchant_id).await?;
+    utils::when(!custom_roles.contains_key(role_id), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid role_id {role_id}")
+        }))
+    })
+}
+
 // Add api_key_expiry task to the process_tracker table.
 // Construct ProcessTrackerNew struct with all required fields, and schedule the first email.
 // After first email has been sent, update the schedule_time based on retry_count in execute_workflow().
@@ -581,6 +609,7 @@ impl From<storage::HashedApiKey> for HashedApiKey {
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
     use super::*;
+    use crate::db::{configs::ConfigInterface, MockDb};
 
     #[tokio::test]
     async fn test_hashing_and_verification() {
@@ -608,4 +637,50 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    #[tokio::test]
+    async fn test_api_key_role_must_be_invitable_or_a_custom_role_of_the_merchant() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let custom_roles = authorization::CustomRoles::from([(
+            "custom_role".to_string(),
+            authorization::CustomRole {
+                role_name: "Custom Role".to_string(),
+                permissions: vec![authorization::permissions::Permission::PaymentRead],
+            },
+        )]);
+        mockdb
+            .insert_config(storage::ConfigNew {
+                key: authorization::get_custom_roles_key("merchant1"),
+                config: serde_json::to_string(&custom_roles).unwrap(),
+            })
+            .await
+            .unwrap();
+
+        assert!(validate_api_key_role(
+            &mockdb,
+            consts::user_role::ROLE_ID_MERCHANT_DEVELOPER,
+            "merchant1"
+        )
+        .await
+        .is_ok());
+        assert!(validate_api_key_role(&mockdb, "custom_role", "merchant1")
+            .await
+            .is_ok());
+
+        // Roles which cannot be invited are not assignable to API keys either
+        assert!(validate_api_key_role(
+            &mockdb,
+            consts::user_role::ROLE_ID_INTERNAL_ADMIN,
+            "merchant1"
+        )
+        .await
+        .is_err());
+        // The custom roles of another merchant are not assignable
+        assert!(validate_api_key_role(&mockdb, "custom_role", "merchant2")
+            .await
+            .is_err());
+    }
 }
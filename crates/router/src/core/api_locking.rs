@@ -44,6 +44,20 @@ impl LockingInput {
             API_LOCK_PREFIX, merchant_id, self.api_identifier, self.unique_locking_key
         )
     }
+
+    /// Error returned when the lock is still held by another request once the retries are
+    /// exhausted. The payment flows are locked on the payment_id, so that concurrent requests on
+    /// the same payment, such as two confirms, are told that the payment is being processed.
+    fn get_busy_error(&self) -> errors::ApiErrorResponse {
+        match self.api_identifier {
+            lock_utils::ApiIdentifier::Payments => {
+                errors::ApiErrorResponse::PaymentBeingProcessed {
+                    payment_id: self.unique_locking_key.clone(),
+                }
+            }
+            _ => errors::ApiErrorResponse::ResourceBusy,
+        }
+    }
 }
 
 impl LockAction {
@@ -102,7 +116,7 @@ impl LockAction {
                     }
                 }
 
-                Err(errors::ApiErrorResponse::ResourceBusy).into_report()
+                Err(input.get_busy_error()).into_report()
             }
             Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
         }
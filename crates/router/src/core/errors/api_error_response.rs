@@ -122,11 +122,18 @@ pub enum ApiErrorResponse {
     VerificationFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Dispute operation failed while processing with connector. Retry operation")]
     DisputeFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ConnectorError, code = "CE_09", message = "{connector} did not respond within {timeout_in_secs} seconds")]
+    ConnectorTimeout {
+        connector: String,
+        timeout_in_secs: u64,
+    },
 
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
     #[error(error_type = ErrorType::LockTimeout, code = "HE_00", message = "Resource is busy. Please try again later.")]
     ResourceBusy,
+    #[error(error_type = ErrorType::LockTimeout, code = "HE_06", message = "The payment with payment_id {payment_id} is being processed by another request. Please try again later.")]
+    PaymentBeingProcessed { payment_id: String },
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "Duplicate refund request. Refund already attempted with the refund ID")]
     DuplicateRefundRequest,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "Duplicate mandate request. Mandate already attempted with the Mandate ID")]
@@ -142,6 +149,8 @@ pub enum ApiErrorResponse {
     DuplicatePaymentMethod,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment with the specified payment_id already exists in our records")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "A payment with the specified merchant_order_reference_id already exists in our records")]
+    DuplicateOrderReference { payment_id: String },
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payout with the specified payout_id '{payout_id}' already exists in our records")]
     DuplicatePayout { payout_id: String },
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The config with the specified key already exists in our records")]
@@ -240,6 +249,10 @@ pub enum ApiErrorResponse {
     CurrencyNotSupported { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_24", message = "Merchant connector account is configured with invalid {config}")]
     InvalidConnectorConfiguration { config: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_25", message = "The payment has reached the maximum of {max_attempts} attempts")]
+    MaximumRetriesExceeded { max_attempts: i16 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_26", message = "Payment method type {payment_method_type} is not allowed for this payment")]
+    PaymentMethodTypeNotAllowed { payment_method_type: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_01", message = "Failed to convert currency to minor unit")]
     CurrencyConversionFailed,
 }
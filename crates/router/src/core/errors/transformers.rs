@@ -115,6 +115,7 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::DisputeFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 1, "Dispute operation failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()})))
             }
+            Self::ConnectorTimeout { connector, timeout_in_secs } => AER::ConnectorError(ApiError::new("CE", 9, format!("{connector} did not respond within {timeout_in_secs} seconds"), Some(Extra {connector: Some(connector.clone()), ..Default::default()})), StatusCode::GATEWAY_TIMEOUT),
             Self::InvalidCardData { data } => AER::BadRequest(ApiError::new("CE", 4, "The card data is invalid", Some(Extra { data: data.clone(), ..Default::default()}))),
             Self::CardExpired { data } => AER::BadRequest(ApiError::new("CE", 5, "The card has expired", Some(Extra { data: data.clone(), ..Default::default()}))),
             Self::RefundFailed { data } => AER::BadRequest(ApiError::new("CE", 6, "Refund failed while processing with connector. Retry refund", Some(Extra { data: data.clone(), ..Default::default()}))),
@@ -137,6 +138,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::DuplicatePayment { payment_id } => {
                 AER::BadRequest(ApiError::new("HE", 1, "The payment with the specified payment_id already exists in our records", Some(Extra {reason: Some(format!("{payment_id} already exists")), ..Default::default()})))
             }
+            Self::DuplicateOrderReference { payment_id } => {
+                AER::BadRequest(ApiError::new("HE", 1, "A payment with the specified merchant_order_reference_id already exists in our records", Some(Extra {reason: Some(format!("The order reference is already used by the payment {payment_id}")), ..Default::default()})))
+            }
             Self::DuplicatePayout { payout_id } => {
                 AER::BadRequest(ApiError::new("HE", 1, format!("The payout with the specified payout_id '{payout_id}' already exists in our records"), None))
             }
@@ -265,12 +269,21 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ResourceBusy => {
                 AER::Unprocessable(ApiError::new("WE", 5, "There was an issue processing the webhook body", None))
             }
+            Self::PaymentBeingProcessed { payment_id } => {
+                AER::Conflict(ApiError::new("HE", 6, format!("The payment with payment_id {payment_id} is being processed by another request. Please try again later."), None))
+            }
             Self::PaymentLinkNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Payment Link does not exist in our records", None))
             }
             Self::InvalidConnectorConfiguration {config} => {
                 AER::BadRequest(ApiError::new("IR", 24, format!("Merchant connector account is configured with invalid {config}"), None))
             }
+            Self::MaximumRetriesExceeded { max_attempts } => {
+                AER::BadRequest(ApiError::new("IR", 25, format!("The payment has reached the maximum of {max_attempts} attempts"), None))
+            }
+            Self::PaymentMethodTypeNotAllowed { payment_method_type } => {
+                AER::BadRequest(ApiError::new("IR", 26, format!("Payment method type {payment_method_type} is not allowed for this payment"), None))
+            }
             Self::CurrencyConversionFailed => {
                 AER::Unprocessable(ApiError::new("HE", 2, "Failed to convert currency to minor unit", None))
             }
@@ -1,6 +1,6 @@
 use common_utils::errors::CustomResult;
 
-use crate::services::ApplicationResponse;
+use crate::{consts, services::ApplicationResponse};
 
 pub type UserResult<T> = CustomResult<T, UserErrors>;
 pub type UserResponse<T> = CustomResult<ApplicationResponse<T>, UserErrors>;
@@ -54,6 +54,44 @@ pub enum UserErrors {
     MerchantIdParsingError,
     #[error("ChangePasswordError")]
     ChangePasswordError,
+    #[error("UserRoleExpired")]
+    UserRoleExpired,
+    #[error("TooManyRequests")]
+    TooManyRequests { retry_after_in_secs: i64 },
+    #[error("RoleChangeRequestNotFound")]
+    RoleChangeRequestNotFound,
+    #[error("RoleChangeRequestAlreadyPending")]
+    RoleChangeRequestAlreadyPending,
+    #[error("RoleChangeSelfApproval")]
+    RoleChangeSelfApproval,
+    #[error("CannotRemoveLastAdmin")]
+    CannotRemoveLastAdmin,
+    #[error("InvalidUserRoleAttribute")]
+    InvalidUserRoleAttribute,
+    #[error("InvalidRolePreferences")]
+    InvalidRolePreferences,
+    #[error("RolePreferencesNotFound")]
+    RolePreferencesNotFound,
+    #[error("InvalidPermissionOverride")]
+    InvalidPermissionOverride,
+    #[error("PermissionOverrideNotFound")]
+    PermissionOverrideNotFound,
+    #[error("RoleNameAlreadyExists")]
+    RoleNameAlreadyExists,
+    #[error("InvalidRoleName")]
+    InvalidRoleName,
+    #[error("RoleDefinitionsAlreadyExist")]
+    RoleDefinitionsAlreadyExist,
+    #[error("InsufficientPermission")]
+    InsufficientPermission,
+    #[error("UserNotAssociatedWithMerchant")]
+    UserNotAssociatedWithMerchant,
+    #[error("InvalidListCursor")]
+    InvalidListCursor,
+    #[error("InvalidPageSize")]
+    InvalidPageSize,
+    #[error("RoleDefinitionsChanged")]
+    RoleDefinitionsChanged,
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -157,6 +195,119 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 "Old and new password cannot be same",
                 None,
             )),
+            Self::UserRoleExpired => AER::Unauthorized(ApiError::new(
+                sub_code,
+                30,
+                "Access to the merchant has expired",
+                None,
+            )),
+            Self::TooManyRequests {
+                retry_after_in_secs,
+            } => AER::TooManyRequests(ApiError::new(
+                sub_code,
+                31,
+                format!("Too many requests, retry after {retry_after_in_secs} seconds"),
+                None,
+            )),
+            Self::RoleChangeRequestNotFound => AER::NotFound(ApiError::new(
+                sub_code,
+                32,
+                "Role change request not found or expired",
+                None,
+            )),
+            Self::RoleChangeRequestAlreadyPending => AER::Conflict(ApiError::new(
+                sub_code,
+                33,
+                "A role change request is already pending for the user",
+                None,
+            )),
+            Self::RoleChangeSelfApproval => AER::ForbiddenCommonResource(ApiError::new(
+                sub_code,
+                34,
+                "Role change request cannot be approved by the user who requested it",
+                None,
+            )),
+            Self::CannotRemoveLastAdmin => AER::BadRequest(ApiError::new(
+                sub_code,
+                35,
+                "Cannot remove the last admin of the merchant",
+                None,
+            )),
+            Self::InvalidUserRoleAttribute => AER::BadRequest(ApiError::new(
+                sub_code,
+                36,
+                "Title and department must be between 1 and 64 characters long",
+                None,
+            )),
+            Self::InvalidRolePreferences => AER::BadRequest(ApiError::new(
+                sub_code,
+                37,
+                "Invalid default landing page or visible widgets",
+                None,
+            )),
+            Self::RolePreferencesNotFound => AER::NotFound(ApiError::new(
+                sub_code,
+                38,
+                "Role preferences not found",
+                None,
+            )),
+            Self::InvalidPermissionOverride => AER::BadRequest(ApiError::new(
+                sub_code,
+                39,
+                "Invalid permission override for the role",
+                None,
+            )),
+            Self::PermissionOverrideNotFound => AER::NotFound(ApiError::new(
+                sub_code,
+                40,
+                "Permission override not found",
+                None,
+            )),
+            Self::RoleNameAlreadyExists => AER::BadRequest(ApiError::new(
+                sub_code,
+                41,
+                "A role with this name already exists for the merchant",
+                None,
+            )),
+            Self::InvalidRoleName => {
+                AER::BadRequest(ApiError::new(sub_code, 42, "Invalid role name", None))
+            }
+            Self::RoleDefinitionsAlreadyExist => AER::BadRequest(ApiError::new(
+                sub_code,
+                43,
+                "The merchant already has different role definitions, set force to replace them",
+                None,
+            )),
+            Self::InsufficientPermission => AER::ForbiddenCommonResource(ApiError::new(
+                sub_code,
+                44,
+                "You don't have the permission to view the roles",
+                None,
+            )),
+            Self::UserNotAssociatedWithMerchant => AER::BadRequest(ApiError::new(
+                sub_code,
+                45,
+                "User is not associated with the merchant",
+                None,
+            )),
+            Self::InvalidListCursor => {
+                AER::BadRequest(ApiError::new(sub_code, 46, "Invalid list cursor", None))
+            }
+            Self::InvalidPageSize => AER::BadRequest(ApiError::new(
+                sub_code,
+                47,
+                format!(
+                    "Page size must be between 1 and {}",
+                    consts::user_role::USER_ROLE_LIST_MAX_LIMIT
+                ),
+                None,
+            )),
+            Self::RoleDefinitionsChanged => AER::Conflict(ApiError::new(
+                sub_code,
+                48,
+                "The role definitions of the merchant were changed concurrently, please retry",
+                None,
+            )),
         }
     }
 }
@@ -13,11 +13,14 @@ use time::PrimitiveDateTime;
 
 use super::errors::{self, RouterResult, StorageErrorExt};
 use crate::{
+    db::StorageInterface,
     errors::RouterResponse,
     routes::AppState,
     services,
     types::{
-        api::payment_link::PaymentLinkResponseExt, domain, storage::enums as storage_enums,
+        api::payment_link::PaymentLinkResponseExt,
+        domain,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
     },
 };
@@ -110,11 +113,7 @@ pub async fn intiate_payment_link_flow(
         .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)?;
     let order_details = validate_order_details(payment_intent.order_details.clone(), currency)?;
 
-    let session_expiry = payment_link.fulfilment_time.unwrap_or_else(|| {
-        payment_intent
-            .created_at
-            .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
-    });
+    let session_expiry = get_payment_link_expiry(&payment_link, payment_intent.created_at);
 
     // converting first letter of merchant name to upperCase
     let merchant_name = capitalize_first_char(&payment_link_config.seller_name);
@@ -255,6 +254,52 @@ pub async fn list_payment_link(
     Ok(services::ApplicationResponse::Json(payment_link_list))
 }
 
+/// Get the time after which the payment link can no longer be used, defaults to the session
+/// expiry counted from the creation of the payment
+pub fn get_payment_link_expiry(
+    payment_link: &storage::PaymentLink,
+    payment_created_at: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    payment_link.fulfilment_time.unwrap_or_else(|| {
+        payment_created_at.saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
+    })
+}
+
+/// Validate that the payment link used to confirm the payment belongs to the payment and has not
+/// expired, and get the payment link details to be returned in the response
+pub async fn get_payment_link_data_for_confirm(
+    db: &dyn StorageInterface,
+    payment_link_id: &str,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<api_models::payments::PaymentLinkResponse> {
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let belongs_to_payment = payment_link.payment_id == payment_intent.payment_id
+        && payment_link.merchant_id == payment_intent.merchant_id
+        && payment_intent.payment_link_id.as_deref() == Some(payment_link_id);
+    if !belongs_to_payment {
+        return Err(errors::ApiErrorResponse::PaymentLinkNotFound).into_report();
+    }
+
+    let payment_link_expiry = get_payment_link_expiry(&payment_link, payment_intent.created_at);
+    if check_payment_link_status(payment_link_expiry)
+        == api_models::payments::PaymentLinkStatus::Expired
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payment link has expired".to_string(),
+        })
+        .into_report();
+    }
+
+    Ok(api_models::payments::PaymentLinkResponse {
+        link: payment_link.link_to_pay,
+        payment_link_id: payment_link.payment_link_id,
+    })
+}
+
 pub fn check_payment_link_status(
     payment_link_expiry: PrimitiveDateTime,
 ) -> api_models::payments::PaymentLinkStatus {
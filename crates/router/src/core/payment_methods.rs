@@ -78,6 +78,8 @@ impl PaymentMethodRetrieve for Oss {
             pm @ Some(api::PaymentMethodData::Reward) => Ok((pm.to_owned(), None)),
             pm @ Some(api::PaymentMethodData::CardRedirect(_)) => Ok((pm.to_owned(), None)),
             pm @ Some(api::PaymentMethodData::GiftCard(_)) => Ok((pm.to_owned(), None)),
+            // Network tokens are forwarded to the connector as is, they are never stored in the locker
+            pm @ Some(api::PaymentMethodData::NetworkToken(_)) => Ok((pm.to_owned(), None)),
             pm_opt @ Some(pm @ api::PaymentMethodData::BankTransfer(_)) => {
                 let payment_token = helpers::store_payment_method_data_in_vault(
                     state,
@@ -287,6 +287,55 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     Ok(surcharge_metadata)
 }
 
+/// Calculates the surcharge of a payment for the payment method resolved on confirm, when no
+/// surcharge was calculated for it while listing the payment methods
+pub async fn perform_surcharge_decision_management_for_payment(
+    state: &AppState,
+    algorithm_ref: routing::RoutingAlgorithmRef,
+    payment_attempt: &oss_storage::PaymentAttempt,
+    payment_intent: &oss_storage::PaymentIntent,
+    billing_address: Option<Address>,
+    (payment_method, payment_method_type, card_network): (
+        common_enums::PaymentMethod,
+        common_enums::PaymentMethodType,
+        Option<common_enums::CardNetwork>,
+    ),
+) -> ConditionalConfigResult<Option<types::SurchargeDetails>> {
+    let algorithm_id = if let Some(id) = algorithm_ref.surcharge_config_algo_id {
+        id
+    } else {
+        return Ok(None);
+    };
+
+    let key = ensure_algorithm_cached(
+        &*state.store,
+        &payment_attempt.merchant_id,
+        algorithm_ref.timestamp,
+        algorithm_id.as_str(),
+    )
+    .await?;
+    let cached_algo = CONF_CACHE
+        .retrieve(&key)
+        .into_report()
+        .change_context(ConfigError::CacheMiss)
+        .attach_printable("Unable to retrieve cached routing algorithm even after refresh")?;
+    let mut backend_input =
+        make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
+            .change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment_method.payment_method = Some(payment_method);
+    backend_input.payment_method.payment_method_type = Some(payment_method_type);
+    backend_input.payment_method.card_network = card_network;
+
+    let surcharge_output =
+        execute_dsl_and_get_conditional_config(backend_input, &cached_algo.cached_alogorith)?;
+    surcharge_output
+        .surcharge_details
+        .map(|surcharge_details| {
+            get_surcharge_details_from_surcharge_output(surcharge_details, payment_attempt)
+        })
+        .transpose()
+}
+
 fn get_surcharge_details_from_surcharge_output(
     surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
     payment_attempt: &oss_storage::PaymentAttempt,
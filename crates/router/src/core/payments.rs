@@ -17,7 +17,7 @@ use api_models::{self, enums, payments::HeaderPayload};
 use common_utils::{ext_traits::AsyncExt, pii, types::Surcharge};
 use data_models::mandates::MandateData;
 use diesel_models::{ephemeral_key, fraud_check::FraudCheck};
-use error_stack::{IntoReport, ResultExt};
+use error_stack::{report, IntoReport, ResultExt};
 use futures::future::join_all;
 use helpers::ApplePayData;
 use masking::Secret;
@@ -45,6 +45,7 @@ use super::{errors::StorageErrorExt, payment_methods::surcharge_decision_configs
 use crate::core::fraud_check as frm_core;
 use crate::{
     configs::settings::{ApplePayPreDecryptFlow, PaymentMethodTypeTokenFilter},
+    consts,
     core::{
         errors::{self, CustomResult, RouterResponse, RouterResult},
         payment_methods::PaymentMethodRetrieve,
@@ -248,7 +249,7 @@ where
                         call_connector_action,
                         &validate_result,
                         schedule_time,
-                        header_payload,
+                        header_payload.clone(),
                         #[cfg(feature = "frm")]
                         frm_info.as_ref().and_then(|fi| fi.suggested_action),
                         #[cfg(not(feature = "frm"))]
@@ -261,7 +262,8 @@ where
                     external_latency = router_data.external_latency;
                     //add connector http status code metrics
                     add_connector_http_status_code_metrics(connector_http_status_code);
-                    operation
+                    let connector_timed_out = is_connector_timeout(&router_data);
+                    let payment_data = operation
                         .to_post_update_tracker()?
                         .update_tracker(
                             state,
@@ -270,7 +272,9 @@ where
                             router_data,
                             merchant_account.storage_scheme,
                         )
-                        .await?
+                        .await?;
+                    validate_connector_timeout(&payment_data, connector_timed_out)?;
+                    payment_data
                 }
 
                 api::ConnectorCallType::Retryable(connectors) => {
@@ -303,7 +307,7 @@ where
                         call_connector_action,
                         &validate_result,
                         schedule_time,
-                        header_payload,
+                        header_payload.clone(),
                         #[cfg(feature = "frm")]
                         frm_info.as_ref().and_then(|fi| fi.suggested_action),
                         #[cfg(not(feature = "frm"))]
@@ -349,7 +353,8 @@ where
                     external_latency = router_data.external_latency;
                     //add connector http status code metrics
                     add_connector_http_status_code_metrics(connector_http_status_code);
-                    operation
+                    let connector_timed_out = is_connector_timeout(&router_data);
+                    let payment_data = operation
                         .to_post_update_tracker()?
                         .update_tracker(
                             state,
@@ -358,7 +363,9 @@ where
                             router_data,
                             merchant_account.storage_scheme,
                         )
-                        .await?
+                        .await?;
+                    validate_connector_timeout(&payment_data, connector_timed_out)?;
+                    payment_data
                 }
 
                 api::ConnectorCallType::SessionMultiple(connectors) => {
@@ -561,7 +568,11 @@ where
             .await
             {
                 Ok(surcharge_details) => Some(surcharge_details),
-                Err(err) if err.current_context() == &RedisError::NotFound => None,
+                // the surcharge calculated on confirm is used when none was calculated while
+                // listing the payment methods
+                Err(err) if err.current_context() == &RedisError::NotFound => {
+                    payment_data.surcharge_details.clone()
+                }
                 Err(err) => {
                     Err(err).change_context(errors::ApiErrorResponse::InternalServerError)?
                 }
@@ -579,7 +590,8 @@ where
                         &payment_data.payment_attempt,
                     ))
                 });
-        payment_data.surcharge_details = surcharge_details;
+        payment_data.surcharge_details =
+            surcharge_details.or(payment_data.surcharge_details.take());
     }
     Ok(())
 }
@@ -691,6 +703,7 @@ where
             .flat_map(|c| c.foreign_try_into())
             .collect()
     });
+    let x_hs_latency = header_payload.x_hs_latency;
     let (payment_data, req, customer, connector_http_status_code, external_latency) =
         payments_operation_core::<_, _, _, _, Ctx>(
             &state,
@@ -715,7 +728,7 @@ where
         &state.conf.connector_request_reference_id_config,
         connector_http_status_code,
         external_latency,
-        header_payload.x_hs_latency,
+        x_hs_latency,
     )
 }
 
@@ -1086,20 +1099,49 @@ where
     )
     .await?;
 
-    let mut router_data = payment_data
-        .construct_router_data(
+    // The wallet session token, if it has to be prefetched, is fetched concurrently with the
+    // construction of the router data and the access token call for the connector
+    let router_data_and_access_token = async {
+        let router_data = payment_data
+            .construct_router_data(
+                state,
+                connector.connector.id(),
+                merchant_account,
+                key_store,
+                customer,
+                &merchant_connector_account,
+            )
+            .await?;
+
+        let add_access_token_result = router_data
+            .add_access_token(state, &connector, merchant_account)
+            .await?;
+
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>((
+            router_data,
+            add_access_token_result,
+        ))
+    };
+
+    let (router_data_and_access_token, prefetched_session_token) = tokio::join!(
+        router_data_and_access_token,
+        prefetch_wallet_session_token(
             state,
-            connector.connector.id(),
             merchant_account,
             key_store,
+            &connector,
+            operation,
+            payment_data,
             customer,
             &merchant_connector_account,
         )
-        .await?;
+    );
 
-    let add_access_token_result = router_data
-        .add_access_token(state, &connector, merchant_account)
-        .await?;
+    let (mut router_data, add_access_token_result) = router_data_and_access_token?;
+
+    if let Some(session_token) = prefetched_session_token {
+        payment_data.sessions_token.push(session_token);
+    }
 
     let mut should_continue_further = access_token::update_router_data_with_access_token_result(
         &add_access_token_result,
@@ -1166,7 +1208,7 @@ where
 
     // In case of authorize flow, pre-task and post-tasks are being called in build request
     // if we do not want to proceed further, then the function will return Ok(None, false)
-    let (connector_request, should_continue_further) = if should_continue_further {
+    let (mut connector_request, should_continue_further) = if should_continue_further {
         // Check if the actual flow specific request can be built with available data
         router_data
             .build_flow_specific_connector_request(state, &connector, call_connector_action.clone())
@@ -1175,6 +1217,17 @@ where
         (None, false)
     };
 
+    // The authorization request is built in the previous step, it is sent with the timeout
+    // configured for the connector
+    if let Some(request) = connector_request.as_mut() {
+        let timeout_in_secs = state
+            .conf
+            .connector_timeouts
+            .get_timeout_in_secs(&connector.connector_name.to_string());
+        request.set_timeout_in_secs(Some(timeout_in_secs));
+        payment_data.connector_timeout_in_secs = Some(timeout_in_secs);
+    }
+
     if should_add_task_to_process_tracker(payment_data) {
         operation
             .to_domain()?
@@ -1234,6 +1287,35 @@ where
     router_data_res
 }
 
+fn is_connector_timeout<F, Req>(
+    router_data: &router_types::RouterData<F, Req, router_types::PaymentsResponseData>,
+) -> bool {
+    router_data.response.as_ref().err().map_or(false, |error| {
+        error.code == consts::REQUEST_TIMEOUT_ERROR_CODE
+    })
+}
+
+/// Fails the payment with a timeout error once the trackers are updated, when the connector did
+/// not respond to the authorization request within the timeout configured for it
+fn validate_connector_timeout<F: Clone>(
+    payment_data: &PaymentData<F>,
+    connector_timed_out: bool,
+) -> RouterResult<()> {
+    match payment_data.connector_timeout_in_secs {
+        Some(timeout_in_secs) if connector_timed_out => {
+            Err(report!(errors::ApiErrorResponse::ConnectorTimeout {
+                connector: payment_data
+                    .payment_attempt
+                    .connector
+                    .clone()
+                    .unwrap_or_default(),
+                timeout_in_secs,
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn call_multiple_connectors_service<F, Op, Req, Ctx>(
     state: &AppState,
@@ -1354,6 +1436,9 @@ where
     Ok(payment_data)
 }
 
+/// Creates the customer at the connector decided for the payment, before the router data of the
+/// connector call is constructed, if the connector requires a customer and none was created there
+/// for the customer yet. Otherwise the customer already created at the connector is reused
 pub async fn call_create_connector_customer_if_required<F, Req>(
     state: &AppState,
     customer: &Option<domain::Customer>,
@@ -1584,6 +1669,132 @@ pub fn is_preprocessing_required_for_wallets(connector_name: String) -> bool {
     connector_name == *"trustpay" || connector_name == *"payme"
 }
 
+/// Fetches the session token of the connector for Apple Pay and Google Pay payments during
+/// confirm, so that the client does not have to make a separate session call.
+/// Any failure is logged and `None` is returned, in which case the session token has to be
+/// obtained through the separate session call.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn prefetch_wallet_session_token<F, Req, Ctx>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector: &api::ConnectorData,
+    operation: &BoxedOperation<'_, F, Req, Ctx>,
+    payment_data: &PaymentData<F>,
+    customer: &Option<domain::Customer>,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+) -> Option<api_models::payments::SessionToken>
+where
+    F: Send + Clone + Sync,
+    Ctx: PaymentMethodRetrieve,
+{
+    let payment_method_type = match (
+        payment_data.payment_method_data.as_ref(),
+        payment_data.payment_attempt.payment_method_type,
+    ) {
+        (
+            Some(api_models::payments::PaymentMethodData::Wallet(_)),
+            Some(
+                payment_method_type @ (storage_enums::PaymentMethodType::ApplePay
+                | storage_enums::PaymentMethodType::GooglePay),
+            ),
+        ) => payment_method_type,
+        _ => return None,
+    };
+
+    let is_prefetch_enabled = state
+        .conf
+        .session_token_prefetch
+        .connectors_with_session_token_prefetch
+        .contains(&connector.connector_name);
+
+    // Connectors which require preprocessing for wallets return the session token in the
+    // preprocessing response
+    if !is_prefetch_enabled
+        || !payment_data.sessions_token.is_empty()
+        || !is_operation_confirm(operation)
+        || is_preprocessing_required_for_wallets(connector.connector_name.to_string())
+    {
+        return None;
+    }
+
+    let session_connector = api::ConnectorData {
+        get_token: api::GetToken::from(payment_method_type),
+        ..connector.clone()
+    };
+
+    let session_router_data =
+        match transformers::construct_payment_router_data::<F, router_types::PaymentsSessionData>(
+            state,
+            payment_data.clone(),
+            connector.connector.id(),
+            merchant_account,
+            key_store,
+            customer,
+            merchant_connector_account,
+        )
+        .await
+        {
+            Ok(router_data) => {
+                let request = router_data.request.clone();
+                let response = router_data.response.clone();
+                helpers::router_data_type_conversion::<_, api::Session, _, _, _, _>(
+                    router_data,
+                    request,
+                    response,
+                )
+            }
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    "Failed to construct the router data for prefetching the session token"
+                );
+                return None;
+            }
+        };
+
+    match session_router_data
+        .decide_flows(
+            state,
+            &session_connector,
+            customer,
+            CallConnectorAction::Trigger,
+            merchant_account,
+            None,
+            key_store,
+        )
+        .await
+    {
+        Ok(router_data) => match router_data.response {
+            Ok(router_types::PaymentsResponseData::SessionResponse { session_token })
+                if !matches!(
+                    session_token,
+                    api_models::payments::SessionToken::NoSessionTokenReceived
+                ) =>
+            {
+                Some(session_token)
+            }
+            response => {
+                logger::info!(
+                    ?response,
+                    "Session token was not received from {} during confirm",
+                    connector.connector_name
+                );
+                None
+            }
+        },
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to prefetch the session token from {}",
+                connector.connector_name
+            );
+            None
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn construct_profile_id_and_get_mca<'a, F>(
     state: &'a AppState,
@@ -2032,6 +2243,18 @@ where
     pub incremental_authorization_details: Option<IncrementalAuthorizationDetails>,
     pub authorizations: Vec<diesel_models::authorization::Authorization>,
     pub frm_metadata: Option<serde_json::Value>,
+    pub connector_selection_source: Option<api_models::enums::ConnectorSelectionSource>,
+    pub amount_change: Option<AmountChangeDetails>,
+    /// Timeout of the authorization request resolved for the connector of the payment
+    pub connector_timeout_in_secs: Option<u64>,
+    /// Saved payment method of the customer with which the payment is confirmed
+    pub payment_method_id: Option<String>,
+    /// Whether confirming the payment created a new attempt, set only on confirm
+    pub is_new_attempt: Option<bool>,
+    /// Non-fatal issues detected while confirming the payment, reported in the confirm response
+    pub warnings: Vec<api_models::payments::PaymentWarning>,
+    /// Version of 3DS through which the customer is authenticated, set only on confirm
+    pub preferred_3ds_version: Option<api_models::enums::ThreeDsVersion>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -2042,6 +2265,13 @@ pub struct IncrementalAuthorizationDetails {
     pub authorization_id: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct AmountChangeDetails {
+    pub amount: i64,
+    pub original_amount: i64,
+    pub requires_reauthorization: bool,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct RecurringMandatePaymentData {
     pub payment_method_type: Option<storage_enums::PaymentMethodType>, //required for making recurring payment using saved payment method through stripe
@@ -2138,7 +2368,9 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
             ))
         }
         "CompleteAuthorize" => true,
-        "PaymentApprove" => true,
+        "PaymentApprove" => {
+            helpers::should_capture_on_approval(payment_data.payment_attempt.capture_method)
+        }
         "PaymentReject" => true,
         "PaymentSession" => true,
         "PaymentIncrementalAuthorization" => matches!(
@@ -2313,6 +2545,275 @@ pub async fn get_filters_for_payments(
     ))
 }
 
+/// Resolves the connector a payment would be confirmed through with the given payment method, and
+/// returns the fields the connector requires to confirm it. The connector is resolved with the
+/// routing run on confirm, and nothing is persisted.
+pub async fn payments_confirm_preflight(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api_models::payments::PaymentsConfirmPreflightRequest,
+) -> RouterResponse<api_models::payments::PaymentsConfirmPreflightResponse> {
+    let retrieve_request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(req.payment_id.clone()),
+        client_secret: req.client_secret.clone(),
+        ..Default::default()
+    };
+
+    let operations::GetTrackerResponse {
+        mut payment_data,
+        business_profile,
+        ..
+    } = operations::GetTracker::<
+        api::Authorize,
+        PaymentData<api::Authorize>,
+        api::PaymentsRetrieveRequest,
+        crate::core::payment_methods::Oss,
+    >::get_trackers(
+        &PaymentStatus,
+        &state,
+        &retrieve_request.resource_id,
+        &retrieve_request,
+        None,
+        &merchant_account,
+        &key_store,
+        services::AuthFlow::Merchant,
+    )
+    .await?;
+
+    let client_secret_expiry =
+        helpers::get_client_secret_expiry(&*state.store, &merchant_account.merchant_id).await;
+    helpers::authenticate_client_secret(
+        req.client_secret.as_ref(),
+        &payment_data.payment_intent,
+        client_secret_expiry,
+    )?;
+    helpers::validate_payment_status_against_not_allowed_statuses(
+        &payment_data.payment_intent.status,
+        &[
+            storage_enums::IntentStatus::Cancelled,
+            storage_enums::IntentStatus::Succeeded,
+            storage_enums::IntentStatus::Processing,
+            storage_enums::IntentStatus::RequiresCapture,
+            storage_enums::IntentStatus::RequiresMerchantAction,
+        ],
+        "confirm",
+    )?;
+
+    payment_data.payment_attempt.payment_method = Some(req.payment_method);
+    payment_data.payment_attempt.payment_method_type = Some(req.payment_method_type);
+
+    let connector_data = match connector_selection(
+        &state,
+        &merchant_account,
+        &business_profile,
+        &key_store,
+        &mut payment_data,
+        None,
+        None,
+    )
+    .await?
+    {
+        ConnectorCallType::PreDetermined(connector_data) => Some(connector_data),
+        ConnectorCallType::Retryable(connectors) => connectors.into_iter().next(),
+        ConnectorCallType::SessionMultiple(_) => None,
+    };
+
+    let is_mandate_payment = payment_data.payment_attempt.mandate_details.is_some()
+        || payment_data.payment_intent.setup_future_usage
+            == Some(storage_enums::FutureUsage::OffSession);
+
+    let connector_required_fields = connector_data.as_ref().and_then(|connector_data| {
+        state
+            .conf
+            .required_fields
+            .0
+            .get(&req.payment_method)
+            .and_then(|payment_method_types| payment_method_types.0.get(&req.payment_method_type))
+            .and_then(|connector_fields| {
+                connector_fields.fields.get(&connector_data.connector_name)
+            })
+    });
+
+    let mut required_fields = Vec::new();
+    let mut optional_fields = Vec::new();
+    if let Some(connector_required_fields) = connector_required_fields {
+        required_fields.extend(connector_required_fields.common.values().cloned());
+        if is_mandate_payment {
+            required_fields.extend(connector_required_fields.mandate.values().cloned());
+        } else {
+            required_fields.extend(connector_required_fields.non_mandate.values().cloned());
+            optional_fields.extend(
+                connector_required_fields
+                    .mandate
+                    .iter()
+                    .filter(|(field, _)| {
+                        !connector_required_fields.common.contains_key(*field)
+                            && !connector_required_fields.non_mandate.contains_key(*field)
+                    })
+                    .map(|(_, field_info)| field_info.clone()),
+            );
+        }
+    }
+    required_fields.sort_by(|a, b| a.required_field.cmp(&b.required_field));
+    optional_fields.sort_by(|a, b| a.required_field.cmp(&b.required_field));
+
+    let required_browser_info_fields = match (
+        &connector_data,
+        payment_data.payment_attempt.authentication_type,
+    ) {
+        (Some(connector_data), Some(storage_enums::AuthenticationType::ThreeDs)) => {
+            helpers::get_browser_info_fields_required_by_connector(
+                &state,
+                &connector_data.connector_name.to_string(),
+            )
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect()
+        }
+        _ => Vec::new(),
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::PaymentsConfirmPreflightResponse {
+            payment_id: payment_data.payment_intent.payment_id,
+            connector: connector_data
+                .map(|connector_data| connector_data.connector_name.to_string()),
+            required_fields,
+            optional_fields,
+            required_browser_info_fields,
+            mandate_data_required: is_mandate_payment
+                && payment_data.payment_attempt.mandate_details.is_none(),
+        },
+    ))
+}
+
+/// Authorizes a payment of the test merchant with a test card and voids it, to check that payments
+/// can be processed end to end. Only meant to be run by the deep health check, against a merchant
+/// whose connectors are in sandbox.
+#[instrument(skip_all)]
+pub async fn health_check_end_to_end(
+    state: &AppState,
+    merchant_id: &str,
+    connector: Option<api_models::enums::Connector>,
+    card_number: &str,
+) -> CustomResult<(), errors::HealthCheckEndToEndError> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .change_context(errors::HealthCheckEndToEndError::MerchantNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .change_context(errors::HealthCheckEndToEndError::MerchantNotFound)?;
+
+    let card = api::Card {
+        card_number: card_number
+            .parse()
+            .into_report()
+            .change_context(errors::HealthCheckEndToEndError::AuthorizationFailed)
+            .attach_printable("Invalid end to end probe card number")?,
+        card_exp_month: Secret::new("12".to_string()),
+        card_exp_year: Secret::new((common_utils::date_time::now().year() + 1).to_string()),
+        card_holder_name: Some(Secret::new("Health Check".to_string())),
+        card_cvc: Secret::new("123".to_string()),
+        card_issuer: None,
+        card_network: None,
+        card_type: None,
+        card_issuing_country: None,
+        bank_code: None,
+        nick_name: None,
+    };
+    let payment_request = api::PaymentsRequest {
+        amount: Some(api::Amount::from(consts::HEALTH_CHECK_END_TO_END_AMOUNT)),
+        currency: Some(storage_enums::Currency::USD),
+        capture_method: Some(storage_enums::CaptureMethod::Manual),
+        confirm: Some(true),
+        payment_method: Some(storage_enums::PaymentMethod::Card),
+        payment_method_data: Some(api_models::payments::PaymentMethodData::Card(card)),
+        description: Some("End to end health check".to_string()),
+        ..Default::default()
+    };
+
+    let authorize_response = payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        crate::core::payment_methods::Oss,
+    >(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        connector.map(|connector| vec![connector]),
+        HeaderPayload::default(),
+    )
+    .await
+    .change_context(errors::HealthCheckEndToEndError::AuthorizationFailed)?;
+    let payment = match authorize_response {
+        services::ApplicationResponse::Json(payment)
+        | services::ApplicationResponse::JsonWithHeaders((payment, _)) => payment,
+        _ => Err(errors::HealthCheckEndToEndError::AuthorizationFailed)
+            .into_report()
+            .attach_printable("Unexpected response to the end to end probe authorization")?,
+    };
+    if payment.status != storage_enums::IntentStatus::RequiresCapture {
+        Err(errors::HealthCheckEndToEndError::AuthorizationFailed)
+            .into_report()
+            .attach_printable(format!(
+                "End to end probe authorization ended with status {}",
+                payment.status
+            ))?
+    }
+    let payment_id = payment
+        .payment_id
+        .ok_or(errors::HealthCheckEndToEndError::AuthorizationFailed)
+        .into_report()
+        .attach_printable("Missing payment_id in the end to end probe authorization")?;
+
+    let void_response = payments_core::<
+        api::Void,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        crate::core::payment_methods::Oss,
+    >(
+        state.clone(),
+        merchant_account,
+        key_store,
+        PaymentCancel,
+        api::PaymentsCancelRequest {
+            payment_id,
+            cancellation_reason: Some("End to end health check".to_string()),
+            merchant_connector_details: None,
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    )
+    .await
+    .change_context(errors::HealthCheckEndToEndError::VoidFailed)?;
+    match void_response {
+        services::ApplicationResponse::Json(payment)
+        | services::ApplicationResponse::JsonWithHeaders((payment, _))
+            if payment.status == storage_enums::IntentStatus::Cancelled =>
+        {
+            Ok(())
+        }
+        _ => Err(errors::HealthCheckEndToEndError::VoidFailed)
+            .into_report()
+            .attach_printable("End to end probe payment was not voided"),
+    }
+}
+
 pub async fn add_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -2562,6 +3063,8 @@ where
         .attach_printable("Invalid connector name received in 'routed_through'")?;
 
         routing_data.routed_through = Some(connector_name.clone());
+        payment_data.connector_selection_source =
+            Some(api_models::enums::ConnectorSelectionSource::PriorAttempt);
         return Ok(api::ConnectorCallType::PreDetermined(connector_data));
     }
 
@@ -2584,6 +3087,8 @@ where
             routing_data.merchant_connector_id =
                 mandate_connector_details.merchant_connector_id.clone();
         }
+        payment_data.connector_selection_source =
+            Some(api_models::enums::ConnectorSelectionSource::PriorAttempt);
         return Ok(api::ConnectorCallType::PreDetermined(connector_data));
     }
 
@@ -2615,6 +3120,8 @@ where
             {
                 routing_data.business_sub_label = choice.sub_label.clone();
             }
+            payment_data.connector_selection_source =
+                Some(api_models::enums::ConnectorSelectionSource::DefaultAlgorithm);
             return Ok(api::ConnectorCallType::PreDetermined(connector_data));
         }
     }
@@ -2641,6 +3148,13 @@ where
             .attach_printable("failed eligibility analysis and fallback")?;
         }
 
+        helpers::prioritize_healthy_connector(
+            &state,
+            &mut connectors,
+            &mut payment_data.payment_attempt,
+        )
+        .await;
+
         let first_connector_choice = connectors
             .first()
             .ok_or(errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration)
@@ -2675,6 +3189,8 @@ where
             routing_data.business_sub_label = first_connector_choice.sub_label.clone();
         }
         routing_data.routing_info.algorithm = Some(routing_algorithm);
+        payment_data.connector_selection_source =
+            Some(api_models::enums::ConnectorSelectionSource::ExplicitRouting);
         return Ok(api::ConnectorCallType::Retryable(connector_data));
     }
 
@@ -2700,6 +3216,13 @@ where
             .attach_printable("failed eligibility analysis and fallback")?;
         }
 
+        helpers::prioritize_healthy_connector(
+            &state,
+            &mut connectors,
+            &mut payment_data.payment_attempt,
+        )
+        .await;
+
         let first_connector_choice = connectors
             .first()
             .ok_or(errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration)
@@ -2733,9 +3256,13 @@ where
         {
             routing_data.business_sub_label = first_connector_choice.sub_label;
         }
+        payment_data.connector_selection_source =
+            Some(api_models::enums::ConnectorSelectionSource::ExplicitRouting);
         return Ok(api::ConnectorCallType::Retryable(connector_data));
     }
 
+    payment_data.connector_selection_source =
+        Some(api_models::enums::ConnectorSelectionSource::DefaultAlgorithm);
     route_connector_v1(
         &state,
         merchant_account,
@@ -2906,7 +3433,7 @@ where
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
-    let connectors = routing::perform_eligibility_analysis_with_fallback(
+    let mut connectors = routing::perform_eligibility_analysis_with_fallback(
         &state.clone(),
         key_store,
         merchant_account.modified_at.assume_utc().unix_timestamp(),
@@ -2920,6 +3447,13 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    helpers::prioritize_healthy_connector(
+        state,
+        &mut connectors,
+        &mut payment_data.payment_attempt,
+    )
+    .await;
+
     let first_connector_choice = connectors
         .first()
         .ok_or(errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration)
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, str::FromStr};
 
 use api_models::payments::{CardToken, GetPaymentMethodType, RequestSurchargeDetails};
 use base64::Engine;
@@ -35,12 +35,15 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::{ConnectorRequestReferenceIdConfig, Server, TempLockerEnableConfig},
+    configs::settings::{
+        self, ConnectorRequestReferenceIdConfig, PaymentPayloadLimits, Server,
+        TempLockerEnableConfig,
+    },
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payment_methods::{cards, vault, PaymentMethodRetrieve},
+        payment_methods::{cards, surcharge_decision_configs, vault, PaymentMethodRetrieve},
         payments,
     },
     db::StorageInterface,
@@ -459,6 +462,88 @@ pub async fn get_token_pm_type_mandate_details(
     }
 }
 
+/// Validates the acceptance of the customer on confirm. The acceptance is required when the payment
+/// sets up a mandate, and an online acceptance must carry the IP address and the user agent of the
+/// customer, which the connectors forward as the proof of the consent
+pub fn validate_customer_acceptance(
+    customer_acceptance: Option<&api::CustomerAcceptance>,
+    setup_future_usage: Option<storage_enums::FutureUsage>,
+    is_recurring_mandate_payment: bool,
+) -> RouterResult<()> {
+    let is_mandate_setup = setup_future_usage == Some(storage_enums::FutureUsage::OffSession)
+        && !is_recurring_mandate_payment;
+
+    match customer_acceptance {
+        None if is_mandate_setup => Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "customer_acceptance"
+        })),
+        None => Ok(()),
+        Some(customer_acceptance) => match customer_acceptance.acceptance_type {
+            api::AcceptanceType::Online => {
+                let online_mandate = customer_acceptance
+                    .online
+                    .as_ref()
+                    .get_required_value("customer_acceptance.online")?;
+                utils::when(online_mandate.ip_address.is_none(), || {
+                    Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "customer_acceptance.online.ip_address"
+                    }))
+                })?;
+                utils::when(online_mandate.user_agent.is_empty(), || {
+                    Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "customer_acceptance.online.user_agent"
+                    }))
+                })
+            }
+            api::AcceptanceType::Offline => Ok(()),
+        },
+    }
+}
+
+/// Validates the mandate details resolved for the payment, a recurring mandate payment carries both
+/// the payment method data and the connector of the mandate, and cannot set up another mandate
+pub fn validate_mandate_details_consistency(
+    payment_method: Option<storage_enums::PaymentMethod>,
+    setup_mandate: Option<&MandateData>,
+    recurring_mandate_payment_data: Option<&payments::RecurringMandatePaymentData>,
+    mandate_connector: Option<&payments::MandateConnectorDetails>,
+) -> RouterResult<()> {
+    let is_recurring_mandate_payment =
+        recurring_mandate_payment_data.is_some() || mandate_connector.is_some();
+
+    utils::when(
+        setup_mandate.is_some() && is_recurring_mandate_payment,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A payment made with an existing mandate cannot set up a new mandate"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        recurring_mandate_payment_data.is_some() != mandate_connector.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+                format!(
+                    "Inconsistent mandate details, recurring mandate payment data present: {}, mandate connector present: {}",
+                    recurring_mandate_payment_data.is_some(),
+                    mandate_connector.is_some()
+                ),
+            )
+        },
+    )?;
+
+    utils::when(
+        is_recurring_mandate_payment && payment_method.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+                "Payment method of the mandate is missing from the mandate details",
+            )
+        },
+    )
+}
+
 pub async fn get_token_for_recurring_mandate(
     state: &AppState,
     req: &api::PaymentsRequest,
@@ -682,48 +767,158 @@ pub fn validate_card_data(
             },
         )?;
 
-        let exp_month = card
-            .card_exp_month
-            .peek()
-            .to_string()
-            .parse::<u8>()
-            .into_report()
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "card_exp_month",
-            })?;
-        let month = ::cards::CardExpirationMonth::try_from(exp_month).change_context(
-            errors::ApiErrorResponse::PreconditionFailed {
-                message: "Invalid Expiry Month".to_string(),
-            },
-        )?;
-        let mut year_str = card.card_exp_year.peek().to_string();
-        if year_str.len() == 2 {
-            year_str = format!("20{}", year_str);
-        }
-        let exp_year = year_str.parse::<u16>().into_report().change_context(
-            errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "card_exp_year",
-            },
-        )?;
-        let year = ::cards::CardExpirationYear::try_from(exp_year).change_context(
-            errors::ApiErrorResponse::PreconditionFailed {
-                message: "Invalid Expiry Year".to_string(),
-            },
-        )?;
+        validate_card_expiry(&card.card_exp_month, &card.card_exp_year)?;
+    }
+    Ok(())
+}
 
-        let card_expiration = ::cards::CardExpiration { month, year };
-        let is_expired = card_expiration.is_expired().change_context(
-            errors::ApiErrorResponse::PreconditionFailed {
-                message: "Invalid card data".to_string(),
+#[instrument(skip_all)]
+pub fn validate_card_expiry(
+    card_exp_month: &masking::Secret<String>,
+    card_exp_year: &masking::Secret<String>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let exp_month = card_exp_month
+        .peek()
+        .to_string()
+        .parse::<u8>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_exp_month",
+        })?;
+    let month = ::cards::CardExpirationMonth::try_from(exp_month).change_context(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Invalid Expiry Month".to_string(),
+        },
+    )?;
+    let mut year_str = card_exp_year.peek().to_string();
+    if year_str.len() == 2 {
+        year_str = format!("20{}", year_str);
+    }
+    let exp_year = year_str.parse::<u16>().into_report().change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_exp_year",
+        },
+    )?;
+    let year = ::cards::CardExpirationYear::try_from(exp_year).change_context(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Invalid Expiry Year".to_string(),
+        },
+    )?;
+
+    let card_expiration = ::cards::CardExpiration { month, year };
+    let is_expired = card_expiration.is_expired().change_context(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Invalid card data".to_string(),
+        },
+    )?;
+    if is_expired {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Card Expired".to_string()
+        }))?
+    }
+    Ok(())
+}
+
+/// The token number itself is validated while deserializing, the cryptogram is required since
+/// the token cannot be authorized without it
+pub fn validate_network_token_data(
+    network_token_data: &api_models::payments::NetworkTokenData,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let is_cryptogram_missing = network_token_data
+        .token_cryptogram
+        .as_ref()
+        .map_or(true, |cryptogram| cryptogram.peek().trim().is_empty());
+    utils::when(is_cryptogram_missing, || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payment_method_data.network_token.token_cryptogram",
+        }))
+    })?;
+
+    if let Some(eci) = network_token_data.eci.as_ref() {
+        utils::when(
+            eci.len() != 2 || !eci.chars().all(|c| c.is_ascii_digit()),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_method_data.network_token.eci",
+                }))
             },
         )?;
-        if is_expired {
-            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
-                message: "Card Expired".to_string()
-            }))?
-        }
     }
-    Ok(())
+
+    validate_card_expiry(
+        &network_token_data.token_exp_month,
+        &network_token_data.token_exp_year,
+    )
+}
+
+/// A zero amount payment only verifies the payment method for future usage, which is carried out
+/// through the setup mandate flow of the connector instead of an authorization
+pub fn validate_zero_amount_payment(
+    amount: api::Amount,
+    setup_future_usage: Option<api_enums::FutureUsage>,
+    payment_type: Option<api_enums::PaymentType>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if amount != api::Amount::Zero {
+        return Ok(());
+    }
+
+    utils::when(setup_future_usage.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount can be zero only when setup_future_usage is set, to verify the payment method"
+                .to_string(),
+        }))
+    })?;
+
+    utils::when(
+        payment_type != Some(api_enums::PaymentType::SetupMandate),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payment_type must be setup_mandate for zero amount payments".to_string(),
+            }))
+        },
+    )
+}
+
+/// Detects whether confirm changes the amount of an existing attempt. The amount of an attempt
+/// which was already authorized cannot be changed, while an attempt which was sent to the connector
+/// before needs a new authorization for the changed amount
+pub fn get_amount_change_on_confirm(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    request_amount: Option<api::Amount>,
+) -> RouterResult<Option<payments::AmountChangeDetails>> {
+    let amount = match request_amount.map(i64::from) {
+        Some(amount) if amount != payment_attempt.amount => amount,
+        _ => return Ok(None),
+    };
+
+    let is_past_authorization = matches!(
+        payment_attempt.status,
+        storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::PartialChargedAndChargeable
+            | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::VoidInitiated
+            | storage_enums::AttemptStatus::Voided
+            | storage_enums::AttemptStatus::VoidFailed
+            | storage_enums::AttemptStatus::AutoRefunded
+    );
+    utils::when(is_past_authorization, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "amount cannot be changed once the payment attempt is {}",
+                payment_attempt.status
+            ),
+        }))
+    })?;
+
+    Ok(Some(payments::AmountChangeDetails {
+        amount,
+        original_amount: payment_intent.amount,
+        requires_reauthorization: payment_attempt.connector_transaction_id.is_some(),
+    }))
 }
 
 pub fn infer_payment_type(
@@ -1560,6 +1755,53 @@ pub async fn retrieve_card_with_permanent_token(
     Ok(api::PaymentMethodData::Card(api_card))
 }
 
+/// Resolves a saved payment method referenced by its id into the token data used to retrieve it,
+/// after checking that it was saved for the customer of the payment
+pub async fn get_token_data_for_payment_method_id(
+    state: &AppState,
+    payment_method_id: &str,
+    payment_intent: &PaymentIntent,
+    customer: &Option<domain::Customer>,
+) -> RouterResult<storage::PaymentTokenData> {
+    let payment_method = state
+        .store
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        payment_method.merchant_id != payment_intent.merchant_id,
+        || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+    )?;
+
+    let customer_id = customer
+        .as_ref()
+        .map(|customer| customer.customer_id.as_str())
+        .or(payment_intent.customer_id.as_deref());
+    utils::when(
+        customer_id != Some(payment_method.customer_id.as_str()),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "payment_method_id does not belong to the customer of the payment"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(
+        payment_method.payment_method != storage_enums::PaymentMethod::Card,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "only saved cards can be referenced by payment_method_id".to_string(),
+            }))
+        },
+    )?;
+
+    Ok(storage::PaymentTokenData::permanent_card(
+        payment_method.payment_method_id,
+    ))
+}
+
 pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
     operation: BoxedOperation<'a, F, R, Ctx>,
     state: &'a AppState,
@@ -1588,11 +1830,36 @@ pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
     }
 
     let token = payment_data.token.clone();
+    let payment_method_id = payment_data.payment_method_id.clone();
+
+    // Merchants requiring an explicit payment method are never charged through the token stored on
+    // the attempt, confirm has to carry the payment method or token itself
+    if payments::is_operation_confirm(&operation)
+        && payment_data.mandate_id.is_none()
+        && request.is_none()
+        && token.is_none()
+        && payment_method_id.is_none()
+        && is_explicit_payment_method_required(state, &payment_data.payment_intent.merchant_id)
+            .await
+    {
+        return Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound))
+            .attach_printable("Payment method or token is required to confirm the payment");
+    }
 
     let hyperswitch_token = match payment_data.mandate_id {
         Some(_) => token.map(storage::PaymentTokenData::temporary_generic),
         None => {
-            if let Some(token) = token {
+            if let Some(payment_method_id) = payment_method_id.as_deref() {
+                Some(
+                    get_token_data_for_payment_method_id(
+                        state,
+                        payment_method_id,
+                        &payment_data.payment_intent,
+                        customer,
+                    )
+                    .await?,
+                )
+            } else if let Some(token) = token {
                 let redis_conn = state
                     .store
                     .get_redis_conn()
@@ -1660,6 +1927,14 @@ pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
             .await
             .attach_printable("in 'make_pm_data'")?;
 
+            // Saved cards are only checked for expiry when they are referenced directly, tokens
+            // are issued for cards which were valid when the customer listed them
+            if let (Some(_), Some((api::PaymentMethodData::Card(card), _))) =
+                (&payment_method_id, &payment_method_details)
+            {
+                validate_card_expiry(&card.card_exp_month, &card.card_exp_year)?;
+            }
+
             Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(
                 if let Some((payment_method_data, payment_method)) = payment_method_details {
                     payment_data.payment_attempt.payment_method = Some(payment_method);
@@ -1671,6 +1946,10 @@ pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
         }
 
         (Some(_), _) => {
+            if let Some(api::PaymentMethodData::NetworkToken(network_token_data)) = request {
+                validate_network_token_data(network_token_data)?;
+            }
+
             let payment_method_data = Ctx::retrieve_payment_method(
                 request,
                 state,
@@ -1787,83 +2066,905 @@ pub(crate) fn validate_capture_method(
     )
 }
 
+/// Validates that the connector the payment is routed through supports the capture method of the
+/// payment, so that unsupported combinations are rejected before the payment is updated
 #[instrument(skip_all)]
-pub(crate) fn validate_status_with_capture_method(
-    status: storage_enums::IntentStatus,
-    capture_method: storage_enums::CaptureMethod,
+pub fn validate_capture_method_supported_by_connector(
+    state: &AppState,
+    payment_attempt: &PaymentAttempt,
 ) -> RouterResult<()> {
-    if status == storage_enums::IntentStatus::Processing
-        && !(capture_method == storage_enums::CaptureMethod::ManualMultiple)
-    {
-        return Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
-            field_name: "capture_method".to_string(),
-            current_flow: "captured".to_string(),
-            current_value: capture_method.to_string(),
-            states: "manual_multiple".to_string()
-        }));
+    let connector_name = match payment_attempt.connector.as_ref() {
+        Some(connector_name) => connector_name,
+        None => return Ok(()),
+    };
+
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        payment_attempt.merchant_connector_id.clone(),
+    )?;
+
+    connector
+        .connector
+        .validate_capture_method(payment_attempt.capture_method)
+        .change_context(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Capture method {} is not supported by {connector_name}",
+                payment_attempt.capture_method.unwrap_or_default()
+            ),
+        })
+}
+
+/// Currencies supported by the connectors which do not support every currency. The connectors
+/// which are not listed support every currency.
+fn get_supported_currencies(
+    connector: api_enums::Connector,
+) -> Option<&'static [api_enums::Currency]> {
+    match connector {
+        api_enums::Connector::Forte
+        | api_enums::Connector::Helcim
+        | api_enums::Connector::Prophetpay
+        | api_enums::Connector::Stax => Some(&[api_enums::Currency::USD]),
+        _ => None,
     }
-    utils::when(
-        status != storage_enums::IntentStatus::RequiresCapture
-            && status != storage_enums::IntentStatus::PartiallyCapturedAndCapturable
-            && status != storage_enums::IntentStatus::Processing,
-        || {
-            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
-                field_name: "payment.status".to_string(),
-                current_flow: "captured".to_string(),
-                current_value: status.to_string(),
-                states: "requires_capture, partially_captured_and_capturable, processing"
-                    .to_string()
-            }))
-        },
-    )
 }
 
-#[instrument(skip_all)]
-pub(crate) fn validate_amount_to_capture(
-    amount: i64,
-    amount_to_capture: Option<i64>,
+/// Rejects the payments in a currency which the connector the payment is routed through does not
+/// support, instead of failing during the authorization
+pub fn validate_currency_supported_by_connector<F: Clone>(
+    payment_data: &PaymentData<F>,
 ) -> RouterResult<()> {
-    utils::when(
-        amount_to_capture.is_some() && (Some(amount) < amount_to_capture),
-        || {
-            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
-                message: "amount_to_capture is greater than amount".to_string()
+    let connector_name = match payment_data.payment_attempt.connector.as_deref() {
+        Some(connector_name) => connector_name,
+        None => return Ok(()),
+    };
+
+    let supported_currencies = connector_name
+        .parse::<api_enums::Connector>()
+        .ok()
+        .and_then(get_supported_currencies);
+
+    match supported_currencies {
+        Some(supported_currencies) if !supported_currencies.contains(&payment_data.currency) => {
+            Err(report!(errors::ApiErrorResponse::CurrencyNotSupported {
+                message: format!(
+                    "Currency {} is not supported by the connector {connector_name}",
+                    payment_data.currency
+                )
             }))
-        },
-    )
+        }
+        _ => Ok(()),
+    }
 }
 
-#[instrument(skip_all)]
-pub(crate) fn validate_payment_method_fields_present(
-    req: &api::PaymentsRequest,
+pub fn validate_network_token_supported_by_connector<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
 ) -> RouterResult<()> {
-    utils::when(
-        req.payment_method.is_none() && req.payment_method_data.is_some(),
-        || {
-            Err(errors::ApiErrorResponse::MissingRequiredField {
-                field_name: "payment_method",
-            })
-        },
-    )?;
+    if !matches!(
+        payment_data.payment_method_data,
+        Some(api::PaymentMethodData::NetworkToken(_))
+    ) {
+        return Ok(());
+    }
+
+    let connector_name = match payment_data.payment_attempt.connector.as_deref() {
+        Some(connector_name) => connector_name,
+        None => return Ok(()),
+    };
+
+    let is_network_token_supported =
+        connector_name
+            .parse::<api_enums::Connector>()
+            .map_or(false, |connector| {
+                state
+                    .conf
+                    .network_token_supported_connectors
+                    .connector_list
+                    .contains(&connector)
+            });
+
+    utils::when(!is_network_token_supported, || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!("Network tokens are not supported by {connector_name}")
+        }))
+    })
+}
+
+/// Derives the idempotency key forwarded to the connector with the authorization of the attempt.
+/// The key only depends on the attempt id and the retry counter of the payment, so that repeated
+/// connector calls for the same attempt carry the same key and are not processed twice.
+pub fn get_connector_idempotency_key(attempt_id: &str, retry_count: i16) -> String {
+    format!("{attempt_id}_{retry_count}")
+}
+
+/// Validates the installment plan selected for the payment against the installment plans offered
+/// by the connector for the payment method type. Installment plans past their expiry are no
+/// longer offered.
+pub fn validate_installment_plan_for_connector<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let installment_plan = match payment_data.payment_attempt.installment_plan.clone() {
+        Some(installment_plan) => installment_plan
+            .parse_value::<api_models::payments::InstallmentPlan>("InstallmentPlan")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deserialize the installment plan of the payment")?,
+        None => return Ok(()),
+    };
+
+    let connector_name = match payment_data.payment_attempt.connector.as_deref() {
+        Some(connector_name) => connector_name,
+        None => return Ok(()),
+    };
+
+    let installment_plans = state
+        .conf
+        .connector_installment_plans
+        .0
+        .get(connector_name)
+        .filter(|installment_plans| {
+            payment_data
+                .payment_attempt
+                .payment_method_type
+                .map_or(false, |payment_method_type| {
+                    installment_plans
+                        .payment_method_types
+                        .contains(&payment_method_type)
+                })
+        })
+        .ok_or(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Installments are not supported by {connector_name} for this payment method"
+            )
+        }))?;
+
+    let plan = installment_plans
+        .plans
+        .iter()
+        .find(|plan| plan.plan_id == installment_plan.plan_id)
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Installment plan {} is not offered by {connector_name}",
+                installment_plan.plan_id
+            )
+        }))?;
 
     utils::when(
-        !matches!(
-            req.payment_method,
-            Some(api_enums::PaymentMethod::Card) | None
-        ) && (req.payment_method_type.is_none()),
+        plan.expires_at.map_or(false, |expires_at| {
+            expires_at <= common_utils::date_time::now_unix_timestamp()
+        }),
         || {
-            Err(errors::ApiErrorResponse::MissingRequiredField {
-                field_name: "payment_method_type",
-            })
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Installment plan {} has expired", plan.plan_id)
+            }))
         },
     )?;
 
     utils::when(
-        req.payment_method.is_some()
-            && req.payment_method_data.is_none()
-            && req.payment_token.is_none(),
+        !plan
+            .installment_options
+            .contains(&installment_plan.number_of_installments),
         || {
-            Err(errors::ApiErrorResponse::MissingRequiredField {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Installment plan {} does not offer {} installments",
+                    plan.plan_id, installment_plan.number_of_installments
+                )
+            }))
+        },
+    )
+}
+
+/// Validates that the billing country, and the shipping country when configured, of the payment
+/// is one of the countries the connector the payment is routed through operates in. Connectors
+/// which are not geographically restricted are not checked.
+pub fn validate_country_supported_by_connector<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let connector_name = match payment_data.payment_attempt.connector.as_deref() {
+        Some(connector_name) => connector_name,
+        None => return Ok(()),
+    };
+
+    let (supported_countries, validate_shipping) = match state
+        .conf
+        .connector_supported_countries
+        .0
+        .get(connector_name)
+    {
+        Some(settings::SupportedCountriesConfig {
+            countries: Some(countries),
+            validate_shipping,
+        }) => (countries, *validate_shipping),
+        Some(_) | None => return Ok(()),
+    };
+
+    let addresses = std::iter::once((
+        payment_data.address.billing.as_ref(),
+        "billing.address.country",
+        "Billing",
+    ))
+    .chain(validate_shipping.then_some((
+        payment_data.address.shipping.as_ref(),
+        "shipping.address.country",
+        "Shipping",
+    )));
+
+    for (address, field_name, address_type) in addresses {
+        let country = address
+            .and_then(|address| address.address.as_ref())
+            .and_then(|address_details| address_details.country)
+            .ok_or(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name
+            }))?;
+        utils::when(!supported_countries.contains(&country), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{address_type} country {country} is not supported by {connector_name}"
+                )
+            }))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Rejects the payment method types which are not in the allowed payment method types of the
+/// payment, when they are set. A payment method type which is not known yet is let through, since
+/// card payments and payments with a saved payment method can omit it. Such payments are checked
+/// again once their payment method data is resolved.
+pub fn validate_payment_method_type_allowed(
+    payment_intent: &PaymentIntent,
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+) -> RouterResult<()> {
+    let allowed_payment_method_types = match payment_intent.allowed_payment_method_types.clone() {
+        Some(allowed_payment_method_types) => allowed_payment_method_types
+            .parse_value::<Vec<api_enums::PaymentMethodType>>("Vec<PaymentMethodType>")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deserialize the allowed payment method types")?,
+        None => return Ok(()),
+    };
+
+    let payment_method_type = match payment_method_type {
+        Some(payment_method_type) => payment_method_type,
+        None => return Ok(()),
+    };
+
+    utils::when(
+        !allowed_payment_method_types.contains(&payment_method_type),
+        || {
+            Err(report!(
+                errors::ApiErrorResponse::PaymentMethodTypeNotAllowed {
+                    payment_method_type: payment_method_type.to_string(),
+                }
+            ))
+        },
+    )
+}
+
+/// Validates the network transaction id passed for a merchant initiated transaction, which is
+/// only applicable to payments made off session
+pub fn validate_network_transaction_id(
+    network_transaction_id: Option<&str>,
+    off_session: Option<bool>,
+) -> RouterResult<()> {
+    match network_transaction_id {
+        Some(network_transaction_id) if network_transaction_id.trim().is_empty() => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`network_transaction_id` must not be empty".to_string()
+            }))
+        }
+        Some(_) if off_session != Some(true) => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`off_session` should be `true` when `network_transaction_id` is passed"
+                    .into()
+            }))
+        }
+        Some(_) | None => Ok(()),
+    }
+}
+
+/// Rejects off session merchant initiated transactions which neither refer to a mandate nor to the
+/// network transaction id of the customer initiated transaction, when the connector the payment is
+/// routed through requires it
+#[instrument(skip_all)]
+pub fn validate_network_transaction_id_required_by_connector<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let is_off_session_merchant_initiated_transaction = payment_data.payment_intent.off_session
+        == Some(true)
+        && payment_data.setup_mandate.is_none()
+        && payment_data.mandate_id.is_none()
+        && payment_data.recurring_mandate_payment_data.is_none();
+
+    let is_network_transaction_id_required = payment_data
+        .payment_attempt
+        .connector
+        .as_deref()
+        .and_then(|connector_name| connector_name.parse::<api_enums::Connector>().ok())
+        .map_or(false, |connector| {
+            state
+                .conf
+                .network_transaction_id_required_connectors
+                .connector_list
+                .contains(&connector)
+        });
+
+    utils::when(
+        is_off_session_merchant_initiated_transaction
+            && is_network_transaction_id_required
+            && payment_data
+                .payment_attempt
+                .network_transaction_id
+                .is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "network_transaction_id"
+            }))
+        },
+    )
+}
+
+/// Checks the statement descriptor name and suffix against the limits of the connector the payment
+/// is routed through. Descriptors which do not comply are rejected, unless the merchant has enabled
+/// sanitization, in which case they are adjusted to the limits of the connector.
+#[instrument(skip_all)]
+pub async fn validate_statement_descriptor_for_connector<F: Clone>(
+    state: &AppState,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let (connector_name, constraint) = match payment_data
+        .payment_attempt
+        .connector
+        .clone()
+        .and_then(|connector_name| {
+            state
+                .conf
+                .statement_descriptor_constraints
+                .0
+                .get(&connector_name)
+                .map(|constraint| (connector_name, constraint))
+        }) {
+        Some(connector_constraint) => connector_constraint,
+        None => return Ok(()),
+    };
+
+    let is_compliant = |descriptor: &Option<String>| {
+        descriptor.as_deref().map_or(true, |descriptor| {
+            is_statement_descriptor_compliant(descriptor, constraint)
+        })
+    };
+    if is_compliant(&payment_data.payment_intent.statement_descriptor_name)
+        && is_compliant(&payment_data.payment_intent.statement_descriptor_suffix)
+    {
+        return Ok(());
+    }
+
+    if is_statement_descriptor_sanitization_enabled(state, &payment_data.payment_intent.merchant_id)
+        .await
+    {
+        let sanitize = |descriptor: &mut Option<String>| {
+            if let Some(descriptor) = descriptor.as_mut() {
+                *descriptor = sanitize_statement_descriptor(descriptor, constraint);
+            }
+        };
+        sanitize(&mut payment_data.payment_intent.statement_descriptor_name);
+        sanitize(&mut payment_data.payment_intent.statement_descriptor_suffix);
+        return Ok(());
+    }
+
+    for (field_name, descriptor) in [
+        (
+            "statement_descriptor_name",
+            &payment_data.payment_intent.statement_descriptor_name,
+        ),
+        (
+            "statement_descriptor_suffix",
+            &payment_data.payment_intent.statement_descriptor_suffix,
+        ),
+    ] {
+        if let Some(descriptor) = descriptor {
+            if descriptor.chars().count() > constraint.max_length {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "`{field_name}` must be at most {} characters long for {connector_name}",
+                        constraint.max_length
+                    )
+                }));
+            }
+            if !is_statement_descriptor_charset_valid(descriptor, constraint) {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "`{field_name}` must only contain alphanumeric characters and spaces for {connector_name}"
+                    )
+                }));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the browser information carries the fields required by the connector the payment is
+/// routed through to run a 3DS authentication. Payments which do not use 3DS are not checked.
+pub fn validate_browser_info_for_connector<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    if payment_data.payment_attempt.authentication_type != Some(enums::AuthenticationType::ThreeDs)
+    {
+        return Ok(());
+    }
+
+    let required_fields =
+        match payment_data
+            .payment_attempt
+            .connector
+            .as_ref()
+            .and_then(|connector_name| {
+                state
+                    .conf
+                    .three_ds_browser_info_requirements
+                    .0
+                    .get(connector_name)
+            }) {
+            Some(required_fields) => required_fields,
+            None => return Ok(()),
+        };
+
+    let browser_info = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| {
+            browser_info.parse_value::<crate::types::BrowserInformation>("BrowserInformation")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "browser_info",
+        })?
+        .unwrap_or_default();
+
+    let missing_fields = required_fields
+        .iter()
+        .filter(|field| !is_browser_info_field_present(&browser_info, **field))
+        .map(|field| get_browser_info_field_name(*field))
+        .collect::<Vec<_>>();
+
+    utils::when(!missing_fields.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredFields {
+            field_names: missing_fields,
+        }))
+    })
+}
+
+/// Returns the fields of the browser information the connector requires to run a 3DS
+/// authentication
+pub fn get_browser_info_fields_required_by_connector(
+    state: &AppState,
+    connector_name: &str,
+) -> Vec<&'static str> {
+    state
+        .conf
+        .three_ds_browser_info_requirements
+        .0
+        .get(connector_name)
+        .map(|required_fields| {
+            required_fields
+                .iter()
+                .map(|field| get_browser_info_field_name(*field))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn is_browser_info_field_present(
+    browser_info: &crate::types::BrowserInformation,
+    field: settings::BrowserInfoField,
+) -> bool {
+    match field {
+        settings::BrowserInfoField::AcceptHeader => browser_info.accept_header.is_some(),
+        settings::BrowserInfoField::UserAgent => browser_info.user_agent.is_some(),
+        settings::BrowserInfoField::Language => browser_info.language.is_some(),
+        settings::BrowserInfoField::ColorDepth => browser_info.color_depth.is_some(),
+        settings::BrowserInfoField::ScreenHeight => browser_info.screen_height.is_some(),
+        settings::BrowserInfoField::ScreenWidth => browser_info.screen_width.is_some(),
+        settings::BrowserInfoField::TimeZone => browser_info.time_zone.is_some(),
+        settings::BrowserInfoField::JavaEnabled => browser_info.java_enabled.is_some(),
+        settings::BrowserInfoField::JavaScriptEnabled => browser_info.java_script_enabled.is_some(),
+        settings::BrowserInfoField::IpAddress => browser_info.ip_address.is_some(),
+    }
+}
+
+fn get_browser_info_field_name(field: settings::BrowserInfoField) -> &'static str {
+    match field {
+        settings::BrowserInfoField::AcceptHeader => "browser_info.accept_header",
+        settings::BrowserInfoField::UserAgent => "browser_info.user_agent",
+        settings::BrowserInfoField::Language => "browser_info.language",
+        settings::BrowserInfoField::ColorDepth => "browser_info.color_depth",
+        settings::BrowserInfoField::ScreenHeight => "browser_info.screen_height",
+        settings::BrowserInfoField::ScreenWidth => "browser_info.screen_width",
+        settings::BrowserInfoField::TimeZone => "browser_info.time_zone",
+        settings::BrowserInfoField::JavaEnabled => "browser_info.java_enabled",
+        settings::BrowserInfoField::JavaScriptEnabled => "browser_info.java_script_enabled",
+        settings::BrowserInfoField::IpAddress => "browser_info.ip_address",
+    }
+}
+
+fn is_statement_descriptor_charset_valid(
+    descriptor: &str,
+    constraint: &settings::StatementDescriptorConstraint,
+) -> bool {
+    !constraint.alphanumeric_only
+        || descriptor
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == ' ')
+}
+
+fn is_statement_descriptor_compliant(
+    descriptor: &str,
+    constraint: &settings::StatementDescriptorConstraint,
+) -> bool {
+    descriptor.chars().count() <= constraint.max_length
+        && is_statement_descriptor_charset_valid(descriptor, constraint)
+}
+
+fn sanitize_statement_descriptor(
+    descriptor: &str,
+    constraint: &settings::StatementDescriptorConstraint,
+) -> String {
+    descriptor
+        .chars()
+        .filter(|character| {
+            !constraint.alphanumeric_only || character.is_ascii_alphanumeric() || *character == ' '
+        })
+        .take(constraint.max_length)
+        .collect()
+}
+
+async fn is_statement_descriptor_sanitization_enabled(state: &AppState, merchant_id: &str) -> bool {
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::SANITIZE_STATEMENT_DESCRIPTOR_CONFIG_PREFIX
+    );
+    let db = &*state.store;
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|sanitize_config| {
+            sanitize_config
+                .config
+                .parse::<bool>()
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Statement descriptor sanitization config parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(sanitize_statement_descriptor_config_error=?err);
+        })
+        .unwrap_or(false)
+}
+
+/// Whether confirm has to carry the payment method of the payment for the merchant, instead of
+/// falling back to the payment token stored on the attempt. Lenient unless configured otherwise
+pub async fn is_explicit_payment_method_required(state: &AppState, merchant_id: &str) -> bool {
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::EXPLICIT_PAYMENT_METHOD_REQUIRED_CONFIG_PREFIX
+    );
+    let db = &*state.store;
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|explicit_payment_method_config| {
+            explicit_payment_method_config
+                .config
+                .parse::<bool>()
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Explicit payment method config parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(explicit_payment_method_required_config_error=?err);
+        })
+        .unwrap_or(false)
+}
+
+/// 3DS version policy of the merchant, maintained in the configs table. The configured default is
+/// used when the merchant has no policy of their own
+async fn get_three_ds_version_enforcement(
+    state: &AppState,
+    merchant_id: &str,
+) -> settings::ThreeDsVersionEnforcement {
+    let default_enforcement = state.conf.three_ds_version.default_enforcement;
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::THREE_DS_VERSION_ENFORCEMENT_CONFIG_PREFIX
+    );
+    let db = &*state.store;
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("null".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|enforcement_config| {
+            serde_json::from_str::<Option<settings::ThreeDsVersionEnforcement>>(
+                &enforcement_config.config,
+            )
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("3DS version enforcement config parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(three_ds_version_enforcement_config_error=?err);
+        })
+        .ok()
+        .flatten()
+        .unwrap_or(default_enforcement)
+}
+
+/// Checks whether the connector supports the preferred 3DS version of the payment. When it does
+/// not, the payment is rejected if the 3DS version policy of the merchant is hard, otherwise
+/// `false` is returned and the connector authenticates the customer through the version it
+/// supports
+#[instrument(skip_all)]
+pub async fn validate_preferred_3ds_version(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+    preferred_3ds_version: api_enums::ThreeDsVersion,
+) -> RouterResult<bool> {
+    let supported_3ds_version = match api_enums::Connector::from_str(connector_name) {
+        Ok(connector)
+            if state
+                .conf
+                .three_ds_version
+                .connectors_with_3ds2
+                .contains(&connector) =>
+        {
+            api_enums::ThreeDsVersion::V2
+        }
+        _ => api_enums::ThreeDsVersion::V1,
+    };
+
+    if preferred_3ds_version <= supported_3ds_version {
+        return Ok(true);
+    }
+
+    match get_three_ds_version_enforcement(state, merchant_id).await {
+        settings::ThreeDsVersionEnforcement::Hard => {
+            Err(report!(errors::ApiErrorResponse::NotSupported {
+                message: format!(
+                    "3DS version {preferred_3ds_version} is not supported by {connector_name}"
+                ),
+            }))
+        }
+        settings::ThreeDsVersionEnforcement::Soft => Ok(false),
+    }
+}
+
+/// Fraud score thresholds of a merchant. Payments scoring below the manual review threshold
+/// proceed, payments scoring from the manual review threshold are held for review by the merchant
+/// and payments scoring from the fail threshold are failed.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub struct FrmScoreThresholds {
+    pub manual_review: i32,
+    pub fail: i32,
+}
+
+impl FrmScoreThresholds {
+    /// Suggestion for the fraud score along with the threshold which the score reached, `None`
+    /// when the payment can proceed
+    pub fn get_suggestion(&self, frm_score: i32) -> Option<(api_enums::FrmSuggestion, i32)> {
+        if frm_score >= self.fail {
+            Some((api_enums::FrmSuggestion::FrmCancelTransaction, self.fail))
+        } else if frm_score >= self.manual_review {
+            Some((
+                api_enums::FrmSuggestion::FrmManualReview,
+                self.manual_review,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Fraud score thresholds of the merchant, maintained in the configs table. Payments are not
+/// gated on their fraud score when the merchant has no thresholds configured
+#[instrument(skip_all)]
+pub async fn get_frm_score_thresholds(
+    state: &AppState,
+    merchant_id: &str,
+) -> Option<FrmScoreThresholds> {
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::FRM_SCORE_THRESHOLDS_CONFIG_PREFIX
+    );
+    let db = &*state.store;
+    db.find_config_by_key(key.as_str())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|thresholds_config| {
+            let thresholds: FrmScoreThresholds = thresholds_config
+                .config
+                .parse_struct("FrmScoreThresholds")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Fraud score thresholds config parsing failed")?;
+            Ok(thresholds)
+        })
+        .map_err(|err| {
+            logger::debug!(frm_score_thresholds_config_error=?err);
+        })
+        .ok()
+}
+
+/// Connectors which are currently marked as unhealthy, maintained in the configs table
+#[instrument(skip_all)]
+pub async fn get_unhealthy_connectors(
+    state: &AppState,
+) -> Vec<api_models::enums::RoutableConnectors> {
+    let db = &*state.store;
+    db.find_config_by_key_unwrap_or(
+        consts::UNHEALTHY_CONNECTORS_CONFIG_KEY,
+        Some("[]".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .and_then(|unhealthy_connectors_config| {
+        serde_json::from_str::<Vec<api_models::enums::RoutableConnectors>>(
+            &unhealthy_connectors_config.config,
+        )
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unhealthy connectors config parsing failed")
+    })
+    .map_err(|err| {
+        logger::error!(unhealthy_connectors_config_error=?err);
+    })
+    .unwrap_or_default()
+}
+
+/// Moves the first healthy connector of the routing-provided chain to the front, so that it is
+/// the one the payment is routed through. The index of the selected connector in the chain is
+/// recorded on the attempt, unless there was no chain to fall back on.
+///
+/// When every connector of the chain is unhealthy, the chain is left as is and the attempt is
+/// tagged as routed to an unhealthy connector.
+pub async fn prioritize_healthy_connector(
+    state: &AppState,
+    connectors: &mut Vec<api::routing::RoutableConnectorChoice>,
+    payment_attempt: &mut PaymentAttempt,
+) {
+    let unhealthy_connectors = get_unhealthy_connectors(state).await;
+    let fallback_index = connectors
+        .iter()
+        .position(|choice| !unhealthy_connectors.contains(&choice.connector));
+
+    match fallback_index {
+        Some(fallback_index) => {
+            for skipped_connector in connectors.iter().take(fallback_index) {
+                metrics::CONNECTOR_HEALTH_BASED_SKIP_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::request::add_attributes(
+                        "connector",
+                        skipped_connector.connector.to_string(),
+                    )],
+                );
+            }
+            if fallback_index > 0 {
+                logger::info!(
+                    fallback_index,
+                    "Primary connector is unhealthy, falling back to the next healthy connector"
+                );
+                let connector_choice = connectors.remove(fallback_index);
+                connectors.insert(0, connector_choice);
+            }
+        }
+        None => {
+            if let Some(primary_connector) = connectors.first() {
+                logger::warn!(
+                    connector = %primary_connector.connector,
+                    "Every connector of the chain is unhealthy, routing through the primary connector"
+                );
+                metrics::UNHEALTHY_CONNECTOR_FORCED_ROUTING_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::request::add_attributes(
+                        "connector",
+                        primary_connector.connector.to_string(),
+                    )],
+                );
+                payment_attempt.routed_to_unhealthy_connector = Some(true);
+            }
+        }
+    }
+
+    if connectors.len() > 1 {
+        payment_attempt.connector_fallback_index = i16::try_from(fallback_index.unwrap_or(0)).ok();
+    }
+}
+
+#[instrument(skip_all)]
+pub(crate) fn validate_status_with_capture_method(
+    status: storage_enums::IntentStatus,
+    capture_method: storage_enums::CaptureMethod,
+) -> RouterResult<()> {
+    if status == storage_enums::IntentStatus::Processing
+        && !(capture_method == storage_enums::CaptureMethod::ManualMultiple)
+    {
+        return Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+            field_name: "capture_method".to_string(),
+            current_flow: "captured".to_string(),
+            current_value: capture_method.to_string(),
+            states: "manual_multiple".to_string()
+        }));
+    }
+    utils::when(
+        status != storage_enums::IntentStatus::RequiresCapture
+            && status != storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+            && status != storage_enums::IntentStatus::Processing,
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                field_name: "payment.status".to_string(),
+                current_flow: "captured".to_string(),
+                current_value: status.to_string(),
+                states: "requires_capture, partially_captured_and_capturable, processing"
+                    .to_string()
+            }))
+        },
+    )
+}
+
+#[instrument(skip_all)]
+pub(crate) fn validate_amount_to_capture(
+    amount: i64,
+    amount_to_capture: Option<i64>,
+) -> RouterResult<()> {
+    utils::when(
+        amount_to_capture.is_some() && (Some(amount) < amount_to_capture),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount_to_capture is greater than amount".to_string()
+            }))
+        },
+    )
+}
+
+#[instrument(skip_all)]
+pub(crate) fn validate_payment_method_fields_present(
+    req: &api::PaymentsRequest,
+) -> RouterResult<()> {
+    utils::when(
+        req.payment_method.is_none() && req.payment_method_data.is_some(),
+        || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "payment_method",
+            })
+        },
+    )?;
+
+    utils::when(
+        !matches!(
+            req.payment_method,
+            Some(api_enums::PaymentMethod::Card) | None
+        ) && (req.payment_method_type.is_none()),
+        || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "payment_method_type",
+            })
+        },
+    )?;
+
+    utils::when(
+        req.payment_method.is_some()
+            && req.payment_method_data.is_none()
+            && req.payment_token.is_none()
+            && req.payment_method_id.is_none(),
+        || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
                 field_name: "payment_method_data",
             })
         },
@@ -2048,6 +3149,26 @@ pub fn validate_payment_method_type_against_payment_method(
     }
 }
 
+/// Whether the customer completes the payment after it is confirmed, such as by paying a voucher or
+/// by transferring the amount to a bank account, so that confirming the payment produces the
+/// instructions for the customer rather than an authorization. The payment method data must be of
+/// the payment method of the attempt for the payment to be considered deferred
+pub fn is_deferred_payment_method(
+    payment_method: Option<api_enums::PaymentMethod>,
+    payment_method_data: Option<&api::PaymentMethodData>,
+) -> bool {
+    matches!(
+        (payment_method, payment_method_data),
+        (
+            Some(api_enums::PaymentMethod::Voucher),
+            Some(api::PaymentMethodData::Voucher(_))
+        ) | (
+            Some(api_enums::PaymentMethod::BankTransfer),
+            Some(api::PaymentMethodData::BankTransfer(_))
+        )
+    )
+}
+
 pub fn check_force_psync_precondition(status: &storage_enums::AttemptStatus) -> bool {
     !matches!(
         status,
@@ -2407,10 +3528,195 @@ pub fn generate_mandate(
     }
 }
 
-// A function to manually authenticate the client secret with intent fulfillment time
+/// Validity (in seconds) of the client secrets of the merchant, when configured. Without it, the
+/// client secret stays valid until the session of the payment expires
+#[instrument(skip_all)]
+pub async fn get_client_secret_expiry(db: &dyn StorageInterface, merchant_id: &str) -> Option<u32> {
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::CLIENT_SECRET_EXPIRY_CONFIG_PREFIX
+    );
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("null".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|client_secret_expiry_config| {
+            serde_json::from_str::<Option<u32>>(&client_secret_expiry_config.config)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Client secret expiry config parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(client_secret_expiry_config_error=?err);
+        })
+        .ok()
+        .flatten()
+}
+
+pub async fn get_max_attempts_per_payment(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    default_max_attempts: i16,
+) -> i16 {
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::MAX_ATTEMPTS_PER_PAYMENT_CONFIG_PREFIX
+    );
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("null".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|max_attempts_config| {
+            serde_json::from_str::<Option<i16>>(&max_attempts_config.config)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Max attempts per payment config parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(max_attempts_per_payment_config_error=?err);
+        })
+        .ok()
+        .flatten()
+        .unwrap_or(default_max_attempts)
+}
+
+pub async fn get_confirm_replay_grace_window(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    default_grace_window_in_secs: u64,
+) -> u64 {
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::CONFIRM_REPLAY_GRACE_WINDOW_CONFIG_PREFIX
+    );
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("null".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|grace_window_config| {
+            serde_json::from_str::<Option<u64>>(&grace_window_config.config)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Confirm replay grace window config parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(confirm_replay_grace_window_config_error=?err);
+        })
+        .ok()
+        .flatten()
+        .unwrap_or(default_grace_window_in_secs)
+}
+
+/// Whether the confirm is a retry of a confirm which already succeeded, made with the client
+/// secret of the payment within the grace window of the merchant. Clients which lost the response
+/// of the successful confirm are then returned the payment instead of an error.
+pub async fn is_succeeded_confirm_replay(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    request: &api::PaymentsRequest,
+) -> RouterResult<bool> {
+    let (payment_id, client_secret) = match (&request.payment_id, &request.client_secret) {
+        (Some(api::PaymentIdType::PaymentIntentId(payment_id)), Some(client_secret)) => {
+            (payment_id, client_secret)
+        }
+        _ => return Ok(false),
+    };
+
+    let db = &*state.store;
+    let grace_window_in_secs = get_confirm_replay_grace_window(
+        db,
+        &merchant_account.merchant_id,
+        state.conf.confirm_replay.grace_window_in_secs,
+    )
+    .await;
+    if grace_window_in_secs == 0 {
+        return Ok(false);
+    }
+
+    // A payment which cannot be found is left to the confirm, which reports it
+    let payment_intent = match db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => payment_intent,
+        Err(err)
+            if matches!(
+                err.current_context(),
+                errors::DataStorageError::ValueNotFound(_)
+            ) =>
+        {
+            return Ok(false)
+        }
+        Err(err) => {
+            return Err(err.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to find the payment intent of the confirm replay")
+        }
+    };
+
+    let active_attempt = match db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_intent.payment_id.as_str(),
+            &merchant_account.merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(active_attempt) => active_attempt,
+        Err(err)
+            if matches!(
+                err.current_context(),
+                errors::DataStorageError::ValueNotFound(_)
+            ) =>
+        {
+            return Ok(false)
+        }
+        Err(err) => {
+            return Err(err.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to find the payment attempt of the confirm replay")
+        }
+    };
+    // Measured from the attempt which charged the payment, as the modification time of the
+    // payment intent moves with every later update of the payment
+    let succeeded_at = (active_attempt.status == storage_enums::AttemptStatus::Charged)
+        .then_some(active_attempt.modified_at);
+
+    Ok(is_within_confirm_replay_grace_window(
+        &payment_intent,
+        succeeded_at,
+        client_secret,
+        grace_window_in_secs,
+        common_utils::date_time::now(),
+    ))
+}
+
+/// Whether a confirm made with the client secret at the current time falls within the grace
+/// window of the succeeded payment, which is measured from the time the payment succeeded at
+fn is_within_confirm_replay_grace_window(
+    payment_intent: &PaymentIntent,
+    succeeded_at: Option<time::PrimitiveDateTime>,
+    client_secret: &String,
+    grace_window_in_secs: u64,
+    current_time: time::PrimitiveDateTime,
+) -> bool {
+    let grace_window =
+        time::Duration::seconds(i64::try_from(grace_window_in_secs).unwrap_or(i64::MAX));
+
+    grace_window_in_secs > 0
+        && payment_intent.status == storage_enums::IntentStatus::Succeeded
+        && payment_intent.client_secret.as_ref() == Some(client_secret)
+        && succeeded_at.map_or(false, |succeeded_at| {
+            current_time <= succeeded_at.saturating_add(grace_window)
+        })
+}
+
+// A function to manually authenticate the client secret with intent fulfillment time, or with the
+// client secret expiry of the merchant when configured
 pub fn authenticate_client_secret(
     request_client_secret: Option<&String>,
     payment_intent: &PaymentIntent,
+    client_secret_expiry: Option<u32>,
 ) -> Result<(), errors::ApiErrorResponse> {
     match (request_client_secret, &payment_intent.client_secret) {
         (Some(req_cs), Some(pi_cs)) => {
@@ -2419,13 +3725,20 @@ pub fn authenticate_client_secret(
             } else {
                 let current_timestamp = common_utils::date_time::now();
 
-                let session_expiry = payment_intent.session_expiry.unwrap_or(
-                    payment_intent
+                let client_secret_expiry = match client_secret_expiry {
+                    Some(client_secret_expiry) => payment_intent
                         .created_at
-                        .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
-                );
+                        .saturating_add(time::Duration::seconds(client_secret_expiry.into())),
+                    None => payment_intent.session_expiry.unwrap_or(
+                        payment_intent
+                            .created_at
+                            .saturating_add(time::Duration::seconds(
+                                consts::DEFAULT_SESSION_EXPIRY,
+                            )),
+                    ),
+                };
 
-                fp_utils::when(current_timestamp > session_expiry, || {
+                fp_utils::when(current_timestamp > client_secret_expiry, || {
                     Err(errors::ApiErrorResponse::ClientSecretExpired)
                 })
             }
@@ -2464,6 +3777,25 @@ pub(crate) fn validate_payment_status_against_not_allowed_statuses(
     })
 }
 
+/// A saved payment method referenced by `payment_method_id` replaces the payment method data, so
+/// it cannot be combined with another source of the payment method
+pub(crate) fn validate_payment_method_id_in_request(
+    req: &api::PaymentsRequest,
+) -> RouterResult<()> {
+    utils::when(
+        req.payment_method_id.is_some()
+            && (req.payment_method_data.is_some()
+                || req.payment_token.is_some()
+                || req.mandate_id.is_some()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payment_method_id cannot be combined with payment_method_data, payment_token or mandate_id"
+                    .to_string(),
+            }))
+        },
+    )
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_pm_or_token_given(
     payment_method: &Option<api_enums::PaymentMethod>,
@@ -2508,7 +3840,9 @@ pub async fn verify_payment_intent_time_and_client_secret(
                 .await
                 .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
-            authenticate_client_secret(Some(&cs), &payment_intent)?;
+            let client_secret_expiry =
+                get_client_secret_expiry(db, &merchant_account.merchant_id).await;
+            authenticate_client_secret(Some(&cs), &payment_intent, client_secret_expiry)?;
             Ok(payment_intent)
         })
         .await
@@ -2580,24 +3914,135 @@ pub fn get_business_details(
             })),
         },
     }
-}
-
-#[inline]
-pub(crate) fn get_payment_id_from_client_secret(cs: &str) -> RouterResult<String> {
-    let (payment_id, _) = cs
-        .rsplit_once("_secret_")
-        .ok_or(errors::ApiErrorResponse::ClientSecretInvalid)
-        .into_report()?;
-    Ok(payment_id.to_string())
-}
-
-#[cfg(test)]
-mod tests {
-
-    use super::*;
+}
+
+#[inline]
+pub(crate) fn get_payment_id_from_client_secret(cs: &str) -> RouterResult<String> {
+    let (payment_id, _) = cs
+        .rsplit_once("_secret_")
+        .ok_or(errors::ApiErrorResponse::ClientSecretInvalid)
+        .into_report()?;
+    Ok(payment_id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_authenticate_client_secret_fulfillment_time_not_expired() {
+        let payment_intent = PaymentIntent {
+            id: 21,
+            payment_id: "23".to_string(),
+            merchant_id: "22".to_string(),
+            status: storage_enums::IntentStatus::RequiresCapture,
+            amount: 200,
+            currency: None,
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            fingerprint_id: None,
+            merchant_order_reference_id: None,
+            off_session: None,
+            client_secret: Some("1".to_string()),
+            active_attempt: data_models::RemoteStorageObject::ForeignID("nopes".to_string()),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            request_incremental_authorization: Some(
+                common_enums::RequestIncrementalAuthorization::default(),
+            ),
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: Some(
+                common_utils::date_time::now()
+                    .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
+            ),
+        };
+        let req_cs = Some("1".to_string());
+        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent, None).is_ok());
+        // Check if the result is an Ok variant
+    }
+
+    #[test]
+    fn test_authenticate_client_secret_client_secret_expiry_expired() {
+        let payment_intent = PaymentIntent {
+            id: 21,
+            payment_id: "23".to_string(),
+            merchant_id: "22".to_string(),
+            status: storage_enums::IntentStatus::RequiresCapture,
+            amount: 200,
+            currency: None,
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now().saturating_sub(time::Duration::seconds(120)),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            fingerprint_id: None,
+            merchant_order_reference_id: None,
+            off_session: None,
+            client_secret: Some("1".to_string()),
+            active_attempt: data_models::RemoteStorageObject::ForeignID("nopes".to_string()),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            request_incremental_authorization: Some(
+                common_enums::RequestIncrementalAuthorization::default(),
+            ),
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: Some(
+                common_utils::date_time::now()
+                    .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
+            ),
+        };
+        let req_cs = Some("1".to_string());
+        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent, Some(60)).is_err());
+    }
 
     #[test]
-    fn test_authenticate_client_secret_fulfillment_time_not_expired() {
+    fn test_authenticate_client_secret_fulfillment_time_expired() {
         let payment_intent = PaymentIntent {
             id: 21,
             payment_id: "23".to_string(),
@@ -2615,11 +4060,12 @@ mod tests {
             billing_address_id: None,
             statement_descriptor_name: None,
             statement_descriptor_suffix: None,
-            created_at: common_utils::date_time::now(),
+            created_at: common_utils::date_time::now().saturating_sub(time::Duration::seconds(20)),
             modified_at: common_utils::date_time::now(),
+            fingerprint_id: None,
+            merchant_order_reference_id: None,
             last_synced: None,
             setup_future_usage: None,
-            fingerprint_id: None,
             off_session: None,
             client_secret: Some("1".to_string()),
             active_attempt: data_models::RemoteStorageObject::ForeignID("nopes".to_string()),
@@ -2647,12 +4093,11 @@ mod tests {
             ),
         };
         let req_cs = Some("1".to_string());
-        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
-        // Check if the result is an Ok variant
+        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent, None).is_err())
     }
 
     #[test]
-    fn test_authenticate_client_secret_fulfillment_time_expired() {
+    fn test_authenticate_client_secret_expired() {
         let payment_intent = PaymentIntent {
             id: 21,
             payment_id: "23".to_string(),
@@ -2672,11 +4117,12 @@ mod tests {
             statement_descriptor_suffix: None,
             created_at: common_utils::date_time::now().saturating_sub(time::Duration::seconds(20)),
             modified_at: common_utils::date_time::now(),
-            fingerprint_id: None,
             last_synced: None,
             setup_future_usage: None,
             off_session: None,
-            client_secret: Some("1".to_string()),
+            client_secret: None,
+            fingerprint_id: None,
+            merchant_order_reference_id: None,
             active_attempt: data_models::RemoteStorageObject::ForeignID("nopes".to_string()),
             business_country: None,
             business_label: None,
@@ -2702,16 +4148,16 @@ mod tests {
             ),
         };
         let req_cs = Some("1".to_string());
-        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
+        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent, None).is_err())
     }
 
     #[test]
-    fn test_authenticate_client_secret_expired() {
+    fn test_validate_profile_id_against_payment_intent() {
         let payment_intent = PaymentIntent {
             id: 21,
             payment_id: "23".to_string(),
             merchant_id: "22".to_string(),
-            status: storage_enums::IntentStatus::RequiresCapture,
+            status: storage_enums::IntentStatus::RequiresPaymentMethod,
             amount: 200,
             currency: None,
             amount_captured: None,
@@ -2724,13 +4170,14 @@ mod tests {
             billing_address_id: None,
             statement_descriptor_name: None,
             statement_descriptor_suffix: None,
-            created_at: common_utils::date_time::now().saturating_sub(time::Duration::seconds(20)),
+            created_at: common_utils::date_time::now(),
             modified_at: common_utils::date_time::now(),
+            fingerprint_id: None,
+            merchant_order_reference_id: None,
             last_synced: None,
             setup_future_usage: None,
             off_session: None,
-            client_secret: None,
-            fingerprint_id: None,
+            client_secret: Some("1".to_string()),
             active_attempt: data_models::RemoteStorageObject::ForeignID("nopes".to_string()),
             business_country: None,
             business_label: None,
@@ -2740,7 +4187,7 @@ mod tests {
             feature_metadata: None,
             attempt_count: 1,
             payment_link_id: None,
-            profile_id: None,
+            profile_id: Some("pro_1".to_string()),
             merchant_decision: None,
             payment_confirm_source: None,
             surcharge_applicable: None,
@@ -2755,8 +4202,498 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
         };
-        let req_cs = Some("1".to_string());
-        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
+        assert!(validate_profile_id_against_payment_intent(None, &payment_intent).is_ok());
+        assert!(validate_profile_id_against_payment_intent(
+            Some(&"pro_1".to_string()),
+            &payment_intent
+        )
+        .is_ok());
+        assert!(validate_profile_id_against_payment_intent(
+            Some(&"pro_2".to_string()),
+            &payment_intent
+        )
+        .is_err());
+    }
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_unique_violation_on_merchant_order_reference_is_duplicate_order_reference() {
+        #[allow(clippy::expect_used)]
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        mockdb.payment_intents.lock().await.push(PaymentIntent {
+            id: 21,
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            status: storage_enums::IntentStatus::RequiresConfirmation,
+            amount: 200,
+            currency: None,
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            fingerprint_id: None,
+            merchant_order_reference_id: Some("order_1".to_string()),
+            last_synced: None,
+            setup_future_usage: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: data_models::RemoteStorageObject::ForeignID("nopes".to_string()),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: None,
+        });
+        let unique_violation = data_models::errors::StorageError::DuplicateValue {
+            entity: "payment_intent",
+            key: None,
+        };
+        let storage_scheme = storage_enums::MerchantStorageScheme::PostgresOnly;
+
+        // The reference is held by another payment
+        assert!(matches!(
+            get_duplicate_order_reference_error(
+                &mockdb,
+                &unique_violation,
+                Some("order_1"),
+                "merchant_1",
+                "pay_2",
+                storage_scheme,
+            )
+            .await
+            .unwrap(),
+            Some(errors::ApiErrorResponse::DuplicateOrderReference { payment_id })
+                if payment_id == "pay_1"
+        ));
+
+        // The violation is on the payment id, the payment holding the reference being the same
+        assert!(get_duplicate_order_reference_error(
+            &mockdb,
+            &unique_violation,
+            Some("order_1"),
+            "merchant_1",
+            "pay_1",
+            storage_scheme,
+        )
+        .await
+        .unwrap()
+        .is_none());
+
+        // The payment carries no reference
+        assert!(get_duplicate_order_reference_error(
+            &mockdb,
+            &unique_violation,
+            None,
+            "merchant_1",
+            "pay_2",
+            storage_scheme,
+        )
+        .await
+        .unwrap()
+        .is_none());
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_confirm_replay_grace_window_of_the_merchant_overrides_the_default() {
+        use crate::db::configs::ConfigInterface;
+
+        #[allow(clippy::expect_used)]
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        mockdb
+            .insert_config(storage::ConfigNew {
+                key: format!(
+                    "{}_merchant_1",
+                    consts::CONFIRM_REPLAY_GRACE_WINDOW_CONFIG_PREFIX
+                ),
+                config: "0".to_string(),
+            })
+            .await
+            .unwrap();
+
+        // The replay can be turned off for a merchant
+        assert_eq!(
+            get_confirm_replay_grace_window(&mockdb, "merchant_1", 300).await,
+            0
+        );
+        assert_eq!(
+            get_confirm_replay_grace_window(&mockdb, "merchant_2", 300).await,
+            300
+        );
+    }
+
+    fn new_payment_intent_with_status(status: storage_enums::IntentStatus) -> PaymentIntent {
+        PaymentIntent {
+            id: 21,
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            status,
+            amount: 200,
+            currency: None,
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            fingerprint_id: None,
+            merchant_order_reference_id: None,
+            off_session: None,
+            client_secret: Some("pay_1_secret".to_string()),
+            active_attempt: data_models::RemoteStorageObject::ForeignID("pay_1_1".to_string()),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: None,
+        }
+    }
+
+    #[test]
+    fn test_confirm_replay_grace_window() {
+        let succeeded_at = common_utils::date_time::now();
+        let within_window = succeeded_at.saturating_add(time::Duration::seconds(60));
+        let client_secret = "pay_1_secret".to_string();
+        let payment_intent = new_payment_intent_with_status(storage_enums::IntentStatus::Succeeded);
+
+        assert!(is_within_confirm_replay_grace_window(
+            &payment_intent,
+            Some(succeeded_at),
+            &client_secret,
+            300,
+            within_window,
+        ));
+
+        // Only a succeeded payment is replayed
+        assert!(!is_within_confirm_replay_grace_window(
+            &new_payment_intent_with_status(storage_enums::IntentStatus::Processing),
+            Some(succeeded_at),
+            &client_secret,
+            300,
+            within_window,
+        ));
+        // The active attempt is not charged
+        assert!(!is_within_confirm_replay_grace_window(
+            &payment_intent,
+            None,
+            &client_secret,
+            300,
+            within_window,
+        ));
+        // The client secret of another payment
+        assert!(!is_within_confirm_replay_grace_window(
+            &payment_intent,
+            Some(succeeded_at),
+            &"pay_2_secret".to_string(),
+            300,
+            within_window,
+        ));
+        // The grace window passed
+        assert!(!is_within_confirm_replay_grace_window(
+            &payment_intent,
+            Some(succeeded_at),
+            &client_secret,
+            30,
+            within_window,
+        ));
+        // The replay is turned off
+        assert!(!is_within_confirm_replay_grace_window(
+            &payment_intent,
+            Some(succeeded_at),
+            &client_secret,
+            0,
+            succeeded_at,
+        ));
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[test]
+    fn test_payment_method_type_allowed() {
+        let mut payment_intent =
+            new_payment_intent_with_status(storage_enums::IntentStatus::RequiresConfirmation);
+        payment_intent.allowed_payment_method_types = Some(serde_json::json!(["credit"]));
+
+        assert!(validate_payment_method_type_allowed(
+            &payment_intent,
+            Some(api_enums::PaymentMethodType::Credit)
+        )
+        .is_ok());
+        // Card confirms and confirms with a saved payment method can omit the type
+        assert!(validate_payment_method_type_allowed(&payment_intent, None).is_ok());
+
+        let error = validate_payment_method_type_allowed(
+            &payment_intent,
+            Some(api_enums::PaymentMethodType::GooglePay),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PaymentMethodTypeNotAllowed { .. }
+        ));
+
+        // Any type is allowed when the allowed types are not set
+        payment_intent.allowed_payment_method_types = None;
+        assert!(validate_payment_method_type_allowed(
+            &payment_intent,
+            Some(api_enums::PaymentMethodType::GooglePay)
+        )
+        .is_ok());
+    }
+
+    #[allow(clippy::unwrap_used, clippy::expect_used)]
+    #[actix_rt::test]
+    async fn test_preferred_3ds_version_enforcement() {
+        use tokio::sync::oneshot;
+
+        use crate::db::StorageImpl;
+
+        let mut conf = settings::Settings::new().expect("invalid settings");
+        conf.three_ds_version = settings::ThreeDsVersionConfig {
+            connectors_with_3ds2: [api_enums::Connector::Stripe].into_iter().collect(),
+            default_enforcement: settings::ThreeDsVersionEnforcement::Hard,
+        };
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let api_client = Box::new(services::MockApiClient);
+        let state = AppState::with_storage(conf, StorageImpl::Mock, tx, api_client).await;
+        state
+            .store
+            .insert_config(storage::ConfigNew {
+                key: format!(
+                    "{}_merchant_soft",
+                    consts::THREE_DS_VERSION_ENFORCEMENT_CONFIG_PREFIX
+                ),
+                config: "\"soft\"".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(validate_preferred_3ds_version(
+            &state,
+            "merchant_hard",
+            "stripe",
+            api_enums::ThreeDsVersion::V2
+        )
+        .await
+        .unwrap());
+        assert!(validate_preferred_3ds_version(
+            &state,
+            "merchant_hard",
+            "adyen",
+            api_enums::ThreeDsVersion::V1
+        )
+        .await
+        .unwrap());
+
+        // A V2 preference on a V1-only connector is rejected under the default hard policy
+        let error = validate_preferred_3ds_version(
+            &state,
+            "merchant_hard",
+            "adyen",
+            api_enums::ThreeDsVersion::V2,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::NotSupported { .. }
+        ));
+
+        // and lets the payment through without the preference under a soft policy
+        assert!(!validate_preferred_3ds_version(
+            &state,
+            "merchant_soft",
+            "adyen",
+            api_enums::ThreeDsVersion::V2
+        )
+        .await
+        .unwrap());
+    }
+
+    #[test]
+    fn test_statement_descriptor_compliance() {
+        let constraint = settings::StatementDescriptorConstraint {
+            max_length: 5,
+            alphanumeric_only: true,
+        };
+
+        assert!(is_statement_descriptor_compliant("ACME1", &constraint));
+        assert!(is_statement_descriptor_compliant("AC ME", &constraint));
+        assert!(!is_statement_descriptor_compliant("ACME12", &constraint));
+        assert!(!is_statement_descriptor_compliant("AC*ME", &constraint));
+        assert!(!is_statement_descriptor_compliant("CAFÉ", &constraint));
+
+        // The length is counted in characters, not bytes
+        let constraint = settings::StatementDescriptorConstraint {
+            max_length: 5,
+            alphanumeric_only: false,
+        };
+        assert!(is_statement_descriptor_compliant("CAFÉ*", &constraint));
+        assert!(is_statement_descriptor_compliant("日本の店舗", &constraint));
+        assert!(!is_statement_descriptor_compliant(
+            "日本の店舗だ",
+            &constraint
+        ));
+    }
+
+    #[test]
+    fn test_sanitize_statement_descriptor() {
+        let constraint = settings::StatementDescriptorConstraint {
+            max_length: 5,
+            alphanumeric_only: true,
+        };
+
+        assert_eq!(sanitize_statement_descriptor("ACME1", &constraint), "ACME1");
+        assert_eq!(
+            sanitize_statement_descriptor("ACME12", &constraint),
+            "ACME1"
+        );
+        // The characters outside the charset are removed before the descriptor is truncated
+        assert_eq!(
+            sanitize_statement_descriptor("A*C-M.E 12", &constraint),
+            "ACME "
+        );
+        assert_eq!(
+            sanitize_statement_descriptor("CAFÉ 12", &constraint),
+            "CAF 1"
+        );
+
+        let constraint = settings::StatementDescriptorConstraint {
+            max_length: 5,
+            alphanumeric_only: false,
+        };
+        assert_eq!(
+            sanitize_statement_descriptor("CAFÉ*12", &constraint),
+            "CAFÉ*"
+        );
+        assert_eq!(
+            sanitize_statement_descriptor("日本の店舗だ", &constraint),
+            "日本の店舗"
+        );
+
+        // A sanitized descriptor is always compliant
+        for descriptor in ["ACME*STORE", "CAFÉ 12", "日本の店舗だ", ""] {
+            for alphanumeric_only in [true, false] {
+                let constraint = settings::StatementDescriptorConstraint {
+                    max_length: 5,
+                    alphanumeric_only,
+                };
+                assert!(is_statement_descriptor_compliant(
+                    &sanitize_statement_descriptor(descriptor, &constraint),
+                    &constraint
+                ));
+            }
+        }
+    }
+
+    fn new_order_details(
+        amount: i64,
+        quantity: u16,
+        tax_amount: Option<i64>,
+    ) -> api_models::payments::OrderDetailsWithAmount {
+        api_models::payments::OrderDetailsWithAmount {
+            product_name: "shirt".to_string(),
+            quantity,
+            amount,
+            tax_amount,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_order_details_tax_breakdown() {
+        // Without a tax breakdown the order details are not checked against the amount
+        assert!(validate_order_details_tax_breakdown(
+            &[
+                new_order_details(500, 2, None),
+                new_order_details(100, 1, None)
+            ],
+            6000
+        )
+        .is_ok());
+        assert!(validate_order_details_tax_breakdown(&[], 6000).is_ok());
+
+        // The amounts and taxes add up to the amount, a missing tax counting as zero
+        assert!(validate_order_details_tax_breakdown(
+            &[
+                new_order_details(500, 2, Some(100)),
+                new_order_details(100, 1, None)
+            ],
+            1200
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_order_details_tax_breakdown_is_rejected() {
+        let invalid_order_details = [
+            // The total does not match the amount
+            (
+                vec![
+                    new_order_details(500, 2, Some(100)),
+                    new_order_details(100, 1, None),
+                ],
+                1100,
+            ),
+            // Negative tax
+            (vec![new_order_details(1200, 1, Some(-200))], 1000),
+            // Negative amount
+            (vec![new_order_details(-100, 1, Some(1100))], 1000),
+            // Zero quantity
+            (vec![new_order_details(1000, 0, Some(0))], 0),
+            // Overflow
+            (vec![new_order_details(i64::MAX, 2, Some(0))], 1000),
+        ];
+
+        for (order_details, amount) in invalid_order_details {
+            let result = validate_order_details_tax_breakdown(&order_details, amount);
+            assert!(matches!(
+                result.as_ref().map_err(|err| err.current_context()),
+                Err(errors::ApiErrorResponse::InvalidRequestData { .. })
+            ));
+        }
     }
 }
 
@@ -3172,6 +5109,18 @@ impl AttemptType {
             unified_code: None,
             unified_message: None,
             net_amount: old_payment_attempt.amount,
+            // The merchant initiated transaction still refers to the same customer initiated transaction
+            network_transaction_id: old_payment_attempt.network_transaction_id,
+            connector_fallback_index: None,
+            routed_to_unhealthy_connector: None,
+            installment_plan: None,
+            connector_idempotency_key: None,
+            frm_score: None,
+            frm_score_threshold: None,
+            customer_acceptance: None,
+            correlation_id: None,
+            error_classification: None,
+            authentication_escalation_reason: None,
         }
     }
 
@@ -3313,6 +5262,132 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_validate_return_url() {
+        let default_allowlist = api_models::admin::ReturnUrlAllowlist::default();
+        assert!(
+            super::validate_return_url("https://example.com/return", &default_allowlist).is_ok()
+        );
+        assert!(
+            super::validate_return_url("http://example.com/return", &default_allowlist).is_ok()
+        );
+        assert!(super::validate_return_url("javascript:alert(1)", &default_allowlist).is_err());
+        assert!(super::validate_return_url("not a url", &default_allowlist).is_err());
+
+        let https_only_allowlist = api_models::admin::ReturnUrlAllowlist {
+            https_only: true,
+            ..Default::default()
+        };
+        assert!(
+            super::validate_return_url("https://example.com/return", &https_only_allowlist).is_ok()
+        );
+        assert!(
+            super::validate_return_url("http://example.com/return", &https_only_allowlist).is_err()
+        );
+
+        let host_allowlist = api_models::admin::ReturnUrlAllowlist {
+            disabled: false,
+            https_only: false,
+            allowed_schemes: Some(vec!["https".to_string(), "myapp".to_string()]),
+            allowed_hosts: Some(vec!["example.com".to_string(), "*.shop.com".to_string()]),
+        };
+        assert!(super::validate_return_url("https://example.com", &host_allowlist).is_ok());
+        assert!(super::validate_return_url("myapp://example.com/done", &host_allowlist).is_ok());
+        assert!(super::validate_return_url("https://eu.shop.com", &host_allowlist).is_ok());
+        assert!(super::validate_return_url("https://EU.Shop.com", &host_allowlist).is_ok());
+        assert!(super::validate_return_url("myapp://EU.SHOP.COM/done", &host_allowlist).is_ok());
+        assert!(super::validate_return_url("https://evilshop.com", &host_allowlist).is_err());
+        assert!(super::validate_return_url("https://attacker.com", &host_allowlist).is_err());
+
+        let disabled_allowlist = api_models::admin::ReturnUrlAllowlist {
+            disabled: true,
+            ..Default::default()
+        };
+        assert!(super::validate_return_url("http://localhost:8080", &disabled_allowlist).is_ok());
+    }
+
+    #[test]
+    fn test_validate_payment_payload_limits() {
+        let limits = crate::configs::settings::PaymentPayloadLimits {
+            max_field_size_in_bytes: 64,
+            max_field_key_count: 3,
+        };
+
+        let request = api_models::payments::PaymentsRequest {
+            metadata: Some(masking::Secret::new(
+                serde_json::json!({"order": {"id": "ord_1"}}),
+            )),
+            ..Default::default()
+        };
+        assert!(super::validate_payment_payload_limits(&request, &limits).is_ok());
+
+        let request = api_models::payments::PaymentsRequest {
+            metadata: Some(masking::Secret::new(
+                serde_json::json!({"a": 1, "b": {"c": 2, "d": 3}}),
+            )),
+            ..Default::default()
+        };
+        assert!(super::validate_payment_payload_limits(&request, &limits).is_err());
+
+        let request = api_models::payments::PaymentsRequest {
+            metadata: Some(masking::Secret::new(
+                serde_json::json!({"note": "x".repeat(100)}),
+            )),
+            ..Default::default()
+        };
+        assert!(super::validate_payment_payload_limits(&request, &limits).is_err());
+    }
+
+    #[test]
+    fn test_is_payment_held_for_review() {
+        use super::storage_enums::{FraudCheckStatus, IntentStatus};
+
+        assert!(super::is_payment_held_for_review(
+            IntentStatus::RequiresMerchantAction,
+            None,
+            Some(FraudCheckStatus::ManualReview),
+        ));
+        // A held authorization which was synced with the connector
+        assert!(super::is_payment_held_for_review(
+            IntentStatus::RequiresCapture,
+            None,
+            Some(FraudCheckStatus::Fraud),
+        ));
+        // An authorization which the fraud check let through
+        assert!(!super::is_payment_held_for_review(
+            IntentStatus::RequiresCapture,
+            None,
+            Some(FraudCheckStatus::Legit),
+        ));
+        // A payment which was already reviewed
+        assert!(!super::is_payment_held_for_review(
+            IntentStatus::RequiresMerchantAction,
+            Some("approved"),
+            Some(FraudCheckStatus::ManualReview),
+        ));
+        assert!(!super::is_payment_held_for_review(
+            IntentStatus::Succeeded,
+            None,
+            Some(FraudCheckStatus::ManualReview),
+        ));
+    }
+
+    #[test]
+    fn test_should_capture_on_approval() {
+        use super::storage_enums::CaptureMethod;
+
+        assert!(super::should_capture_on_approval(None));
+        assert!(super::should_capture_on_approval(Some(
+            CaptureMethod::Automatic
+        )));
+        assert!(!super::should_capture_on_approval(Some(
+            CaptureMethod::Manual
+        )));
+        assert!(!super::should_capture_on_approval(Some(
+            CaptureMethod::ManualMultiple
+        )));
+    }
 }
 
 #[instrument(skip_all)]
@@ -3444,6 +5519,12 @@ pub async fn get_additional_payment_data(
         api_models::payments::PaymentMethodData::CardToken(_) => {
             api_models::payments::AdditionalPaymentData::CardToken {}
         }
+        api_models::payments::PaymentMethodData::NetworkToken(network_token_data) => {
+            api_models::payments::AdditionalPaymentData::NetworkToken {
+                last4: Some(network_token_data.network_token.clone().get_last4()),
+                card_network: network_token_data.card_network.clone(),
+            }
+        }
     }
 }
 
@@ -3451,15 +5532,224 @@ pub fn validate_customer_access(
     payment_intent: &PaymentIntent,
     auth_flow: services::AuthFlow,
     request: &api::PaymentsRequest,
+    is_off_session_confirm: bool,
 ) -> Result<(), errors::ApiErrorResponse> {
-    if auth_flow == services::AuthFlow::Client && request.customer_id.is_some() {
-        let is_same_customer = request.customer_id == payment_intent.customer_id;
-        if !is_same_customer {
+    let is_same_customer = request.customer_id == payment_intent.customer_id;
+    match auth_flow {
+        services::AuthFlow::Client if request.customer_id.is_some() && !is_same_customer => {
             Err(errors::ApiErrorResponse::GenericUnauthorized {
                 message: "Unauthorised access to update customer".to_string(),
-            })?;
+            })
+        }
+        // The customer is not present to authorize an off session confirm, so the payment can
+        // only be charged to the customer it was created for
+        services::AuthFlow::Merchant
+            if is_off_session_confirm
+                && request.customer_id.is_some()
+                && payment_intent.customer_id.is_some()
+                && !is_same_customer =>
+        {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`customer_id` cannot be updated by an off session confirm".to_string(),
+            })
+        }
+        services::AuthFlow::Client | services::AuthFlow::Merchant => Ok(()),
+    }
+}
+
+/// Whether the confirm is a server initiated off session confirm, which is authenticated by the
+/// API key of the merchant instead of the client secret, and has no customer present
+pub fn is_off_session_confirm(
+    payment_intent: &PaymentIntent,
+    auth_flow: services::AuthFlow,
+    request: &api::PaymentsRequest,
+    mandate_type: Option<&api::MandateTransactionType>,
+) -> bool {
+    auth_flow == services::AuthFlow::Merchant
+        && request.client_secret.is_none()
+        && request.off_session.or(payment_intent.off_session) == Some(true)
+        && !matches!(
+            mandate_type,
+            Some(api::MandateTransactionType::NewMandateTransaction)
+        )
+}
+
+/// Rejects off session confirms which neither charge a mandate nor refer to the network
+/// transaction id of the customer initiated transaction
+pub fn validate_mandate_for_off_session_confirm(
+    request: &api::PaymentsRequest,
+    mandate_type: Option<&api::MandateTransactionType>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let is_recurring_mandate_transaction = matches!(
+        mandate_type,
+        Some(api::MandateTransactionType::RecurringMandateTransaction)
+    );
+    fp_utils::when(
+        !is_recurring_mandate_transaction && request.network_transaction_id.is_none(),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "`mandate_id` is required for an off session confirm without the client secret"
+                        .to_string(),
+            })
+        },
+    )
+}
+
+pub fn validate_return_url(
+    return_url: &str,
+    return_url_allowlist: &api_models::admin::ReturnUrlAllowlist,
+) -> Result<(), errors::ApiErrorResponse> {
+    if return_url_allowlist.disabled {
+        return Ok(());
+    }
+
+    let url =
+        url::Url::parse(return_url).map_err(|_| errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "return_url",
+        })?;
+
+    let is_scheme_allowed = match &return_url_allowlist.allowed_schemes {
+        Some(allowed_schemes) => allowed_schemes
+            .iter()
+            .any(|scheme| scheme.eq_ignore_ascii_case(url.scheme())),
+        None if return_url_allowlist.https_only => url.scheme() == "https",
+        None => matches!(url.scheme(), "https" | "http"),
+    };
+
+    // The host is only lowercased by the url parser for the special schemes such as https, the
+    // hosts of the app schemes are kept as is
+    let is_host_allowed = match url.host_str().map(str::to_ascii_lowercase) {
+        Some(host) if !host.is_empty() => {
+            return_url_allowlist
+                .allowed_hosts
+                .as_ref()
+                .map_or(true, |allowed_hosts| {
+                    allowed_hosts
+                        .iter()
+                        .map(|allowed_host| allowed_host.to_ascii_lowercase())
+                        .any(|allowed_host| match allowed_host.strip_prefix("*.") {
+                            Some(domain) => host
+                                .strip_suffix(domain)
+                                .map_or(false, |subdomain| subdomain.ends_with('.')),
+                            None => allowed_host == host,
+                        })
+                })
+        }
+        _ => false,
+    };
+
+    if is_scheme_allowed && is_host_allowed {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "return_url",
+        })
+    }
+}
+
+pub fn validate_profile_id_against_payment_intent(
+    request_profile_id: Option<&String>,
+    payment_intent: &PaymentIntent,
+) -> Result<(), errors::ApiErrorResponse> {
+    match request_profile_id {
+        Some(request_profile_id)
+            if payment_intent.profile_id.as_ref() != Some(request_profile_id) =>
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The payment does not belong to the business profile '{request_profile_id}', it belongs to the business profile '{}'",
+                    payment_intent.profile_id.as_deref().unwrap_or_default()
+                ),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether a payment is held for a manual review by the fraud check and was not reviewed yet.
+/// The authorization of such a payment is held at the connector, leaving the payment in
+/// `RequiresMerchantAction`, or in `RequiresCapture` once it was synced with the connector.
+pub fn is_payment_held_for_review(
+    intent_status: storage_enums::IntentStatus,
+    merchant_decision: Option<&str>,
+    frm_status: Option<storage_enums::FraudCheckStatus>,
+) -> bool {
+    let is_flagged_by_fraud_check = matches!(
+        frm_status,
+        Some(storage_enums::FraudCheckStatus::ManualReview)
+            | Some(storage_enums::FraudCheckStatus::Fraud)
+    );
+
+    merchant_decision.is_none()
+        && match intent_status {
+            storage_enums::IntentStatus::RequiresMerchantAction => true,
+            storage_enums::IntentStatus::RequiresCapture => is_flagged_by_fraud_check,
+            _ => false,
+        }
+}
+
+/// Whether approving a payment held for a review captures it. The capture of a payment held for a
+/// review is deferred, so that the capture method requested for the payment applies once approved.
+pub fn should_capture_on_approval(capture_method: Option<storage_enums::CaptureMethod>) -> bool {
+    matches!(
+        capture_method,
+        None | Some(storage_enums::CaptureMethod::Automatic)
+            | Some(storage_enums::CaptureMethod::Scheduled)
+    )
+}
+
+/// Records the reviewer on the fraud check of a payment held for a manual review, before the
+/// decision of the reviewer is carried out. Nothing is recorded for payments which are not held
+/// for a review.
+#[instrument(skip_all)]
+pub async fn record_frm_manual_review(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+    reviewed_by: String,
+) -> RouterResult<()> {
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let fraud_check = db
+        .find_fraud_check_by_payment_id_if_present(
+            payment_id.to_owned(),
+            merchant_account.merchant_id.clone(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the fraud check of the payment")?;
+
+    match fraud_check {
+        Some(fraud_check)
+            if is_payment_held_for_review(
+                payment_intent.status,
+                payment_intent.merchant_decision.as_deref(),
+                Some(fraud_check.frm_status),
+            ) =>
+        {
+            let last_step = fraud_check.last_step;
+            db.update_fraud_check_response_with_attempt_id(
+                fraud_check,
+                storage::FraudCheckUpdate::ManualReviewUpdate {
+                    reviewed_by,
+                    last_step,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the reviewer of the fraud check")?;
         }
+        _ => logger::debug!("Payment is not held for a review, the reviewer is not recorded"),
     }
+
     Ok(())
 }
 
@@ -3697,6 +5987,80 @@ impl ApplePayData {
     }
 }
 
+/// Calculates the surcharge of the payment from the surcharge decision config of the merchant, for
+/// the payment method resolved on confirm. Payments which already carry a surcharge are not
+/// surcharged again.
+pub async fn calculate_surcharge_details_on_confirm(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+    payment_method_data: Option<&api_models::payments::PaymentMethodData>,
+    billing_address: Option<api_models::payments::Address>,
+) -> RouterResult<Option<payments::types::SurchargeDetails>> {
+    if payment_attempt.surcharge_amount.is_some() {
+        return Ok(None);
+    }
+
+    let surcharge_key_params =
+        match payment_method_data.and_then(get_key_params_for_surcharge_details) {
+            Some(surcharge_key_params) => surcharge_key_params,
+            None => return Ok(None),
+        };
+
+    let algorithm_ref: api_models::routing::RoutingAlgorithmRef = merchant_account
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("routing algorithm"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode the routing algorithm")?
+        .unwrap_or_default();
+
+    surcharge_decision_configs::perform_surcharge_decision_management_for_payment(
+        state,
+        algorithm_ref,
+        payment_attempt,
+        payment_intent,
+        billing_address,
+        surcharge_key_params,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to calculate the surcharge of the payment")
+}
+
+/// Validates the surcharged total of the payment against the surcharge sent by the client on
+/// confirm, so that the customer is not charged a total other than the one shown to them
+pub fn validate_surcharged_amount(
+    request_surcharge_details: Option<RequestSurchargeDetails>,
+    payment_attempt: &PaymentAttempt,
+    surcharge_details: Option<&payments::types::SurchargeDetails>,
+) -> RouterResult<()> {
+    let request_surcharge_details = match request_surcharge_details {
+        Some(request_surcharge_details) => request_surcharge_details,
+        None => return Ok(()),
+    };
+
+    let expected_amount =
+        payment_attempt.amount + request_surcharge_details.get_total_surcharge_amount();
+    let surcharged_amount = surcharge_details
+        .map(|surcharge_details| surcharge_details.final_amount)
+        .unwrap_or(
+            payment_attempt.amount
+                + payment_attempt.surcharge_amount.unwrap_or(0)
+                + payment_attempt.tax_amount.unwrap_or(0),
+        );
+
+    utils::when(expected_amount != surcharged_amount, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The surcharged amount {surcharged_amount} does not match the expected amount {expected_amount}"
+            )
+        }))
+    })
+}
+
 pub fn get_key_params_for_surcharge_details(
     payment_method_data: &api_models::payments::PaymentMethodData,
 ) -> Option<(
@@ -3766,6 +6130,11 @@ pub fn get_key_params_for_surcharge_details(
             gift_card.get_payment_method_type(),
             None,
         )),
+        api_models::payments::PaymentMethodData::NetworkToken(network_token_data) => Some((
+            common_enums::PaymentMethod::Card,
+            common_enums::PaymentMethodType::Credit,
+            network_token_data.card_network.clone(),
+        )),
         api_models::payments::PaymentMethodData::CardToken(_) => None,
     }
 }
@@ -3828,6 +6197,189 @@ pub async fn get_gsm_record(
         .ok()
 }
 
+/// Classifies the error returned by the connector, using the classification configured for the
+/// connector and the error code when present. Otherwise, rate limiting and unavailability of the
+/// connector are retryable, while timeouts, server errors and conflicts leave the outcome of the
+/// payment unknown and need to be reviewed before retrying.
+pub fn classify_connector_error(
+    state: &AppState,
+    connector_name: &str,
+    error_response: &types::ErrorResponse,
+) -> api_enums::ConnectorErrorClassification {
+    let configured_classification = state
+        .conf
+        .connector_error_classification
+        .0
+        .get(connector_name)
+        .and_then(|classification| classification.get(&error_response.code))
+        .copied();
+
+    configured_classification.unwrap_or(
+        if error_response.code == consts::REQUEST_TIMEOUT_ERROR_CODE {
+            api_enums::ConnectorErrorClassification::NeedsReview
+        } else {
+            match error_response.status_code {
+                429 | 503 => api_enums::ConnectorErrorClassification::Retryable,
+                409 | 500..=511 => api_enums::ConnectorErrorClassification::NeedsReview,
+                _ => api_enums::ConnectorErrorClassification::NonRetryable,
+            }
+        },
+    )
+}
+
+/// Validates that the merchant order reference is not already used by another payment of the
+/// merchant. The existing payment is returned in the error so that the merchant can reconcile it.
+/// The payment intents of the merchants on the KV scheme are only in redis until they are
+/// drained, so for them the reference is also reserved in redis for the payment
+pub async fn validate_merchant_order_reference_id(
+    state: &AppState,
+    merchant_order_reference_id: &str,
+    merchant_id: &str,
+    payment_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    utils::when(
+        merchant_order_reference_id.len() > consts::MERCHANT_ORDER_REFERENCE_ID_MAX_LENGTH,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "merchant_order_reference_id must be at most {} characters long",
+                    consts::MERCHANT_ORDER_REFERENCE_ID_MAX_LENGTH
+                ),
+            })
+        },
+    )?;
+
+    if let Some(existing_payment_id) = find_other_payment_with_merchant_order_reference_id(
+        &*state.store,
+        merchant_order_reference_id,
+        merchant_id,
+        payment_id,
+        storage_scheme,
+    )
+    .await?
+    {
+        return Err(report!(errors::ApiErrorResponse::DuplicateOrderReference {
+            payment_id: existing_payment_id,
+        }));
+    }
+
+    match storage_scheme {
+        storage_enums::MerchantStorageScheme::RedisKv => {
+            reserve_merchant_order_reference_id(
+                state,
+                merchant_order_reference_id,
+                merchant_id,
+                payment_id,
+            )
+            .await
+        }
+        storage_enums::MerchantStorageScheme::PostgresOnly => Ok(()),
+    }
+}
+
+/// Reserves the merchant order reference for the payment with a `SETNX`, so that two payments
+/// racing for the same reference can't both get it. The reservation lasts as long as the payment
+/// intent is kept in redis, after which the payment intent is found in the database
+async fn reserve_merchant_order_reference_id(
+    state: &AppState,
+    merchant_order_reference_id: &str,
+    merchant_id: &str,
+    payment_id: &str,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let redis_key = format!("merchant_order_reference_{merchant_id}_{merchant_order_reference_id}");
+    let reservation = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            redis_key.as_str(),
+            payment_id.to_string(),
+            Some(i64::from(state.conf.kv_config.ttl)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reserve the merchant order reference")?;
+
+    match reservation {
+        redis_interface::SetnxReply::KeySet => Ok(()),
+        redis_interface::SetnxReply::KeyNotSet => {
+            let reserved_payment_id = redis_conn
+                .get_key::<String>(redis_key.as_str())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to get the reservation of the merchant order reference",
+                )?;
+
+            // The payment already holding the reservation is retrying with the same reference
+            utils::when(reserved_payment_id != payment_id, || {
+                Err(errors::ApiErrorResponse::DuplicateOrderReference {
+                    payment_id: reserved_payment_id.clone(),
+                })
+            })
+        }
+    }
+}
+
+/// Finds the payment, other than the given one, which uses the merchant order reference
+async fn find_other_payment_with_merchant_order_reference_id(
+    db: &dyn StorageInterface,
+    merchant_order_reference_id: &str,
+    merchant_id: &str,
+    payment_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<Option<String>> {
+    let existing_payment_intent = db
+        .find_optional_payment_intent_by_merchant_order_reference_id_merchant_id(
+            merchant_order_reference_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding payment intent by merchant order reference id")?;
+
+    Ok(existing_payment_intent
+        .map(|payment_intent| payment_intent.payment_id)
+        .filter(|existing_payment_id| existing_payment_id != payment_id))
+}
+
+/// Gets the error for a unique violation on writing a payment intent with a merchant order
+/// reference. The violation is on the merchant order reference when another payment uses it, in
+/// which case `DuplicateOrderReference` is returned, rather than the error of the caller
+pub async fn get_duplicate_order_reference_error(
+    db: &dyn StorageInterface,
+    error: &data_models::errors::StorageError,
+    merchant_order_reference_id: Option<&str>,
+    merchant_id: &str,
+    payment_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<Option<errors::ApiErrorResponse>> {
+    match (error, merchant_order_reference_id) {
+        (
+            data_models::errors::StorageError::DuplicateValue { .. },
+            Some(merchant_order_reference_id),
+        ) => Ok(find_other_payment_with_merchant_order_reference_id(
+            db,
+            merchant_order_reference_id,
+            merchant_id,
+            payment_id,
+            storage_scheme,
+        )
+        .await?
+        .map(
+            |existing_payment_id| errors::ApiErrorResponse::DuplicateOrderReference {
+                payment_id: existing_payment_id,
+            },
+        )),
+        _ => Ok(None),
+    }
+}
+
 pub fn validate_order_details_amount(
     order_details: Vec<api_models::payments::OrderDetailsWithAmount>,
     amount: i64,
@@ -3852,6 +6404,123 @@ pub fn validate_order_details_amount(
     }
 }
 
+/// Validates the products of the order when their tax breakdown is provided, so that connectors
+/// accepting enhanced (Level 2/3) data receive products which reconcile to the payment amount.
+/// Products without any tax breakdown are passed through to the connectors as they are.
+pub fn validate_order_details_tax_breakdown(
+    order_details: &[api_models::payments::OrderDetailsWithAmount],
+    amount: i64,
+) -> RouterResult<()> {
+    if order_details.iter().all(|order| order.tax_amount.is_none()) {
+        return Ok(());
+    }
+
+    let mut total_order_details_amount: i64 = 0;
+    for (index, order) in order_details.iter().enumerate() {
+        let tax_amount = order.tax_amount.unwrap_or_default();
+
+        utils::when(order.quantity == 0, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("order_details[{index}].quantity must be greater than 0"),
+            }))
+        })?;
+        utils::when(order.amount < 0 || tax_amount < 0, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("order_details[{index}] must not have a negative amount or tax"),
+            }))
+        })?;
+
+        total_order_details_amount = order
+            .amount
+            .checked_mul(i64::from(order.quantity))
+            .and_then(|order_amount| order_amount.checked_add(tax_amount))
+            .and_then(|order_amount| total_order_details_amount.checked_add(order_amount))
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Total of order details is too large".to_string(),
+            })
+            .into_report()?;
+    }
+
+    utils::when(total_order_details_amount != amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Total of order details including tax ({total_order_details_amount}) doesn't match amount in payment request ({amount})"
+            ),
+        }))
+    })
+}
+
+/// Validates that the metadata, order_details and connector_metadata fields of the request are
+/// within the configured size and key count limits, before they are persisted in the payment intent
+pub fn validate_payment_payload_limits(
+    request: &api::PaymentsRequest,
+    limits: &PaymentPayloadLimits,
+) -> Result<(), errors::ApiErrorResponse> {
+    let metadata = request
+        .metadata
+        .as_ref()
+        .map(|metadata| metadata.peek().clone());
+    let order_details = request
+        .get_order_details_as_value()
+        .map_err(|_| errors::ApiErrorResponse::InternalServerError)?
+        .map(|order_details| {
+            serde_json::Value::Array(
+                order_details
+                    .into_iter()
+                    .map(|order| order.expose())
+                    .collect(),
+            )
+        });
+    let connector_metadata = request
+        .get_connector_metadata_as_value()
+        .map_err(|_| errors::ApiErrorResponse::InternalServerError)?;
+
+    [
+        ("metadata", metadata),
+        ("order_details", order_details),
+        ("connector_metadata", connector_metadata),
+    ]
+    .into_iter()
+    .filter_map(|(field_name, value)| value.map(|value| (field_name, value)))
+    .try_for_each(|(field_name, value)| {
+        let size_in_bytes = serde_json::to_vec(&value)
+            .map_err(|_| errors::ApiErrorResponse::InternalServerError)?
+            .len();
+        if size_in_bytes > limits.max_field_size_in_bytes {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{field_name} must not exceed {} bytes",
+                    limits.max_field_size_in_bytes
+                ),
+            });
+        }
+
+        if count_json_keys(&value) > limits.max_field_key_count {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{field_name} must not contain more than {} keys",
+                    limits.max_field_key_count
+                ),
+            });
+        }
+
+        Ok(())
+    })
+}
+
+fn count_json_keys(value: &serde_json::Value) -> usize {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.values().map(|value| 1 + count_json_keys(value)).sum()
+        }
+        serde_json::Value::Array(values) => values.iter().map(count_json_keys).sum(),
+        serde_json::Value::Null
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::String(_) => 0,
+    }
+}
+
 // This function validates the client secret expiry set by the merchant in the request
 pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErrorResponse> {
     if !(consts::MIN_SESSION_EXPIRY..=consts::MAX_SESSION_EXPIRY).contains(&session_expiry) {
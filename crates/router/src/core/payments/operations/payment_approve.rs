@@ -21,7 +21,7 @@ use crate::{
         domain,
         storage::{self, enums as storage_enums},
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
@@ -57,13 +57,31 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-        helpers::validate_payment_status_against_not_allowed_statuses(
-            &payment_intent.status,
-            &[
-                storage_enums::IntentStatus::Failed,
-                storage_enums::IntentStatus::Succeeded,
-            ],
-            "approve",
+        let attempt_id = payment_intent.active_attempt.get_id().clone();
+        let frm_response = db
+        .find_fraud_check_by_payment_id(payment_intent.payment_id.clone(), merchant_account.merchant_id.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable_lazy(|| {
+            format!("Error while retrieving frm_response, merchant_id: {}, payment_id: {attempt_id}", &merchant_account.merchant_id)
+        });
+
+        // Only payments held for a manual review, and not reviewed yet, can be approved
+        utils::when(
+            !helpers::is_payment_held_for_review(
+                payment_intent.status,
+                payment_intent.merchant_decision.as_deref(),
+                frm_response
+                    .as_ref()
+                    .ok()
+                    .map(|fraud_check| fraud_check.frm_status),
+            ),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "You cannot approve this payment because it is not held for a review"
+                        .to_string(),
+                })
+            },
         )?;
 
         let profile_id = payment_intent
@@ -81,7 +99,6 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 id: profile_id.to_string(),
             })?;
 
-        let attempt_id = payment_intent.active_attempt.get_id().clone();
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                 &payment_intent.payment_id,
@@ -121,14 +138,6 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         payment_intent.shipping_address_id = shipping_address.clone().map(|i| i.address_id);
         payment_intent.billing_address_id = billing_address.clone().map(|i| i.address_id);
 
-        let frm_response = db
-        .find_fraud_check_by_payment_id(payment_intent.payment_id.clone(), merchant_account.merchant_id.clone())
-        .await
-        .change_context(errors::ApiErrorResponse::PaymentNotFound)
-        .attach_printable_lazy(|| {
-            format!("Error while retrieving frm_response, merchant_id: {}, payment_id: {attempt_id}", &merchant_account.merchant_id)
-        });
-
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -165,6 +174,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             incremental_authorization_details: None,
             authorizations: vec![],
             frm_metadata: None,
+            connector_selection_source: None,
+            amount_change: None,
+            connector_timeout_in_secs: None,
+            payment_method_id: None,
+            is_new_attempt: None,
+            warnings: vec![],
+            preferred_3ds_version: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -200,10 +216,19 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
     where
         F: 'b + Send,
     {
+        // The held authorization is released as an authorized payment. It is captured by the
+        // connector call that follows when the payment was to be captured automatically, and is
+        // otherwise left for the merchant to capture.
         let intent_status_update = storage::PaymentIntentUpdate::ApproveUpdate {
+            status: storage_enums::IntentStatus::RequiresCapture,
             merchant_decision: Some(api_models::enums::MerchantDecision::Approved.to_string()),
             updated_by: storage_scheme.to_string(),
         };
+        let attempt_status_update = storage::PaymentAttemptUpdate::StatusUpdate {
+            status: storage_enums::AttemptStatus::Authorized,
+            updated_by: storage_scheme.to_string(),
+        };
+
         payment_data.payment_intent = db
             .store
             .update_payment_intent(
@@ -214,6 +239,16 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        payment_data.payment_attempt = db
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_data.payment_attempt.clone(),
+                attempt_status_update,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
         Ok((Box::new(self), payment_data))
     }
 }
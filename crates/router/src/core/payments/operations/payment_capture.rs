@@ -218,6 +218,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             incremental_authorization_details: None,
             authorizations: vec![],
             frm_metadata: None,
+            connector_selection_source: None,
+            amount_change: None,
+            connector_timeout_in_secs: None,
+            payment_method_id: None,
+            is_new_attempt: None,
+            warnings: vec![],
+            preferred_3ds_version: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -20,6 +20,7 @@ use crate::{
     core::{
         blocklist::utils as blocklist_utils,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_link,
         payment_methods::PaymentMethodRetrieve,
         payments::{
             self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
@@ -28,15 +29,16 @@ use crate::{
         utils as core_utils,
     },
     db::StorageInterface,
-    routes::AppState,
+    routes::{metrics, AppState},
     services,
     types::{
         self,
         api::{self, PaymentIdTypeExt},
         domain,
         storage::{self, enums as storage_enums},
+        transformers::ForeignTryFrom,
     },
-    utils::{self, OptionExt},
+    utils::{self, OptionExt, ValueExt},
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
@@ -69,7 +71,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         let store = state.clone().store;
         let m_merchant_id = merchant_id.clone();
         let payment_intent_fut = tokio::spawn(
-            async move {
+            record_confirm_stage_time("payment_intent", merchant_id.clone(), None, async move {
                 store
                     .find_payment_intent_by_payment_id_merchant_id(
                         &payment_id,
@@ -78,7 +80,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     )
                     .map(|x| x.change_context(errors::ApiErrorResponse::PaymentNotFound))
                     .await
-            }
+            })
             .in_current_span(),
         );
 
@@ -89,7 +91,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         let m_key_store = key_store.clone();
 
         let mandate_details_fut = tokio::spawn(
-            async move {
+            record_confirm_stage_time("mandate_details", merchant_id.clone(), None, async move {
                 helpers::get_token_pm_type_mandate_details(
                     &m_state,
                     &m_request,
@@ -98,7 +100,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     &m_key_store,
                 )
                 .await
-            }
+            })
             .in_current_span(),
         );
 
@@ -115,7 +117,26 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             )?;
         }
 
-        helpers::validate_customer_access(&payment_intent, auth_flow, request)?;
+        helpers::validate_payment_payload_limits(request, &state.conf.payment_payload_limits)?;
+
+        let is_off_session_confirm = helpers::is_off_session_confirm(
+            &payment_intent,
+            auth_flow,
+            request,
+            mandate_type.as_ref(),
+        );
+
+        helpers::validate_customer_access(
+            &payment_intent,
+            auth_flow,
+            request,
+            is_off_session_confirm,
+        )?;
+
+        helpers::validate_profile_id_against_payment_intent(
+            request.profile_id.as_ref(),
+            &payment_intent,
+        )?;
 
         helpers::validate_payment_status_against_not_allowed_statuses(
             &payment_intent.status,
@@ -129,7 +150,20 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             "confirm",
         )?;
 
-        helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+        if is_off_session_confirm {
+            // Off session confirms are authenticated by the API key, the mandate stands in for
+            // the consent of the customer
+            helpers::validate_mandate_for_off_session_confirm(request, mandate_type.as_ref())?;
+        } else {
+            let client_secret_expiry =
+                helpers::get_client_secret_expiry(&*state.store, &merchant_account.merchant_id)
+                    .await;
+            helpers::authenticate_client_secret(
+                request.client_secret.as_ref(),
+                &payment_intent,
+                client_secret_expiry,
+            )?;
+        }
 
         let customer_details = helpers::get_customer_details_from_request(request);
 
@@ -144,18 +178,23 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         let store = state.store.clone();
 
-        let business_profile_fut = tokio::spawn(async move {
-            store
-                .find_business_profile_by_profile_id(&profile_id)
-                .map(|business_profile_result| {
-                    business_profile_result.to_not_found_response(
-                        errors::ApiErrorResponse::BusinessProfileNotFound {
-                            id: profile_id.to_string(),
-                        },
-                    )
-                })
-                .await
-        });
+        let business_profile_fut = tokio::spawn(record_confirm_stage_time(
+            "business_profile",
+            merchant_id.clone(),
+            None,
+            async move {
+                store
+                    .find_business_profile_by_profile_id(&profile_id)
+                    .map(|business_profile_result| {
+                        business_profile_result.to_not_found_response(
+                            errors::ApiErrorResponse::BusinessProfileNotFound {
+                                id: profile_id.to_string(),
+                            },
+                        )
+                    })
+                    .await
+            },
+        ));
 
         let store = state.store.clone();
 
@@ -163,7 +202,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         let m_merchant_id = merchant_id.clone();
 
         let payment_attempt_fut = tokio::spawn(
-            async move {
+            record_confirm_stage_time("payment_attempt", merchant_id.clone(), None, async move {
                 store
                     .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                         m_payment_id.as_str(),
@@ -173,7 +212,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     )
                     .map(|x| x.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
                     .await
-            }
+            })
             .in_current_span(),
         );
 
@@ -187,7 +226,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         let m_key_store = key_store.clone();
 
         let shipping_address_fut = tokio::spawn(
-            async move {
+            record_confirm_stage_time("shipping_address", merchant_id.clone(), None, async move {
                 helpers::create_or_update_address_for_payment_by_request(
                     store.as_ref(),
                     m_request_shipping.as_ref(),
@@ -201,7 +240,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     storage_scheme,
                 )
                 .await
-            }
+            })
             .in_current_span(),
         );
 
@@ -215,7 +254,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         let m_key_store = key_store.clone();
 
         let billing_address_fut = tokio::spawn(
-            async move {
+            record_confirm_stage_time("billing_address", merchant_id.clone(), None, async move {
                 helpers::create_or_update_address_for_payment_by_request(
                     store.as_ref(),
                     m_request_billing.as_ref(),
@@ -229,7 +268,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     storage_scheme,
                 )
                 .await
-            }
+            })
             .in_current_span(),
         );
 
@@ -238,7 +277,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         let m_request_merchant_connector_details = request.merchant_connector_details.clone();
 
         let config_update_fut = tokio::spawn(
-            async move {
+            record_confirm_stage_time("config_update", merchant_id.clone(), None, async move {
                 m_request_merchant_connector_details
                     .async_map(|mcd| async {
                         helpers::insert_merchant_connector_creds_to_config(
@@ -250,43 +289,43 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     })
                     .map(|x| x.transpose())
                     .await
-            }
+            })
             .in_current_span(),
         );
 
         // Based on whether a retry can be performed or not, fetch relevant entities
-        let (mut payment_attempt, shipping_address, billing_address, business_profile) =
-            match payment_intent.status {
-                api_models::enums::IntentStatus::RequiresCustomerAction
-                | api_models::enums::IntentStatus::RequiresMerchantAction
-                | api_models::enums::IntentStatus::RequiresPaymentMethod
-                | api_models::enums::IntentStatus::RequiresConfirmation => {
-                    // Normal payment
-                    let (payment_attempt, shipping_address, billing_address, business_profile, _) =
-                        tokio::try_join!(
-                            utils::flatten_join_error(payment_attempt_fut),
-                            utils::flatten_join_error(shipping_address_fut),
-                            utils::flatten_join_error(billing_address_fut),
-                            utils::flatten_join_error(business_profile_fut),
-                            utils::flatten_join_error(config_update_fut)
-                        )?;
-
-                    (
-                        payment_attempt,
-                        shipping_address,
-                        billing_address,
-                        business_profile,
-                    )
-                }
-                _ => {
-                    // Retry payment
-                    let (
-                        mut payment_attempt,
-                        shipping_address,
-                        billing_address,
-                        business_profile,
-                        _,
-                    ) = tokio::try_join!(
+        let (
+            mut payment_attempt,
+            shipping_address,
+            billing_address,
+            business_profile,
+            is_new_attempt,
+        ) = match payment_intent.status {
+            api_models::enums::IntentStatus::RequiresCustomerAction
+            | api_models::enums::IntentStatus::RequiresMerchantAction
+            | api_models::enums::IntentStatus::RequiresPaymentMethod
+            | api_models::enums::IntentStatus::RequiresConfirmation => {
+                // Normal payment
+                let (payment_attempt, shipping_address, billing_address, business_profile, _) = tokio::try_join!(
+                    utils::flatten_join_error(payment_attempt_fut),
+                    utils::flatten_join_error(shipping_address_fut),
+                    utils::flatten_join_error(billing_address_fut),
+                    utils::flatten_join_error(business_profile_fut),
+                    utils::flatten_join_error(config_update_fut)
+                )?;
+
+                (
+                    payment_attempt,
+                    shipping_address,
+                    billing_address,
+                    business_profile,
+                    false,
+                )
+            }
+            _ => {
+                // Retry payment
+                let (mut payment_attempt, shipping_address, billing_address, business_profile, _) =
+                    tokio::try_join!(
                         utils::flatten_join_error(payment_attempt_fut),
                         utils::flatten_join_error(shipping_address_fut),
                         utils::flatten_join_error(billing_address_fut),
@@ -294,32 +333,48 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                         utils::flatten_join_error(config_update_fut)
                     )?;
 
-                    let attempt_type = helpers::get_attempt_type(
-                        &payment_intent,
-                        &payment_attempt,
-                        request,
-                        "confirm",
-                    )?;
+                let attempt_type = helpers::get_attempt_type(
+                    &payment_intent,
+                    &payment_attempt,
+                    request,
+                    "confirm",
+                )?;
 
-                    // 3
-                    (payment_intent, payment_attempt) = attempt_type
-                        .modify_payment_intent_and_payment_attempt(
-                            request,
-                            payment_intent,
-                            payment_attempt,
-                            &*state.store,
-                            storage_scheme,
-                        )
-                        .await?;
+                let is_new_attempt = attempt_type == helpers::AttemptType::New;
 
-                    (
-                        payment_attempt,
-                        shipping_address,
-                        billing_address,
-                        business_profile,
+                if is_new_attempt {
+                    let max_attempts = helpers::get_max_attempts_per_payment(
+                        &*state.store,
+                        &payment_intent.merchant_id,
+                        state.conf.payment_attempts.max_attempts_per_payment,
                     )
+                    .await;
+
+                    utils::when(payment_intent.attempt_count >= max_attempts, || {
+                        Err(errors::ApiErrorResponse::MaximumRetriesExceeded { max_attempts })
+                    })?;
                 }
-            };
+
+                // 3
+                (payment_intent, payment_attempt) = attempt_type
+                    .modify_payment_intent_and_payment_attempt(
+                        request,
+                        payment_intent,
+                        payment_attempt,
+                        &*state.store,
+                        storage_scheme,
+                    )
+                    .await?;
+
+                (
+                    payment_attempt,
+                    shipping_address,
+                    billing_address,
+                    business_profile,
+                    is_new_attempt,
+                )
+            }
+        };
 
         payment_intent.order_details = request
             .get_order_details_as_value()
@@ -339,6 +394,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             recurring_mandate_payment_data,
             mandate_connector,
         ) = mandate_details;
+        helpers::validate_mandate_details_consistency(
+            payment_method,
+            setup_mandate.as_ref(),
+            recurring_mandate_payment_data.as_ref(),
+            mandate_connector.as_ref(),
+        )?;
 
         let browser_info = request
             .browser_info
@@ -352,15 +413,55 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         helpers::validate_card_data(request.payment_method_data.clone())?;
 
-        let token = token.or_else(|| payment_attempt.payment_token.clone());
+        let payment_link_data = match request.payment_link_id.as_deref() {
+            Some(payment_link_id) => Some(
+                payment_link::get_payment_link_data_for_confirm(
+                    &*state.store,
+                    payment_link_id,
+                    &payment_intent,
+                )
+                .await?,
+            ),
+            None => None,
+        };
 
-        helpers::validate_pm_or_token_given(
-            &request.payment_method,
+        // The payment method selected on the hosted payment link page is resolved from the
+        // payment method data, when it is not sent in the request
+        let payment_method_selected_via_link = match (
+            &payment_link_data,
+            request.payment_method,
             &request.payment_method_data,
-            &request.payment_method_type,
-            &mandate_type,
-            &token,
-        )?;
+        ) {
+            (Some(_), None, Some(payment_method_data)) => Some(
+                storage_enums::PaymentMethod::foreign_try_from(payment_method_data.clone())?,
+            ),
+            _ => None,
+        };
+        let payment_method = payment_method.or(payment_method_selected_via_link);
+
+        // The payment token stored on the attempt is not reused for merchants requiring an explicit
+        // payment method, a confirm without one is rejected when making the payment method data
+        let explicit_payment_method_required =
+            helpers::is_explicit_payment_method_required(state, merchant_id).await;
+        let token = if explicit_payment_method_required {
+            token
+        } else {
+            token.or_else(|| payment_attempt.payment_token.clone())
+        };
+
+        // The saved payment method referenced by payment_method_id replaces the token
+        let payment_method_omitted = token.is_none() && request.payment_method_data.is_none();
+        if request.payment_method_id.is_none()
+            && !(explicit_payment_method_required && payment_method_omitted)
+        {
+            helpers::validate_pm_or_token_given(
+                &request.payment_method.or(payment_method_selected_via_link),
+                &request.payment_method_data,
+                &request.payment_method_type,
+                &mandate_type,
+                &token,
+            )?;
+        }
 
         payment_attempt.payment_method = payment_method.or(payment_attempt.payment_method);
         payment_attempt.browser_info = browser_info;
@@ -373,8 +474,62 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         payment_attempt.capture_method = request.capture_method.or(payment_attempt.capture_method);
 
+        payment_intent.off_session = request.off_session.or(payment_intent.off_session);
+        helpers::validate_network_transaction_id(
+            request.network_transaction_id.as_deref(),
+            payment_intent.off_session,
+        )?;
+        payment_attempt.network_transaction_id = request
+            .network_transaction_id
+            .clone()
+            .or(payment_attempt.network_transaction_id);
+        payment_attempt.installment_plan = request
+            .installment_plan
+            .as_ref()
+            .map(Encode::<api_models::payments::InstallmentPlan>::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the installment plan")?
+            .or(payment_attempt.installment_plan);
+        payment_attempt.connector_idempotency_key = Some(helpers::get_connector_idempotency_key(
+            &payment_attempt.attempt_id,
+            payment_intent.attempt_count,
+        ));
+
+        let amount_change = helpers::get_amount_change_on_confirm(
+            &payment_intent,
+            &payment_attempt,
+            request.amount,
+        )?;
+        if let Some(amount_change) = &amount_change {
+            payment_attempt.amount = amount_change.amount;
+            payment_intent.amount = amount_change.amount;
+        }
+
+        if let Some(order_details) = &payment_intent.order_details {
+            let order_details = order_details
+                .iter()
+                .map(|order_details| {
+                    order_details
+                        .to_owned()
+                        .parse_value::<api_models::payments::OrderDetailsWithAmount>(
+                            "OrderDetailsWithAmount",
+                        )
+                        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                            field_name: "order_details",
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            helpers::validate_order_details_tax_breakdown(&order_details, payment_intent.amount)?;
+        }
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
+        helpers::validate_zero_amount_payment(
+            amount,
+            payment_intent.setup_future_usage,
+            request.payment_type,
+        )?;
 
         helpers::validate_customer_id_mandatory_cases(
             request.setup_future_usage.is_some(),
@@ -397,12 +552,28 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .map(|a| a.to_string())
             .or(payment_intent.return_url);
 
+        payment_intent
+            .return_url
+            .as_deref()
+            .map(|return_url| {
+                helpers::validate_return_url(
+                    return_url,
+                    &merchant_account.get_return_url_allowlist(),
+                )
+            })
+            .transpose()?;
+
         payment_intent.allowed_payment_method_types = request
             .get_allowed_payment_method_types_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error converting allowed_payment_types to Value")?
             .or(payment_intent.allowed_payment_method_types);
 
+        helpers::validate_payment_method_type_allowed(
+            &payment_intent,
+            payment_attempt.payment_method_type,
+        )?;
+
         payment_intent.connector_metadata = request
             .get_connector_metadata_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -415,6 +586,19 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .attach_printable("Error converting feature_metadata to Value")?
             .or(payment_intent.feature_metadata);
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
+
+        if let Some(merchant_order_reference_id) = &request.merchant_order_reference_id {
+            helpers::validate_merchant_order_reference_id(
+                state,
+                merchant_order_reference_id,
+                merchant_id,
+                &payment_intent.payment_id,
+                storage_scheme,
+            )
+            .await?;
+            payment_intent.merchant_order_reference_id = Some(merchant_order_reference_id.clone());
+        }
+
         payment_intent.request_incremental_authorization = request
             .request_incremental_authorization
             .map(|request_incremental_authorization| {
@@ -429,11 +613,36 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .clone()
             .or(payment_attempt.business_sub_label);
 
+        // The acceptance passed on its own takes precedence over the one of the mandate data
+        let customer_acceptance = request.customer_acceptance.clone().or_else(|| {
+            request
+                .mandate_data
+                .as_ref()
+                .and_then(|mandate_data| mandate_data.customer_acceptance.clone())
+        });
+        helpers::validate_customer_acceptance(
+            customer_acceptance.as_ref(),
+            payment_intent.setup_future_usage,
+            recurring_mandate_payment_data.is_some() || mandate_connector.is_some(),
+        )?;
+
         // The operation merges mandate data from both request and payment_attempt
         setup_mandate = setup_mandate.map(|mut sm| {
             sm.mandate_type = payment_attempt.mandate_details.clone().or(sm.mandate_type);
+            sm.customer_acceptance = customer_acceptance
+                .clone()
+                .map(Into::into)
+                .or(sm.customer_acceptance);
             sm
         });
+        payment_attempt.customer_acceptance = customer_acceptance
+            .map(|acceptance| {
+                utils::Encode::<api::CustomerAcceptance>::encode_to_value(&acceptance)
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the customer acceptance")?
+            .or(payment_attempt.customer_acceptance);
 
         let additional_pm_data = request
             .payment_method_data
@@ -450,6 +659,35 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 payment_method_data.apply_additional_payment_data(additional_payment_data)
             });
 
+        let surcharge_details = helpers::calculate_surcharge_details_on_confirm(
+            state,
+            merchant_account,
+            &payment_intent,
+            &payment_attempt,
+            payment_method_data_after_card_bin_call.as_ref(),
+            billing_address.as_ref().map(|address| address.into()),
+        )
+        .await?;
+        helpers::validate_surcharged_amount(
+            request.surcharge_details,
+            &payment_attempt,
+            surcharge_details.as_ref(),
+        )?;
+
+        let mut warnings = Vec::new();
+        if let Some(surcharge_details) = surcharge_details
+            .as_ref()
+            .filter(|_| request.surcharge_details.is_none())
+        {
+            warnings.push(api_models::payments::PaymentWarning {
+                code: api_models::payments::PaymentWarningCode::SurchargeApplied,
+                message: format!(
+                    "A surcharge of {} was applied from the surcharge configuration",
+                    surcharge_details.final_amount - surcharge_details.original_amount
+                ),
+            });
+        }
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -480,12 +718,19 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             ephemeral_key: None,
             multiple_capture_data: None,
             redirect_response: None,
-            surcharge_details: None,
+            surcharge_details,
             frm_message: None,
-            payment_link_data: None,
+            payment_link_data,
             incremental_authorization_details: None,
             authorizations: vec![],
             frm_metadata: request.frm_metadata.clone(),
+            connector_selection_source: None,
+            amount_change,
+            connector_timeout_in_secs: None,
+            payment_method_id: request.payment_method_id.clone(),
+            is_new_attempt: Some(is_new_attempt),
+            warnings,
+            preferred_3ds_version: request.preferred_3ds_version,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -547,6 +792,12 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
             Err(errors::ApiErrorResponse::PaymentMethodNotFound)
         })?;
 
+        // The payment method type may only be known once the payment method data is resolved
+        helpers::validate_payment_method_type_allowed(
+            &payment_data.payment_intent,
+            payment_data.payment_attempt.payment_method_type,
+        )?;
+
         Ok((op, payment_method_data))
     }
 
@@ -601,6 +852,17 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
         payment_data: &mut PaymentData<F>,
         _merchant_account: &domain::MerchantAccount,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
+        helpers::validate_capture_method_supported_by_connector(
+            state,
+            &payment_data.payment_attempt,
+        )?;
+        helpers::validate_currency_supported_by_connector(payment_data)?;
+        helpers::validate_network_transaction_id_required_by_connector(state, payment_data)?;
+        helpers::validate_network_token_supported_by_connector(state, payment_data)?;
+        helpers::validate_installment_plan_for_connector(state, payment_data)?;
+        helpers::validate_country_supported_by_connector(state, payment_data)?;
+        helpers::validate_statement_descriptor_for_connector(state, payment_data).await?;
+        helpers::validate_browser_info_for_connector(state, payment_data)?;
         populate_surcharge_details(state, payment_data).await
     }
 }
@@ -632,6 +894,105 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let browser_info = payment_data.payment_attempt.browser_info.clone();
         let frm_message = payment_data.frm_message.clone();
 
+        if let Some(amount_change) = payment_data
+            .amount_change
+            .as_ref()
+            .filter(|amount_change| amount_change.requires_reauthorization)
+        {
+            logger::warn!(
+                attempt_id = %payment_data.payment_attempt.attempt_id,
+                original_amount = amount_change.original_amount,
+                amount = amount_change.amount,
+                "amount of the attempt was changed at confirm, a new authorization is required"
+            );
+            payment_data
+                .warnings
+                .push(api_models::payments::PaymentWarning {
+                    code: api_models::payments::PaymentWarningCode::ReauthorizationRequired,
+                    message: format!(
+                        "The amount was changed from {} to {}, a new authorization is required",
+                        amount_change.original_amount, amount_change.amount
+                    ),
+                });
+        }
+
+        let connector_name = payment_data
+            .payment_attempt
+            .connector
+            .as_deref()
+            .unwrap_or_default();
+        if payment_data.payment_attempt.routed_to_unhealthy_connector == Some(true) {
+            payment_data
+                .warnings
+                .push(api_models::payments::PaymentWarning {
+                    code: api_models::payments::PaymentWarningCode::UnhealthyConnectorUsed,
+                    message: format!(
+                        "The payment was routed through {connector_name}, which is unhealthy, as no healthy connector was available"
+                    ),
+                });
+        } else if payment_data
+            .payment_attempt
+            .connector_fallback_index
+            .map_or(false, |fallback_index| fallback_index > 0)
+        {
+            payment_data
+                .warnings
+                .push(api_models::payments::PaymentWarning {
+                    code: api_models::payments::PaymentWarningCode::FallbackConnectorUsed,
+                    message: format!(
+                        "The payment was routed through the fallback connector {connector_name}"
+                    ),
+                });
+        }
+
+        // The preferred 3DS version only applies to the payments authenticated through 3DS
+        if let Some(preferred_3ds_version) = payment_data.preferred_3ds_version {
+            let is_supported = match payment_data.payment_attempt.authentication_type {
+                Some(storage_enums::AuthenticationType::ThreeDs) if !connector_name.is_empty() => {
+                    let is_supported = helpers::validate_preferred_3ds_version(
+                        state,
+                        &payment_data.payment_attempt.merchant_id,
+                        connector_name,
+                        preferred_3ds_version,
+                    )
+                    .await?;
+                    if !is_supported {
+                        payment_data
+                            .warnings
+                            .push(api_models::payments::PaymentWarning {
+                                code: api_models::payments::PaymentWarningCode::ThreeDsVersionFallback,
+                                message: format!(
+                                    "3DS version {preferred_3ds_version} is not supported by {connector_name}, the customer is authenticated through a lower version"
+                                ),
+                            });
+                    }
+                    is_supported
+                }
+                _ => false,
+            };
+            if !is_supported {
+                payment_data.preferred_3ds_version = None;
+            }
+        }
+
+        // The fraud score is gated through the thresholds of the merchant, which can only escalate
+        // the suggestion of the FRM connector
+        let frm_score = frm_message
+            .as_ref()
+            .and_then(|fraud_check| fraud_check.frm_score);
+        let frm_score_suggestion = match frm_score {
+            Some(frm_score) => {
+                helpers::get_frm_score_thresholds(state, &payment_data.payment_attempt.merchant_id)
+                    .await
+                    .and_then(|thresholds| thresholds.get_suggestion(frm_score))
+            }
+            None => None,
+        };
+        let (frm_suggestion, frm_score_threshold) = match (frm_suggestion, frm_score_suggestion) {
+            (Some(FrmSuggestion::FrmCancelTransaction), _) | (_, None) => (frm_suggestion, None),
+            (_, Some((score_suggestion, threshold))) => (Some(score_suggestion), Some(threshold)),
+        };
+
         let (mut intent_status, mut attempt_status, (error_code, error_message)) =
             match frm_suggestion {
                 Some(FrmSuggestion::FrmCancelTransaction) => (
@@ -649,6 +1010,19 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     storage_enums::AttemptStatus::Unresolved,
                     (None, None),
                 ),
+                // The connector responds with the instructions for the customer to complete the
+                // payment, the payment stays with the customer until the connector notifies us
+                _ if helpers::is_deferred_payment_method(
+                    payment_method,
+                    payment_data.payment_method_data.as_ref(),
+                ) =>
+                {
+                    (
+                        storage_enums::IntentStatus::RequiresCustomerAction,
+                        storage_enums::AttemptStatus::AuthenticationPending,
+                        (None, None),
+                    )
+                }
                 _ => (
                     storage_enums::IntentStatus::Processing,
                     storage_enums::AttemptStatus::Pending,
@@ -658,6 +1032,17 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
 
         let connector = payment_data.payment_attempt.connector.clone();
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
+        let network_transaction_id = payment_data.payment_attempt.network_transaction_id.clone();
+        let connector_fallback_index = payment_data.payment_attempt.connector_fallback_index;
+        let routed_to_unhealthy_connector =
+            payment_data.payment_attempt.routed_to_unhealthy_connector;
+        let installment_plan = payment_data.payment_attempt.installment_plan.clone();
+        let customer_acceptance = payment_data.payment_attempt.customer_acceptance.clone();
+        let connector_idempotency_key = payment_data
+            .payment_attempt
+            .connector_idempotency_key
+            .clone();
+        let correlation_id = header_payload.x_correlation_id.clone();
 
         let straight_through_algorithm = payment_data
             .payment_attempt
@@ -879,36 +1264,50 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .map(|surcharge_details| surcharge_details.tax_on_surcharge_amount);
 
         let payment_attempt_fut = tokio::spawn(
-            async move {
-                m_db.update_payment_attempt_with_attempt_id(
-                    m_payment_data_payment_attempt,
-                    storage::PaymentAttemptUpdate::ConfirmUpdate {
-                        amount: payment_data.payment_attempt.amount,
-                        currency: payment_data.currency,
-                        status: attempt_status,
-                        payment_method,
-                        authentication_type,
-                        browser_info: m_browser_info,
-                        connector: m_connector,
-                        payment_token: m_payment_token,
-                        payment_method_data: m_additional_pm_data,
-                        payment_method_type,
-                        payment_experience,
-                        business_sub_label: m_business_sub_label,
-                        straight_through_algorithm: m_straight_through_algorithm,
-                        error_code: m_error_code,
-                        error_message: m_error_message,
-                        amount_capturable: Some(authorized_amount),
-                        updated_by: storage_scheme.to_string(),
-                        merchant_connector_id,
-                        surcharge_amount,
-                        tax_amount,
-                    },
-                    storage_scheme,
-                )
-                .map(|x| x.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
-                .await
-            }
+            record_confirm_stage_time(
+                "update_payment_attempt",
+                payment_data.payment_intent.merchant_id.clone(),
+                connector.clone(),
+                async move {
+                    m_db.update_payment_attempt_with_attempt_id(
+                        m_payment_data_payment_attempt,
+                        storage::PaymentAttemptUpdate::ConfirmUpdate {
+                            amount: payment_data.payment_attempt.amount,
+                            currency: payment_data.currency,
+                            status: attempt_status,
+                            payment_method,
+                            authentication_type,
+                            browser_info: m_browser_info,
+                            connector: m_connector,
+                            payment_token: m_payment_token,
+                            payment_method_data: m_additional_pm_data,
+                            payment_method_type,
+                            payment_experience,
+                            business_sub_label: m_business_sub_label,
+                            straight_through_algorithm: m_straight_through_algorithm,
+                            error_code: m_error_code,
+                            error_message: m_error_message,
+                            amount_capturable: Some(authorized_amount),
+                            updated_by: storage_scheme.to_string(),
+                            merchant_connector_id,
+                            surcharge_amount,
+                            tax_amount,
+                            network_transaction_id,
+                            connector_fallback_index,
+                            routed_to_unhealthy_connector,
+                            installment_plan,
+                            connector_idempotency_key,
+                            frm_score,
+                            frm_score_threshold,
+                            customer_acceptance,
+                            correlation_id,
+                        },
+                        storage_scheme,
+                    )
+                    .map(|x| x.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
+                    .await
+                },
+            )
             .in_current_span(),
         );
 
@@ -926,37 +1325,73 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let m_db = state.clone().store;
         let m_storage_scheme = storage_scheme.to_string();
         let session_expiry = m_payment_data_payment_intent.session_expiry;
+        let merchant_order_reference_id = m_payment_data_payment_intent
+            .merchant_order_reference_id
+            .clone();
+        let m_merchant_order_reference_id = merchant_order_reference_id.clone();
+        let m_merchant_id = m_payment_data_payment_intent.merchant_id.clone();
+        let m_payment_id = m_payment_data_payment_intent.payment_id.clone();
 
         let payment_intent_fut = tokio::spawn(
-            async move {
-                m_db.update_payment_intent(
-                    m_payment_data_payment_intent,
-                    storage::PaymentIntentUpdate::Update {
-                        amount: payment_data.payment_intent.amount,
-                        currency: payment_data.currency,
-                        setup_future_usage,
-                        status: intent_status,
-                        customer_id: m_customer_id,
-                        shipping_address_id: m_shipping_address_id,
-                        billing_address_id: m_billing_address_id,
-                        return_url: m_return_url,
-                        business_country,
-                        business_label: m_business_label,
-                        description: m_description,
-                        statement_descriptor_name: m_statement_descriptor_name,
-                        statement_descriptor_suffix: m_statement_descriptor_suffix,
-                        order_details: m_order_details,
-                        metadata: m_metadata,
-                        payment_confirm_source: header_payload.payment_confirm_source,
-                        updated_by: m_storage_scheme,
-                        fingerprint_id,
-                        session_expiry,
-                    },
-                    storage_scheme,
-                )
-                .map(|x| x.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
-                .await
-            }
+            record_confirm_stage_time(
+                "update_payment_intent",
+                payment_data.payment_intent.merchant_id.clone(),
+                connector.clone(),
+                async move {
+                    let payment_intent_update_result = m_db
+                        .update_payment_intent(
+                            m_payment_data_payment_intent,
+                            storage::PaymentIntentUpdate::Update {
+                                amount: payment_data.payment_intent.amount,
+                                currency: payment_data.currency,
+                                setup_future_usage,
+                                status: intent_status,
+                                customer_id: m_customer_id,
+                                shipping_address_id: m_shipping_address_id,
+                                billing_address_id: m_billing_address_id,
+                                return_url: m_return_url,
+                                business_country,
+                                business_label: m_business_label,
+                                description: m_description,
+                                statement_descriptor_name: m_statement_descriptor_name,
+                                statement_descriptor_suffix: m_statement_descriptor_suffix,
+                                order_details: m_order_details,
+                                metadata: m_metadata,
+                                payment_confirm_source: header_payload.payment_confirm_source,
+                                updated_by: m_storage_scheme,
+                                fingerprint_id,
+                                merchant_order_reference_id,
+                                session_expiry,
+                            },
+                            storage_scheme,
+                        )
+                        .await;
+
+                    match payment_intent_update_result {
+                        Ok(payment_intent) => Ok(payment_intent),
+                        Err(error) => {
+                            let duplicate_order_reference =
+                                helpers::get_duplicate_order_reference_error(
+                                    &*m_db,
+                                    error.current_context(),
+                                    m_merchant_order_reference_id.as_deref(),
+                                    &m_merchant_id,
+                                    &m_payment_id,
+                                    storage_scheme,
+                                )
+                                .await?;
+                            match duplicate_order_reference {
+                                Some(duplicate_order_reference) => {
+                                    Err(error.change_context(duplicate_order_reference))
+                                }
+                                None => Err(error).to_not_found_response(
+                                    errors::ApiErrorResponse::PaymentNotFound,
+                                ),
+                            }
+                        }
+                    }
+                },
+            )
             .in_current_span(),
         );
 
@@ -968,19 +1403,24 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                 let m_updated_customer = updated_customer.clone();
                 let m_db = state.clone().store;
                 tokio::spawn(
-                    async move {
-                        m_db.update_customer_by_customer_id_merchant_id(
-                            m_customer_customer_id,
-                            m_customer_merchant_id,
-                            m_updated_customer,
-                            &m_key_store,
-                        )
-                        .await
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Failed to update CustomerConnector in customer")?;
-
-                        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(())
-                    }
+                    record_confirm_stage_time(
+                        "update_customer",
+                        payment_data.payment_intent.merchant_id.clone(),
+                        connector.clone(),
+                        async move {
+                            m_db.update_customer_by_customer_id_merchant_id(
+                                m_customer_customer_id,
+                                m_customer_merchant_id,
+                                m_updated_customer,
+                                &m_key_store,
+                            )
+                            .await
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to update CustomerConnector in customer")?;
+
+                            Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(())
+                        },
+                    )
                     .in_current_span(),
                 )
             } else {
@@ -1030,6 +1470,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
             })?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_payment_method_id_in_request(request)?;
 
         let mandate_type =
             helpers::validate_mandate(request, payments::is_operation_confirm(self))?;
@@ -1056,3 +1497,30 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
         ))
     }
 }
+
+/// Records the time taken by one of the futures joined in the confirm flow. The time is recorded
+/// within the spawned task, so that each future is measured once and the time spent waiting on the
+/// other futures of the join is not attributed to it.
+async fn record_confirm_stage_time<Fut, R>(
+    stage: &'static str,
+    merchant_id: String,
+    connector: Option<String>,
+    future: Fut,
+) -> R
+where
+    Fut: futures::Future<Output = R>,
+{
+    let mut attributes = vec![
+        metrics::request::add_attributes("stage", stage),
+        metrics::request::add_attributes("merchant_id", merchant_id),
+    ];
+    if let Some(connector) = connector {
+        attributes.push(metrics::request::add_attributes("connector", connector));
+    }
+    metrics::request::record_operation_time(
+        future,
+        &metrics::PAYMENT_CONFIRM_STAGE_TIME,
+        &attributes,
+    )
+    .await
+}
@@ -234,12 +234,44 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         )
         .await?;
 
-        payment_intent = db
+        if let Some(merchant_order_reference_id) = &request.merchant_order_reference_id {
+            helpers::validate_merchant_order_reference_id(
+                state,
+                merchant_order_reference_id,
+                merchant_id,
+                &payment_id,
+                storage_scheme,
+            )
+            .await?;
+        }
+
+        payment_intent = match db
             .insert_payment_intent(payment_intent_new, storage_scheme)
             .await
-            .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
-                payment_id: payment_id.clone(),
-            })?;
+        {
+            Ok(payment_intent) => Ok(payment_intent),
+            Err(error) => {
+                let duplicate_order_reference = helpers::get_duplicate_order_reference_error(
+                    db,
+                    error.current_context(),
+                    request.merchant_order_reference_id.as_deref(),
+                    merchant_id,
+                    &payment_id,
+                    storage_scheme,
+                )
+                .await?;
+                match duplicate_order_reference {
+                    Some(duplicate_order_reference) => {
+                        Err(error.change_context(duplicate_order_reference))
+                    }
+                    None => Err(error).to_duplicate_response(
+                        errors::ApiErrorResponse::DuplicatePayment {
+                            payment_id: payment_id.clone(),
+                        },
+                    ),
+                }
+            }
+        }?;
 
         if let Some(order_details) = &request.order_details {
             helpers::validate_order_details_amount(
@@ -376,6 +408,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             incremental_authorization_details: None,
             authorizations: vec![],
             frm_metadata: request.frm_metadata.clone(),
+            connector_selection_source: None,
+            amount_change: None,
+            connector_timeout_in_secs: None,
+            payment_method_id: None,
+            is_new_attempt: None,
+            warnings: vec![],
+            preferred_3ds_version: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -826,6 +865,7 @@ impl PaymentCreate {
             incremental_authorization_allowed: None,
             authorization_count: None,
             fingerprint_id: None,
+            merchant_order_reference_id: request.merchant_order_reference_id.clone(),
             session_expiry: Some(session_expiry),
         })
     }
@@ -187,6 +187,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 frm_message: None,
                 payment_link_data: None,
                 frm_metadata: None,
+                connector_selection_source: None,
+                amount_change: None,
+                connector_timeout_in_secs: None,
+                payment_method_id: None,
+                is_new_attempt: None,
+                warnings: vec![],
+                preferred_3ds_version: None,
             },
             Some(payments::CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -491,6 +491,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             }
                         }
                     };
+                    let error_classification = payments_helpers::classify_connector_error(
+                        state,
+                        &router_data.connector,
+                        &err,
+                    );
                     (
                         None,
                         Some(storage::PaymentAttemptUpdate::ErrorUpdate {
@@ -506,6 +511,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             unified_code: option_gsm.clone().map(|gsm| gsm.unified_code),
                             unified_message: option_gsm.map(|gsm| gsm.unified_message),
                             connector_transaction_id: err.connector_transaction_id,
+                            error_classification: Some(error_classification),
                         }),
                     )
                 }
@@ -606,6 +612,20 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         metrics::SUCCESSFUL_PAYMENT.add(&metrics::CONTEXT, 1, &[]);
                     }
 
+                    // A deferred payment which the connector reports as pending is waiting for the
+                    // customer to follow the instructions returned by the connector
+                    let updated_attempt_status = if updated_attempt_status
+                        == enums::AttemptStatus::Pending
+                        && connector_metadata.is_some()
+                        && payments_helpers::is_deferred_payment_method(
+                            payment_data.payment_attempt.payment_method,
+                            payment_data.payment_method_data.as_ref(),
+                        ) {
+                        enums::AttemptStatus::AuthenticationPending
+                    } else {
+                        updated_attempt_status
+                    };
+
                     utils::add_apple_pay_payment_status_metrics(
                         router_data.status,
                         router_data.apple_pay_flow.clone(),
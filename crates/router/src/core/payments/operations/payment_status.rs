@@ -237,7 +237,13 @@ async fn get_tracker_for_sync<
     )
     .await?;
 
-    helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+    let client_secret_expiry =
+        helpers::get_client_secret_expiry(db, &merchant_account.merchant_id).await;
+    helpers::authenticate_client_secret(
+        request.client_secret.as_ref(),
+        &payment_intent,
+        client_secret_expiry,
+    )?;
 
     let payment_id_str = payment_attempt.payment_id.clone();
 
@@ -422,6 +428,13 @@ async fn get_tracker_for_sync<
         incremental_authorization_details: None,
         authorizations,
         frm_metadata: None,
+        connector_selection_source: None,
+        amount_change: None,
+        connector_timeout_in_secs: None,
+        payment_method_id: None,
+        is_new_attempt: None,
+        warnings: vec![],
+        preferred_3ds_version: None,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
@@ -72,7 +72,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .setup_future_usage
             .or(payment_intent.setup_future_usage);
 
-        helpers::validate_customer_access(&payment_intent, auth_flow, request)?;
+        helpers::validate_customer_access(&payment_intent, auth_flow, request, false)?;
 
         helpers::validate_card_data(request.payment_method_data.clone())?;
 
@@ -87,7 +87,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             "update",
         )?;
 
-        helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+        let client_secret_expiry = helpers::get_client_secret_expiry(db, merchant_id).await;
+        helpers::authenticate_client_secret(
+            request.client_secret.as_ref(),
+            &payment_intent,
+            client_secret_expiry,
+        )?;
         let (
             token,
             payment_method,
@@ -215,6 +220,18 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
         Self::populate_payment_intent_with_request(&mut payment_intent, request);
 
+        if let Some(merchant_order_reference_id) = &request.merchant_order_reference_id {
+            helpers::validate_merchant_order_reference_id(
+                state,
+                merchant_order_reference_id,
+                merchant_id,
+                &payment_intent.payment_id,
+                storage_scheme,
+            )
+            .await?;
+            payment_intent.merchant_order_reference_id = Some(merchant_order_reference_id.clone());
+        }
+
         let token = token.or_else(|| payment_attempt.payment_token.clone());
 
         if request.confirm.unwrap_or(false) {
@@ -380,6 +397,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             incremental_authorization_details: None,
             authorizations: vec![],
             frm_metadata: request.frm_metadata.clone(),
+            connector_selection_source: None,
+            amount_change: None,
+            connector_timeout_in_secs: None,
+            payment_method_id: None,
+            is_new_attempt: None,
+            warnings: vec![],
+            preferred_3ds_version: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -594,8 +618,12 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let order_details = payment_data.payment_intent.order_details.clone();
         let metadata = payment_data.payment_intent.metadata.clone();
         let session_expiry = payment_data.payment_intent.session_expiry;
+        let merchant_order_reference_id = payment_data
+            .payment_intent
+            .merchant_order_reference_id
+            .clone();
 
-        payment_data.payment_intent = state
+        let payment_intent_update_result = state
             .store
             .update_payment_intent(
                 payment_data.payment_intent.clone(),
@@ -618,12 +646,35 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     payment_confirm_source: None,
                     updated_by: storage_scheme.to_string(),
                     fingerprint_id: None,
+                    merchant_order_reference_id: merchant_order_reference_id.clone(),
                     session_expiry,
                 },
                 storage_scheme,
             )
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            .await;
+
+        payment_data.payment_intent = match payment_intent_update_result {
+            Ok(payment_intent) => Ok(payment_intent),
+            Err(error) => {
+                let duplicate_order_reference = helpers::get_duplicate_order_reference_error(
+                    &*state.store,
+                    error.current_context(),
+                    merchant_order_reference_id.as_deref(),
+                    &payment_data.payment_intent.merchant_id,
+                    &payment_data.payment_intent.payment_id,
+                    storage_scheme,
+                )
+                .await?;
+                match duplicate_order_reference {
+                    Some(duplicate_order_reference) => {
+                        Err(error.change_context(duplicate_order_reference))
+                    }
+                    None => {
+                        Err(error).to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                    }
+                }
+            }
+        }?;
 
         payment_data.mandate_id = payment_data.mandate_id.clone();
 
@@ -74,6 +74,7 @@ where
             original_connector_data.connector_name,
         )
         .await
+            && is_attempt_available_for_step_up(state, payment_data).await
     } else {
         false
     };
@@ -182,6 +183,41 @@ pub async fn is_step_up_enabled_for_merchant_connector(
         .unwrap_or(false)
 }
 
+/// The step-up creates a new attempt on the payment, so it is only done while the payment has not
+/// reached the maximum number of attempts configured for the merchant
+#[instrument(skip_all)]
+pub async fn is_attempt_available_for_step_up<F: Clone>(
+    state: &app::AppState,
+    payment_data: &payments::PaymentData<F>,
+) -> bool {
+    let max_attempts = payments::helpers::get_max_attempts_per_payment(
+        &*state.store,
+        &payment_data.payment_intent.merchant_id,
+        state.conf.payment_attempts.max_attempts_per_payment,
+    )
+    .await;
+
+    let is_attempt_available = payment_data.payment_intent.attempt_count < max_attempts;
+    if !is_attempt_available {
+        logger::info!(
+            max_attempts,
+            "skipping step-up as the payment has reached the maximum number of attempts"
+        );
+    }
+    is_attempt_available
+}
+
+/// Reason recorded on the stepped-up attempt, which is the error code of the soft decline that the
+/// connector returned for the previous attempt, or its status when no error was returned
+fn get_step_up_reason<F, FData>(
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> String {
+    match router_data.response.as_ref() {
+        Err(error_response) => error_response.code.clone(),
+        Ok(_) => router_data.status.to_string(),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn get_retries(
     state: &app::AppState,
@@ -333,11 +369,12 @@ where
     FData: Send,
 {
     let new_attempt_count = payment_data.payment_intent.attempt_count + 1;
+    let authentication_escalation_reason = is_step_up.then(|| get_step_up_reason(&router_data));
     let new_payment_attempt = make_new_payment_attempt(
         connector,
         payment_data.payment_attempt.clone(),
         new_attempt_count,
-        is_step_up,
+        authentication_escalation_reason,
     );
 
     let db = &*state.store;
@@ -417,6 +454,11 @@ where
                     unified_code: option_gsm.clone().map(|gsm| gsm.unified_code),
                     unified_message: option_gsm.map(|gsm| gsm.unified_message),
                     connector_transaction_id: error_response.connector_transaction_id.clone(),
+                    error_classification: Some(payments::helpers::classify_connector_error(
+                        state,
+                        &router_data.connector,
+                        error_response,
+                    )),
                 },
                 storage_scheme,
             )
@@ -456,7 +498,7 @@ pub fn make_new_payment_attempt(
     connector: String,
     old_payment_attempt: storage::PaymentAttempt,
     new_attempt_count: i16,
-    is_step_up: bool,
+    authentication_escalation_reason: Option<String>,
 ) -> storage::PaymentAttemptNew {
     let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
     storage::PaymentAttemptNew {
@@ -481,11 +523,12 @@ pub fn make_new_payment_attempt(
         capture_method: old_payment_attempt.capture_method,
         capture_on: old_payment_attempt.capture_on,
         confirm: old_payment_attempt.confirm,
-        authentication_type: if is_step_up {
+        authentication_type: if authentication_escalation_reason.is_some() {
             Some(storage_enums::AuthenticationType::ThreeDs)
         } else {
             old_payment_attempt.authentication_type
         },
+        authentication_escalation_reason,
 
         amount_to_capture: old_payment_attempt.amount_to_capture,
         mandate_id: old_payment_attempt.mandate_id,
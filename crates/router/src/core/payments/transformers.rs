@@ -1,13 +1,15 @@
 use std::{fmt::Debug, marker::PhantomData, str::FromStr};
 
-use api_models::payments::{FrmMessage, RequestSurchargeDetails};
+use api_models::payments::{
+    ConnectorOutcome, ConnectorRawResponse, FrmMessage, RequestSurchargeDetails,
+};
 use common_enums::RequestIncrementalAuthorization;
 use common_utils::{consts::X_HS_LATENCY, fp_utils};
 use diesel_models::ephemeral_key;
 use error_stack::{report, IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
-use super::{flows::Feature, PaymentData};
+use super::PaymentData;
 use crate::{
     configs::settings::{ConnectorRequestReferenceIdConfig, Server},
     connector::{Helcim, Nexinets},
@@ -39,7 +41,6 @@ pub async fn construct_payment_router_data<'a, F, T>(
 ) -> RouterResult<types::RouterData<F, T, types::PaymentsResponseData>>
 where
     T: TryFrom<PaymentAdditionalData<'a, F>>,
-    types::RouterData<F, T, types::PaymentsResponseData>: Feature<F, T>,
     F: Clone,
     error_stack::Report<errors::ApiErrorResponse>:
         From<<T as TryFrom<PaymentAdditionalData<'a, F>>>::Error>,
@@ -358,6 +359,21 @@ where
     let payment_attempt = payment_data.payment_attempt;
     let payment_intent = payment_data.payment_intent;
     let payment_link_data = payment_data.payment_link_data;
+    let connector_selection_source = payment_data.connector_selection_source;
+    let is_new_attempt = payment_data.is_new_attempt;
+    let warnings = payment_data.warnings;
+    let (connector_outcome, connector_raw) =
+        normalize_connector_response(&payment_attempt, operation, connector_http_status_code)
+            .unzip();
+    let (original_amount, requires_reauthorization) = payment_data
+        .amount_change
+        .map(|amount_change| {
+            (
+                Some(amount_change.original_amount),
+                Some(amount_change.requires_reauthorization),
+            )
+        })
+        .unwrap_or_default();
 
     let currency = payment_attempt
         .currency
@@ -701,16 +717,25 @@ where
                         .set_payment_link(payment_link_data)
                         .set_profile_id(payment_intent.profile_id)
                         .set_attempt_count(payment_intent.attempt_count)
+                        .set_is_new_attempt(is_new_attempt)
                         .set_merchant_connector_id(payment_attempt.merchant_connector_id)
+                        .set_connector_selection_source(connector_selection_source)
+                        .set_original_amount(original_amount)
+                        .set_requires_reauthorization(requires_reauthorization)
                         .set_unified_code(payment_attempt.unified_code)
                         .set_unified_message(payment_attempt.unified_message)
+                        .set_error_classification(payment_attempt.error_classification)
                         .set_incremental_authorization_allowed(
                             payment_intent.incremental_authorization_allowed,
                         )
                         .set_fingerprint(payment_intent.fingerprint_id)
+                        .set_merchant_order_reference_id(payment_intent.merchant_order_reference_id)
                         .set_authorization_count(payment_intent.authorization_count)
                         .set_incremental_authorizations(incremental_authorizations_response)
                         .set_expires_on(payment_intent.session_expiry)
+                        .set_warnings(warnings)
+                        .set_connector_outcome(connector_outcome)
+                        .set_connector_raw(connector_raw)
                         .to_owned(),
                     headers,
                 ))
@@ -770,14 +795,23 @@ where
                 allowed_payment_method_types: payment_intent.allowed_payment_method_types,
                 reference_id: payment_attempt.connector_response_reference_id,
                 attempt_count: payment_intent.attempt_count,
+                is_new_attempt,
                 payment_link: payment_link_data,
                 surcharge_details,
                 unified_code: payment_attempt.unified_code,
                 unified_message: payment_attempt.unified_message,
+                error_classification: payment_attempt.error_classification,
                 incremental_authorization_allowed: payment_intent.incremental_authorization_allowed,
                 authorization_count: payment_intent.authorization_count,
                 incremental_authorizations: incremental_authorizations_response,
                 expires_on: payment_intent.session_expiry,
+                merchant_order_reference_id: payment_intent.merchant_order_reference_id,
+                connector_selection_source,
+                original_amount,
+                requires_reauthorization,
+                warnings,
+                connector_outcome,
+                connector_raw,
                 ..Default::default()
             },
             headers,
@@ -798,6 +832,37 @@ where
     output
 }
 
+/// Maps the status and the error details which the connector returned for the attempt into fields
+/// which are the same for every connector, and keeps the error details of the connector as they
+/// were returned. These are part of the confirm response only, once a connector was chosen
+fn normalize_connector_response<Op: Debug>(
+    payment_attempt: &storage::PaymentAttempt,
+    operation: &Op,
+    connector_http_status_code: Option<u16>,
+) -> Option<(ConnectorOutcome, ConnectorRawResponse)> {
+    if !payments::is_operation_confirm(operation) || payment_attempt.connector.is_none() {
+        return None;
+    }
+
+    let status = enums::IntentStatus::foreign_from(payment_attempt.status);
+    let is_failure = status == enums::IntentStatus::Failed;
+    let connector_outcome = ConnectorOutcome {
+        status,
+        is_failure,
+        error_code: payment_attempt.unified_code.clone(),
+        error_message: payment_attempt.unified_message.clone(),
+        error_classification: payment_attempt.error_classification,
+    };
+    let connector_raw = ConnectorRawResponse {
+        code: payment_attempt.error_code.clone(),
+        message: payment_attempt.error_message.clone(),
+        reason: payment_attempt.error_reason.clone(),
+        http_status_code: connector_http_status_code,
+    };
+
+    Some((connector_outcome, connector_raw))
+}
+
 pub fn third_party_sdk_session_next_action<Op>(
     payment_attempt: &storage::PaymentAttempt,
     operation: &Op,
@@ -951,6 +1016,7 @@ pub fn change_order_details_to_new_type(
         product_name: order_details.product_name,
         quantity: order_details.quantity,
         amount: order_amount,
+        tax_amount: None,
         product_img_link: order_details.product_img_link,
         requires_shipping: order_details.requires_shipping,
         product_id: order_details.product_id,
@@ -987,6 +1053,22 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
+        let installment_plan: Option<api_models::payments::InstallmentPlan> = attempt
+            .installment_plan
+            .clone()
+            .map(|plan| plan.parse_value("InstallmentPlan"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "installment_plan",
+            })?;
+        let customer_acceptance: Option<api_models::payments::CustomerAcceptance> = attempt
+            .customer_acceptance
+            .clone()
+            .map(|acceptance| acceptance.parse_value("CustomerAcceptance"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "customer_acceptance",
+            })?;
 
         let order_category = additional_data
             .payment_data
@@ -1066,7 +1148,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             setup_future_usage: payment_data.payment_intent.setup_future_usage,
             mandate_id: payment_data.mandate_id.clone(),
             off_session: payment_data.mandate_id.as_ref().map(|_| true),
+            network_transaction_id: payment_data.payment_attempt.network_transaction_id,
             setup_mandate_details: payment_data.setup_mandate.clone(),
+            installment_plan,
+            connector_idempotency_key: payment_data.payment_attempt.connector_idempotency_key,
+            customer_acceptance: customer_acceptance.map(Into::into),
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
             statement_descriptor: payment_data.payment_intent.statement_descriptor_name,
@@ -1096,6 +1182,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                     | Some(RequestIncrementalAuthorization::Default)
             ),
             metadata: additional_data.payment_data.payment_intent.metadata,
+            preferred_3ds_version: payment_data.preferred_3ds_version,
         })
     }
 }
@@ -1593,6 +1680,7 @@ impl ForeignFrom<payments::FraudCheck> for FrmMessage {
             frm_score: fraud_check.frm_score,
             frm_reason: fraud_check.frm_reason,
             frm_error: fraud_check.frm_error,
+            reviewed_by: fraud_check.reviewed_by,
         }
     }
 }
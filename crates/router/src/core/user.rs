@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use api_models::user as user_api;
 use diesel_models::{enums::UserStatus, user as storage_user, user_role::UserRoleNew};
 #[cfg(feature = "email")]
@@ -15,7 +17,7 @@ use crate::services::email::types as email_types;
 use crate::{
     consts,
     routes::AppState,
-    services::{authentication as auth, ApplicationResponse},
+    services::{authentication as auth, authorization, ApplicationResponse},
     types::domain,
     utils,
 };
@@ -356,7 +358,13 @@ pub async fn invite_user(
             .attach_printable("User Inviting themself");
     }
 
-    utils::user_role::validate_role_id(request.role_id.as_str())?;
+    utils::user_role::validate_role_id_for_merchant(
+        &state,
+        request.role_id.as_str(),
+        user_from_token.merchant_id.as_str(),
+    )
+    .await?;
+    utils::user_role::validate_role_expiry(request.expires_at)?;
     let invitee_email = domain::UserEmail::from_pii_email(request.email.clone())?;
 
     let invitee_user = state
@@ -366,9 +374,15 @@ pub async fn invite_user(
 
     if let Ok(invitee_user) = invitee_user {
         let invitee_user_from_db = domain::UserFromStorage::from(invitee_user);
+        utils::user_role::remove_lingering_expired_user_role(
+            &state,
+            invitee_user_from_db.get_user_id(),
+            user_from_token.merchant_id.as_str(),
+        )
+        .await?;
 
         let now = common_utils::date_time::now();
-        state
+        let user_role = state
             .store
             .insert_user_role(UserRoleNew {
                 user_id: invitee_user_from_db.get_user_id().to_owned(),
@@ -380,6 +394,9 @@ pub async fn invite_user(
                 last_modified_by: user_from_token.user_id,
                 created_at: now,
                 last_modified: now,
+                expires_at: request.expires_at,
+                title: None,
+                department: None,
             })
             .await
             .map_err(|e| {
@@ -389,6 +406,7 @@ pub async fn invite_user(
                     e.change_context(UserErrors::InternalServerError)
                 }
             })?;
+        utils::user_role::add_user_role_expiry_task(&state, &user_role).await?;
 
         Ok(ApplicationResponse::Json(user_api::InviteUserResponse {
             is_email_sent: false,
@@ -408,7 +426,7 @@ pub async fn invite_user(
             .change_context(UserErrors::InternalServerError)?;
 
         let now = common_utils::date_time::now();
-        state
+        let user_role = state
             .store
             .insert_user_role(UserRoleNew {
                 user_id: new_user.get_user_id().to_owned(),
@@ -420,6 +438,9 @@ pub async fn invite_user(
                 last_modified_by: user_from_token.user_id,
                 created_at: now,
                 last_modified: now,
+                expires_at: request.expires_at,
+                title: None,
+                department: None,
             })
             .await
             .map_err(|e| {
@@ -429,6 +450,7 @@ pub async fn invite_user(
                     e.change_context(UserErrors::InternalServerError)
                 }
             })?;
+        utils::user_role::add_user_role_expiry_task(&state, &user_role).await?;
 
         let is_email_sent;
         #[cfg(feature = "email")]
@@ -647,44 +669,112 @@ pub async fn create_merchant_account(
     Ok(ApplicationResponse::StatusOk)
 }
 
+/// Lists a page of the merchants the user is part of, ordered by when the user joined them. The
+/// merchants whose invitation was not accepted yet are not included.
 pub async fn list_merchant_ids_for_user(
     state: AppState,
     user: auth::UserFromToken,
-) -> UserResponse<Vec<user_api::UserMerchantAccount>> {
-    let merchant_ids = utils::user_role::get_merchant_ids_for_user(&state, &user.user_id).await?;
+    req: user_api::UserRoleListQuery,
+) -> UserResponse<user_api::ListMerchantsForUserResponse> {
+    let limit = utils::user_role::get_user_role_list_limit(req.limit)?;
+    let cursor = req
+        .cursor
+        .as_deref()
+        .map(utils::user_role::decode_user_role_list_cursor)
+        .transpose()?;
 
-    let merchant_accounts = state
+    let user_roles = state
         .store
-        .list_multiple_merchant_accounts(merchant_ids)
+        .list_user_roles_by_user_id_after_cursor(&user.user_id, cursor, limit + 1)
         .await
         .change_context(UserErrors::InternalServerError)?;
+    let (user_roles, next_cursor) =
+        utils::user_role::paginate_user_role_list(user_roles, limit, |user_role| user_role);
+    let user_roles = user_roles
+        .into_iter()
+        .filter(|user_role| user_role.status != UserStatus::InvitationSent)
+        .collect::<Vec<_>>();
+    let suspended_merchant_ids = user_roles
+        .iter()
+        .filter(|user_role| user_role.status == UserStatus::Suspended)
+        .map(|user_role| user_role.merchant_id.clone())
+        .collect::<HashSet<_>>();
+
+    let mut merchant_accounts = state
+        .store
+        .list_multiple_merchant_accounts(
+            user_roles
+                .iter()
+                .map(|user_role| user_role.merchant_id.clone())
+                .collect(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|acc| (acc.merchant_id.clone(), acc))
+        .collect::<HashMap<_, _>>();
 
     Ok(ApplicationResponse::Json(
-        merchant_accounts
-            .into_iter()
-            .map(|acc| user_api::UserMerchantAccount {
-                merchant_id: acc.merchant_id,
-                merchant_name: acc.merchant_name,
-            })
-            .collect(),
+        user_api::ListMerchantsForUserResponse {
+            merchant_accounts: user_roles
+                .iter()
+                .filter_map(|user_role| merchant_accounts.remove(&user_role.merchant_id))
+                .map(|acc| user_api::UserMerchantAccount {
+                    is_suspended: suspended_merchant_ids.contains(&acc.merchant_id),
+                    merchant_id: acc.merchant_id,
+                    merchant_name: acc.merchant_name,
+                })
+                .collect(),
+            next_cursor,
+        },
     ))
 }
 
+/// Lists a page of the users of the merchant, ordered by when they joined the merchant
 pub async fn get_users_for_merchant_account(
     state: AppState,
     user_from_token: auth::UserFromToken,
-) -> UserResponse<user_api::GetUsersResponse> {
-    let users = state
+    req: user_api::UserRoleListQuery,
+) -> UserResponse<user_api::ListUsersResponse> {
+    let limit = utils::user_role::get_user_role_list_limit(req.limit)?;
+    let cursor = req
+        .cursor
+        .as_deref()
+        .map(utils::user_role::decode_user_role_list_cursor)
+        .transpose()?;
+
+    let custom_roles =
+        authorization::get_custom_roles(&*state.store, user_from_token.merchant_id.as_str())
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+    let users_and_roles = state
         .store
-        .find_users_and_roles_by_merchant_id(user_from_token.merchant_id.as_str())
+        .find_users_and_roles_by_merchant_id_after_cursor(
+            user_from_token.merchant_id.as_str(),
+            cursor,
+            limit + 1,
+        )
         .await
         .change_context(UserErrors::InternalServerError)
-        .attach_printable("No users for given merchant id")?
+        .attach_printable("No users for given merchant id")?;
+    let (users_and_roles, next_cursor) =
+        utils::user_role::paginate_user_role_list(users_and_roles, limit, |(_, role)| role);
+    let users = users_and_roles
         .into_iter()
-        .filter_map(|(user, role)| domain::UserAndRoleJoined(user, role).try_into().ok())
+        .filter_map(|(user, role)| {
+            let custom_role_name = custom_roles
+                .get(role.role_id.as_str())
+                .map(|custom_role| custom_role.role_name.clone());
+            domain::UserAndRoleJoined(user, role, custom_role_name)
+                .try_into()
+                .ok()
+        })
         .collect();
 
-    Ok(ApplicationResponse::Json(user_api::GetUsersResponse(users)))
+    Ok(ApplicationResponse::Json(user_api::ListUsersResponse {
+        users,
+        next_cursor,
+    }))
 }
 
 #[cfg(feature = "email")]
@@ -1,22 +1,61 @@
 use api_models::user_role as user_role_api;
-use diesel_models::{enums::UserStatus, user_role::UserRoleUpdate};
-use error_stack::ResultExt;
+use common_utils::ext_traits::{Encode, StringExt};
+use diesel_models::{
+    enums::UserStatus,
+    user_role::{
+        RolePreferences, RolePreferencesNew, RolePreferencesUpdate, UserRole,
+        UserRoleChangeRequestNew, UserRoleNew, UserRoleUpdate,
+    },
+};
+use error_stack::{report, ResultExt};
 use router_env::logger;
+use time::PrimitiveDateTime;
 
 use crate::{
-    core::errors::{UserErrors, UserResponse},
+    consts,
+    core::errors::{UserErrors, UserResponse, UserResult},
     routes::AppState,
     services::{
         authentication::{self as auth},
-        authorization::{info, predefined_permissions},
+        authorization::{self, info, predefined_permissions},
         ApplicationResponse,
     },
+    types::domain,
     utils,
 };
 
+/// Checks that the user has the permission configured for viewing the roles, so that any user of
+/// the merchant cannot enumerate the permissions and the roles
+async fn check_role_info_access(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+) -> UserResult<()> {
+    let permissions = authorization::get_permissions_for_merchant(
+        &*state.store,
+        &user_from_token.role_id,
+        &user_from_token.merchant_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    authorization::check_authorization(&state.conf.role_info_access.view_permission, &permissions)
+        .change_context(UserErrors::InsufficientPermission)?;
+
+    authorization::check_user_not_suspended(
+        &*state.store,
+        &user_from_token.user_id,
+        &user_from_token.merchant_id,
+    )
+    .await
+    .change_context(UserErrors::InsufficientPermission)
+}
+
 pub async fn get_authorization_info(
-    _state: AppState,
+    state: AppState,
+    user_from_token: auth::UserFromToken,
 ) -> UserResponse<user_role_api::AuthorizationInfoResponse> {
+    check_role_info_access(&state, &user_from_token).await?;
+
     Ok(ApplicationResponse::Json(
         user_role_api::AuthorizationInfoResponse(
             info::get_authorization_info()
@@ -27,94 +66,1702 @@ pub async fn get_authorization_info(
     ))
 }
 
-pub async fn list_roles(_state: AppState) -> UserResponse<user_role_api::ListRolesResponse> {
-    Ok(ApplicationResponse::Json(user_role_api::ListRolesResponse(
-        predefined_permissions::PREDEFINED_PERMISSIONS
-            .iter()
-            .filter_map(|(role_id, role_info)| {
-                utils::user_role::get_role_name_and_permission_response(role_info).map(
-                    |(permissions, role_name)| user_role_api::RoleInfoResponse {
-                        permissions,
-                        role_id,
-                        role_name,
-                    },
-                )
-            })
-            .collect(),
-    )))
+pub async fn list_roles(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<user_role_api::ListRolesResponse> {
+    check_role_info_access(&state, &user_from_token).await?;
+
+    let preferences = state
+        .store
+        .list_role_preferences_by_merchant_id_user_id(
+            user_from_token.merchant_id.as_str(),
+            user_from_token.user_id.as_str(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    let permission_overrides = authorization::get_permission_overrides(
+        &*state.store,
+        user_from_token.merchant_id.as_str(),
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+    let custom_roles =
+        authorization::get_custom_roles(&*state.store, user_from_token.merchant_id.as_str())
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+
+    let role_infos = predefined_permissions::get_predefined_permissions();
+    let predefined_roles = role_infos.iter().filter_map(|(role_id, role_info)| {
+        let permissions = authorization::apply_permission_override(
+            role_info.get_permissions(),
+            permission_overrides.get(*role_id),
+        );
+        role_info
+            .get_name()
+            .map(|role_name| user_role_api::RoleInfoResponse {
+                permissions: permissions.iter().map(|&per| per.into()).collect(),
+                permission_info: utils::user_role::get_permission_info_response(&permissions),
+                role_id: role_id.to_string(),
+                role_name: role_name.to_string(),
+                preferences: utils::user_role::resolve_role_preferences(
+                    &preferences,
+                    role_id,
+                    user_from_token.user_id.as_str(),
+                ),
+            })
+    });
+    let custom_roles =
+        custom_roles
+            .into_iter()
+            .map(|(role_id, custom_role)| user_role_api::RoleInfoResponse {
+                permissions: custom_role
+                    .permissions
+                    .iter()
+                    .map(|&per| per.into())
+                    .collect(),
+                permission_info: utils::user_role::get_permission_info_response(
+                    &custom_role.permissions,
+                ),
+                preferences: utils::user_role::resolve_role_preferences(
+                    &preferences,
+                    role_id.as_str(),
+                    user_from_token.user_id.as_str(),
+                ),
+                role_id,
+                role_name: custom_role.role_name,
+            });
+
+    Ok(ApplicationResponse::Json(user_role_api::ListRolesResponse(
+        predefined_roles.chain(custom_roles).collect(),
+    )))
+}
+
+pub async fn get_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    role: user_role_api::GetRoleRequest,
+) -> UserResponse<user_role_api::RoleInfoResponse> {
+    let preferences = state
+        .store
+        .list_role_preferences_by_merchant_id_user_id(
+            user_from_token.merchant_id.as_str(),
+            user_from_token.user_id.as_str(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    let permission_overrides = authorization::get_permission_overrides(
+        &*state.store,
+        user_from_token.merchant_id.as_str(),
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    let (role_name, permissions) =
+        match predefined_permissions::get_predefined_permissions().get(role.role_id.as_str()) {
+            Some(role_info) => (
+                role_info
+                    .get_name()
+                    .ok_or(UserErrors::InvalidRoleId)?
+                    .to_string(),
+                authorization::apply_permission_override(
+                    role_info.get_permissions(),
+                    permission_overrides.get(role.role_id.as_str()),
+                ),
+            ),
+            None => {
+                let custom_role = authorization::get_custom_roles(
+                    &*state.store,
+                    user_from_token.merchant_id.as_str(),
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?
+                .remove(role.role_id.as_str())
+                .ok_or(UserErrors::InvalidRoleId)?;
+                (custom_role.role_name, custom_role.permissions)
+            }
+        };
+
+    Ok(ApplicationResponse::Json(user_role_api::RoleInfoResponse {
+        permissions: permissions.iter().map(|&per| per.into()).collect(),
+        permission_info: utils::user_role::get_permission_info_response(&permissions),
+        preferences: utils::user_role::resolve_role_preferences(
+            &preferences,
+            role.role_id.as_str(),
+            user_from_token.user_id.as_str(),
+        ),
+        role_id: role.role_id,
+        role_name,
+    }))
+}
+
+pub async fn get_role_from_token(
+    state: AppState,
+    user: auth::UserFromToken,
+) -> UserResponse<Vec<user_role_api::Permission>> {
+    Ok(ApplicationResponse::Json(
+        authorization::get_permissions_for_merchant(
+            &*state.store,
+            user.role_id.as_str(),
+            user.merchant_id.as_str(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Invalid Role Id in JWT")?
+        .iter()
+        .map(|&per| per.into())
+        .collect(),
+    ))
+}
+
+/// Returns the role of the user in every merchant they are part of, along with the permissions
+/// each role grants in its merchant. Suspended and expired roles are included and marked as such.
+pub async fn get_user_access_map(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::GetUserAccessMapRequest,
+) -> UserResponse<user_role_api::UserAccessMapResponse> {
+    let user_id = match req.user_id {
+        Some(user_id) if user_id != user_from_token.user_id => {
+            if !utils::user_role::is_internal_role(&user_from_token.role_id) {
+                return Err(UserErrors::InsufficientPermission.into()).attach_printable(
+                    "Only internal users can look up the access of another user",
+                );
+            }
+            user_id
+        }
+        _ => user_from_token.user_id,
+    };
+
+    let user_roles = state
+        .store
+        .list_user_roles_by_user_id(&user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let mut merchants = Vec::with_capacity(user_roles.len());
+    for user_role in user_roles {
+        let permission_overrides =
+            authorization::get_permission_overrides(&*state.store, &user_role.merchant_id)
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+        let custom_roles = authorization::get_custom_roles(&*state.store, &user_role.merchant_id)
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+
+        let role_name = predefined_permissions::get_role_name_from_id(&user_role.role_id)
+            .map(ToString::to_string)
+            .or_else(|| {
+                custom_roles
+                    .get(&user_role.role_id)
+                    .map(|custom_role| custom_role.role_name.clone())
+            });
+        let permissions = authorization::resolve_permissions_for_merchant(
+            &user_role.role_id,
+            &permission_overrides,
+            &custom_roles,
+        )
+        .unwrap_or_default();
+        let is_expired = user_role.is_expired();
+        let status = match user_role.status {
+            UserStatus::Active => user_role_api::UserStatus::Active,
+            UserStatus::InvitationSent => user_role_api::UserStatus::InvitationSent,
+            UserStatus::Suspended => user_role_api::UserStatus::Suspended,
+        };
+
+        merchants.push(user_role_api::MerchantAccessResponse {
+            is_effective: user_role.status == UserStatus::Active && !is_expired,
+            merchant_id: user_role.merchant_id,
+            role_id: user_role.role_id,
+            role_name,
+            permissions: permissions.iter().map(|&per| per.into()).collect(),
+            status,
+            expires_at: user_role.expires_at,
+            is_expired,
+        });
+    }
+
+    Ok(ApplicationResponse::Json(
+        user_role_api::UserAccessMapResponse { user_id, merchants },
+    ))
+}
+
+pub async fn update_user_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::UpdateUserRoleRequest,
+) -> UserResponse<user_role_api::UpdateUserRoleResponse> {
+    if req.role_id.is_none() && req.title.is_none() && req.department.is_none() {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Neither the role nor the attributes of the user are updated");
+    }
+    if let Some(role_id) = req.role_id.as_deref() {
+        utils::user_role::validate_role_id_for_merchant(
+            &state,
+            role_id,
+            user_from_token.merchant_id.as_str(),
+        )
+        .await?;
+    }
+    utils::user_role::validate_role_expiry(req.expires_at)?;
+    utils::user_role::validate_user_role_attribute(req.title.as_deref())?;
+    utils::user_role::validate_user_role_attribute(req.department.as_deref())?;
+
+    if user_from_token.user_id == req.user_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Admin User Changing their role");
+    }
+
+    utils::user_role::check_role_change_rate_limit(&state, &user_from_token.user_id).await?;
+
+    let target_user_role =
+        find_managed_user_role(&state, &user_from_token, req.user_id.as_str()).await?;
+
+    // The attributes do not affect the permissions of the user, so they are updated right away
+    // even when the role change awaits approval
+    if req.title.is_some() || req.department.is_some() {
+        update_attributes_of_managed_user(
+            &state,
+            &user_from_token,
+            &target_user_role,
+            req.title,
+            req.department,
+        )
+        .await?;
+    }
+
+    let role_id = match req.role_id {
+        Some(role_id) => role_id,
+        None => {
+            return Ok(ApplicationResponse::Json(
+                user_role_api::UpdateUserRoleResponse {
+                    status: user_role_api::RoleUpdateStatus::Updated,
+                    request_id: None,
+                },
+            ))
+        }
+    };
+
+    if utils::user_role::is_sensitive_role(&state, role_id.as_str()) {
+        let request_id = create_role_change_request(
+            &state,
+            &user_from_token,
+            req.user_id,
+            role_id,
+            req.expires_at,
+        )
+        .await?;
+        return Ok(ApplicationResponse::Json(
+            user_role_api::UpdateUserRoleResponse {
+                status: user_role_api::RoleUpdateStatus::PendingApproval,
+                request_id: Some(request_id),
+            },
+        ));
+    }
+
+    update_role_of_managed_user(
+        &state,
+        &user_from_token,
+        target_user_role,
+        role_id,
+        req.expires_at,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_role_api::UpdateUserRoleResponse {
+            status: user_role_api::RoleUpdateStatus::Updated,
+            request_id: None,
+        },
+    ))
+}
+
+pub async fn assign_or_update_user_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::AssignOrUpdateUserRoleRequest,
+) -> UserResponse<user_role_api::AssignOrUpdateUserRoleResponse> {
+    utils::user_role::validate_role_id_for_merchant(
+        &state,
+        req.role_id.as_str(),
+        user_from_token.merchant_id.as_str(),
+    )
+    .await?;
+    utils::user_role::validate_role_expiry(req.expires_at)?;
+
+    if user_from_token.user_id == req.user_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Admin User Changing their role");
+    }
+
+    utils::user_role::check_role_change_rate_limit(&state, &user_from_token.user_id).await?;
+
+    let find_target_user_role = || {
+        state.store.find_user_role_by_user_id_merchant_id(
+            req.user_id.as_str(),
+            user_from_token.merchant_id.as_str(),
+        )
+    };
+
+    if utils::user_role::is_sensitive_role(&state, req.role_id.as_str()) {
+        match find_target_user_role().await {
+            Ok(target_user_role) => {
+                validate_can_manage_user(&state, &user_from_token, &target_user_role).await?
+            }
+            Err(e) if e.current_context().is_db_not_found() => {
+                validate_user_exists(&state, req.user_id.as_str()).await?
+            }
+            Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+        }
+        let request_id = create_role_change_request(
+            &state,
+            &user_from_token,
+            req.user_id,
+            req.role_id,
+            req.expires_at,
+        )
+        .await?;
+        return Ok(ApplicationResponse::Json(
+            user_role_api::AssignOrUpdateUserRoleResponse {
+                status: user_role_api::RoleAssignmentStatus::PendingApproval,
+                request_id: Some(request_id),
+            },
+        ));
+    }
+
+    let status = match find_target_user_role().await {
+        Ok(target_user_role) => {
+            update_role_of_managed_user(
+                &state,
+                &user_from_token,
+                target_user_role,
+                req.role_id.clone(),
+                req.expires_at,
+            )
+            .await?;
+            user_role_api::RoleAssignmentStatus::Updated
+        }
+        Err(e) if e.current_context().is_db_not_found() => {
+            validate_user_exists(&state, req.user_id.as_str()).await?;
+            validate_can_assign_role(&state, &user_from_token, req.role_id.as_str()).await?;
+            utils::user_role::remove_lingering_expired_user_role(
+                &state,
+                req.user_id.as_str(),
+                user_from_token.merchant_id.as_str(),
+            )
+            .await?;
+
+            match state
+                .store
+                .insert_user_role(new_user_role(
+                    &user_from_token,
+                    req.user_id.clone(),
+                    req.role_id.clone(),
+                    req.expires_at,
+                ))
+                .await
+            {
+                Ok(user_role) => {
+                    utils::user_role::add_user_role_expiry_task(&state, &user_role).await?;
+                    user_role_api::RoleAssignmentStatus::Created
+                }
+                // Another request created the user role in the meantime, fall back to an update
+                Err(e) if e.current_context().is_db_unique_violation() => {
+                    let target_user_role = find_target_user_role()
+                        .await
+                        .change_context(UserErrors::InternalServerError)?;
+                    update_role_of_managed_user(
+                        &state,
+                        &user_from_token,
+                        target_user_role,
+                        req.role_id.clone(),
+                        req.expires_at,
+                    )
+                    .await?;
+                    user_role_api::RoleAssignmentStatus::Updated
+                }
+                Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+            }
+        }
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    };
+
+    Ok(ApplicationResponse::Json(
+        user_role_api::AssignOrUpdateUserRoleResponse {
+            status,
+            request_id: None,
+        },
+    ))
+}
+
+pub async fn reset_user_role_to_default(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::ResetUserRoleRequest,
+) -> UserResponse<user_role_api::UpdateUserRoleResponse> {
+    let default_role_id = utils::user_role::get_default_role_id_for_merchant(
+        &state,
+        user_from_token.merchant_id.as_str(),
+    )
+    .await?;
+    utils::user_role::validate_role_id_for_merchant(
+        &state,
+        default_role_id.as_str(),
+        user_from_token.merchant_id.as_str(),
+    )
+    .await?;
+
+    if user_from_token.user_id == req.user_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Admin User Changing their role");
+    }
+
+    utils::user_role::check_role_change_rate_limit(&state, &user_from_token.user_id).await?;
+
+    let target_user_role =
+        find_managed_user_role(&state, &user_from_token, req.user_id.as_str()).await?;
+
+    if target_user_role.role_id == default_role_id {
+        logger::info!("User is already assigned the default role of the merchant");
+        return Ok(ApplicationResponse::Json(
+            user_role_api::UpdateUserRoleResponse {
+                status: user_role_api::RoleUpdateStatus::Updated,
+                request_id: None,
+            },
+        ));
+    }
+
+    // Moving a user out of a sensitive role needs the same approval as moving them into one
+    if utils::user_role::is_sensitive_role(&state, default_role_id.as_str())
+        || utils::user_role::is_sensitive_role(&state, target_user_role.role_id.as_str())
+    {
+        let request_id = create_role_change_request(
+            &state,
+            &user_from_token,
+            req.user_id,
+            default_role_id,
+            None,
+        )
+        .await?;
+        return Ok(ApplicationResponse::Json(
+            user_role_api::UpdateUserRoleResponse {
+                status: user_role_api::RoleUpdateStatus::PendingApproval,
+                request_id: Some(request_id),
+            },
+        ));
+    }
+
+    update_role_of_managed_user(
+        &state,
+        &user_from_token,
+        target_user_role,
+        default_role_id,
+        None,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_role_api::UpdateUserRoleResponse {
+            status: user_role_api::RoleUpdateStatus::Updated,
+            request_id: None,
+        },
+    ))
+}
+
+pub async fn delete_user_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::DeleteUserRoleRequest,
+) -> UserResponse<()> {
+    if user_from_token.user_id == req.user_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("User deleting their own role");
+    }
+
+    utils::user_role::check_role_change_rate_limit(&state, &user_from_token.user_id).await?;
+
+    let target_user_role =
+        find_managed_user_role(&state, &user_from_token, req.user_id.as_str()).await?;
+
+    if utils::user_role::is_admin_role(target_user_role.role_id.as_str()) {
+        // The check for another admin and the delete run in one transaction, so that concurrent
+        // removals cannot leave the merchant without an admin
+        let is_deleted = state
+            .store
+            .delete_user_role_by_user_id_merchant_id_keeping_admin(
+                target_user_role.user_id.as_str(),
+                target_user_role.merchant_id.as_str(),
+                &consts::user_role::ADMIN_ROLE_IDS,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+        common_utils::fp_utils::when(!is_deleted, || {
+            Err(report!(UserErrors::CannotRemoveLastAdmin))
+                .attach_printable("The change would leave the merchant without an admin")
+        })?;
+    } else {
+        state
+            .store
+            .delete_user_role_by_user_id_merchant_id(
+                target_user_role.user_id.as_str(),
+                target_user_role.merchant_id.as_str(),
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+    }
+
+    // The tokens issued to the user would otherwise keep granting access until they expire
+    utils::user_role::invalidate_user_tokens(&state, &target_user_role.user_id).await?;
+
+    utils::user_role::trigger_user_role_webhook(
+        &state,
+        diesel_models::enums::EventType::UserRoleDeleted,
+        user_role_api::UserRoleWebhookContent {
+            merchant_id: target_user_role.merchant_id,
+            user_id: target_user_role.user_id,
+            old_role: Some(target_user_role.role_id),
+            new_role: None,
+            actor: Some(user_from_token.user_id),
+        },
+    );
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn suspend_user_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::SuspendUserRoleRequest,
+) -> UserResponse<()> {
+    if user_from_token.user_id == req.user_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("User suspending their own access");
+    }
+
+    let target_user_role = find_managed_user_role(&state, &user_from_token, &req.user_id).await?;
+
+    match target_user_role.status {
+        UserStatus::Suspended => {
+            logger::info!("Access of the user to the merchant is already suspended");
+            return Ok(ApplicationResponse::StatusOk);
+        }
+        UserStatus::InvitationSent => {
+            return Err(UserErrors::InvalidRoleOperation.into())
+                .attach_printable("Only users who accepted their invitation can be suspended");
+        }
+        UserStatus::Active => {}
+    }
+
+    update_status_of_managed_user(
+        &state,
+        &user_from_token,
+        &target_user_role,
+        UserStatus::Suspended,
+    )
+    .await?;
+    utils::user_role::invalidate_user_tokens(&state, &target_user_role.user_id).await?;
+
+    utils::user_role::trigger_user_role_webhook(
+        &state,
+        diesel_models::enums::EventType::UserRoleSuspended,
+        user_role_api::UserRoleWebhookContent {
+            merchant_id: target_user_role.merchant_id,
+            user_id: target_user_role.user_id,
+            old_role: Some(target_user_role.role_id.clone()),
+            new_role: Some(target_user_role.role_id),
+            actor: Some(user_from_token.user_id),
+        },
+    );
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn resume_user_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::ResumeUserRoleRequest,
+) -> UserResponse<()> {
+    let target_user_role = find_managed_user_role(&state, &user_from_token, &req.user_id).await?;
+
+    if target_user_role.status != UserStatus::Suspended {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Access of the user to the merchant is not suspended");
+    }
+
+    update_status_of_managed_user(
+        &state,
+        &user_from_token,
+        &target_user_role,
+        UserStatus::Active,
+    )
+    .await?;
+    utils::user_role::clear_user_role_access_cache(
+        &state,
+        &target_user_role.user_id,
+        &target_user_role.merchant_id,
+    )
+    .await?;
+
+    utils::user_role::trigger_user_role_webhook(
+        &state,
+        diesel_models::enums::EventType::UserRoleResumed,
+        user_role_api::UserRoleWebhookContent {
+            merchant_id: target_user_role.merchant_id,
+            user_id: target_user_role.user_id,
+            old_role: Some(target_user_role.role_id.clone()),
+            new_role: Some(target_user_role.role_id),
+            actor: Some(user_from_token.user_id),
+        },
+    );
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn approve_user_role_change(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::ApproveUserRoleChangeRequest,
+) -> UserResponse<()> {
+    let change_request = state
+        .store
+        .find_user_role_change_request_by_request_id_merchant_id(
+            req.request_id.as_str(),
+            user_from_token.merchant_id.as_str(),
+        )
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::RoleChangeRequestNotFound)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    utils::user_role::validate_role_change_approver(
+        user_from_token.user_id.as_str(),
+        user_from_token.role_id.as_str(),
+        change_request.requested_by.as_str(),
+    )?;
+
+    if user_from_token.user_id == change_request.user_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Admin User Approving a change of their role");
+    }
+
+    utils::user_role::validate_role_id_for_merchant(
+        &state,
+        change_request.role_id.as_str(),
+        user_from_token.merchant_id.as_str(),
+    )
+    .await?;
+    utils::user_role::validate_role_expiry(change_request.role_expires_at)?;
+    utils::user_role::check_role_change_rate_limit(&state, &user_from_token.user_id).await?;
+
+    // Removing the request before applying it ensures that concurrent approvals of the same
+    // request apply the change only once
+    state
+        .store
+        .delete_user_role_change_request(change_request.request_id.as_str())
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::RoleChangeRequestNotFound)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    match state
+        .store
+        .find_user_role_by_user_id_merchant_id(
+            change_request.user_id.as_str(),
+            user_from_token.merchant_id.as_str(),
+        )
+        .await
+    {
+        Ok(target_user_role) => {
+            update_role_of_managed_user(
+                &state,
+                &user_from_token,
+                target_user_role,
+                change_request.role_id,
+                change_request.role_expires_at,
+            )
+            .await?
+        }
+        Err(e) if e.current_context().is_db_not_found() => {
+            validate_can_assign_role(&state, &user_from_token, change_request.role_id.as_str())
+                .await?;
+            utils::user_role::remove_lingering_expired_user_role(
+                &state,
+                change_request.user_id.as_str(),
+                user_from_token.merchant_id.as_str(),
+            )
+            .await?;
+            let user_role = state
+                .store
+                .insert_user_role(new_user_role(
+                    &user_from_token,
+                    change_request.user_id,
+                    change_request.role_id,
+                    change_request.role_expires_at,
+                ))
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+            utils::user_role::add_user_role_expiry_task(&state, &user_role).await?
+        }
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn list_user_role_change_requests(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::ListUserRoleChangeRequestsRequest,
+) -> UserResponse<user_role_api::ListUserRoleChangeRequestsResponse> {
+    let change_requests = state
+        .store
+        .list_user_role_change_requests_by_merchant_id(user_from_token.merchant_id.as_str())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::Json(
+        user_role_api::ListUserRoleChangeRequestsResponse(
+            change_requests
+                .into_iter()
+                .filter(|change_request| {
+                    req.user_id
+                        .as_ref()
+                        .map_or(true, |user_id| &change_request.user_id == user_id)
+                })
+                .filter(|change_request| {
+                    !req.approvable_only
+                        || (change_request.requested_by != user_from_token.user_id
+                            && change_request.user_id != user_from_token.user_id)
+                })
+                .map(
+                    |change_request| user_role_api::UserRoleChangeRequestResponse {
+                        request_id: change_request.request_id,
+                        user_id: change_request.user_id,
+                        role_id: change_request.role_id,
+                        role_expires_at: change_request.role_expires_at,
+                        requested_by: change_request.requested_by,
+                        created_at: change_request.created_at,
+                        expires_at: change_request.expires_at,
+                    },
+                )
+                .collect(),
+        ),
+    ))
+}
+
+/// Lists the users of the merchant whose role grants the permission, with the permission overrides
+/// and the custom roles of the merchant applied. Users whose access is suspended are left out,
+/// since they cannot make use of the permission.
+pub async fn list_users_with_permission(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::ListUsersWithPermissionRequest,
+) -> UserResponse<api_models::user::GetUsersResponse> {
+    let merchant_id = user_from_token.merchant_id.as_str();
+    let permission: authorization::permissions::Permission = req.permission.into();
+    let permission_overrides = authorization::get_permission_overrides(&*state.store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    let custom_roles = authorization::get_custom_roles(&*state.store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let users = state
+        .store
+        .find_users_and_roles_by_merchant_id(merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to fetch the users of the merchant")?
+        .into_iter()
+        .filter(|(_, user_role)| {
+            user_role.status != UserStatus::Suspended
+                && authorization::resolve_permissions_for_merchant(
+                    user_role.role_id.as_str(),
+                    &permission_overrides,
+                    &custom_roles,
+                )
+                .map_or(false, |permissions| permissions.contains(&permission))
+        })
+        .filter_map(|(user, user_role)| {
+            let custom_role_name = custom_roles
+                .get(user_role.role_id.as_str())
+                .map(|custom_role| custom_role.role_name.clone());
+            domain::UserAndRoleJoined(user, user_role, custom_role_name)
+                .try_into()
+                .ok()
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        api_models::user::GetUsersResponse(users),
+    ))
+}
+
+/// Lists the pending role changes of the merchant which the user can approve, that is the ones
+/// neither requested by nor targeting the user
+pub async fn update_role_preferences(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::UpdateRolePreferencesRequest,
+) -> UserResponse<user_role_api::RolePreferencesResponse> {
+    utils::user_role::validate_role_id_for_merchant(
+        &state,
+        req.role_id.as_str(),
+        user_from_token.merchant_id.as_str(),
+    )
+    .await?;
+    utils::user_role::validate_role_preferences(
+        req.default_landing_page.as_deref(),
+        req.visible_widgets.as_deref(),
+    )?;
+
+    let preferences = upsert_role_preferences(
+        &state,
+        &user_from_token,
+        req.role_id,
+        None,
+        req.default_landing_page,
+        req.visible_widgets,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(preferences.into()))
+}
+
+pub async fn delete_role_preferences(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::DeleteRolePreferencesRequest,
+) -> UserResponse<()> {
+    state
+        .store
+        .delete_role_preferences(
+            user_from_token.merchant_id.as_str(),
+            req.role_id.as_str(),
+            None,
+        )
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::RolePreferencesNotFound)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn update_user_preferences(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::UpdateUserPreferencesRequest,
+) -> UserResponse<user_role_api::RolePreferencesResponse> {
+    utils::user_role::validate_role_preferences(
+        req.default_landing_page.as_deref(),
+        req.visible_widgets.as_deref(),
+    )?;
+
+    let preferences = upsert_role_preferences(
+        &state,
+        &user_from_token,
+        user_from_token.role_id.clone(),
+        Some(user_from_token.user_id.clone()),
+        req.default_landing_page,
+        req.visible_widgets,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(preferences.into()))
+}
+
+pub async fn delete_user_preferences(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<()> {
+    state
+        .store
+        .delete_role_preferences(
+            user_from_token.merchant_id.as_str(),
+            user_from_token.role_id.as_str(),
+            Some(user_from_token.user_id.as_str()),
+        )
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::RolePreferencesNotFound)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn update_role_permission_overrides(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::UpdateRolePermissionOverridesRequest,
+) -> UserResponse<user_role_api::RoleInfoResponse> {
+    let role_override = authorization::RolePermissionOverride {
+        granted: req.granted.into_iter().map(Into::into).collect(),
+        revoked: req.revoked.into_iter().map(Into::into).collect(),
+    };
+    utils::user_role::validate_permission_override(
+        req.role_id.as_str(),
+        &role_override.granted,
+        &role_override.revoked,
+    )?;
+
+    update_role_config(
+        &state,
+        &authorization::get_permission_overrides_key(user_from_token.merchant_id.as_str()),
+        |permission_overrides: &mut authorization::PermissionOverrides| {
+            permission_overrides.insert(req.role_id.clone(), role_override.clone());
+            Ok(())
+        },
+    )
+    .await?;
+
+    get_role(
+        state,
+        user_from_token,
+        user_role_api::GetRoleRequest {
+            role_id: req.role_id,
+        },
+    )
+    .await
 }
 
-pub async fn get_role(
-    _state: AppState,
-    role: user_role_api::GetRoleRequest,
+pub async fn delete_role_permission_overrides(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::DeleteRolePermissionOverridesRequest,
+) -> UserResponse<()> {
+    update_role_config(
+        &state,
+        &authorization::get_permission_overrides_key(user_from_token.merchant_id.as_str()),
+        |permission_overrides: &mut authorization::PermissionOverrides| {
+            permission_overrides
+                .remove(req.role_id.as_str())
+                .map(|_| ())
+                .ok_or(report!(UserErrors::PermissionOverrideNotFound))
+        },
+    )
+    .await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn clone_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::CloneRoleRequest,
 ) -> UserResponse<user_role_api::RoleInfoResponse> {
-    let info = predefined_permissions::PREDEFINED_PERMISSIONS
-        .get_key_value(role.role_id.as_str())
-        .and_then(|(role_id, role_info)| {
-            utils::user_role::get_role_name_and_permission_response(role_info).map(
-                |(permissions, role_name)| user_role_api::RoleInfoResponse {
+    let role_name = utils::user_role::validate_role_name(req.role_name.as_str())?;
+    let merchant_id = user_from_token.merchant_id.as_str();
+
+    // Internal and organization roles cannot be cloned, as their permissions go beyond the
+    // merchant. A predefined role is cloned with the permission overrides of the merchant applied.
+    let predefined_role_permissions =
+        if predefined_permissions::is_role_invitable(req.role_id.as_str()) {
+            Some(
+                authorization::get_permissions_for_merchant(
+                    &*state.store,
+                    req.role_id.as_str(),
+                    merchant_id,
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?,
+            )
+        } else {
+            None
+        };
+
+    let role_id =
+        common_utils::generate_id_with_default_len(consts::user_role::CUSTOM_ROLE_ID_PREFIX);
+
+    // The custom roles are checked and updated together, so that a role cloned concurrently with
+    // the same name or from a role deleted in the meantime is rejected rather than overwritten
+    update_role_config(
+        &state,
+        &authorization::get_custom_roles_key(merchant_id),
+        |custom_roles: &mut authorization::CustomRoles| {
+            let permissions = match predefined_role_permissions.clone() {
+                Some(permissions) => permissions,
+                None => custom_roles
+                    .get(req.role_id.as_str())
+                    .map(|custom_role| custom_role.permissions.clone())
+                    .ok_or(UserErrors::InvalidRoleId)?,
+            };
+
+            let is_name_taken = predefined_permissions::get_predefined_permissions()
+                .values()
+                .filter_map(|role_info| role_info.get_name())
+                .chain(
+                    custom_roles
+                        .values()
+                        .map(|custom_role| custom_role.role_name.as_str()),
+                )
+                .any(|existing_name| existing_name.eq_ignore_ascii_case(role_name.as_str()));
+            if is_name_taken {
+                return Err(UserErrors::RoleNameAlreadyExists.into());
+            }
+
+            custom_roles.insert(
+                role_id.clone(),
+                authorization::CustomRole {
+                    role_name: role_name.clone(),
                     permissions,
-                    role_id,
-                    role_name,
                 },
-            )
+            );
+            Ok(())
+        },
+    )
+    .await?;
+
+    get_role(
+        state,
+        user_from_token,
+        user_role_api::GetRoleRequest { role_id },
+    )
+    .await
+}
+
+pub async fn export_roles(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<user_role_api::RoleDefinitions> {
+    let merchant_id = user_from_token.merchant_id.as_str();
+    let permission_overrides = authorization::get_permission_overrides(&*state.store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    let custom_roles = authorization::get_custom_roles(&*state.store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let mut predefined_roles = predefined_permissions::get_predefined_permissions()
+        .keys()
+        .filter(|role_id| predefined_permissions::is_role_invitable(role_id))
+        .map(|role_id| {
+            let role_override = permission_overrides
+                .get(*role_id)
+                .cloned()
+                .unwrap_or_default();
+            user_role_api::PredefinedRoleDefinition {
+                role_id: role_id.to_string(),
+                granted: role_override.granted.into_iter().map(Into::into).collect(),
+                revoked: role_override.revoked.into_iter().map(Into::into).collect(),
+            }
         })
-        .ok_or(UserErrors::InvalidRoleId)?;
+        .collect::<Vec<_>>();
+    predefined_roles.sort_by(|a, b| a.role_id.cmp(&b.role_id));
+
+    let mut custom_roles = custom_roles
+        .into_iter()
+        .map(
+            |(role_id, custom_role)| user_role_api::CustomRoleDefinition {
+                role_id,
+                role_name: custom_role.role_name,
+                permissions: custom_role
+                    .permissions
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            },
+        )
+        .collect::<Vec<_>>();
+    custom_roles.sort_by(|a, b| a.role_id.cmp(&b.role_id));
 
-    Ok(ApplicationResponse::Json(info))
+    Ok(ApplicationResponse::Json(user_role_api::RoleDefinitions {
+        predefined_roles,
+        custom_roles,
+    }))
 }
 
-pub async fn get_role_from_token(
-    _state: AppState,
-    user: auth::UserFromToken,
-) -> UserResponse<Vec<user_role_api::Permission>> {
+/// Restores the role definitions of the merchant from an export. Importing the role definitions
+/// the merchant already has is a no-op, and different role definitions are only replaced when
+/// `force` is set.
+pub async fn import_roles(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::ImportRolesRequest,
+) -> UserResponse<user_role_api::ImportRolesResponse> {
+    let merchant_id = user_from_token.merchant_id.as_str();
+    let (imported_overrides, imported_custom_roles) =
+        utils::user_role::validate_role_definitions(req.roles)?;
+
+    let permission_overrides_key = authorization::get_permission_overrides_key(merchant_id);
+    let custom_roles_key = authorization::get_custom_roles_key(merchant_id);
+    let (current_overrides, permission_overrides) =
+        find_role_config::<authorization::PermissionOverrides>(&state, &permission_overrides_key)
+            .await?;
+    let (current_custom_roles, custom_roles) =
+        find_role_config::<authorization::CustomRoles>(&state, &custom_roles_key).await?;
+
+    if permission_overrides == imported_overrides && custom_roles == imported_custom_roles {
+        return Ok(ApplicationResponse::Json(
+            user_role_api::ImportRolesResponse {
+                status: user_role_api::RoleImportStatus::Unchanged,
+            },
+        ));
+    }
+
+    let has_role_definitions = !permission_overrides.is_empty() || !custom_roles.is_empty();
+    if has_role_definitions && !req.force {
+        return Err(UserErrors::RoleDefinitionsAlreadyExist.into());
+    }
+
+    // Both role configs are only replaced when they were not changed since they were compared, an
+    // import is not retried on the new role definitions as it would discard them
+    let imported_overrides_config = set_role_config(
+        &state,
+        &permission_overrides_key,
+        current_overrides,
+        &imported_overrides,
+    )
+    .await?
+    .ok_or(UserErrors::RoleDefinitionsChanged)?;
+
+    // The configs table has no transactions spanning several keys, so the permission overrides are
+    // restored when the custom roles cannot be stored, leaving the previous definitions in place
+    let custom_roles_result = set_role_config(
+        &state,
+        &custom_roles_key,
+        current_custom_roles,
+        &imported_custom_roles,
+    )
+    .await
+    .and_then(|stored| stored.ok_or(report!(UserErrors::RoleDefinitionsChanged)));
+
+    if let Err(error) = custom_roles_result {
+        set_role_config(
+            &state,
+            &permission_overrides_key,
+            Some(imported_overrides_config),
+            &permission_overrides,
+        )
+        .await
+        .map_err(|restore_error| {
+            logger::error!(
+                ?restore_error,
+                "Failed to restore the permission overrides after a failed role import"
+            );
+        })
+        .ok();
+        return Err(error);
+    }
+
     Ok(ApplicationResponse::Json(
-        predefined_permissions::PREDEFINED_PERMISSIONS
-            .get(user.role_id.as_str())
-            .ok_or(UserErrors::InternalServerError.into())
-            .attach_printable("Invalid Role Id in JWT")?
-            .get_permissions()
-            .iter()
-            .map(|&per| per.into())
-            .collect(),
+        user_role_api::ImportRolesResponse {
+            status: user_role_api::RoleImportStatus::Imported,
+        },
     ))
 }
 
-pub async fn update_user_role(
-    state: AppState,
-    user_from_token: auth::UserFromToken,
-    req: user_role_api::UpdateUserRoleRequest,
-) -> UserResponse<()> {
-    let merchant_id = user_from_token.merchant_id;
-    let role_id = req.role_id.clone();
-    utils::user_role::validate_role_id(role_id.as_str())?;
+/// Reads a role config of the merchant, such as its permission overrides or custom roles, from
+/// the database rather than the cache, as it is read to be updated. The stored config is returned
+/// along with the role config, which is empty when the merchant has none.
+async fn find_role_config<T>(state: &AppState, key: &str) -> UserResult<(Option<String>, T)>
+where
+    T: Default + serde::de::DeserializeOwned,
+{
+    let stored_config = match state.store.find_config_by_key_from_db(key).await {
+        Ok(config) => Some(config.config),
+        Err(error) if error.current_context().is_db_not_found() => None,
+        Err(error) => {
+            return Err(error)
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to fetch the role config of the merchant")
+        }
+    };
 
-    if user_from_token.user_id == req.user_id {
+    let role_config: T = match stored_config.as_ref() {
+        Some(config) => config
+            .parse_struct(std::any::type_name::<T>())
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to parse the role config of the merchant")?,
+        None => T::default(),
+    };
+
+    Ok((stored_config, role_config))
+}
+
+/// Stores a role config of the merchant when the stored config is still `stored_config`, and
+/// returns the new stored config, or `None` when the role config was changed in the meantime.
+/// The cached config used to authorize the requests is invalidated by the update.
+async fn set_role_config<T: serde::Serialize>(
+    state: &AppState,
+    key: &str,
+    stored_config: Option<String>,
+    role_config: &T,
+) -> UserResult<Option<String>> {
+    let config = Encode::<T>::encode_to_string_of_json(role_config)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to serialize the role config of the merchant")?;
+
+    Ok(state
+        .store
+        .set_config_by_key_if_unchanged(key, stored_config, config)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to update the role config of the merchant")?
+        .map(|config| config.config))
+}
+
+/// Applies `update` to a role config of the merchant and returns the updated role config. The
+/// role config is only written back when it was not changed since it was read, otherwise `update`
+/// is applied again to the new role config, so that concurrent updates are not lost.
+async fn update_role_config<T, F>(state: &AppState, key: &str, update: F) -> UserResult<T>
+where
+    T: Default + serde::Serialize + serde::de::DeserializeOwned,
+    F: Fn(&mut T) -> UserResult<()>,
+{
+    for _ in 0..consts::user_role::ROLE_CONFIG_UPDATE_ATTEMPTS {
+        let (stored_config, mut role_config) = find_role_config::<T>(state, key).await?;
+        update(&mut role_config)?;
+
+        if set_role_config(state, key, stored_config, &role_config)
+            .await?
+            .is_some()
+        {
+            return Ok(role_config);
+        }
+    }
+
+    Err(UserErrors::RoleDefinitionsChanged.into())
+}
+
+/// Updates the preferences of the role, or of the user in the role when a user is given, and
+/// creates them if they were not set before
+async fn upsert_role_preferences(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    role_id: String,
+    user_id: Option<String>,
+    default_landing_page: Option<String>,
+    visible_widgets: Option<Vec<String>>,
+) -> UserResult<RolePreferences> {
+    let visible_widgets: Option<Vec<Option<String>>> =
+        visible_widgets.map(|visible_widgets| visible_widgets.into_iter().map(Some).collect());
+
+    let update_preferences = || {
+        state.store.update_role_preferences(
+            user_from_token.merchant_id.as_str(),
+            role_id.as_str(),
+            user_id.as_deref(),
+            RolePreferencesUpdate::UpdatePreferences {
+                default_landing_page: default_landing_page.clone(),
+                visible_widgets: visible_widgets.clone(),
+                modified_by: user_from_token.user_id.clone(),
+            },
+        )
+    };
+
+    match update_preferences().await {
+        Ok(preferences) => return Ok(preferences),
+        Err(e) if e.current_context().is_db_not_found() => {}
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    }
+
+    let now = common_utils::date_time::now();
+    match state
+        .store
+        .insert_role_preferences(RolePreferencesNew {
+            merchant_id: user_from_token.merchant_id.clone(),
+            role_id: role_id.clone(),
+            user_id: user_id.clone(),
+            default_landing_page: default_landing_page.clone(),
+            visible_widgets: visible_widgets.clone(),
+            created_by: user_from_token.user_id.clone(),
+            created_at: now,
+            last_modified_by: user_from_token.user_id.clone(),
+            last_modified_at: now,
+        })
+        .await
+    {
+        Ok(preferences) => Ok(preferences),
+        // Another request created the preferences in the meantime, fall back to an update
+        Err(e) if e.current_context().is_db_unique_violation() => update_preferences()
+            .await
+            .change_context(UserErrors::InternalServerError),
+        Err(e) => Err(e.change_context(UserErrors::InternalServerError)),
+    }
+}
+
+/// Holds the change of the role of a user to a sensitive role until a second admin approves it,
+/// returns the id of the created request
+async fn create_role_change_request(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    user_id: String,
+    role_id: String,
+    expires_at: Option<PrimitiveDateTime>,
+) -> UserResult<String> {
+    // The approver is checked again when the change is applied, the requester is checked here so
+    // that a change they could not make themselves is not put up for approval
+    validate_can_assign_role(state, user_from_token, role_id.as_str()).await?;
+
+    // An expired request of the user should not block a new one
+    match state
+        .store
+        .delete_expired_user_role_change_request_by_user_id_merchant_id(
+            user_id.as_str(),
+            user_from_token.merchant_id.as_str(),
+        )
+        .await
+    {
+        Ok(_) => {}
+        Err(e) if e.current_context().is_db_not_found() => {}
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    }
+
+    let now = common_utils::date_time::now();
+    let change_request = state
+        .store
+        .insert_user_role_change_request(UserRoleChangeRequestNew {
+            request_id: common_utils::generate_id(consts::ID_LENGTH, "role_change"),
+            user_id,
+            merchant_id: user_from_token.merchant_id.clone(),
+            role_id,
+            role_expires_at: expires_at,
+            requested_by: user_from_token.user_id.clone(),
+            created_at: now,
+            expires_at: now
+                + time::Duration::seconds(state.conf.role_change_approval.request_expiry_in_secs),
+        })
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_unique_violation() {
+                e.change_context(UserErrors::RoleChangeRequestAlreadyPending)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    Ok(change_request.request_id)
+}
+
+fn new_user_role(
+    user_from_token: &auth::UserFromToken,
+    user_id: String,
+    role_id: String,
+    expires_at: Option<PrimitiveDateTime>,
+) -> UserRoleNew {
+    let now = common_utils::date_time::now();
+    UserRoleNew {
+        user_id,
+        merchant_id: user_from_token.merchant_id.clone(),
+        role_id,
+        org_id: user_from_token.org_id.clone(),
+        status: UserStatus::Active,
+        created_by: user_from_token.user_id.clone(),
+        last_modified_by: user_from_token.user_id.clone(),
+        created_at: now,
+        last_modified: now,
+        expires_at,
+        title: None,
+        department: None,
+    }
+}
+
+async fn validate_user_exists(state: &AppState, user_id: &str) -> UserResult<()> {
+    state
+        .store
+        .find_user_by_id(user_id)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::UserNotFound)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })
+}
+
+/// Checks that the user can manage the target user, from the effective permissions of their roles
+/// in the merchant, so that the permission overrides and the custom roles of the merchant are
+/// taken into account as when authorizing their requests
+async fn validate_can_manage_user(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    target_user_role: &UserRole,
+) -> UserResult<()> {
+    let merchant_id = user_from_token.merchant_id.as_str();
+    let permission_overrides = authorization::get_permission_overrides(&*state.store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    let custom_roles = authorization::get_custom_roles(&*state.store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let resolve_permissions = |role_id: &str| {
+        authorization::resolve_permissions_for_merchant(
+            role_id,
+            &permission_overrides,
+            &custom_roles,
+        )
+    };
+
+    let can_manage_user = match (
+        resolve_permissions(user_from_token.role_id.as_str()),
+        resolve_permissions(target_user_role.role_id.as_str()),
+    ) {
+        (Some(actor_permissions), Some(target_permissions)) => {
+            utils::user_role::can_manage_user(&actor_permissions, &target_permissions)
+        }
+        _ => false,
+    };
+
+    if !can_manage_user {
         return Err(UserErrors::InvalidRoleOperation.into())
-            .attach_printable("Admin User Changing their role");
+            .attach_printable("User does not have enough permissions to manage the target user");
+    }
+    Ok(())
+}
+
+/// Checks that the user holds every permission of the role they assign, so that a user cannot
+/// grant another user a role with more rights than their own
+async fn validate_can_assign_role(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    role_id: &str,
+) -> UserResult<()> {
+    let merchant_id = user_from_token.merchant_id.as_str();
+    let actor_permissions = authorization::get_permissions_for_merchant(
+        &*state.store,
+        user_from_token.role_id.as_str(),
+        merchant_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+    let role_permissions =
+        authorization::get_permissions_for_merchant(&*state.store, role_id, merchant_id)
+            .await
+            .change_context(UserErrors::InvalidRoleId)?;
+
+    if !utils::user_role::can_assign_role(&actor_permissions, &role_permissions) {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("User does not have every permission of the role being assigned");
+    }
+    Ok(())
+}
+
+async fn find_managed_user_role(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    user_id: &str,
+) -> UserResult<UserRole> {
+    let target_user_role = match state
+        .store
+        .find_user_role_by_user_id_merchant_id(user_id, user_from_token.merchant_id.as_str())
+        .await
+    {
+        Ok(target_user_role) => target_user_role,
+        Err(e) if e.current_context().is_db_not_found() => {
+            return Err(get_user_role_not_found_error(state, user_from_token, user_id).await)
+        }
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    };
+
+    validate_can_manage_user(state, user_from_token, &target_user_role).await?;
+    Ok(target_user_role)
+}
+
+/// Tells apart a target user who does not exist from one who is not associated with the merchant
+/// of the caller. Only callers permitted to manage the users of the merchant are told the
+/// difference, other callers get a generic error so that they cannot find out which users exist
+async fn get_user_role_not_found_error(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    user_id: &str,
+) -> error_stack::Report<UserErrors> {
+    let can_manage_users = authorization::get_permissions_for_merchant(
+        &*state.store,
+        &user_from_token.role_id,
+        &user_from_token.merchant_id,
+    )
+    .await
+    .map_or(false, |permissions| {
+        authorization::check_authorization(
+            &authorization::permissions::Permission::UsersWrite,
+            &permissions,
+        )
+        .is_ok()
+    });
+
+    if !can_manage_users {
+        return report!(UserErrors::InvalidRoleOperation)
+            .attach_printable("UserId MerchantId not found");
+    }
+
+    match state.store.find_user_by_id(user_id).await {
+        Ok(_) => report!(UserErrors::UserNotAssociatedWithMerchant)
+            .attach_printable("UserId MerchantId not found"),
+        Err(e) if e.current_context().is_db_not_found() => e
+            .change_context(UserErrors::UserNotFound)
+            .attach_printable("UserId not found"),
+        Err(e) => e.change_context(UserErrors::InternalServerError),
+    }
+}
+
+async fn update_status_of_managed_user(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    target_user_role: &UserRole,
+    status: UserStatus,
+) -> UserResult<()> {
+    let update = UserRoleUpdate::UpdateStatus {
+        status,
+        modified_by: user_from_token.user_id.clone(),
+    };
+
+    if status != UserStatus::Active
+        && utils::user_role::is_admin_role(target_user_role.role_id.as_str())
+    {
+        // The check for another admin and the update run in one transaction, so that concurrent
+        // removals cannot leave the merchant without an admin
+        state
+            .store
+            .update_user_role_by_user_id_merchant_id_keeping_admin(
+                target_user_role.user_id.as_str(),
+                target_user_role.merchant_id.as_str(),
+                &consts::user_role::ADMIN_ROLE_IDS,
+                update,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?
+            .ok_or(report!(UserErrors::CannotRemoveLastAdmin))
+            .attach_printable("The change would leave the merchant without an admin")?;
+    } else {
+        state
+            .store
+            .update_user_role_by_user_id_merchant_id(
+                target_user_role.user_id.as_str(),
+                target_user_role.merchant_id.as_str(),
+                update,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
     }
 
+    logger::info!(
+        actor = %user_from_token.user_id,
+        user_id = %target_user_role.user_id,
+        merchant_id = %target_user_role.merchant_id,
+        old_status = %target_user_role.status,
+        new_status = %status,
+        "Updated the status of the user role"
+    );
+
+    Ok(())
+}
+
+async fn update_role_of_managed_user(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    target_user_role: UserRole,
+    role_id: String,
+    expires_at: Option<PrimitiveDateTime>,
+) -> UserResult<()> {
+    validate_can_manage_user(state, user_from_token, &target_user_role).await?;
+    validate_can_assign_role(state, user_from_token, role_id.as_str()).await?;
+
+    // Any previous expiry of the role is cleared unless a new one is specified
+    let update = UserRoleUpdate::UpdateRole {
+        role_id: role_id.clone(),
+        modified_by: user_from_token.user_id.clone(),
+        expires_at,
+    };
+
+    // Demoting an admin, or letting their admin role expire, removes an admin from the merchant
+    let is_admin_removed = utils::user_role::is_admin_role(target_user_role.role_id.as_str())
+        && (!utils::user_role::is_admin_role(role_id.as_str()) || expires_at.is_some());
+    let updated_user_role = if is_admin_removed {
+        // The check for another admin and the update run in one transaction, so that concurrent
+        // removals cannot leave the merchant without an admin
+        state
+            .store
+            .update_user_role_by_user_id_merchant_id_keeping_admin(
+                target_user_role.user_id.as_str(),
+                target_user_role.merchant_id.as_str(),
+                &consts::user_role::ADMIN_ROLE_IDS,
+                update,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?
+            .ok_or(report!(UserErrors::CannotRemoveLastAdmin))
+            .attach_printable("The change would leave the merchant without an admin")?
+    } else {
+        state
+            .store
+            .update_user_role_by_user_id_merchant_id(
+                target_user_role.user_id.as_str(),
+                target_user_role.merchant_id.as_str(),
+                update,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?
+    };
+
+    utils::user_role::trigger_user_role_webhook(
+        state,
+        diesel_models::enums::EventType::UserRoleUpdated,
+        user_role_api::UserRoleWebhookContent {
+            merchant_id: updated_user_role.merchant_id.clone(),
+            user_id: updated_user_role.user_id.clone(),
+            old_role: Some(target_user_role.role_id),
+            new_role: Some(updated_user_role.role_id.clone()),
+            actor: Some(user_from_token.user_id.clone()),
+        },
+    );
+
+    // The tokens issued to the user still carry the previous role
+    utils::user_role::invalidate_user_tokens(state, &updated_user_role.user_id).await?;
+
+    utils::user_role::add_user_role_expiry_task(state, &updated_user_role).await
+}
+
+async fn update_attributes_of_managed_user(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    target_user_role: &UserRole,
+    title: Option<String>,
+    department: Option<String>,
+) -> UserResult<()> {
     state
         .store
         .update_user_role_by_user_id_merchant_id(
-            req.user_id.as_str(),
-            merchant_id.as_str(),
-            UserRoleUpdate::UpdateRole {
-                role_id,
-                modified_by: user_from_token.user_id,
+            target_user_role.user_id.as_str(),
+            target_user_role.merchant_id.as_str(),
+            UserRoleUpdate::UpdateAttributes {
+                title: title.clone(),
+                department: department.clone(),
+                modified_by: user_from_token.user_id.clone(),
             },
         )
         .await
-        .map_err(|e| {
-            if e.current_context().is_db_not_found() {
-                return e
-                    .change_context(UserErrors::InvalidRoleOperation)
-                    .attach_printable("UserId MerchantId not found");
-            }
-            e.change_context(UserErrors::InternalServerError)
-        })?;
+        .change_context(UserErrors::InternalServerError)?;
 
-    Ok(ApplicationResponse::StatusOk)
+    logger::info!(
+        actor = %user_from_token.user_id,
+        user_id = %target_user_role.user_id,
+        merchant_id = %target_user_role.merchant_id,
+        ?title,
+        ?department,
+        "Updated the attributes of the user role"
+    );
+
+    Ok(())
 }
 
 pub async fn accept_invitation(
@@ -123,6 +1770,18 @@ pub async fn accept_invitation(
     req: user_role_api::AcceptInvitationRequest,
 ) -> UserResponse<user_role_api::AcceptInvitationResponse> {
     let user_role = futures::future::join_all(req.merchant_ids.iter().map(|merchant_id| async {
+        // A suspended user cannot restore their access by accepting the invitation again
+        let is_suspended = state
+            .store
+            .find_user_role_by_user_id_merchant_id(user_token.user_id.as_str(), merchant_id)
+            .await
+            .map(|user_role| user_role.status == UserStatus::Suspended)
+            .unwrap_or(false);
+        if is_suspended {
+            logger::info!(%merchant_id, "Access of the user to the merchant is suspended");
+            return None;
+        }
+
         state
             .store
             .update_user_role_by_user_id_merchant_id(
@@ -161,3 +1820,207 @@ pub async fn accept_invitation(
 
     Ok(ApplicationResponse::StatusOk)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, services};
+
+    fn new_user_role(user_id: &str, role_id: &str) -> UserRoleNew {
+        UserRoleNew {
+            user_id: user_id.to_string(),
+            merchant_id: "merchant1".to_string(),
+            role_id: role_id.to_string(),
+            org_id: "org1".to_string(),
+            status: UserStatus::Active,
+            created_by: "admin".to_string(),
+            last_modified_by: "admin".to_string(),
+            created_at: common_utils::date_time::now(),
+            last_modified: common_utils::date_time::now(),
+            expires_at: None,
+            title: None,
+            department: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_token_is_rejected_after_user_role_is_deleted() {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let api_client = Box::new(services::MockApiClient);
+        let state = AppState::with_storage(conf, StorageImpl::Mock, tx, api_client).await;
+
+        state
+            .store
+            .insert_user_role(new_user_role(
+                "admin_user",
+                consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+            ))
+            .await
+            .unwrap();
+        state
+            .store
+            .insert_user_role(new_user_role(
+                "deleted_user",
+                consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY,
+            ))
+            .await
+            .unwrap();
+
+        // A token issued to the user before their role was deleted
+        let issued_at = common_utils::date_time::now_unix_timestamp() - 1;
+        authorization::check_token_not_invalidated(&*state.store, "deleted_user", issued_at)
+            .await
+            .unwrap();
+
+        delete_user_role(
+            state.clone(),
+            auth::UserFromToken {
+                user_id: "admin_user".to_string(),
+                merchant_id: "merchant1".to_string(),
+                role_id: consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
+                org_id: "org1".to_string(),
+            },
+            user_role_api::DeleteUserRoleRequest {
+                user_id: "deleted_user".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(authorization::check_token_not_invalidated(
+            &*state.store,
+            "deleted_user",
+            issued_at
+        )
+        .await
+        .is_err());
+    }
+
+    #[actix_rt::test]
+    async fn test_last_admin_of_merchant_is_kept() {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let api_client = Box::new(services::MockApiClient);
+        let state = AppState::with_storage(conf, StorageImpl::Mock, tx, api_client).await;
+
+        for user_id in ["first_admin", "second_admin"] {
+            state
+                .store
+                .insert_user_role(new_user_role(
+                    user_id,
+                    consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+                ))
+                .await
+                .unwrap();
+        }
+
+        let suspended = state
+            .store
+            .update_user_role_by_user_id_merchant_id_keeping_admin(
+                "first_admin",
+                "merchant1",
+                &consts::user_role::ADMIN_ROLE_IDS,
+                UserRoleUpdate::UpdateStatus {
+                    status: UserStatus::Suspended,
+                    modified_by: "second_admin".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(suspended.unwrap().status, UserStatus::Suspended);
+
+        // The suspended admin no longer counts, so the other admin is the last one
+        assert!(!state
+            .store
+            .delete_user_role_by_user_id_merchant_id_keeping_admin(
+                "second_admin",
+                "merchant1",
+                &consts::user_role::ADMIN_ROLE_IDS,
+            )
+            .await
+            .unwrap());
+        assert!(state
+            .store
+            .find_user_role_by_user_id_merchant_id("second_admin", "merchant1")
+            .await
+            .is_ok());
+
+        // The suspended admin can still be removed while an active admin remains
+        assert!(state
+            .store
+            .delete_user_role_by_user_id_merchant_id_keeping_admin(
+                "first_admin",
+                "merchant1",
+                &consts::user_role::ADMIN_ROLE_IDS,
+            )
+            .await
+            .unwrap());
+
+        // The last admin can neither be demoted nor have their admin role expire
+        let org_admin = auth::UserFromToken {
+            user_id: "org_admin".to_string(),
+            merchant_id: "merchant1".to_string(),
+            role_id: consts::user_role::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
+            org_id: "org1".to_string(),
+        };
+        let find_second_admin = || {
+            state
+                .store
+                .find_user_role_by_user_id_merchant_id("second_admin", "merchant1")
+        };
+        for (role_id, expires_at) in [
+            (consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY, None),
+            (
+                consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+                Some(common_utils::date_time::now() + time::Duration::days(1)),
+            ),
+        ] {
+            let error = update_role_of_managed_user(
+                &state,
+                &org_admin,
+                find_second_admin().await.unwrap(),
+                role_id.to_string(),
+                expires_at,
+            )
+            .await
+            .unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                UserErrors::CannotRemoveLastAdmin
+            ));
+        }
+        let second_admin = find_second_admin().await.unwrap();
+        assert_eq!(
+            second_admin.role_id,
+            consts::user_role::ROLE_ID_MERCHANT_ADMIN
+        );
+        assert!(second_admin.expires_at.is_none());
+
+        // The admin can be demoted once another active admin remains
+        state
+            .store
+            .insert_user_role(new_user_role(
+                "third_admin",
+                consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+            ))
+            .await
+            .unwrap();
+        update_role_of_managed_user(
+            &state,
+            &org_admin,
+            find_second_admin().await.unwrap(),
+            consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY.to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            find_second_admin().await.unwrap().role_id,
+            consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY
+        );
+    }
+}
@@ -887,6 +887,195 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     Ok(())
 }
 
+/// Tracking data of the scheduler task which retries the delivery of a user role webhook
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct UserRoleWebhookTrackingData {
+    pub event_id: String,
+    pub event_type: enums::EventType,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: time::PrimitiveDateTime,
+    pub content: api_models::user_role::UserRoleWebhookContent,
+}
+
+/// Notifies the merchant about a change of the role of one of its users. A failed delivery is
+/// not propagated to the caller, it is retried by the scheduler instead so that the role change
+/// itself is never affected by it
+#[instrument(skip_all)]
+pub async fn create_event_and_trigger_user_role_webhook(
+    state: AppState,
+    event_type: enums::EventType,
+    content: api_models::user_role::UserRoleWebhookContent,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if !state.conf.webhooks.outgoing_enabled {
+        return Ok(());
+    }
+
+    let (merchant_account, business_profile) =
+        match get_merchant_account_and_default_business_profile(&state, &content.merchant_id)
+            .await?
+        {
+            Some(merchant_details) => merchant_details,
+            None => {
+                logger::info!(
+                    merchant_id = %content.merchant_id,
+                    "Skipping user role webhook as the merchant has no default business profile"
+                );
+                return Ok(());
+            }
+        };
+
+    let event = state
+        .store
+        .insert_event(storage::EventNew {
+            event_id: generate_id(consts::ID_LENGTH, "evt"),
+            event_type,
+            event_class: enums::EventClass::UserRoles,
+            is_webhook_notified: false,
+            intent_reference_id: None,
+            primary_object_id: content.user_id.clone(),
+            primary_object_type: enums::EventObjectType::UserRoleDetails,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("Failed to insert event in events table")?;
+
+    let tracking_data = UserRoleWebhookTrackingData {
+        event_id: event.event_id,
+        event_type,
+        timestamp: event.created_at,
+        content,
+    };
+
+    if let Err(error) = trigger_user_role_webhook_to_merchant(
+        state.clone(),
+        &merchant_account,
+        business_profile,
+        &tracking_data,
+    )
+    .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to deliver user role webhook, scheduling a retry"
+        );
+        add_user_role_webhook_retry_task(&*state.store, &tracking_data).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn get_merchant_account_and_default_business_profile(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<
+    Option<(
+        domain::MerchantAccount,
+        diesel_models::business_profile::BusinessProfile,
+    )>,
+    errors::ApiErrorResponse,
+> {
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let profile_id = match merchant_account.default_profile.as_deref() {
+        Some(profile_id) => profile_id,
+        None => return Ok(None),
+    };
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    Ok(Some((merchant_account, business_profile)))
+}
+
+pub async fn trigger_user_role_webhook_to_merchant(
+    state: AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    tracking_data: &UserRoleWebhookTrackingData,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let outgoing_webhook = api::OutgoingWebhook {
+        merchant_id: merchant_account.merchant_id.clone(),
+        event_id: tracking_data.event_id.clone(),
+        event_type: tracking_data.event_type,
+        content: api::OutgoingWebhookContent::UserRoleDetails(Box::new(
+            tracking_data.content.clone(),
+        )),
+        timestamp: tracking_data.timestamp,
+    };
+
+    match merchant_account.get_compatible_connector() {
+        #[cfg(feature = "stripe")]
+        Some(api_models::enums::Connector::Stripe) => {
+            trigger_webhook_to_merchant::<stripe_webhooks::StripeOutgoingWebhook>(
+                business_profile,
+                outgoing_webhook,
+                state,
+            )
+            .await
+        }
+        _ => {
+            trigger_webhook_to_merchant::<api_models::webhooks::OutgoingWebhook>(
+                business_profile,
+                outgoing_webhook,
+                state,
+            )
+            .await
+        }
+    }
+}
+
+async fn add_user_role_webhook_retry_task(
+    db: &dyn StorageInterface,
+    tracking_data: &UserRoleWebhookTrackingData,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let runner = consts::user_role::USER_ROLE_WEBHOOK_RETRY_RUNNER;
+    let current_time = common_utils::date_time::now();
+    let schedule_time = scheduler::utils::get_time_from_delta(scheduler::utils::get_schedule_time(
+        scheduler::consumer::types::process_data::ConnectorPTMapping::default(),
+        &tracking_data.content.merchant_id,
+        0,
+    ))
+    .unwrap_or(current_time);
+    let tracking_data_value = serde_json::to_value(tracking_data)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize user role webhook tracking data")?;
+
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: format!("{runner}_{}", tracking_data.event_id),
+        name: Some(String::from(runner)),
+        tag: vec![String::from(consts::user_role::USER_ROLE_WEBHOOK_RETRY_TAG)],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: String::new(),
+        tracking_data: tracking_data_value,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule user role webhook retry task")?;
+
+    Ok(())
+}
+
 pub async fn webhooks_wrapper<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetrieve>(
     flow: &impl router_env::types::FlowMetric,
     state: AppState,
@@ -234,6 +234,7 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            role_id: api_key.role_id,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -406,6 +407,7 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                role_id: None,
             })
             .await
             .unwrap();
@@ -421,6 +423,7 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                role_id: None,
             })
             .await
             .unwrap();
@@ -498,6 +501,7 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            role_id: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
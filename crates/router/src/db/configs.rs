@@ -39,6 +39,13 @@ pub trait ConfigInterface {
         key: &str,
     ) -> CustomResult<storage::Config, errors::StorageError>;
 
+    /// Finds the config without creating it when it is missing. The absence of the config is
+    /// cached as well, so that a missing config does not cost a database lookup on every call.
+    async fn find_config_by_key_optional(
+        &self,
+        key: &str,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError>;
+
     async fn update_config_by_key(
         &self,
         key: &str,
@@ -51,6 +58,16 @@ pub trait ConfigInterface {
         config_update: storage::ConfigUpdate,
     ) -> CustomResult<storage::Config, errors::StorageError>;
 
+    /// Sets the config only when its value is still `current_config`, or when it does not exist
+    /// yet if `current_config` is `None`. `None` is returned when the config was changed in the
+    /// meantime, so that the caller can retry on the new value.
+    async fn set_config_by_key_if_unchanged(
+        &self,
+        key: &str,
+        current_config: Option<String>,
+        config: String,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError>;
+
     async fn delete_config_by_key(&self, key: &str) -> CustomResult<bool, errors::StorageError>;
 }
 
@@ -89,6 +106,54 @@ impl ConfigInterface for Store {
         .await
     }
 
+    //update or insert in DB when unchanged and remove in redis and cache
+    async fn set_config_by_key_if_unchanged(
+        &self,
+        key: &str,
+        current_config: Option<String>,
+        config: String,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError> {
+        let set_config_in_database = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            let result = match current_config {
+                Some(current_config) => {
+                    storage::Config::update_by_key_if_unchanged(
+                        &conn,
+                        key,
+                        current_config,
+                        storage::ConfigUpdate::Update {
+                            config: Some(config),
+                        },
+                    )
+                    .await
+                }
+                None => {
+                    storage::ConfigNew {
+                        key: key.to_string(),
+                        config,
+                    }
+                    .insert(&conn)
+                    .await
+                }
+            }
+            .map_err(Into::<errors::StorageError>::into)
+            .into_report();
+
+            match result {
+                Ok(config) => Ok(Some(config)),
+                Err(err)
+                    if err.current_context().is_db_not_found()
+                        || err.current_context().is_db_unique_violation() =>
+                {
+                    Ok(None)
+                }
+                Err(err) => Err(err),
+            }
+        };
+
+        cache::publish_and_redact(self, CacheKind::Config(key.into()), set_config_in_database).await
+    }
+
     async fn find_config_by_key_from_db(
         &self,
         key: &str,
@@ -115,6 +180,32 @@ impl ConfigInterface for Store {
         cache::get_or_populate_in_memory(self, key, find_config_by_key_from_db, &CONFIG_CACHE).await
     }
 
+    //check in cache, then redis then finally DB, and cache the config even when it is missing
+    async fn find_config_by_key_optional(
+        &self,
+        key: &str,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError> {
+        let find_optional_config_by_key_from_db = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            match storage::Config::find_by_key(&conn, key)
+                .await
+                .map_err(Into::<errors::StorageError>::into)
+                .into_report()
+            {
+                Ok(config) => Ok(Some(config)),
+                Err(err) if err.current_context().is_db_not_found() => Ok(None),
+                Err(err) => Err(err),
+            }
+        };
+        cache::get_or_populate_in_memory(
+            self,
+            key,
+            find_optional_config_by_key_from_db,
+            &CONFIG_CACHE,
+        )
+        .await
+    }
+
     async fn find_config_by_key_unwrap_or(
         &self,
         key: &str,
@@ -226,6 +317,39 @@ impl ConfigInterface for MockDb {
         result
     }
 
+    async fn set_config_by_key_if_unchanged(
+        &self,
+        key: &str,
+        current_config: Option<String>,
+        config: String,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError> {
+        let mut configs = self.configs.lock().await;
+        let existing_config = configs.iter_mut().find(|c| c.key == key);
+
+        match (existing_config, current_config) {
+            (Some(existing_config), Some(current_config))
+                if existing_config.config == current_config =>
+            {
+                existing_config.config = config;
+                Ok(Some(existing_config.clone()))
+            }
+            (None, None) => {
+                let config_new = storage::Config {
+                    id: configs
+                        .len()
+                        .try_into()
+                        .into_report()
+                        .change_context(errors::StorageError::MockDbError)?,
+                    key: key.to_string(),
+                    config,
+                };
+                configs.push(config_new.clone());
+                Ok(Some(config_new))
+            }
+            _ => Ok(None),
+        }
+    }
+
     async fn delete_config_by_key(&self, key: &str) -> CustomResult<bool, errors::StorageError> {
         let mut configs = self.configs.lock().await;
         let result = configs
@@ -269,4 +393,61 @@ impl ConfigInterface for MockDb {
     ) -> CustomResult<storage::Config, errors::StorageError> {
         self.find_config_by_key(key).await
     }
+
+    async fn find_config_by_key_optional(
+        &self,
+        key: &str,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError> {
+        let configs = self.configs.lock().await;
+        Ok(configs.iter().find(|c| c.key == key).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::db::{configs::ConfigInterface, MockDb};
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_set_config_by_key_if_unchanged() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        assert!(mockdb
+            .find_config_by_key_optional("key")
+            .await
+            .unwrap()
+            .is_none());
+
+        let created = mockdb
+            .set_config_by_key_if_unchanged("key", None, "first".to_string())
+            .await
+            .unwrap();
+        assert_eq!(created.unwrap().config, "first");
+
+        // A second writer which also saw the config missing must not overwrite it
+        let conflicting_insert = mockdb
+            .set_config_by_key_if_unchanged("key", None, "second".to_string())
+            .await
+            .unwrap();
+        assert!(conflicting_insert.is_none());
+
+        let updated = mockdb
+            .set_config_by_key_if_unchanged("key", Some("first".to_string()), "third".to_string())
+            .await
+            .unwrap();
+        assert_eq!(updated.unwrap().config, "third");
+
+        // An update based on a stale value is rejected, so that the update it raced is not lost
+        let stale_update = mockdb
+            .set_config_by_key_if_unchanged("key", Some("first".to_string()), "fourth".to_string())
+            .await
+            .unwrap();
+        assert!(stale_update.is_none());
+
+        let config = mockdb.find_config_by_key_optional("key").await.unwrap();
+        assert_eq!(config.unwrap().config, "third");
+    }
 }
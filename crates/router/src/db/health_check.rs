@@ -1,39 +1,113 @@
+use std::{collections::HashMap, time::Duration};
+
 use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
+use common_utils::{consts::ID_LENGTH, generate_id};
 use diesel_models::ConfigNew;
-use error_stack::ResultExt;
+use error_stack::{report, IntoReport, ResultExt};
+use redis_interface::{PubsubInterface, RedisConnectionPool, RedisEntryId};
 use router_env::logger;
 
 use super::{MockDb, StorageInterface, Store};
 use crate::{
     connection,
-    consts::LOCKER_HEALTH_CALL_PATH,
+    consts::{
+        EXPECTED_SCHEMA_VERSION, HEALTH_CHECK_PUBSUB_CHANNEL, HEALTH_CHECK_TEST_KEY,
+        HEALTH_CHECK_TEST_STREAM, LOCKER_HEALTH_CALL_PATH,
+    },
     core::errors::{self, CustomResult},
     routes,
     services::api as services,
     types::storage,
 };
 
+/// Result of a successful database health check
+#[derive(Debug, Clone)]
+pub struct DatabaseHealth {
+    /// Latest schema version applied to the database, if any
+    pub schema_version: Option<String>,
+    /// Difference (in milliseconds) between the clock of the database server and the clock of
+    /// this node, positive when the database is ahead
+    pub clock_skew_ms: i64,
+}
+
 #[async_trait::async_trait]
 pub trait HealthCheckInterface {
-    async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError>;
+    /// When `read_only` is set, only the read probe is run against the replica
+    async fn health_check_db(
+        &self,
+        read_only: bool,
+    ) -> CustomResult<DatabaseHealth, errors::HealthCheckDBError>;
+    /// Establishing the connection is retried up to `connection_retries` times, with a delay
+    /// starting at `retry_base_delay` and doubling after each attempt
     async fn health_check_redis(
         &self,
         db: &dyn StorageInterface,
+        connection_retries: u32,
+        retry_base_delay: Duration,
+    ) -> CustomResult<(), errors::HealthCheckRedisError>;
+    /// Runs the key and stream probes against a Redis instance other than the one of the store
+    async fn health_check_redis_instance(
+        &self,
+        redis_conn: &RedisConnectionPool,
+    ) -> CustomResult<(), errors::HealthCheckRedisError>;
+    /// Publishes a message to the health check channel and waits for the subscriber to receive it
+    async fn health_check_redis_pubsub(
+        &self,
+        db: &dyn StorageInterface,
+        timeout: Duration,
     ) -> CustomResult<(), errors::HealthCheckRedisError>;
+    /// Calls the health endpoint of the locker, which must respond successfully within `timeout`
     async fn health_check_locker(
         &self,
         state: &routes::AppState,
+        timeout: Duration,
     ) -> CustomResult<(), errors::HealthCheckLockerError>;
+    /// Returns the number of payments which have been processing for longer than `threshold`
+    async fn health_check_stuck_payments(
+        &self,
+        threshold: Duration,
+    ) -> CustomResult<i64, errors::HealthCheckDBError>;
+    /// Returns the number of entries in the dead letter stream of the drainer
+    async fn health_check_dead_letter_count(
+        &self,
+        db: &dyn StorageInterface,
+        stream_name: &str,
+    ) -> CustomResult<u64, errors::HealthCheckRedisError>;
+    /// Returns up to `limit` entries of the dead letter stream of the drainer along with their
+    /// identifiers, most recent first
+    async fn get_dead_letter_entries(
+        &self,
+        db: &dyn StorageInterface,
+        stream_name: &str,
+        limit: usize,
+    ) -> CustomResult<Vec<(String, HashMap<String, String>)>, errors::HealthCheckRedisError>;
+    /// Returns the number of bytes the most lagging Redis replica is behind the master, `None`
+    /// when no replica is connected to the node
+    async fn health_check_redis_replication_lag(
+        &self,
+        db: &dyn StorageInterface,
+    ) -> CustomResult<Option<u64>, errors::HealthCheckRedisError>;
+    /// Deletes the config row left behind by a database health check which did not complete,
+    /// returns whether such a row was found. The key written by the Redis health check expires
+    /// on its own, so it is not deleted here
+    async fn delete_health_check_artifacts(&self)
+        -> CustomResult<bool, errors::HealthCheckDBError>;
 }
 
 #[async_trait::async_trait]
 impl HealthCheckInterface for Store {
-    async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError> {
-        let conn = connection::pg_connection_write(self)
-            .await
-            .change_context(errors::HealthCheckDBError::DBError)?;
+    async fn health_check_db(
+        &self,
+        read_only: bool,
+    ) -> CustomResult<DatabaseHealth, errors::HealthCheckDBError> {
+        let conn = if read_only {
+            connection::pg_connection_read(self).await
+        } else {
+            connection::pg_connection_write(self).await
+        }
+        .change_context(errors::HealthCheckDBError::DBError)?;
 
-        let _data = conn
+        let database_health = conn
             .transaction_async(|conn| {
                 Box::pin(async move {
                     let query =
@@ -45,95 +119,452 @@ impl HealthCheckInterface for Store {
 
                     logger::debug!("Database read was successful");
 
-                    let config = ConfigNew {
-                        key: "test_key".to_string(),
-                        config: "test_value".to_string(),
-                    };
+                    if !read_only {
+                        let config = ConfigNew {
+                            key: HEALTH_CHECK_TEST_KEY.to_string(),
+                            config: "test_value".to_string(),
+                        };
 
-                    config.insert(&conn).await.map_err(|err| {
-                        logger::error!(write_err=?err,"Error while writing to database");
-                        errors::HealthCheckDBError::DBWriteError
-                    })?;
+                        // The row is upserted, so that a row left behind by a health check which
+                        // did not complete does not fail the write
+                        config.upsert(&conn).await.map_err(|err| {
+                            logger::error!(write_err=?err,"Error while writing to database");
+                            errors::HealthCheckDBError::DBWriteError
+                        })?;
 
-                    logger::debug!("Database write was successful");
+                        logger::debug!("Database write was successful");
 
-                    storage::Config::delete_by_key(&conn, "test_key").await.map_err(|err| {
-                        logger::error!(delete_err=?err,"Error while deleting element in the database");
-                        errors::HealthCheckDBError::DBDeleteError
-                    })?;
+                        storage::Config::delete_by_key(&conn, HEALTH_CHECK_TEST_KEY).await.map_err(|err| {
+                            logger::error!(delete_err=?err,"Error while deleting element in the database");
+                            errors::HealthCheckDBError::DBDeleteError
+                        })?;
+
+                        logger::debug!("Database delete was successful");
+                    }
+
+                    let query = diesel::select(diesel::dsl::sql::<
+                        diesel::sql_types::Nullable<diesel::sql_types::Text>,
+                    >(
+                        "(SELECT MAX(version) FROM __diesel_schema_migrations)",
+                    ));
+                    let schema_version: Option<String> =
+                        query.get_result_async(&conn).await.map_err(|err| {
+                            logger::error!(read_err=?err,"Error while reading the applied schema version");
+                            errors::HealthCheckDBError::SchemaVersionReadError
+                        })?;
 
-                    logger::debug!("Database delete was successful");
+                    logger::debug!("Database schema version read was successful");
 
-                    Ok::<_, errors::HealthCheckDBError>(())
+                    // `now()` is frozen at the start of the transaction, so the clock is read with
+                    // `clock_timestamp()` and compared with the middle of the round trip
+                    let requested_at = time::OffsetDateTime::now_utc();
+                    let query = diesel::select(diesel::dsl::sql::<diesel::sql_types::BigInt>(
+                        "(EXTRACT(EPOCH FROM clock_timestamp()) * 1000)::BIGINT",
+                    ));
+                    let db_time_in_millis: i64 =
+                        query.get_result_async(&conn).await.map_err(|err| {
+                            logger::error!(read_err=?err,"Error while reading the time of the database");
+                            errors::HealthCheckDBError::ClockReadError
+                        })?;
+                    let received_at = time::OffsetDateTime::now_utc();
+
+                    let local_time_in_millis = (requested_at.unix_timestamp_nanos()
+                        + received_at.unix_timestamp_nanos())
+                        / 2
+                        / 1_000_000;
+                    let clock_skew_ms =
+                        i64::try_from(i128::from(db_time_in_millis) - local_time_in_millis)
+                            .unwrap_or(i64::MAX);
+
+                    logger::debug!("Database clock read was successful");
+
+                    Ok::<_, errors::HealthCheckDBError>(DatabaseHealth {
+                        schema_version,
+                        clock_skew_ms,
+                    })
                 })
             })
             .await?;
 
-        Ok(())
+        Ok(database_health)
     }
 
     async fn health_check_redis(
         &self,
         db: &dyn StorageInterface,
+        connection_retries: u32,
+        retry_base_delay: Duration,
     ) -> CustomResult<(), errors::HealthCheckRedisError> {
-        let redis_conn = db
-            .get_redis_conn()
-            .change_context(errors::HealthCheckRedisError::RedisConnectionError)?;
+        // The first command is retried along with obtaining the connection, since it is the one
+        // failing while the client reconnects after a restart of Redis
+        let mut attempt = 0;
+        let redis_conn = loop {
+            let result = match db.get_redis_conn() {
+                Ok(redis_conn) => redis_conn
+                    .serialize_and_set_key_with_expiry(HEALTH_CHECK_TEST_KEY, "test_value", 30)
+                    .await
+                    .change_context(errors::HealthCheckRedisError::SetFailed)
+                    .map(|_| redis_conn),
+                Err(err) => {
+                    Err(err.change_context(errors::HealthCheckRedisError::RedisConnectionError))
+                }
+            };
+
+            match result {
+                Ok(redis_conn) => break redis_conn,
+                Err(err) if attempt < connection_retries => {
+                    let delay = retry_base_delay.saturating_mul(2u32.saturating_pow(attempt));
+                    logger::warn!(
+                        redis_err = ?err,
+                        attempt,
+                        ?delay,
+                        "Redis health check connection failed, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        };
+
+        logger::debug!("Redis set_key was successful");
 
+        probe_redis_key(&redis_conn).await?;
+        probe_redis_stream(&redis_conn).await
+    }
+
+    async fn health_check_redis_instance(
+        &self,
+        redis_conn: &RedisConnectionPool,
+    ) -> CustomResult<(), errors::HealthCheckRedisError> {
         redis_conn
-            .serialize_and_set_key_with_expiry("test_key", "test_value", 30)
+            .serialize_and_set_key_with_expiry(HEALTH_CHECK_TEST_KEY, "test_value", 30)
             .await
             .change_context(errors::HealthCheckRedisError::SetFailed)?;
 
         logger::debug!("Redis set_key was successful");
 
+        probe_redis_key(redis_conn).await?;
+        probe_redis_stream(redis_conn).await
+    }
+
+    async fn health_check_redis_pubsub(
+        &self,
+        db: &dyn StorageInterface,
+        timeout: Duration,
+    ) -> CustomResult<(), errors::HealthCheckRedisError> {
+        let redis_conn = db
+            .get_redis_conn()
+            .change_context(errors::HealthCheckRedisError::RedisConnectionError)?;
+
+        // The receiver is created before subscribing, so that the probe message cannot be missed
+        let mut message_rx = redis_conn.subscriber.on_message();
+
         redis_conn
-            .get_key("test_key")
+            .subscriber
+            .subscribe::<(), _>(HEALTH_CHECK_PUBSUB_CHANNEL)
             .await
-            .change_context(errors::HealthCheckRedisError::GetFailed)?;
+            .into_report()
+            .change_context(errors::HealthCheckRedisError::SubscribeFailed)?;
 
-        logger::debug!("Redis get_key was successful");
+        logger::debug!("Redis subscribe was successful");
 
-        redis_conn
-            .delete_key("test_key")
+        let probe_message = generate_id(ID_LENGTH, "health");
+        let result = async {
+            redis_conn
+                .publisher
+                .publish::<usize, _, _>(HEALTH_CHECK_PUBSUB_CHANNEL, probe_message.as_str())
+                .await
+                .into_report()
+                .change_context(errors::HealthCheckRedisError::PublishFailed)?;
+
+            logger::debug!("Redis publish was successful");
+
+            let is_received = tokio::time::timeout(timeout, async {
+                while let Ok(message) = message_rx.recv().await {
+                    if &*message.channel == HEALTH_CHECK_PUBSUB_CHANNEL
+                        && message.value.as_str().as_deref() == Some(probe_message.as_str())
+                    {
+                        return true;
+                    }
+                }
+                false
+            })
             .await
-            .change_context(errors::HealthCheckRedisError::DeleteFailed)?;
+            .unwrap_or(false);
 
-        logger::debug!("Redis delete_key was successful");
+            if !is_received {
+                return Err(errors::HealthCheckRedisError::MessageNotReceived).into_report();
+            }
 
-        Ok(())
+            logger::debug!("Redis message was received by the subscriber");
+
+            Ok(())
+        }
+        .await;
+
+        redis_conn
+            .subscriber
+            .unsubscribe::<(), _>(HEALTH_CHECK_PUBSUB_CHANNEL)
+            .await
+            .map_err(|err| logger::error!(unsubscribe_err=?err))
+            .ok();
+
+        result
     }
 
     async fn health_check_locker(
         &self,
         state: &routes::AppState,
+        timeout: Duration,
     ) -> CustomResult<(), errors::HealthCheckLockerError> {
         let locker = &state.conf.locker;
         if !locker.mock_locker {
             let mut url = locker.host_rs.to_owned();
             url.push_str(LOCKER_HEALTH_CALL_PATH);
             let request = services::Request::new(services::Method::Get, &url);
-            services::call_connector_api(state, request)
+            tokio::time::timeout(timeout, services::call_connector_api(state, request))
                 .await
+                .into_report()
+                .change_context(errors::HealthCheckLockerError::LockerTimedOut)?
                 .change_context(errors::HealthCheckLockerError::FailedToCallLocker)?
-                .ok();
+                .map_err(|response| {
+                    logger::error!(locker_status_code = response.status_code);
+                    report!(errors::HealthCheckLockerError::LockerUnhealthy)
+                })?;
         }
 
         logger::debug!("Locker call was successful");
 
         Ok(())
     }
+
+    async fn health_check_stuck_payments(
+        &self,
+        threshold: Duration,
+    ) -> CustomResult<i64, errors::HealthCheckDBError> {
+        let conn = connection::pg_connection_read(self)
+            .await
+            .change_context(errors::HealthCheckDBError::DBError)?;
+
+        let modified_before = common_utils::date_time::now() - threshold;
+        let stuck_payments_count =
+            diesel_models::PaymentIntent::get_count_by_status_modified_before(
+                &conn,
+                storage::enums::IntentStatus::Processing,
+                modified_before,
+            )
+            .await
+            .change_context(errors::HealthCheckDBError::StuckPaymentsCountError)?;
+
+        logger::debug!("Database stuck payments count was successful");
+
+        Ok(stuck_payments_count)
+    }
+
+    async fn health_check_dead_letter_count(
+        &self,
+        db: &dyn StorageInterface,
+        stream_name: &str,
+    ) -> CustomResult<u64, errors::HealthCheckRedisError> {
+        let dead_letter_count = db
+            .get_redis_conn()
+            .change_context(errors::HealthCheckRedisError::RedisConnectionError)?
+            .stream_get_length(stream_name)
+            .await
+            .change_context(errors::HealthCheckRedisError::DeadLetterReadFailed)?;
+
+        logger::debug!("Redis dead letter count was successful");
+
+        Ok(u64::try_from(dead_letter_count).unwrap_or(u64::MAX))
+    }
+
+    async fn get_dead_letter_entries(
+        &self,
+        db: &dyn StorageInterface,
+        stream_name: &str,
+        limit: usize,
+    ) -> CustomResult<Vec<(String, HashMap<String, String>)>, errors::HealthCheckRedisError> {
+        let redis_conn = db
+            .get_redis_conn()
+            .change_context(errors::HealthCheckRedisError::RedisConnectionError)?;
+
+        // The stream is read from its first entry, so the whole stream is read in order to
+        // return the most recent entries. The drainer keeps the stream capped.
+        let dead_letter_count = redis_conn
+            .stream_get_length(stream_name)
+            .await
+            .change_context(errors::HealthCheckRedisError::DeadLetterReadFailed)?;
+
+        if dead_letter_count == 0 || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut stream_read = match redis_conn
+            .stream_read_entries(
+                stream_name,
+                "0-0",
+                Some(u64::try_from(dead_letter_count).unwrap_or(u64::MAX)),
+            )
+            .await
+        {
+            Ok(stream_read) => stream_read,
+            Err(err) => match err.current_context() {
+                redis_interface::errors::RedisError::StreamEmptyOrNotAvailable => {
+                    return Ok(Vec::new())
+                }
+                _ => {
+                    return Err(
+                        err.change_context(errors::HealthCheckRedisError::DeadLetterReadFailed)
+                    )
+                }
+            },
+        };
+
+        Ok(stream_read
+            .remove(stream_name)
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+            .take(limit)
+            .collect())
+    }
+
+    async fn health_check_redis_replication_lag(
+        &self,
+        db: &dyn StorageInterface,
+    ) -> CustomResult<Option<u64>, errors::HealthCheckRedisError> {
+        let replication_info = db
+            .get_redis_conn()
+            .change_context(errors::HealthCheckRedisError::RedisConnectionError)?
+            .get_replication_info()
+            .await
+            .change_context(errors::HealthCheckRedisError::ReplicationInfoReadFailed)?;
+
+        logger::debug!(
+            redis_role = %replication_info.role,
+            connected_replicas = replication_info.replica_offsets.len(),
+            "Redis replication info read was successful"
+        );
+
+        Ok(replication_info.max_lag_bytes())
+    }
+
+    async fn delete_health_check_artifacts(
+        &self,
+    ) -> CustomResult<bool, errors::HealthCheckDBError> {
+        let conn = connection::pg_connection_write(self)
+            .await
+            .change_context(errors::HealthCheckDBError::DBError)?;
+
+        match storage::Config::delete_by_key(&conn, HEALTH_CHECK_TEST_KEY).await {
+            Ok(is_deleted) => Ok(is_deleted),
+            Err(err)
+                if matches!(
+                    err.current_context(),
+                    diesel_models::errors::DatabaseError::NotFound
+                ) =>
+            {
+                Ok(false)
+            }
+            Err(err) => Err(err.change_context(errors::HealthCheckDBError::DBDeleteError)),
+        }
+    }
+}
+
+/// Reads and deletes the key written by the Redis health check
+async fn probe_redis_key(
+    redis_conn: &RedisConnectionPool,
+) -> CustomResult<(), errors::HealthCheckRedisError> {
+    redis_conn
+        .get_key(HEALTH_CHECK_TEST_KEY)
+        .await
+        .change_context(errors::HealthCheckRedisError::GetFailed)?;
+
+    logger::debug!("Redis get_key was successful");
+
+    redis_conn
+        .delete_key(HEALTH_CHECK_TEST_KEY)
+        .await
+        .change_context(errors::HealthCheckRedisError::DeleteFailed)?;
+
+    logger::debug!("Redis delete_key was successful");
+
+    Ok(())
+}
+
+/// Appends an entry to the health check stream, reads it back and deletes the stream, which
+/// covers the commands the drainer and the scheduler rely on
+async fn probe_redis_stream(
+    redis_conn: &RedisConnectionPool,
+) -> CustomResult<(), errors::HealthCheckRedisError> {
+    redis_conn
+        .stream_append_entry(
+            HEALTH_CHECK_TEST_STREAM,
+            &RedisEntryId::AutoGeneratedID,
+            vec![("data", "test_value")],
+        )
+        .await
+        .change_context(errors::HealthCheckRedisError::StreamAppendFailed)?;
+
+    logger::debug!("Redis stream append was successful");
+
+    let stream_read = redis_conn
+        .stream_read_entries(HEALTH_CHECK_TEST_STREAM, "0-0", Some(1))
+        .await
+        .change_context(errors::HealthCheckRedisError::StreamReadFailed)?;
+
+    if stream_read
+        .get(HEALTH_CHECK_TEST_STREAM)
+        .map_or(true, |entries| entries.is_empty())
+    {
+        return Err(errors::HealthCheckRedisError::StreamReadFailed).into_report();
+    }
+
+    logger::debug!("Redis stream read was successful");
+
+    redis_conn
+        .delete_key(HEALTH_CHECK_TEST_STREAM)
+        .await
+        .change_context(errors::HealthCheckRedisError::StreamDeleteFailed)?;
+
+    logger::debug!("Redis stream delete was successful");
+
+    Ok(())
 }
 
 #[async_trait::async_trait]
 impl HealthCheckInterface for MockDb {
-    async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError> {
-        Ok(())
+    async fn health_check_db(
+        &self,
+        _read_only: bool,
+    ) -> CustomResult<DatabaseHealth, errors::HealthCheckDBError> {
+        Ok(DatabaseHealth {
+            schema_version: EXPECTED_SCHEMA_VERSION.map(ToOwned::to_owned),
+            clock_skew_ms: 0,
+        })
     }
 
     async fn health_check_redis(
         &self,
         _: &dyn StorageInterface,
+        _: u32,
+        _: Duration,
+    ) -> CustomResult<(), errors::HealthCheckRedisError> {
+        Ok(())
+    }
+
+    async fn health_check_redis_instance(
+        &self,
+        _: &RedisConnectionPool,
+    ) -> CustomResult<(), errors::HealthCheckRedisError> {
+        Ok(())
+    }
+
+    async fn health_check_redis_pubsub(
+        &self,
+        _: &dyn StorageInterface,
+        _: Duration,
     ) -> CustomResult<(), errors::HealthCheckRedisError> {
         Ok(())
     }
@@ -141,7 +572,55 @@ impl HealthCheckInterface for MockDb {
     async fn health_check_locker(
         &self,
         _: &routes::AppState,
+        _: Duration,
     ) -> CustomResult<(), errors::HealthCheckLockerError> {
         Ok(())
     }
+
+    async fn health_check_stuck_payments(
+        &self,
+        threshold: Duration,
+    ) -> CustomResult<i64, errors::HealthCheckDBError> {
+        let modified_before = common_utils::date_time::now() - threshold;
+        let payment_intents = self.payment_intents.lock().await;
+        let stuck_payments_count = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.status == storage::enums::IntentStatus::Processing
+                    && payment_intent.modified_at < modified_before
+            })
+            .count();
+
+        Ok(i64::try_from(stuck_payments_count).unwrap_or(i64::MAX))
+    }
+
+    async fn health_check_dead_letter_count(
+        &self,
+        _: &dyn StorageInterface,
+        _: &str,
+    ) -> CustomResult<u64, errors::HealthCheckRedisError> {
+        Ok(0)
+    }
+
+    async fn get_dead_letter_entries(
+        &self,
+        _: &dyn StorageInterface,
+        _: &str,
+        _: usize,
+    ) -> CustomResult<Vec<(String, HashMap<String, String>)>, errors::HealthCheckRedisError> {
+        Ok(Vec::new())
+    }
+
+    async fn health_check_redis_replication_lag(
+        &self,
+        _: &dyn StorageInterface,
+    ) -> CustomResult<Option<u64>, errors::HealthCheckRedisError> {
+        Ok(Some(0))
+    }
+
+    async fn delete_health_check_artifacts(
+        &self,
+    ) -> CustomResult<bool, errors::HealthCheckDBError> {
+        Ok(false)
+    }
 }
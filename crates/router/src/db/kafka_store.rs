@@ -43,7 +43,7 @@ use crate::{
         events::EventInterface,
         file::FileMetadataInterface,
         gsm::GsmInterface,
-        health_check::HealthCheckInterface,
+        health_check::{DatabaseHealth, HealthCheckInterface},
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
@@ -264,6 +264,13 @@ impl ConfigInterface for KafkaStore {
         self.diesel_store.find_config_by_key_from_db(key).await
     }
 
+    async fn find_config_by_key_optional(
+        &self,
+        key: &str,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError> {
+        self.diesel_store.find_config_by_key_optional(key).await
+    }
+
     async fn update_config_in_database(
         &self,
         key: &str,
@@ -284,6 +291,17 @@ impl ConfigInterface for KafkaStore {
             .await
     }
 
+    async fn set_config_by_key_if_unchanged(
+        &self,
+        key: &str,
+        current_config: Option<String>,
+        config: String,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError> {
+        self.diesel_store
+            .set_config_by_key_if_unchanged(key, current_config, config)
+            .await
+    }
+
     async fn delete_config_by_key(&self, key: &str) -> CustomResult<bool, errors::StorageError> {
         self.diesel_store.delete_config_by_key(key).await
     }
@@ -1158,6 +1176,21 @@ impl PaymentIntentInterface for KafkaStore {
             .await
     }
 
+    async fn find_optional_payment_intent_by_merchant_order_reference_id_merchant_id(
+        &self,
+        merchant_order_reference_id: &str,
+        merchant_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<storage::PaymentIntent>, errors::DataStorageError> {
+        self.diesel_store
+            .find_optional_payment_intent_by_merchant_order_reference_id_merchant_id(
+                merchant_order_reference_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
@@ -1911,6 +1944,17 @@ impl UserInterface for KafkaStore {
             .find_users_and_roles_by_merchant_id(merchant_id)
             .await
     }
+
+    async fn find_users_and_roles_by_merchant_id_after_cursor(
+        &self,
+        merchant_id: &str,
+        cursor: Option<user_storage::UserRoleListCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<(storage::User, user_storage::UserRole)>, errors::StorageError> {
+        self.diesel_store
+            .find_users_and_roles_by_merchant_id_after_cursor(merchant_id, cursor, limit)
+            .await
+    }
 }
 
 impl RedisConnInterface for KafkaStore {
@@ -1945,6 +1989,16 @@ impl UserRoleInterface for KafkaStore {
             .await
     }
 
+    async fn find_user_role_by_user_id_merchant_id_including_expired(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<user_storage::UserRole, errors::StorageError> {
+        self.diesel_store
+            .find_user_role_by_user_id_merchant_id_including_expired(user_id, merchant_id)
+            .await
+    }
+
     async fn update_user_role_by_user_id_merchant_id(
         &self,
         user_id: &str,
@@ -1956,16 +2010,175 @@ impl UserRoleInterface for KafkaStore {
             .await
     }
 
+    async fn update_user_role_by_user_id_merchant_id_keeping_admin(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: &[&str],
+        update: user_storage::UserRoleUpdate,
+    ) -> CustomResult<Option<user_storage::UserRole>, errors::StorageError> {
+        self.diesel_store
+            .update_user_role_by_user_id_merchant_id_keeping_admin(
+                user_id,
+                merchant_id,
+                admin_role_ids,
+                update,
+            )
+            .await
+    }
+
     async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError> {
         self.diesel_store.delete_user_role(user_id).await
     }
 
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_user_role_by_user_id_merchant_id(user_id, merchant_id)
+            .await
+    }
+
+    async fn delete_user_role_by_user_id_merchant_id_keeping_admin(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: &[&str],
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_user_role_by_user_id_merchant_id_keeping_admin(
+                user_id,
+                merchant_id,
+                admin_role_ids,
+            )
+            .await
+    }
+
+    async fn delete_expired_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_expired_user_role_by_user_id_merchant_id(user_id, merchant_id)
+            .await
+    }
+
     async fn list_user_roles_by_user_id(
         &self,
         user_id: &str,
     ) -> CustomResult<Vec<user_storage::UserRole>, errors::StorageError> {
         self.diesel_store.list_user_roles_by_user_id(user_id).await
     }
+
+    async fn list_user_roles_by_user_id_after_cursor(
+        &self,
+        user_id: &str,
+        cursor: Option<user_storage::UserRoleListCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<user_storage::UserRole>, errors::StorageError> {
+        self.diesel_store
+            .list_user_roles_by_user_id_after_cursor(user_id, cursor, limit)
+            .await
+    }
+
+    async fn list_active_user_roles_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<user_storage::UserRole>, errors::StorageError> {
+        self.diesel_store
+            .list_active_user_roles_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn insert_user_role_change_request(
+        &self,
+        change_request: user_storage::UserRoleChangeRequestNew,
+    ) -> CustomResult<user_storage::UserRoleChangeRequest, errors::StorageError> {
+        self.diesel_store
+            .insert_user_role_change_request(change_request)
+            .await
+    }
+
+    async fn find_user_role_change_request_by_request_id_merchant_id(
+        &self,
+        request_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<user_storage::UserRoleChangeRequest, errors::StorageError> {
+        self.diesel_store
+            .find_user_role_change_request_by_request_id_merchant_id(request_id, merchant_id)
+            .await
+    }
+
+    async fn list_user_role_change_requests_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<user_storage::UserRoleChangeRequest>, errors::StorageError> {
+        self.diesel_store
+            .list_user_role_change_requests_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn delete_user_role_change_request(
+        &self,
+        request_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_user_role_change_request(request_id)
+            .await
+    }
+
+    async fn delete_expired_user_role_change_request_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_expired_user_role_change_request_by_user_id_merchant_id(user_id, merchant_id)
+            .await
+    }
+
+    async fn insert_role_preferences(
+        &self,
+        preferences: user_storage::RolePreferencesNew,
+    ) -> CustomResult<user_storage::RolePreferences, errors::StorageError> {
+        self.diesel_store.insert_role_preferences(preferences).await
+    }
+
+    async fn update_role_preferences(
+        &self,
+        merchant_id: &str,
+        role_id: &str,
+        user_id: Option<&str>,
+        update: user_storage::RolePreferencesUpdate,
+    ) -> CustomResult<user_storage::RolePreferences, errors::StorageError> {
+        self.diesel_store
+            .update_role_preferences(merchant_id, role_id, user_id, update)
+            .await
+    }
+
+    async fn list_role_preferences_by_merchant_id_user_id(
+        &self,
+        merchant_id: &str,
+        user_id: &str,
+    ) -> CustomResult<Vec<user_storage::RolePreferences>, errors::StorageError> {
+        self.diesel_store
+            .list_role_preferences_by_merchant_id_user_id(merchant_id, user_id)
+            .await
+    }
+
+    async fn delete_role_preferences(
+        &self,
+        merchant_id: &str,
+        role_id: &str,
+        user_id: Option<&str>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_role_preferences(merchant_id, role_id, user_id)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2171,21 +2384,96 @@ impl AuthorizationInterface for KafkaStore {
 
 #[async_trait::async_trait]
 impl HealthCheckInterface for KafkaStore {
-    async fn health_check_db(&self) -> CustomResult<(), errors::HealthCheckDBError> {
-        self.diesel_store.health_check_db().await
+    async fn health_check_db(
+        &self,
+        read_only: bool,
+    ) -> CustomResult<DatabaseHealth, errors::HealthCheckDBError> {
+        self.diesel_store.health_check_db(read_only).await
     }
 
     async fn health_check_redis(
         &self,
         db: &dyn StorageInterface,
+        connection_retries: u32,
+        retry_base_delay: std::time::Duration,
+    ) -> CustomResult<(), errors::HealthCheckRedisError> {
+        self.diesel_store
+            .health_check_redis(db, connection_retries, retry_base_delay)
+            .await
+    }
+
+    async fn health_check_redis_instance(
+        &self,
+        redis_conn: &RedisConnectionPool,
+    ) -> CustomResult<(), errors::HealthCheckRedisError> {
+        self.diesel_store
+            .health_check_redis_instance(redis_conn)
+            .await
+    }
+
+    async fn health_check_redis_pubsub(
+        &self,
+        db: &dyn StorageInterface,
+        timeout: std::time::Duration,
     ) -> CustomResult<(), errors::HealthCheckRedisError> {
-        self.diesel_store.health_check_redis(db).await
+        self.diesel_store
+            .health_check_redis_pubsub(db, timeout)
+            .await
     }
 
     async fn health_check_locker(
         &self,
         state: &routes::AppState,
+        timeout: std::time::Duration,
     ) -> CustomResult<(), errors::HealthCheckLockerError> {
-        self.diesel_store.health_check_locker(state).await
+        self.diesel_store.health_check_locker(state, timeout).await
+    }
+
+    async fn health_check_stuck_payments(
+        &self,
+        threshold: std::time::Duration,
+    ) -> CustomResult<i64, errors::HealthCheckDBError> {
+        self.diesel_store
+            .health_check_stuck_payments(threshold)
+            .await
+    }
+
+    async fn health_check_dead_letter_count(
+        &self,
+        db: &dyn StorageInterface,
+        stream_name: &str,
+    ) -> CustomResult<u64, errors::HealthCheckRedisError> {
+        self.diesel_store
+            .health_check_dead_letter_count(db, stream_name)
+            .await
+    }
+
+    async fn get_dead_letter_entries(
+        &self,
+        db: &dyn StorageInterface,
+        stream_name: &str,
+        limit: usize,
+    ) -> CustomResult<
+        Vec<(String, std::collections::HashMap<String, String>)>,
+        errors::HealthCheckRedisError,
+    > {
+        self.diesel_store
+            .get_dead_letter_entries(db, stream_name, limit)
+            .await
+    }
+
+    async fn health_check_redis_replication_lag(
+        &self,
+        db: &dyn StorageInterface,
+    ) -> CustomResult<Option<u64>, errors::HealthCheckRedisError> {
+        self.diesel_store
+            .health_check_redis_replication_lag(db)
+            .await
+    }
+
+    async fn delete_health_check_artifacts(
+        &self,
+    ) -> CustomResult<bool, errors::HealthCheckDBError> {
+        self.diesel_store.delete_health_check_artifacts().await
     }
 }
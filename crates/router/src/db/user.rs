@@ -1,4 +1,7 @@
-use diesel_models::{user as storage, user_role::UserRole};
+use diesel_models::{
+    user as storage,
+    user_role::{UserRole, UserRoleListCursor},
+};
 use error_stack::{IntoReport, ResultExt};
 use masking::Secret;
 
@@ -42,6 +45,13 @@ pub trait UserInterface {
         &self,
         merchant_id: &str,
     ) -> CustomResult<Vec<(storage::User, UserRole)>, errors::StorageError>;
+
+    async fn find_users_and_roles_by_merchant_id_after_cursor(
+        &self,
+        merchant_id: &str,
+        cursor: Option<UserRoleListCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<(storage::User, UserRole)>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -113,6 +123,24 @@ impl UserInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn find_users_and_roles_by_merchant_id_after_cursor(
+        &self,
+        merchant_id: &str,
+        cursor: Option<UserRoleListCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<(storage::User, UserRole)>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::User::find_joined_users_and_roles_by_merchant_id_after_cursor(
+            &conn,
+            merchant_id,
+            cursor,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -250,4 +278,13 @@ impl UserInterface for MockDb {
     ) -> CustomResult<Vec<(storage::User, UserRole)>, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_users_and_roles_by_merchant_id_after_cursor(
+        &self,
+        _merchant_id: &str,
+        _cursor: Option<UserRoleListCursor>,
+        _limit: i64,
+    ) -> CustomResult<Vec<(storage::User, UserRole)>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
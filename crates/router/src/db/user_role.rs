@@ -1,4 +1,4 @@
-use diesel_models::user_role as storage;
+use diesel_models::{enums, user_role as storage};
 use error_stack::{IntoReport, ResultExt};
 
 use super::MockDb;
@@ -20,12 +20,21 @@ pub trait UserRoleInterface {
         user_id: &str,
     ) -> CustomResult<storage::UserRole, errors::StorageError>;
 
+    /// Finds the role of the user in the merchant, a role past its expiry is not found
     async fn find_user_role_by_user_id_merchant_id(
         &self,
         user_id: &str,
         merchant_id: &str,
     ) -> CustomResult<storage::UserRole, errors::StorageError>;
 
+    /// Finds the role of the user in the merchant, including a role past its expiry which was not
+    /// removed yet
+    async fn find_user_role_by_user_id_merchant_id_including_expired(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError>;
+
     async fn update_user_role_by_user_id_merchant_id(
         &self,
         user_id: &str,
@@ -33,12 +42,108 @@ pub trait UserRoleInterface {
         update: storage::UserRoleUpdate,
     ) -> CustomResult<storage::UserRole, errors::StorageError>;
 
+    /// Updates the role of the user in the merchant unless it is the last active admin role of the
+    /// merchant, returning `None` when the role is kept as is
+    async fn update_user_role_by_user_id_merchant_id_keeping_admin(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: &[&str],
+        update: storage::UserRoleUpdate,
+    ) -> CustomResult<Option<storage::UserRole>, errors::StorageError>;
+
     async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError>;
 
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    /// Deletes the role of the user in the merchant unless it is the last active admin role of the
+    /// merchant, returning `false` when the role is kept
+    async fn delete_user_role_by_user_id_merchant_id_keeping_admin(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: &[&str],
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn delete_expired_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
     async fn list_user_roles_by_user_id(
         &self,
         user_id: &str,
     ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
+
+    async fn list_user_roles_by_user_id_after_cursor(
+        &self,
+        user_id: &str,
+        cursor: Option<storage::UserRoleListCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
+
+    async fn list_active_user_roles_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError>;
+
+    async fn insert_user_role_change_request(
+        &self,
+        change_request: storage::UserRoleChangeRequestNew,
+    ) -> CustomResult<storage::UserRoleChangeRequest, errors::StorageError>;
+
+    async fn find_user_role_change_request_by_request_id_merchant_id(
+        &self,
+        request_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRoleChangeRequest, errors::StorageError>;
+
+    async fn list_user_role_change_requests_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::UserRoleChangeRequest>, errors::StorageError>;
+
+    async fn delete_user_role_change_request(
+        &self,
+        request_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn delete_expired_user_role_change_request_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn insert_role_preferences(
+        &self,
+        preferences: storage::RolePreferencesNew,
+    ) -> CustomResult<storage::RolePreferences, errors::StorageError>;
+
+    async fn update_role_preferences(
+        &self,
+        merchant_id: &str,
+        role_id: &str,
+        user_id: Option<&str>,
+        update: storage::RolePreferencesUpdate,
+    ) -> CustomResult<storage::RolePreferences, errors::StorageError>;
+
+    async fn list_role_preferences_by_merchant_id_user_id(
+        &self,
+        merchant_id: &str,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::RolePreferences>, errors::StorageError>;
+
+    async fn delete_role_preferences(
+        &self,
+        merchant_id: &str,
+        role_id: &str,
+        user_id: Option<&str>,
+    ) -> CustomResult<bool, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -82,6 +187,22 @@ impl UserRoleInterface for Store {
         .into_report()
     }
 
+    async fn find_user_role_by_user_id_merchant_id_including_expired(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::find_by_user_id_merchant_id_including_expired(
+            &conn,
+            user_id.to_owned(),
+            merchant_id.to_owned(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn update_user_role_by_user_id_merchant_id(
         &self,
         user_id: &str,
@@ -100,6 +221,26 @@ impl UserRoleInterface for Store {
         .into_report()
     }
 
+    async fn update_user_role_by_user_id_merchant_id_keeping_admin(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: &[&str],
+        update: storage::UserRoleUpdate,
+    ) -> CustomResult<Option<storage::UserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::update_by_user_id_merchant_id_keeping_admin(
+            &conn,
+            user_id.to_owned(),
+            merchant_id.to_owned(),
+            admin_role_ids.iter().map(ToString::to_string).collect(),
+            update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError> {
         let conn = connection::pg_connection_write(self).await?;
         storage::UserRole::delete_by_user_id(&conn, user_id.to_owned())
@@ -108,6 +249,56 @@ impl UserRoleInterface for Store {
             .into_report()
     }
 
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::delete_by_user_id_merchant_id(
+            &conn,
+            user_id.to_owned(),
+            merchant_id.to_owned(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn delete_user_role_by_user_id_merchant_id_keeping_admin(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: &[&str],
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::delete_by_user_id_merchant_id_keeping_admin(
+            &conn,
+            user_id.to_owned(),
+            merchant_id.to_owned(),
+            admin_role_ids.iter().map(ToString::to_string).collect(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn delete_expired_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::delete_expired_by_user_id_merchant_id(
+            &conn,
+            user_id.to_owned(),
+            merchant_id.to_owned(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn list_user_roles_by_user_id(
         &self,
         user_id: &str,
@@ -118,6 +309,162 @@ impl UserRoleInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn list_user_roles_by_user_id_after_cursor(
+        &self,
+        user_id: &str,
+        cursor: Option<storage::UserRoleListCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::list_by_user_id_after_cursor(&conn, user_id.to_owned(), cursor, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_active_user_roles_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::list_active_by_merchant_id(&conn, merchant_id.to_owned())
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_user_role_change_request(
+        &self,
+        change_request: storage::UserRoleChangeRequestNew,
+    ) -> CustomResult<storage::UserRoleChangeRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        change_request
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_user_role_change_request_by_request_id_merchant_id(
+        &self,
+        request_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRoleChangeRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRoleChangeRequest::find_by_request_id_merchant_id(
+            &conn,
+            request_id.to_owned(),
+            merchant_id.to_owned(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn list_user_role_change_requests_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::UserRoleChangeRequest>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRoleChangeRequest::list_by_merchant_id(&conn, merchant_id.to_owned())
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_user_role_change_request(
+        &self,
+        request_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRoleChangeRequest::delete_by_request_id(&conn, request_id.to_owned())
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_expired_user_role_change_request_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRoleChangeRequest::delete_expired_by_user_id_merchant_id(
+            &conn,
+            user_id.to_owned(),
+            merchant_id.to_owned(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn insert_role_preferences(
+        &self,
+        preferences: storage::RolePreferencesNew,
+    ) -> CustomResult<storage::RolePreferences, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        preferences
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_role_preferences(
+        &self,
+        merchant_id: &str,
+        role_id: &str,
+        user_id: Option<&str>,
+        update: storage::RolePreferencesUpdate,
+    ) -> CustomResult<storage::RolePreferences, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RolePreferences::update_by_merchant_id_role_id_user_id(
+            &conn,
+            merchant_id.to_owned(),
+            role_id.to_owned(),
+            user_id.map(ToOwned::to_owned),
+            update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn list_role_preferences_by_merchant_id_user_id(
+        &self,
+        merchant_id: &str,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::RolePreferences>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RolePreferences::list_by_merchant_id_user_id(
+            &conn,
+            merchant_id.to_owned(),
+            user_id.to_owned(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn delete_role_preferences(
+        &self,
+        merchant_id: &str,
+        role_id: &str,
+        user_id: Option<&str>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RolePreferences::delete_by_merchant_id_role_id_user_id(
+            &conn,
+            merchant_id.to_owned(),
+            role_id.to_owned(),
+            user_id.map(ToOwned::to_owned),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -151,6 +498,9 @@ impl UserRoleInterface for MockDb {
             last_modified: user_role.last_modified,
             last_modified_by: user_role.last_modified_by,
             org_id: user_role.org_id,
+            expires_at: user_role.expires_at,
+            title: user_role.title,
+            department: user_role.department,
         };
         user_roles.push(user_role.clone());
         Ok(user_role)
@@ -163,7 +513,7 @@ impl UserRoleInterface for MockDb {
         let user_roles = self.user_roles.lock().await;
         user_roles
             .iter()
-            .find(|user_role| user_role.user_id == user_id)
+            .find(|user_role| user_role.user_id == user_id && !user_role.is_expired())
             .cloned()
             .ok_or(
                 errors::StorageError::ValueNotFound(format!(
@@ -177,6 +527,28 @@ impl UserRoleInterface for MockDb {
         &self,
         user_id: &str,
         merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        let user_roles = self.user_roles.lock().await;
+        user_roles
+            .iter()
+            .find(|user_role| {
+                user_role.user_id == user_id
+                    && user_role.merchant_id == merchant_id
+                    && !user_role.is_expired()
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No user role available for user_id = {user_id} and merchant_id = {merchant_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn find_user_role_by_user_id_merchant_id_including_expired(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<storage::UserRole, errors::StorageError> {
         let user_roles = self.user_roles.lock().await;
         user_roles
@@ -202,24 +574,7 @@ impl UserRoleInterface for MockDb {
             .iter_mut()
             .find(|user_role| user_role.user_id == user_id && user_role.merchant_id == merchant_id)
             .map(|user_role| {
-                *user_role = match &update {
-                    storage::UserRoleUpdate::UpdateRole {
-                        role_id,
-                        modified_by,
-                    } => storage::UserRole {
-                        role_id: role_id.to_string(),
-                        last_modified_by: modified_by.to_string(),
-                        ..user_role.to_owned()
-                    },
-                    storage::UserRoleUpdate::UpdateStatus {
-                        status,
-                        modified_by,
-                    } => storage::UserRole {
-                        status: status.to_owned(),
-                        last_modified_by: modified_by.to_owned(),
-                        ..user_role.to_owned()
-                    },
-                };
+                *user_role = apply_user_role_update(user_role, &update);
                 user_role.to_owned()
             })
             .ok_or(
@@ -230,6 +585,35 @@ impl UserRoleInterface for MockDb {
             )
     }
 
+    async fn update_user_role_by_user_id_merchant_id_keeping_admin(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: &[&str],
+        update: storage::UserRoleUpdate,
+    ) -> CustomResult<Option<storage::UserRole>, errors::StorageError> {
+        // The lock on the user roles is held across the check and the update, so that they are
+        // atomic as in the database
+        let mut user_roles = self.user_roles.lock().await;
+        if !other_active_admin_exists(&user_roles, user_id, merchant_id, admin_role_ids) {
+            return Ok(None);
+        }
+
+        user_roles
+            .iter_mut()
+            .find(|user_role| user_role.user_id == user_id && user_role.merchant_id == merchant_id)
+            .map(|user_role| {
+                *user_role = apply_user_role_update(user_role, &update);
+                Some(user_role.to_owned())
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No user role available for user_id = {user_id} and merchant_id = {merchant_id}"
+                ))
+                .into(),
+            )
+    }
+
     async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError> {
         let mut user_roles = self.user_roles.lock().await;
         let user_role_index = user_roles
@@ -242,6 +626,62 @@ impl UserRoleInterface for MockDb {
         Ok(true)
     }
 
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut user_roles = self.user_roles.lock().await;
+        let user_role_index = user_roles
+            .iter()
+            .position(|user_role| {
+                user_role.user_id == user_id && user_role.merchant_id == merchant_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No user role available for user_id = {user_id} and merchant_id = {merchant_id}"
+            )))?;
+        user_roles.remove(user_role_index);
+        Ok(true)
+    }
+
+    async fn delete_user_role_by_user_id_merchant_id_keeping_admin(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+        admin_role_ids: &[&str],
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut user_roles = self.user_roles.lock().await;
+        if !other_active_admin_exists(&user_roles, user_id, merchant_id, admin_role_ids) {
+            return Ok(false);
+        }
+
+        let user_role_index = user_roles
+            .iter()
+            .position(|user_role| {
+                user_role.user_id == user_id && user_role.merchant_id == merchant_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No user role available for user_id = {user_id} and merchant_id = {merchant_id}"
+            )))?;
+        user_roles.remove(user_role_index);
+        Ok(true)
+    }
+
+    async fn delete_expired_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut user_roles = self.user_roles.lock().await;
+        let initial_len = user_roles.len();
+        user_roles.retain(|user_role| {
+            !(user_role.user_id == user_id
+                && user_role.merchant_id == merchant_id
+                && user_role.is_expired())
+        });
+        Ok(user_roles.len() != initial_len)
+    }
+
     async fn list_user_roles_by_user_id(
         &self,
         user_id: &str,
@@ -252,13 +692,322 @@ impl UserRoleInterface for MockDb {
             .iter()
             .cloned()
             .filter_map(|ele| {
-                if ele.user_id == user_id {
+                if ele.user_id == user_id && !ele.is_expired() {
                     return Some(ele);
                 }
                 None
             })
             .collect())
     }
+
+    async fn list_user_roles_by_user_id_after_cursor(
+        &self,
+        user_id: &str,
+        cursor: Option<storage::UserRoleListCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let user_roles = self.user_roles.lock().await;
+
+        let mut user_roles = user_roles
+            .iter()
+            .filter(|user_role| {
+                user_role.user_id == user_id
+                    && !user_role.is_expired()
+                    && cursor.map_or(true, |cursor| cursor.precedes(user_role))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        user_roles.sort_by_key(|user_role| (user_role.created_at, user_role.id));
+        user_roles.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(user_roles)
+    }
+
+    async fn list_active_user_roles_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::UserRole>, errors::StorageError> {
+        let user_roles = self.user_roles.lock().await;
+
+        Ok(user_roles
+            .iter()
+            .filter(|user_role| {
+                user_role.merchant_id == merchant_id
+                    && user_role.status == enums::UserStatus::Active
+                    && !user_role.is_expired()
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn insert_user_role_change_request(
+        &self,
+        change_request: storage::UserRoleChangeRequestNew,
+    ) -> CustomResult<storage::UserRoleChangeRequest, errors::StorageError> {
+        let mut change_requests = self.user_role_change_requests.lock().await;
+        if change_requests.iter().any(|change_request_inner| {
+            change_request_inner.request_id == change_request.request_id
+                || (change_request_inner.user_id == change_request.user_id
+                    && change_request_inner.merchant_id == change_request.merchant_id)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "user_role_change_request",
+                key: None,
+            })?
+        }
+        let change_request = storage::UserRoleChangeRequest {
+            id: change_requests
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            request_id: change_request.request_id,
+            user_id: change_request.user_id,
+            merchant_id: change_request.merchant_id,
+            role_id: change_request.role_id,
+            role_expires_at: change_request.role_expires_at,
+            requested_by: change_request.requested_by,
+            created_at: change_request.created_at,
+            expires_at: change_request.expires_at,
+        };
+        change_requests.push(change_request.clone());
+        Ok(change_request)
+    }
+
+    async fn find_user_role_change_request_by_request_id_merchant_id(
+        &self,
+        request_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRoleChangeRequest, errors::StorageError> {
+        let change_requests = self.user_role_change_requests.lock().await;
+        change_requests
+            .iter()
+            .find(|change_request| {
+                change_request.request_id == request_id
+                    && change_request.merchant_id == merchant_id
+                    && !change_request.is_expired()
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No user role change request available for request_id = {request_id} and merchant_id = {merchant_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_user_role_change_requests_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::UserRoleChangeRequest>, errors::StorageError> {
+        let change_requests = self.user_role_change_requests.lock().await;
+        Ok(change_requests
+            .iter()
+            .filter(|change_request| {
+                change_request.merchant_id == merchant_id && !change_request.is_expired()
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_user_role_change_request(
+        &self,
+        request_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut change_requests = self.user_role_change_requests.lock().await;
+        let change_request_index = change_requests
+            .iter()
+            .position(|change_request| change_request.request_id == request_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No user role change request available for request_id = {request_id}"
+            )))?;
+        change_requests.remove(change_request_index);
+        Ok(true)
+    }
+
+    async fn delete_expired_user_role_change_request_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut change_requests = self.user_role_change_requests.lock().await;
+        let initial_len = change_requests.len();
+        change_requests.retain(|change_request| {
+            !(change_request.user_id == user_id
+                && change_request.merchant_id == merchant_id
+                && change_request.is_expired())
+        });
+        Ok(change_requests.len() != initial_len)
+    }
+
+    async fn insert_role_preferences(
+        &self,
+        preferences: storage::RolePreferencesNew,
+    ) -> CustomResult<storage::RolePreferences, errors::StorageError> {
+        let mut role_preferences = self.role_preferences.lock().await;
+        if role_preferences.iter().any(|preferences_inner| {
+            preferences_inner.merchant_id == preferences.merchant_id
+                && preferences_inner.role_id == preferences.role_id
+                && preferences_inner.user_id == preferences.user_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "role_preferences",
+                key: None,
+            })?
+        }
+        let preferences = storage::RolePreferences {
+            id: role_preferences
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            merchant_id: preferences.merchant_id,
+            role_id: preferences.role_id,
+            user_id: preferences.user_id,
+            default_landing_page: preferences.default_landing_page,
+            visible_widgets: preferences.visible_widgets,
+            created_by: preferences.created_by,
+            created_at: preferences.created_at,
+            last_modified_by: preferences.last_modified_by,
+            last_modified_at: preferences.last_modified_at,
+        };
+        role_preferences.push(preferences.clone());
+        Ok(preferences)
+    }
+
+    async fn update_role_preferences(
+        &self,
+        merchant_id: &str,
+        role_id: &str,
+        user_id: Option<&str>,
+        update: storage::RolePreferencesUpdate,
+    ) -> CustomResult<storage::RolePreferences, errors::StorageError> {
+        let mut role_preferences = self.role_preferences.lock().await;
+        role_preferences
+            .iter_mut()
+            .find(|preferences| {
+                preferences.merchant_id == merchant_id
+                    && preferences.role_id == role_id
+                    && preferences.user_id.as_deref() == user_id
+            })
+            .map(|preferences| {
+                *preferences = match &update {
+                    storage::RolePreferencesUpdate::UpdatePreferences {
+                        default_landing_page,
+                        visible_widgets,
+                        modified_by,
+                    } => storage::RolePreferences {
+                        default_landing_page: default_landing_page
+                            .clone()
+                            .or(preferences.default_landing_page.clone()),
+                        visible_widgets: visible_widgets
+                            .clone()
+                            .or(preferences.visible_widgets.clone()),
+                        last_modified_by: modified_by.to_owned(),
+                        last_modified_at: common_utils::date_time::now(),
+                        ..preferences.to_owned()
+                    },
+                };
+                preferences.to_owned()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No role preferences available for merchant_id = {merchant_id} and role_id = {role_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_role_preferences_by_merchant_id_user_id(
+        &self,
+        merchant_id: &str,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::RolePreferences>, errors::StorageError> {
+        let role_preferences = self.role_preferences.lock().await;
+        Ok(role_preferences
+            .iter()
+            .filter(|preferences| {
+                preferences.merchant_id == merchant_id
+                    && preferences
+                        .user_id
+                        .as_deref()
+                        .map_or(true, |preferences_user_id| preferences_user_id == user_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_role_preferences(
+        &self,
+        merchant_id: &str,
+        role_id: &str,
+        user_id: Option<&str>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut role_preferences = self.role_preferences.lock().await;
+        let preferences_index = role_preferences
+            .iter()
+            .position(|preferences| {
+                preferences.merchant_id == merchant_id
+                    && preferences.role_id == role_id
+                    && preferences.user_id.as_deref() == user_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No role preferences available for merchant_id = {merchant_id} and role_id = {role_id}"
+            )))?;
+        role_preferences.remove(preferences_index);
+        Ok(true)
+    }
+}
+
+fn apply_user_role_update(
+    user_role: &storage::UserRole,
+    update: &storage::UserRoleUpdate,
+) -> storage::UserRole {
+    match update {
+        storage::UserRoleUpdate::UpdateRole {
+            role_id,
+            modified_by,
+            expires_at,
+        } => storage::UserRole {
+            role_id: role_id.to_string(),
+            last_modified_by: modified_by.to_string(),
+            expires_at: *expires_at,
+            ..user_role.to_owned()
+        },
+        storage::UserRoleUpdate::UpdateStatus {
+            status,
+            modified_by,
+        } => storage::UserRole {
+            status: status.to_owned(),
+            last_modified_by: modified_by.to_owned(),
+            ..user_role.to_owned()
+        },
+        storage::UserRoleUpdate::UpdateAttributes {
+            title,
+            department,
+            modified_by,
+        } => storage::UserRole {
+            title: title.clone().or(user_role.title.clone()),
+            department: department.clone().or(user_role.department.clone()),
+            last_modified_by: modified_by.to_owned(),
+            ..user_role.to_owned()
+        },
+    }
+}
+
+fn other_active_admin_exists(
+    user_roles: &[storage::UserRole],
+    user_id: &str,
+    merchant_id: &str,
+    admin_role_ids: &[&str],
+) -> bool {
+    user_roles.iter().any(|user_role| {
+        user_role.merchant_id == merchant_id
+            && user_role.user_id != user_id
+            && admin_role_ids.contains(&user_role.role_id.as_str())
+            && user_role.status == enums::UserStatus::Active
+            && !user_role.is_expired()
+    })
 }
 
 #[cfg(feature = "kafka_events")]
@@ -41,6 +41,10 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    UserRole {
+        user_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_type(&self) -> Option<OutgoingWebhookEventContent>;
@@ -72,6 +76,13 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&mandate_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::UserRoleDetails(user_role_payload) => {
+                Some(OutgoingWebhookEventContent::UserRole {
+                    user_id: user_role_payload.user_id.clone(),
+                    content: masking::masked_serialize(&user_role_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -197,7 +197,35 @@ pub async fn start_server(conf: settings::Settings) -> ApplicationResult<Server>
             errors::ApplicationError::ApiClientError(error.current_context().clone())
         })?,
     );
+    routes::health::record_process_start();
     let state = Box::pin(routes::AppState::new(conf, tx, api_client)).await;
+    if let Err(error) = services::authorization::refresh_predefined_permissions(&*state.store).await
+    {
+        logger::error!(
+            ?error,
+            "Failed to load the predefined permissions, using the compiled permissions"
+        );
+    }
+    let permission_map_drift = services::authorization::get_permission_map_drift();
+    if !permission_map_drift.is_empty() {
+        logger::error!(
+            ?permission_map_drift,
+            "The predefined roles are inconsistent with the permission modules"
+        );
+    }
+    #[cfg(unix)]
+    tokio::spawn(refresh_predefined_permissions_on_signal(state.clone()));
+    tokio::spawn(routes::health::refresh_deep_health_check(state.clone()));
+    // A row left behind by a health check of a node which stopped while running it is deleted
+    // before serving traffic, so that the first health check does not report a false failure
+    match state.store.delete_health_check_artifacts().await {
+        Ok(true) => logger::info!("Deleted the config row left behind by a database health check"),
+        Ok(false) => {}
+        Err(error) => logger::error!(
+            ?error,
+            "Failed to delete the config row left behind by a database health check"
+        ),
+    }
     let request_body_limit = server.request_body_limit;
     let server = actix_web::HttpServer::new(move || mk_app(state.clone(), request_body_limit))
         .bind((server.host.as_str(), server.port))?
@@ -208,6 +236,34 @@ pub async fn start_server(conf: settings::Settings) -> ApplicationResult<Server>
     Ok(server)
 }
 
+/// Reloads the predefined permissions whenever the process receives a SIGHUP, so that the
+/// permission definitions can be changed without a restart
+#[cfg(unix)]
+async fn refresh_predefined_permissions_on_signal(state: AppState) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangup = match signal(SignalKind::hangup()) {
+        Ok(hangup) => hangup,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to listen for SIGHUP to reload the predefined permissions"
+            );
+            return;
+        }
+    };
+
+    while hangup.recv().await.is_some() {
+        match services::authorization::refresh_predefined_permissions(&*state.store).await {
+            Ok(()) => logger::info!("Reloaded the predefined permissions"),
+            Err(error) => logger::error!(
+                ?error,
+                "Failed to reload the predefined permissions, keeping the permissions in use"
+            ),
+        }
+    }
+}
+
 pub async fn receiver_for_error(rx: oneshot::Receiver<()>, mut server: impl Stop) {
     match rx.await {
         Ok(_) => {
@@ -173,12 +173,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::FieldType,
         api_models::enums::FrmAction,
         api_models::enums::FrmPreferredFlowTypes,
+        api_models::enums::ConnectorSelectionSource,
+        api_models::enums::ThreeDsVersion,
         api_models::enums::RetryAction,
         api_models::enums::AttemptStatus,
         api_models::enums::CaptureStatus,
         api_models::enums::ReconStatus,
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
+        api_models::enums::ConnectorErrorClassification,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::MerchantConnectorUpdate,
         api_models::admin::PrimaryBusinessDetails,
@@ -254,6 +257,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::Card,
         api_models::payments::CardRedirectData,
         api_models::payments::CardToken,
+        api_models::payments::NetworkTokenData,
+        api_models::payments::InstallmentPlan,
+        api_models::payments::PaymentWarning,
+        api_models::payments::PaymentWarningCode,
+        api_models::payments::ConnectorOutcome,
+        api_models::payments::ConnectorRawResponse,
         api_models::payments::CustomerAcceptance,
         api_models::payments::PaymentsRequest,
         api_models::payments::PaymentsCreateRequest,
@@ -358,6 +367,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
+        api_models::user_role::UserRoleWebhookContent,
         api_models::enums::EventType,
         crate::types::api::admin::MerchantAccountResponse,
         crate::types::api::admin::MerchantConnectorId,
@@ -306,7 +306,18 @@ impl Health {
         web::scope("health")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::get().to(health)))
+            .service(web::resource("/liveness").route(web::get().to(liveness)))
             .service(web::resource("/deep_check").route(web::post().to(deep_health_check)))
+            .service(web::resource("/authz").route(web::get().to(authz_self_check)))
+            .service(web::resource("/metrics").route(web::get().to(health_metrics)))
+            .service(
+                web::resource("/simulate_failure")
+                    .route(web::post().to(simulate_health_check_failure)),
+            )
+            .service(
+                web::resource("/drainer/dead_letters")
+                    .route(web::get().to(list_drainer_dead_letters)),
+            )
     }
 }
 
@@ -378,6 +389,9 @@ impl Payments {
                     web::resource("/sync")
                         .route(web::post().to(payments_retrieve_with_gateway_creds)),
                 )
+                .service(
+                    web::resource("/batch/confirm").route(web::post().to(payments_batch_confirm)),
+                )
                 .service(
                     web::resource("/{payment_id}")
                         .route(web::get().to(payments_retrieve))
@@ -386,6 +400,10 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/confirm").route(web::post().to(payments_confirm)),
                 )
+                .service(
+                    web::resource("/{payment_id}/confirm/preflight")
+                        .route(web::post().to(payments_confirm_preflight)),
+                )
                 .service(
                     web::resource("/{payment_id}/cancel").route(web::post().to(payments_cancel)),
                 )
@@ -962,10 +980,51 @@ impl User {
             )
             .service(web::resource("/switch/list").route(web::get().to(list_merchant_ids_for_user)))
             .service(web::resource("/user/list").route(web::get().to(get_user_details)))
+            .service(
+                web::resource("/user/list/permission")
+                    .route(web::get().to(list_users_with_permission)),
+            )
             .service(web::resource("/permission_info").route(web::get().to(get_authorization_info)))
             .service(web::resource("/user/update_role").route(web::post().to(update_user_role)))
+            .service(
+                web::resource("/user/assign_role")
+                    .route(web::post().to(assign_or_update_user_role)),
+            )
+            .service(
+                web::resource("/user/reset_role").route(web::post().to(reset_user_role_to_default)),
+            )
+            .service(web::resource("/user/delete").route(web::delete().to(delete_user_role)))
+            .service(web::resource("/user/suspend").route(web::post().to(suspend_user_role)))
+            .service(web::resource("/user/resume").route(web::post().to(resume_user_role)))
+            .service(
+                web::resource("/user/role_change_requests")
+                    .route(web::get().to(list_user_role_change_requests)),
+            )
+            .service(
+                web::resource("/user/role_change_requests/approve")
+                    .route(web::post().to(approve_user_role_change)),
+            )
             .service(web::resource("/role/list").route(web::get().to(list_roles)))
             .service(web::resource("/role").route(web::get().to(get_role_from_token)))
+            .service(web::resource("/access_map").route(web::get().to(get_user_access_map)))
+            .service(
+                web::resource("/role/preferences")
+                    .route(web::post().to(update_role_preferences))
+                    .route(web::delete().to(delete_role_preferences)),
+            )
+            .service(
+                web::resource("/role/preferences/user")
+                    .route(web::post().to(update_user_preferences))
+                    .route(web::delete().to(delete_user_preferences)),
+            )
+            .service(web::resource("/role/clone").route(web::post().to(clone_role)))
+            .service(web::resource("/role/export").route(web::get().to(export_roles)))
+            .service(web::resource("/role/import").route(web::post().to(import_roles)))
+            .service(
+                web::resource("/role/permission_overrides")
+                    .route(web::post().to(update_role_permission_overrides))
+                    .route(web::delete().to(delete_role_permission_overrides)),
+            )
             .service(web::resource("/role/{role_id}").route(web::get().to(get_role)))
             .service(web::resource("/user/invite").route(web::post().to(invite_user)))
             .service(web::resource("/user/invite/accept").route(web::post().to(accept_invitation)))
@@ -16,7 +16,7 @@ pub async fn retrieve_forex(state: web::Data<AppState>, req: HttpRequest) -> Htt
         (),
         |state, _auth: auth::AuthenticationData, _| currency::retrieve_forex(state),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::ForexRead),
             &auth::JWTAuth(Permission::ForexRead),
             req.headers(),
         ),
@@ -48,7 +48,7 @@ pub async fn convert_forex(
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::ForexRead),
             &auth::JWTAuth(Permission::ForexRead),
             req.headers(),
         ),
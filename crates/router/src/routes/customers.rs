@@ -37,7 +37,7 @@ pub async fn customers_create(
         json_payload.into_inner(),
         |state, auth, req| create_customer(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::CustomerWrite),
             &auth::JWTAuth(Permission::CustomerWrite),
             req.headers(),
         ),
@@ -75,7 +75,14 @@ pub async fn customers_retrieve(
     let auth = if auth::is_jwt_auth(req.headers()) {
         Box::new(auth::JWTAuth(Permission::CustomerRead))
     } else {
-        match auth::is_ephemeral_auth(req.headers(), &*state.store, &payload.customer_id).await {
+        match auth::is_ephemeral_auth(
+            req.headers(),
+            &*state.store,
+            &payload.customer_id,
+            Permission::CustomerRead,
+        )
+        .await
+        {
             Ok(auth) => auth,
             Err(err) => return api::log_and_return_error_response(err),
         }
@@ -118,7 +125,7 @@ pub async fn customers_list(state: web::Data<AppState>, req: HttpRequest) -> Htt
         (),
         |state, auth, _| list_customers(state, auth.merchant_account.merchant_id, auth.key_store),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::CustomerRead),
             &auth::JWTAuth(Permission::CustomerRead),
             req.headers(),
         ),
@@ -160,7 +167,7 @@ pub async fn customers_update(
         json_payload.into_inner(),
         |state, auth, req| update_customer(state, auth.merchant_account, req, auth.key_store),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::CustomerWrite),
             &auth::JWTAuth(Permission::CustomerWrite),
             req.headers(),
         ),
@@ -201,7 +208,7 @@ pub async fn customers_delete(
         payload,
         |state, auth, req| delete_customer(state, auth.merchant_account, req, auth.key_store),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::CustomerWrite),
             &auth::JWTAuth(Permission::CustomerWrite),
             req.headers(),
         ),
@@ -234,7 +241,7 @@ pub async fn get_customer_mandates(
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MandateRead),
             &auth::JWTAuth(Permission::MandateRead),
             req.headers(),
         ),
@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 use super::AppState;
 use crate::{
     core::{api_locking, payments::helpers},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::customers,
 };
 
@@ -24,7 +24,7 @@ pub async fn ephemeral_key_create(
         |state, auth, req| {
             helpers::make_ephemeral_key(state, req.customer_id, auth.merchant_account.merchant_id)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -43,7 +43,7 @@ pub async fn ephemeral_key_delete(
         &req,
         payload,
         |state, _, req| helpers::delete_ephemeral_key(state, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -46,7 +46,7 @@ pub async fn files_create(
         create_file_request,
         |state, auth, req| files_create_core(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::FileWrite),
             &auth::JWTAuth(Permission::FileWrite),
             req.headers(),
         ),
@@ -88,7 +88,7 @@ pub async fn files_delete(
         file_id,
         |state, auth, req| files_delete_core(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::FileWrite),
             &auth::JWTAuth(Permission::FileWrite),
             req.headers(),
         ),
@@ -130,7 +130,7 @@ pub async fn files_retrieve(
         file_id,
         |state, auth, req| files_retrieve_core(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::FileRead),
             &auth::JWTAuth(Permission::FileRead),
             req.headers(),
         ),
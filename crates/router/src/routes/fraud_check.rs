@@ -4,7 +4,7 @@ use router_env::Flow;
 
 use crate::{
     core::{api_locking, fraud_check as frm_core},
-    services::{self, api},
+    services::{self, api, authorization::permissions::Permission},
     types::fraud_check::FraudCheckResponseData,
     AppState,
 };
@@ -23,7 +23,7 @@ pub async fn frm_fulfillment(
         |state, auth, req| {
             frm_core::frm_fulfillment_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &services::authentication::ApiKeyAuth,
+        &services::authentication::ApiKeyAuth(Permission::PaymentWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -1,9 +1,206 @@
-use actix_web::web;
-use api_models::health_check::RouterHealthCheckResponse;
-use router_env::{instrument, logger, tracing};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use actix_web::{http::header, web, HttpRequest, HttpResponse};
+use api_models::health_check::{
+    AuthzSelfCheckResponse, DeepHealthCheckQuery, DrainerDeadLetterEntry,
+    DrainerDeadLetterListQuery, DrainerDeadLetterListResponse, HealthCheckComponent,
+    HealthCheckStatus, RouterHealthCheckResponse, SimulateHealthCheckFailureRequest,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, StrongSecret};
+use once_cell::sync::Lazy;
+use redis_interface::{RedisConnectionPool, RedisSettings};
+use router_env::{instrument, logger, tracing, Flow};
+use tokio::sync::Mutex;
 
 use super::app;
-use crate::{routes::metrics, services};
+use crate::{
+    configs::settings::HealthCheckDependency,
+    consts,
+    core::{api_locking, errors},
+    routes::metrics,
+    services::{self, api, authentication as auth},
+};
+
+/// Encoding of the deep health check response, negotiated through the `Accept` header
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HealthCheckResponseEncoding {
+    Json,
+    MessagePack,
+}
+
+impl HealthCheckResponseEncoding {
+    /// MessagePack is only used when it is explicitly accepted, any other request is served JSON
+    fn from_request(req: &HttpRequest) -> Self {
+        let accepts_msgpack = req
+            .headers()
+            .get(header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .map_or(false, |accept| {
+                accept.split(',').any(|media_range| {
+                    media_range.split(';').next().map_or(false, |media_type| {
+                        media_type
+                            .trim()
+                            .eq_ignore_ascii_case(consts::APPLICATION_MSGPACK)
+                    })
+                })
+            });
+
+        if accepts_msgpack {
+            Self::MessagePack
+        } else {
+            Self::Json
+        }
+    }
+
+    fn content_type(self) -> String {
+        match self {
+            Self::Json => mime::APPLICATION_JSON.to_string(),
+            Self::MessagePack => consts::APPLICATION_MSGPACK.to_string(),
+        }
+    }
+
+    fn encode(self, response: &RouterHealthCheckResponse) -> Vec<u8> {
+        match self {
+            Self::Json => serde_json::to_vec(response).unwrap_or_default(),
+            // Fields are encoded by name so that the consumers do not depend on the field order
+            Self::MessagePack => rmp_serde::to_vec_named(response).unwrap_or_default(),
+        }
+    }
+}
+
+/// Status reported for the dependencies which were not probed
+const SKIPPED_STATUS: &str = "skipped";
+
+/// Status reported for the components whose failure is simulated
+const SIMULATED_FAILURE_STATUS: &str = "Simulated failure";
+
+/// Role under which the primary Redis is reported along with the additional Redis instances
+const PRIMARY_REDIS_INSTANCE: &str = "primary";
+
+/// Instant at which the process started, captured when the server is started
+static PROCESS_START: Lazy<Instant> = Lazy::new(Instant::now);
+
+/// Records the start of the process, from which the uptime reported by the deep health check is
+/// measured
+pub fn record_process_start() {
+    Lazy::force(&PROCESS_START);
+}
+
+/// Version of the build reported by the deep health check
+fn build_version() -> &'static str {
+    #[cfg(feature = "vergen")]
+    let version = router_env::version!();
+    #[cfg(not(feature = "vergen"))]
+    let version = env!("CARGO_PKG_VERSION");
+    version
+}
+
+/// Number of upcoming deep health checks which report a simulated failure, keyed by the component
+static SIMULATED_FAILURES: Lazy<Mutex<HashMap<HealthCheckComponent, u32>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Result of the last deep health check, refreshed in the background and shared across requests so
+/// that frequent probes do not run the database and redis checks on every call
+#[derive(Clone)]
+enum DeepHealthCheckSnapshot {
+    /// No deep health check has been completed since the application started
+    NeverProbed,
+    /// Result of the last completed deep health check
+    Probed {
+        response: RouterHealthCheckResponse,
+        unhealthy_dependencies: Vec<&'static str>,
+        probed_at: Instant,
+    },
+}
+
+/// The lock is only held to read or replace the snapshot, never while the dependencies are probed
+static DEEP_HEALTH_CHECK_SNAPSHOT: Lazy<Mutex<DeepHealthCheckSnapshot>> =
+    Lazy::new(|| Mutex::new(DeepHealthCheckSnapshot::NeverProbed));
+
+/// Result of the last run of the end to end probe
+struct EndToEndProbeResult {
+    status: String,
+    latency: Duration,
+    probed_at: Instant,
+}
+
+/// Result of the last run of the end to end probe, reused until the configured interval elapses
+/// so that the sandbox connector is not called on every deep health check
+static END_TO_END_PROBE: Lazy<Mutex<Option<EndToEndProbeResult>>> = Lazy::new(|| Mutex::new(None));
+
+/// Measurements of a single component taken during the deep health check
+#[derive(Default)]
+struct ComponentMeasurement {
+    last_latency: Duration,
+    success_count: u64,
+    failure_count: u64,
+}
+
+/// Measurements taken during the deep health check, keyed by the component name
+static HEALTH_CHECK_MEASUREMENTS: Lazy<Mutex<BTreeMap<&'static str, ComponentMeasurement>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// Number of deep health check requests probing the dependencies themselves, as they were not
+/// served the snapshot
+static DEEP_HEALTH_CHECK_QUEUE_DEPTH: AtomicU64 = AtomicU64::new(0);
+
+/// Connection pools of the additional Redis instances keyed by their role, created on their first
+/// successful connection and reused by the subsequent deep health checks
+static REDIS_INSTANCE_POOLS: Lazy<Mutex<HashMap<String, Arc<RedisConnectionPool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Whether the latest migration applied to the database is older than the latest migration known
+/// to this binary. The versions are the timestamps of the migrations, which are compared
+/// numerically.
+fn is_schema_behind(expected: &str, applied: Option<&str>) -> bool {
+    match applied {
+        None => true,
+        Some(applied) => match (expected.parse::<u64>(), applied.parse::<u64>()) {
+            (Ok(expected), Ok(applied)) => applied < expected,
+            _ => applied < expected,
+        },
+    }
+}
+
+/// Runs the probe of a dependency, failing it once the probe timeout elapses so that a hung
+/// dependency does not hold up the deep health check
+async fn run_probe<T, E>(
+    probe_timeout: Duration,
+    probe: impl std::future::Future<Output = error_stack::Result<T, E>>,
+) -> Result<T, String>
+where
+    E: error_stack::Context,
+{
+    match tokio::time::timeout(probe_timeout, probe).await {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(_) => Err(format!(
+            "Probe timed out after {} milliseconds",
+            probe_timeout.as_millis()
+        )),
+    }
+}
+
+async fn record_measurement(component: &'static str, latency: Duration, is_healthy: bool) {
+    let mut measurements = HEALTH_CHECK_MEASUREMENTS.lock().await;
+    let measurement = measurements.entry(component).or_default();
+    measurement.last_latency = latency;
+    if is_healthy {
+        measurement.success_count += 1;
+    } else {
+        measurement.failure_count += 1;
+    }
+}
+
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
 #[instrument(skip_all)]
@@ -14,58 +211,986 @@ pub async fn health() -> impl actix_web::Responder {
     actix_web::HttpResponse::Ok().body("health is good")
 }
 
+/// Checks that the runtime is responsive without touching any external dependency, by measuring
+/// the time taken to schedule a trivial task. Orchestrators can use it to tell a wedged process
+/// apart from an unhealthy dependency, which is reported by the deep health check.
+#[instrument(skip_all)]
+pub async fn liveness(state: web::Data<app::AppState>) -> HttpResponse {
+    let max_scheduling_delay = Duration::from_millis(
+        state
+            .conf
+            .health_check
+            .liveness_max_scheduling_delay_in_millis,
+    );
+
+    let probe_start = Instant::now();
+    tokio::task::yield_now().await;
+    let scheduling_delay = match tokio::spawn(async move { probe_start.elapsed() }).await {
+        Ok(scheduling_delay) => scheduling_delay,
+        Err(error) => {
+            logger::error!(?error, "Liveness probe task failed");
+            return HttpResponse::ServiceUnavailable().body("runtime is unresponsive");
+        }
+    };
+
+    if scheduling_delay > max_scheduling_delay {
+        logger::error!(
+            scheduling_delay_in_millis = scheduling_delay.as_millis(),
+            "Liveness probe exceeded the maximum scheduling delay"
+        );
+        return HttpResponse::ServiceUnavailable().body("runtime is unresponsive");
+    }
+
+    HttpResponse::Ok().body("alive")
+}
+
+/// Checks that the predefined roles are consistent with the permission modules, as the deep
+/// health check does, and lists the inconsistencies found. Meant to be run right after deploying
+/// permission changes, without waiting for the cached deep health check result to expire.
+#[instrument(skip_all)]
+pub async fn authz_self_check() -> HttpResponse {
+    let drift = services::authorization::get_permission_map_drift();
+
+    if drift.is_empty() {
+        HttpResponse::Ok().json(AuthzSelfCheckResponse {
+            status: HealthCheckStatus::Healthy,
+            drift,
+        })
+    } else {
+        logger::error!(?drift, "Permission map drift detected");
+        HttpResponse::InternalServerError().json(AuthzSelfCheckResponse {
+            status: HealthCheckStatus::Unhealthy,
+            drift,
+        })
+    }
+}
+
+/// Refreshes the snapshot of the deep health check once every cache TTL, for as long as the
+/// application runs. Each probe is bounded by the probe timeout, so a hung dependency delays the
+/// refresh by at most that long and is reported as unhealthy.
+pub async fn refresh_deep_health_check(state: app::AppState) {
+    let refresh_interval = Duration::from_millis(state.conf.health_check.cache_ttl_in_millis);
+    if refresh_interval.is_zero() {
+        logger::info!("Deep health check caching is disabled, the snapshot is not refreshed");
+        return;
+    }
+
+    loop {
+        let (response, unhealthy_dependencies) = run_deep_health_check(&state, false).await;
+        store_deep_health_check_snapshot(response, unhealthy_dependencies).await;
+        tokio::time::sleep(refresh_interval).await;
+    }
+}
+
+async fn store_deep_health_check_snapshot(
+    response: RouterHealthCheckResponse,
+    unhealthy_dependencies: Vec<&'static str>,
+) {
+    *DEEP_HEALTH_CHECK_SNAPSHOT.lock().await = DeepHealthCheckSnapshot::Probed {
+        response,
+        unhealthy_dependencies,
+        probed_at: Instant::now(),
+    };
+}
+
+/// Serves the snapshot of the dependencies refreshed in the background, while it is within the
+/// configured TTL. The dependencies are probed by the request itself when no snapshot was taken
+/// yet or the snapshot expired, which updates the snapshot with the result. Passing `?fresh=true`
+/// forces such a live probe of every dependency. A live probe costs a round trip to each
+/// dependency, including database writes unless `read_only_db` is set, so it is meant for on-call
+/// use during incidents and not for regular scraping. The response is encoded as MessagePack when
+/// `application/msgpack` is accepted.
 #[instrument(skip_all)]
-pub async fn deep_health_check(state: web::Data<app::AppState>) -> impl actix_web::Responder {
+pub async fn deep_health_check(
+    state: web::Data<app::AppState>,
+    req: HttpRequest,
+    query: web::Query<DeepHealthCheckQuery>,
+) -> impl actix_web::Responder {
     metrics::HEALTH_METRIC.add(&metrics::CONTEXT, 1, &[]);
-    let db = &*state.store;
-    let mut status_code = 200;
-    logger::info!("Deep health check was called");
+    let force_refresh = query.into_inner().fresh;
+    logger::info!(force_refresh, "Deep health check was called");
 
-    logger::debug!("Database health check begin");
+    let cache_ttl = Duration::from_millis(state.conf.health_check.cache_ttl_in_millis);
+    // The snapshot is bypassed while failures are simulated, so that each of the upcoming checks
+    // reports the failure and the snapshot is not affected by it
+    let is_failure_simulated = !SIMULATED_FAILURES.lock().await.is_empty();
 
-    let db_status = match db.health_check_db().await {
-        Ok(_) => "Health is good".to_string(),
-        Err(err) => {
-            status_code = 500;
-            err.to_string()
+    // The snapshot is copied out, so that the lock is not held while the dependencies are probed
+    let snapshot = DEEP_HEALTH_CHECK_SNAPSHOT.lock().await.clone();
+    let cached_result = match snapshot {
+        DeepHealthCheckSnapshot::Probed {
+            response,
+            unhealthy_dependencies,
+            probed_at,
+        } if !force_refresh && !is_failure_simulated && probed_at.elapsed() < cache_ttl => {
+            Some((response, unhealthy_dependencies))
         }
+        DeepHealthCheckSnapshot::Probed { .. } | DeepHealthCheckSnapshot::NeverProbed => None,
     };
-    logger::debug!("Database health check end");
 
-    logger::debug!("Redis health check begin");
+    let (mut response, unhealthy_dependencies) = match cached_result {
+        Some(cached_result) => {
+            logger::debug!("Serving the deep health check snapshot");
+            cached_result
+        }
+        None => {
+            DEEP_HEALTH_CHECK_QUEUE_DEPTH.fetch_add(1, Ordering::Relaxed);
+            let (mut response, mut unhealthy_dependencies) =
+                run_deep_health_check(&state, force_refresh).await;
+            DEEP_HEALTH_CHECK_QUEUE_DEPTH.fetch_sub(1, Ordering::Relaxed);
 
-    let redis_status = match db.health_check_redis(db).await {
-        Ok(_) => "Health is good".to_string(),
-        Err(err) => {
-            status_code = 500;
-            err.to_string()
+            if is_failure_simulated {
+                apply_simulated_failures(&mut response, &mut unhealthy_dependencies).await;
+            } else {
+                store_deep_health_check_snapshot(response.clone(), unhealthy_dependencies.clone())
+                    .await;
+            }
+            (response, unhealthy_dependencies)
         }
     };
 
-    logger::debug!("Redis health check end");
+    // The uptime of a cached result is refreshed, it would otherwise lag behind by up to the TTL
+    response.uptime_seconds = PROCESS_START.elapsed().as_secs();
 
-    logger::debug!("Locker health check begin");
+    let encoding = HealthCheckResponseEncoding::from_request(&req);
+    let response = encoding.encode(&response);
 
-    let locker_status = match db.health_check_locker(&state).await {
-        Ok(_) => "Health is good".to_string(),
-        Err(err) => {
-            status_code = 500;
-            err.to_string()
+    if unhealthy_dependencies.is_empty() {
+        HttpResponse::Ok()
+            .content_type(encoding.content_type())
+            .body(response)
+    } else {
+        // Lets consumers which only inspect the headers find the failed dependencies
+        HttpResponse::InternalServerError()
+            .content_type(encoding.content_type())
+            .insert_header((
+                consts::X_UNHEALTHY_DEPENDENCIES,
+                unhealthy_dependencies.join(","),
+            ))
+            .body(response)
+    }
+}
+
+/// Makes the upcoming deep health checks report a component as unhealthy, without affecting the
+/// component itself. Used to test that the alerting and the orchestrator react to the failure.
+#[instrument(skip_all, fields(flow = ?Flow::SimulateHealthCheckFailure))]
+pub async fn simulate_health_check_failure(
+    state: web::Data<app::AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<SimulateHealthCheckFailureRequest>,
+) -> HttpResponse {
+    let flow = Flow::SimulateHealthCheckFailure;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| simulate_failure(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Lists the most recent entries which the drainer failed to drain after exhausting the retries,
+/// along with the error of the last attempt
+#[instrument(skip_all, fields(flow = ?Flow::DrainerDeadLetterList))]
+pub async fn list_drainer_dead_letters(
+    state: web::Data<app::AppState>,
+    req: HttpRequest,
+    query: web::Query<DrainerDeadLetterListQuery>,
+) -> HttpResponse {
+    let flow = Flow::DrainerDeadLetterList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, req| list_dead_letters(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+async fn list_dead_letters(
+    state: app::AppState,
+    req: DrainerDeadLetterListQuery,
+) -> errors::RouterResponse<DrainerDeadLetterListResponse> {
+    let db = &*state.store;
+    let stream_name = state.conf.drainer.dead_letter_stream_name.as_str();
+    let limit = req
+        .limit
+        .unwrap_or(consts::DEAD_LETTER_LIST_DEFAULT_LIMIT)
+        .min(consts::DEAD_LETTER_LIST_MAX_LIMIT);
+
+    let dead_letter_count = db
+        .health_check_dead_letter_count(db, stream_name)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the drainer dead letter entries")?;
+
+    let entries = db
+        .get_dead_letter_entries(
+            db,
+            stream_name,
+            usize::try_from(limit).unwrap_or(usize::MAX),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read the drainer dead letter entries")?
+        .into_iter()
+        .map(|(_, mut fields)| {
+            let mut take = |field: &str| fields.remove(field).unwrap_or_default();
+            DrainerDeadLetterEntry {
+                entry_id: take("entry_id"),
+                stream_name: take("stream_name"),
+                request_id: take("request_id"),
+                global_id: take("global_id"),
+                operation: take("operation"),
+                table: take("table"),
+                retry_count: take("retry_count").parse().ok(),
+                last_error: take("last_error"),
+                dead_lettered_at: take("dead_lettered_at").parse().ok(),
+            }
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        DrainerDeadLetterListResponse {
+            dead_letter_count,
+            entries,
+        },
+    ))
+}
+
+async fn simulate_failure(
+    state: app::AppState,
+    req: SimulateHealthCheckFailureRequest,
+) -> errors::RouterResponse<()> {
+    if !state.conf.health_check.failure_simulation_enabled {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: "Simulating health check failures is disabled".to_string(),
         }
+        .into());
+    }
+
+    logger::warn!(
+        component = ?req.component,
+        count = req.count,
+        "Simulating a health check failure"
+    );
+
+    let mut simulated_failures = SIMULATED_FAILURES.lock().await;
+    if req.count == 0 {
+        simulated_failures.remove(&req.component);
+    } else {
+        simulated_failures.insert(req.component, req.count);
+    }
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Reports the components whose failure is simulated as unhealthy, and counts the check against
+/// the number of simulated failures
+async fn apply_simulated_failures(
+    response: &mut RouterHealthCheckResponse,
+    unhealthy_dependencies: &mut Vec<&'static str>,
+) {
+    let mut simulated_failures = SIMULATED_FAILURES.lock().await;
+    for (component, remaining_count) in simulated_failures.iter_mut() {
+        let status = SIMULATED_FAILURE_STATUS.to_string();
+        let dependency = match component {
+            HealthCheckComponent::Database => {
+                response.database = status;
+                "database"
+            }
+            HealthCheckComponent::Redis => {
+                response.redis = status;
+                "redis"
+            }
+            HealthCheckComponent::RedisPubsub => {
+                response.redis_pubsub = Some(status);
+                "redis_pubsub"
+            }
+            HealthCheckComponent::Locker => {
+                response.locker = Some(status);
+                "locker"
+            }
+            HealthCheckComponent::Authz => {
+                response.authz = Some(status);
+                "authz"
+            }
+        };
+        if !unhealthy_dependencies.contains(&dependency) {
+            unhealthy_dependencies.push(dependency);
+        }
+        *remaining_count = remaining_count.saturating_sub(1);
+    }
+    simulated_failures.retain(|_, remaining_count| *remaining_count > 0);
+    if !unhealthy_dependencies.is_empty() {
+        response.status = HealthCheckStatus::Unhealthy;
+    }
+}
+
+/// Renders the measurements taken during the deep health check in the prometheus text format
+#[instrument(skip_all)]
+pub async fn health_metrics(state: web::Data<app::AppState>, req: HttpRequest) -> HttpResponse {
+    let health_check_config = &state.conf.health_check;
+    if !health_check_config.metrics_enabled {
+        return HttpResponse::NotFound().finish();
+    }
+
+    if let Some(bearer_token) = &health_check_config.metrics_bearer_token {
+        let is_authorized = req
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            // `StrongSecret` compares in constant time, so the comparison does not leak how much
+            // of the token was guessed right
+            .map_or(false, |token| {
+                StrongSecret::new(token.to_string())
+                    == StrongSecret::new(bearer_token.peek().clone())
+            });
+
+        if !is_authorized {
+            return HttpResponse::Unauthorized().finish();
+        }
+    }
+
+    let measurements = HEALTH_CHECK_MEASUREMENTS.lock().await;
+    let mut body = String::new();
+
+    let _ = writeln!(
+        body,
+        "# HELP router_health_check_latency_seconds Latency of the last health check probe of the component"
+    );
+    let _ = writeln!(body, "# TYPE router_health_check_latency_seconds gauge");
+    for (component, measurement) in measurements.iter() {
+        let _ = writeln!(
+            body,
+            "router_health_check_latency_seconds{{component=\"{component}\"}} {}",
+            measurement.last_latency.as_secs_f64()
+        );
+    }
+
+    let _ = writeln!(
+        body,
+        "# HELP router_health_check_success_total Number of successful health check probes of the component"
+    );
+    let _ = writeln!(body, "# TYPE router_health_check_success_total counter");
+    for (component, measurement) in measurements.iter() {
+        let _ = writeln!(
+            body,
+            "router_health_check_success_total{{component=\"{component}\"}} {}",
+            measurement.success_count
+        );
+    }
+
+    let _ = writeln!(
+        body,
+        "# HELP router_health_check_failure_total Number of failed health check probes of the component"
+    );
+    let _ = writeln!(body, "# TYPE router_health_check_failure_total counter");
+    for (component, measurement) in measurements.iter() {
+        let _ = writeln!(
+            body,
+            "router_health_check_failure_total{{component=\"{component}\"}} {}",
+            measurement.failure_count
+        );
+    }
+    drop(measurements);
+
+    let _ = writeln!(
+        body,
+        "# HELP router_deep_health_check_queue_depth Number of deep health check requests probing the dependencies as the snapshot was not served"
+    );
+    let _ = writeln!(body, "# TYPE router_deep_health_check_queue_depth gauge");
+    let _ = writeln!(
+        body,
+        "router_deep_health_check_queue_depth {}",
+        DEEP_HEALTH_CHECK_QUEUE_DEPTH.load(Ordering::Relaxed)
+    );
+
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(body)
+}
+
+/// Runs the probes in the configured order. When `probe_all` is set, every dependency is probed
+/// even if `stop_on_first_failure` is enabled.
+async fn run_deep_health_check(
+    state: &app::AppState,
+    probe_all: bool,
+) -> (RouterHealthCheckResponse, Vec<&'static str>) {
+    let db = &*state.store;
+    let health_check_config = &state.conf.health_check;
+    let probe_timeout = Duration::from_millis(health_check_config.probe_timeout_in_millis);
+    let mut unhealthy_dependencies = Vec::new();
+    let mut degraded_reasons = Vec::new();
+
+    let probe_order = health_check_config.probe_order.iter().copied().chain(
+        HealthCheckDependency::DEFAULT_ORDER
+            .into_iter()
+            .filter(|dependency| !health_check_config.probe_order.contains(dependency)),
+    );
+
+    // Dependencies which are not probed report as skipped instead of healthy
+    let mut db_status = SKIPPED_STATUS.to_string();
+    let mut redis_status = SKIPPED_STATUS.to_string();
+    let mut redis_pubsub_status = health_check_config
+        .redis_pubsub_enabled
+        .then(|| SKIPPED_STATUS.to_string());
+    let mut locker_status = health_check_config
+        .locker_probe_enabled
+        .then(|| SKIPPED_STATUS.to_string());
+    let mut redis_instances_status = (!health_check_config.redis_instances.is_empty()).then(|| {
+        health_check_config
+            .redis_instances
+            .keys()
+            .map(String::as_str)
+            .chain(std::iter::once(PRIMARY_REDIS_INSTANCE))
+            .map(|role| (role.to_string(), SKIPPED_STATUS.to_string()))
+            .collect::<BTreeMap<_, _>>()
+    });
+    let mut schema_version = None;
+    let mut clock_skew_ms = None;
+
+    for dependency in probe_order {
+        if !probe_all
+            && health_check_config.stop_on_first_failure
+            && !unhealthy_dependencies.is_empty()
+        {
+            logger::debug!(
+                ?dependency,
+                "Skipping health check after a failed dependency"
+            );
+            continue;
+        }
+
+        match dependency {
+            HealthCheckDependency::Database => {
+                logger::debug!("Database health check begin");
+
+                let read_only_db = health_check_config.read_only_db;
+                let probe_start = Instant::now();
+                let mut is_db_healthy = true;
+                db_status = match run_probe(probe_timeout, db.health_check_db(read_only_db)).await {
+                    Ok(database_health) => {
+                        schema_version = database_health.schema_version;
+                        clock_skew_ms = Some(database_health.clock_skew_ms);
+                        // A drifted clock corrupts the expiry of the payments and client secrets,
+                        // it is reported as degraded without failing the health check
+                        let is_clock_skewed = database_health.clock_skew_ms.unsigned_abs()
+                            > health_check_config.max_clock_skew_in_millis;
+                        // The migrations are applied before the nodes are rolled out, so a
+                        // database ahead of the binary is expected during a deploy, only a
+                        // database missing migrations this binary relies on is unhealthy
+                        match (consts::EXPECTED_SCHEMA_VERSION, schema_version.as_deref()) {
+                            (Some(expected), applied) if is_schema_behind(expected, applied) => {
+                                is_db_healthy = false;
+                                logger::error!(
+                                    expected_schema_version = expected,
+                                    applied_schema_version = ?applied,
+                                    "Database schema is behind the version expected by the binary"
+                                );
+                                format!(
+                                    "Unhealthy: schema version behind, expected at least {expected}, found {}",
+                                    applied.unwrap_or("none")
+                                )
+                            }
+                            _ if is_clock_skewed => {
+                                logger::error!(
+                                    clock_skew_ms = database_health.clock_skew_ms,
+                                    "Clock of the node is skewed from the clock of the database"
+                                );
+                                let reason = format!(
+                                    "clock skew of {} milliseconds with the database",
+                                    database_health.clock_skew_ms
+                                );
+                                let status = format!("Degraded: {reason}");
+                                degraded_reasons.push(reason);
+                                status
+                            }
+                            _ if read_only_db => "Read-only health is good".to_string(),
+                            _ => "Health is good".to_string(),
+                        }
+                    }
+                    Err(err) => {
+                        is_db_healthy = false;
+                        err
+                    }
+                };
+                record_measurement("database", probe_start.elapsed(), is_db_healthy).await;
+                if !is_db_healthy {
+                    unhealthy_dependencies.push("database");
+                }
+
+                logger::debug!("Database health check end");
+            }
+            HealthCheckDependency::Redis => {
+                logger::debug!("Redis health check begin");
+
+                let probe_start = Instant::now();
+                let redis_result = run_probe(
+                    probe_timeout,
+                    db.health_check_redis(
+                        db,
+                        health_check_config.redis_connection_retries,
+                        Duration::from_millis(
+                            health_check_config.redis_connection_retry_base_delay_in_millis,
+                        ),
+                    ),
+                )
+                .await;
+                record_measurement("redis", probe_start.elapsed(), redis_result.is_ok()).await;
+                redis_status = match redis_result {
+                    Ok(_) => "Health is good".to_string(),
+                    Err(err) => {
+                        unhealthy_dependencies.push("redis");
+                        err
+                    }
+                };
+
+                // Each instance is reported on its own, a failed instance does not prevent the
+                // remaining ones from being probed
+                if let Some(redis_instances_status) = redis_instances_status.as_mut() {
+                    redis_instances_status
+                        .insert(PRIMARY_REDIS_INSTANCE.to_string(), redis_status.clone());
+
+                    let connection_timeout = Duration::from_millis(
+                        health_check_config.redis_instances_connection_timeout_in_millis,
+                    );
+                    let probe_start = Instant::now();
+                    let mut are_instances_healthy = true;
+                    for (role, redis_settings) in &health_check_config.redis_instances {
+                        let instance_result = run_probe(
+                            probe_timeout,
+                            probe_redis_instance(state, role, redis_settings, connection_timeout),
+                        )
+                        .await;
+                        let instance_status = match instance_result {
+                            Ok(_) => "Health is good".to_string(),
+                            Err(err) => {
+                                logger::error!(redis_instance = role, redis_err = %err);
+                                are_instances_healthy = false;
+                                err
+                            }
+                        };
+                        redis_instances_status.insert(role.clone(), instance_status);
+                    }
+                    record_measurement(
+                        "redis_instances",
+                        probe_start.elapsed(),
+                        are_instances_healthy,
+                    )
+                    .await;
+                    if !are_instances_healthy {
+                        unhealthy_dependencies.push("redis_instances");
+                    }
+                }
+
+                if health_check_config.redis_pubsub_enabled {
+                    let probe_start = Instant::now();
+                    let timeout =
+                        Duration::from_millis(health_check_config.redis_pubsub_timeout_in_millis);
+                    let pubsub_result =
+                        run_probe(probe_timeout, db.health_check_redis_pubsub(db, timeout)).await;
+                    record_measurement(
+                        "redis_pubsub",
+                        probe_start.elapsed(),
+                        pubsub_result.is_ok(),
+                    )
+                    .await;
+                    redis_pubsub_status = Some(match pubsub_result {
+                        Ok(_) => "Health is good".to_string(),
+                        Err(err) => {
+                            unhealthy_dependencies.push("redis_pubsub");
+                            err
+                        }
+                    });
+                }
+
+                logger::debug!("Redis health check end");
+            }
+            HealthCheckDependency::Locker if health_check_config.locker_probe_enabled => {
+                logger::debug!("Locker health check begin");
+
+                let probe_start = Instant::now();
+                let timeout = Duration::from_millis(health_check_config.locker_timeout_in_millis);
+                let locker_result =
+                    run_probe(probe_timeout, db.health_check_locker(state, timeout)).await;
+                record_measurement("locker", probe_start.elapsed(), locker_result.is_ok()).await;
+                // The cards cannot be tokenized while the locker is unreachable, so it fails the
+                // health check only when the locker is the tokenization path
+                let is_locker_critical =
+                    state.conf.locker.locker_enabled && !state.conf.locker.mock_locker;
+                locker_status = Some(match locker_result {
+                    Ok(_) => "Health is good".to_string(),
+                    Err(err) if is_locker_critical => {
+                        unhealthy_dependencies.push("locker");
+                        err
+                    }
+                    Err(err) => {
+                        let reason = format!("locker probe failed: {err}");
+                        let status = format!("Degraded: {reason}");
+                        degraded_reasons.push(reason);
+                        status
+                    }
+                });
+
+                logger::debug!("Locker health check end");
+            }
+            HealthCheckDependency::Locker => {
+                logger::debug!("Locker health check is disabled");
+            }
+        }
+    }
+
+    let mut stuck_payments_count = None;
+    let stuck_payments_status = if !health_check_config.stuck_payments_probe_enabled {
+        None
+    } else if !probe_all
+        && health_check_config.stop_on_first_failure
+        && !unhealthy_dependencies.is_empty()
+    {
+        Some(SKIPPED_STATUS.to_string())
+    } else {
+        logger::debug!("Stuck payments health check begin");
+
+        let threshold_in_secs = health_check_config.stuck_payments_threshold_in_secs;
+        let probe_start = Instant::now();
+        let stuck_payments_result = run_probe(
+            probe_timeout,
+            db.health_check_stuck_payments(Duration::from_secs(threshold_in_secs)),
+        )
+        .await;
+        let status = match stuck_payments_result {
+            Ok(count) => {
+                stuck_payments_count = Some(count);
+                // Stuck payments point to the asynchronous updates not being processed rather
+                // than to this instance being unable to serve requests, so they are reported as
+                // degraded without failing the health check
+                let is_degraded = u64::try_from(count)
+                    .map(|count| count > health_check_config.stuck_payments_max_count)
+                    .unwrap_or(false);
+                record_measurement("stuck_payments", probe_start.elapsed(), !is_degraded).await;
+                if is_degraded {
+                    logger::warn!(
+                        stuck_payments_count = count,
+                        threshold_in_secs,
+                        "Payments are stuck in processing"
+                    );
+                    let reason = format!(
+                        "{count} payments processing for more than {threshold_in_secs} seconds"
+                    );
+                    let status = format!("Degraded: {reason}");
+                    degraded_reasons.push(reason);
+                    status
+                } else {
+                    "Health is good".to_string()
+                }
+            }
+            Err(err) => {
+                record_measurement("stuck_payments", probe_start.elapsed(), false).await;
+                unhealthy_dependencies.push("stuck_payments");
+                err
+            }
+        };
+
+        logger::debug!("Stuck payments health check end");
+        Some(status)
+    };
+
+    let mut dead_letter_count = None;
+    let dead_letter_status = if !health_check_config.dead_letter_probe_enabled {
+        None
+    } else if !probe_all
+        && health_check_config.stop_on_first_failure
+        && !unhealthy_dependencies.is_empty()
+    {
+        Some(SKIPPED_STATUS.to_string())
+    } else {
+        logger::debug!("Dead letter health check begin");
+
+        let probe_start = Instant::now();
+        let dead_letter_result = run_probe(
+            probe_timeout,
+            db.health_check_dead_letter_count(db, &state.conf.drainer.dead_letter_stream_name),
+        )
+        .await;
+        let status = match dead_letter_result {
+            Ok(count) => {
+                dead_letter_count = Some(count);
+                // The entries were already dropped from the drainer stream, they point to data
+                // which needs to be reconciled rather than to this instance being unable to serve
+                // requests, so they are reported as degraded without failing the health check
+                let is_degraded = count > health_check_config.dead_letter_max_count;
+                record_measurement("dead_letter", probe_start.elapsed(), !is_degraded).await;
+                if is_degraded {
+                    logger::warn!(
+                        dead_letter_count = count,
+                        "Drainer entries were dead-lettered"
+                    );
+                    let reason = format!("{count} drainer entries dead-lettered");
+                    let status = format!("Degraded: {reason}");
+                    degraded_reasons.push(reason);
+                    status
+                } else {
+                    "Health is good".to_string()
+                }
+            }
+            Err(err) => {
+                record_measurement("dead_letter", probe_start.elapsed(), false).await;
+                unhealthy_dependencies.push("dead_letter");
+                err
+            }
+        };
+
+        logger::debug!("Dead letter health check end");
+        Some(status)
+    };
+
+    let mut redis_replication_lag_bytes = None;
+    let redis_replication_status = if !health_check_config.redis_replication_probe_enabled {
+        None
+    } else if !probe_all
+        && health_check_config.stop_on_first_failure
+        && !unhealthy_dependencies.is_empty()
+    {
+        Some(SKIPPED_STATUS.to_string())
+    } else {
+        logger::debug!("Redis replication health check begin");
+
+        let probe_start = Instant::now();
+        let replication_lag_result =
+            run_probe(probe_timeout, db.health_check_redis_replication_lag(db)).await;
+        let status = match replication_lag_result {
+            Ok(lag_bytes) => {
+                redis_replication_lag_bytes = lag_bytes;
+                // Writes still reach the master, only the reads served by the replica are stale,
+                // so a lagging or missing replica is reported as degraded
+                let degraded_reason = match lag_bytes {
+                    Some(lag_bytes)
+                        if lag_bytes > health_check_config.redis_replication_max_lag_bytes =>
+                    {
+                        Some(format!(
+                            "Redis replica is {lag_bytes} bytes behind the master"
+                        ))
+                    }
+                    Some(_) => None,
+                    None => Some("No Redis replica is connected to the master".to_string()),
+                };
+                record_measurement(
+                    "redis_replication",
+                    probe_start.elapsed(),
+                    degraded_reason.is_none(),
+                )
+                .await;
+                match degraded_reason {
+                    Some(reason) => {
+                        logger::warn!(
+                            redis_replication_lag_bytes = ?lag_bytes,
+                            "Redis replication is lagging"
+                        );
+                        let status = format!("Degraded: {reason}");
+                        degraded_reasons.push(reason);
+                        status
+                    }
+                    None => "Health is good".to_string(),
+                }
+            }
+            Err(err) => {
+                record_measurement("redis_replication", probe_start.elapsed(), false).await;
+                unhealthy_dependencies.push("redis_replication");
+                err
+            }
+        };
+
+        logger::debug!("Redis replication health check end");
+        Some(status)
+    };
+
+    let mut e2e_latency = None;
+    let e2e_status = match health_check_config.end_to_end_merchant_id.as_deref() {
+        Some(merchant_id) if health_check_config.end_to_end_enabled => {
+            if !probe_all
+                && health_check_config.stop_on_first_failure
+                && !unhealthy_dependencies.is_empty()
+            {
+                Some(SKIPPED_STATUS.to_string())
+            } else {
+                // The lock is held while the probe runs, so that concurrent health checks wait
+                // for its result instead of running the probe again
+                let mut last_probe = END_TO_END_PROBE.lock().await;
+                let min_interval =
+                    Duration::from_secs(health_check_config.end_to_end_min_interval_in_secs);
+                let is_due = last_probe.as_ref().map_or(true, |last_probe| {
+                    last_probe.probed_at.elapsed() >= min_interval
+                });
+
+                if is_due {
+                    logger::debug!("End to end health check begin");
+
+                    let probe_start = Instant::now();
+                    let e2e_result = run_probe(
+                        probe_timeout,
+                        crate::core::payments::health_check_end_to_end(
+                            state,
+                            merchant_id,
+                            health_check_config.end_to_end_connector,
+                            &health_check_config.end_to_end_card_number,
+                        ),
+                    )
+                    .await;
+                    // A failure of the sandbox connector does not prevent this instance from
+                    // serving requests, so it is reported without failing the health check and
+                    // kept out of the measurements of the dependencies
+                    let status = match e2e_result {
+                        Ok(_) => "Health is good".to_string(),
+                        Err(err) => {
+                            logger::error!(e2e_err=%err, "End to end health check failed");
+                            err
+                        }
+                    };
+                    *last_probe = Some(EndToEndProbeResult {
+                        status,
+                        latency: probe_start.elapsed(),
+                        probed_at: Instant::now(),
+                    });
+
+                    logger::debug!("End to end health check end");
+                }
+
+                last_probe.as_ref().map(|last_probe| {
+                    e2e_latency = Some(last_probe.latency.as_millis());
+                    last_probe.status.clone()
+                })
+            }
+        }
+        _ => None,
     };
 
-    logger::debug!("Locker health check end");
+    #[cfg(feature = "olap")]
+    let authz_status = {
+        logger::debug!("Authz health check begin");
+
+        let probe_start = Instant::now();
+        let authz_result = crate::utils::user_role::health_check_authz();
+        record_measurement("authz", probe_start.elapsed(), authz_result.is_ok()).await;
+        let authz_status = match authz_result {
+            Ok(_) => "Health is good".to_string(),
+            Err(err) => {
+                unhealthy_dependencies.push("authz");
+                err.to_string()
+            }
+        };
 
-    let response = serde_json::to_string(&RouterHealthCheckResponse {
-        database: db_status,
-        redis: redis_status,
-        locker: locker_status,
-    })
-    .unwrap_or_default();
+        logger::debug!("Authz health check end");
+        Some(authz_status)
+    };
+    #[cfg(not(feature = "olap"))]
+    let authz_status = None;
 
-    if status_code == 200 {
-        services::http_response_json(response)
+    let status = if !unhealthy_dependencies.is_empty() {
+        HealthCheckStatus::Unhealthy
+    } else if !degraded_reasons.is_empty() {
+        HealthCheckStatus::Degraded
     } else {
-        services::http_server_error_json_response(response)
+        HealthCheckStatus::Healthy
+    };
+
+    (
+        RouterHealthCheckResponse {
+            status,
+            version: build_version().to_string(),
+            uptime_seconds: PROCESS_START.elapsed().as_secs(),
+            degraded_reasons,
+            database: db_status,
+            redis: redis_status,
+            redis_pubsub: redis_pubsub_status,
+            locker: locker_status,
+            schema_version,
+            clock_skew_ms,
+            authz: authz_status,
+            stuck_payments: stuck_payments_status,
+            stuck_payments_count,
+            e2e: e2e_status,
+            e2e_latency_in_millis: e2e_latency,
+            dead_letter: dead_letter_status,
+            dead_letter_count,
+            redis_replication: redis_replication_status,
+            redis_replication_lag_bytes,
+            redis_instances: redis_instances_status,
+        },
+        unhealthy_dependencies,
+    )
+}
+
+/// Runs the key and stream probes against an additional Redis instance, connecting to it on its
+/// first probe
+async fn probe_redis_instance(
+    state: &app::AppState,
+    role: &str,
+    redis_settings: &RedisSettings,
+    connection_timeout: Duration,
+) -> errors::CustomResult<(), errors::HealthCheckRedisError> {
+    let mut redis_instance_pools = REDIS_INSTANCE_POOLS.lock().await;
+    let redis_conn = match redis_instance_pools.get(role) {
+        Some(redis_conn) => Arc::clone(redis_conn),
+        None => {
+            let redis_conn = Arc::new(
+                tokio::time::timeout(connection_timeout, RedisConnectionPool::new(redis_settings))
+                    .await
+                    .into_report()
+                    .change_context(errors::HealthCheckRedisError::RedisConnectionError)?
+                    .change_context(errors::HealthCheckRedisError::RedisConnectionError)?,
+            );
+            redis_instance_pools.insert(role.to_string(), Arc::clone(&redis_conn));
+            redis_conn
+        }
+    };
+    drop(redis_instance_pools);
+
+    state.store.health_check_redis_instance(&redis_conn).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_hung_probe_fails_on_timeout() {
+        let probe_timeout = Duration::from_millis(10);
+
+        let hung_probe_result = run_probe(
+            probe_timeout,
+            std::future::pending::<errors::CustomResult<(), errors::HealthCheckRedisError>>(),
+        )
+        .await;
+        assert_eq!(
+            hung_probe_result,
+            Err("Probe timed out after 10 milliseconds".to_string())
+        );
+
+        let probe_result = run_probe(probe_timeout, async {
+            Ok::<_, error_stack::Report<errors::HealthCheckRedisError>>(())
+        })
+        .await;
+        assert_eq!(probe_result, Ok(()));
+    }
+
+    #[test]
+    fn test_is_schema_behind() {
+        let expected = "20231206120000";
+
+        assert!(is_schema_behind(expected, None));
+        assert!(is_schema_behind(expected, Some("20231130090000")));
+        assert!(!is_schema_behind(expected, Some("20231206120000")));
+        // A database ahead of the binary is expected while the nodes are rolled out
+        assert!(!is_schema_behind(expected, Some("20240102080000")));
+        // The versions are compared numerically, not lexicographically
+        assert!(!is_schema_behind("999", Some("1000")));
     }
 }
@@ -19,6 +19,7 @@ pub enum ApiIdentifier {
     CardsInfo,
     Files,
     Cache,
+    Health,
     Business,
     Verification,
     ApiKeys,
@@ -99,6 +100,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsRetrieve
             | Flow::PaymentsUpdate
             | Flow::PaymentsConfirm
+            | Flow::PaymentsBatchConfirm
+            | Flow::PaymentsConfirmPreflight
             | Flow::PaymentsCapture
             | Flow::PaymentsCancel
             | Flow::PaymentsApprove
@@ -141,6 +144,8 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CacheInvalidate => Self::Cache,
 
+            Flow::SimulateHealthCheckFailure | Flow::DrainerDeadLetterList => Self::Health,
+
             Flow::BusinessProfileCreate
             | Flow::BusinessProfileUpdate
             | Flow::BusinessProfileRetrieve
@@ -184,7 +189,25 @@ impl From<Flow> for ApiIdentifier {
             Flow::ListRoles
             | Flow::GetRole
             | Flow::GetRoleFromToken
+            | Flow::GetUserAccessMap
             | Flow::UpdateUserRole
+            | Flow::AssignOrUpdateUserRole
+            | Flow::ResetUserRoleToDefault
+            | Flow::DeleteUserRole
+            | Flow::SuspendUserRole
+            | Flow::ResumeUserRole
+            | Flow::ApproveUserRoleChange
+            | Flow::ListUserRoleChangeRequests
+            | Flow::ListUsersWithPermission
+            | Flow::UpdateRolePreferences
+            | Flow::DeleteRolePreferences
+            | Flow::UpdateUserPreferences
+            | Flow::DeleteUserPreferences
+            | Flow::UpdateRolePermissionOverrides
+            | Flow::DeleteRolePermissionOverrides
+            | Flow::CloneRole
+            | Flow::ExportRoles
+            | Flow::ImportRoles
             | Flow::GetAuthorizationInfo
             | Flow::AcceptInvitation => Self::UserRole,
 
@@ -42,7 +42,7 @@ pub async fn get_mandate(
         &req,
         mandate_id,
         |state, auth, req| mandate::get_mandate(state, auth.merchant_account, auth.key_store, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::MandateRead),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -83,7 +83,7 @@ pub async fn revoke_mandate(
         |state, auth, req| {
             mandate::revoke_mandate(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::MandateWrite),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -127,7 +127,7 @@ pub async fn retrieve_mandates_list(
             mandate::retrieve_mandates_list(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MandateRead),
             &auth::JWTAuth(Permission::MandateRead),
             req.headers(),
         ),
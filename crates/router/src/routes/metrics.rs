@@ -21,6 +21,7 @@ counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
 
 counter_metric!(PAYMENT_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_PAYMENT, GLOBAL_METER);
+histogram_metric!(PAYMENT_CONFIRM_STAGE_TIME, GLOBAL_METER); // Time taken by each of the concurrent stages of payment confirm
 
 counter_metric!(REFUND_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_REFUND, GLOBAL_METER);
@@ -113,6 +114,9 @@ counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
 
+counter_metric!(CONNECTOR_HEALTH_BASED_SKIP_COUNT, GLOBAL_METER); // Connectors skipped for being unhealthy
+counter_metric!(UNHEALTHY_CONNECTOR_FORCED_ROUTING_COUNT, GLOBAL_METER); // Payments routed to an unhealthy connector as no healthy connector was available
+
 counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process tracker
 counter_metric!(TASKS_RESET_COUNT, GLOBAL_METER); // Tasks reset in process tracker for requeue flow
 
@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use crate::{
     core::{api_locking, payment_link::*},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     AppState,
 };
 
@@ -35,7 +35,11 @@ pub async fn payment_link_retrieve(
 ) -> impl Responder {
     let flow = Flow::PaymentLinkRetrieve;
     let payload = json_payload.into_inner();
-    let (auth_type, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth_type, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
         Ok(auth) => auth,
         Err(err) => return api::log_and_return_error_response(error_stack::report!(err)),
     };
@@ -118,7 +122,7 @@ pub async fn payments_link_list(
         &req,
         payload,
         |state, auth, payload| list_payment_link(state, auth.merchant_account, payload),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentRead),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -8,7 +8,7 @@ use time::PrimitiveDateTime;
 use super::app::AppState;
 use crate::{
     core::{api_locking, errors, payment_methods::cards},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::{
         api::payment_methods::{self, PaymentMethodId},
         storage::payment_method::PaymentTokenData,
@@ -46,7 +46,7 @@ pub async fn create_payment_method_api(
         |state, auth, req| async move {
             cards::add_payment_method(state, req, &auth.merchant_account, &auth.key_store).await
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -83,7 +83,11 @@ pub async fn list_payment_method_api(
 ) -> HttpResponse {
     let flow = Flow::PaymentMethodsList;
     let payload = json_payload.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -148,7 +152,7 @@ pub async fn list_customer_payment_method_api(
                 Some(&customer_id),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -185,7 +189,11 @@ pub async fn list_customer_payment_method_api_client(
 ) -> HttpResponse {
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::CustomerRead,
+    ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -243,7 +251,7 @@ pub async fn payment_method_retrieve_api(
         &req,
         payload,
         |state, auth, pm| cards::retrieve_payment_method(state, pm, auth.key_store),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -290,7 +298,7 @@ pub async fn payment_method_update_api(
                 auth.key_store,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -328,7 +336,7 @@ pub async fn payment_method_delete_api(
         &req,
         pm,
         |state, auth, req| cards::delete_payment_method(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -4,13 +4,18 @@ use crate::{
 };
 pub mod helpers;
 
-use actix_web::{web, Responder};
+use actix_web::{
+    http::header::{HeaderName, HeaderValue},
+    web, Responder,
+};
 use api_models::payments::HeaderPayload;
-use error_stack::{report, IntoReport};
-use router_env::{env, instrument, tracing, types, Flow};
+use error_stack::{report, IntoReport, ResultExt};
+use futures::StreamExt;
+use router_env::{env, instrument, logger, tracing, types, Flow};
 
 use crate::{
     self as app,
+    consts,
     core::{
         errors::{self, http_not_implemented},
         payment_methods::{Oss, PaymentMethodRetrieve},
@@ -141,9 +146,9 @@ pub async fn payments_create(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ApiKeyAuth(Permission::PaymentWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::PaymentWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 req.headers(),
             ),
@@ -256,11 +261,14 @@ pub async fn payments_retrieve(
 
     tracing::Span::current().record("payment_id", &path.to_string());
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -313,10 +321,11 @@ pub async fn payments_retrieve_with_gateway_creds(
     req: actix_web::HttpRequest,
     json_payload: web::Json<payment_types::PaymentRetrieveBodyWithCredentials>,
 ) -> impl Responder {
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentRead) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
     let payload = payment_types::PaymentsRetrieveRequest {
         resource_id: payment_types::PaymentIdType::PaymentIntentId(
             json_payload.payment_id.to_string(),
@@ -394,10 +403,11 @@ pub async fn payments_update(
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentWrite) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -440,7 +450,7 @@ pub async fn payments_update(
     operation_id = "Confirm a Payment",
     security(("api_key" = []), ("publishable_key" = []))
 )]
-#[instrument(skip_all, fields(flow = ?Flow::PaymentsConfirm, payment_id))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsConfirm, payment_id, correlation_id))]
 // #[post("/{payment_id}/confirm")]
 pub async fn payments_confirm(
     state: web::Data<app::AppState>,
@@ -463,33 +473,43 @@ pub async fn payments_confirm(
     tracing::Span::current().record("payment_id", &payment_id);
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
-    let header_payload = match payment_types::HeaderPayload::foreign_try_from(req.headers()) {
+    let mut header_payload = match payment_types::HeaderPayload::foreign_try_from(req.headers()) {
         Ok(headers) => headers,
         Err(err) => {
             return api::log_and_return_error_response(err);
         }
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(e) => return api::log_and_return_error_response(e),
-        };
+    // The correlation id supplied by the client is honored, so that it can be searched for in the
+    // logs of both the client and the connector
+    let correlation_id = header_payload
+        .x_correlation_id
+        .get_or_insert_with(|| common_utils::generate_id(consts::ID_LENGTH, "corr"))
+        .clone();
+    tracing::Span::current().record("correlation_id", &correlation_id);
+
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
-    Box::pin(api::server_wrap(
+    let mut response = Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload,
         |state, auth, req| {
-            authorize_verify_select::<_, Oss>(
-                payments::PaymentConfirm,
+            confirm_or_replay_succeeded::<Oss>(
                 state,
                 auth.merchant_account,
                 auth.key_store,
-                header_payload,
+                header_payload.clone(),
                 req,
                 auth_flow,
             )
@@ -497,8 +517,72 @@ pub async fn payments_confirm(
         &*auth_type,
         locking_action,
     ))
+    .await;
+
+    // Added to the error responses as well, which are the ones that need correlating the most
+    if let (Ok(header_name), Ok(header_value)) = (
+        HeaderName::from_bytes(consts::X_CORRELATION_ID.as_bytes()),
+        HeaderValue::from_str(&correlation_id),
+    ) {
+        response.headers_mut().insert(header_name, header_value);
+    }
+    response
+}
+/// Payments - Batch Confirm
+///
+/// To confirm multiple payments in a single request. Each payment is confirmed independently, so a failure in one payment does not abort the others.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsBatchConfirm))]
+pub async fn payments_batch_confirm(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsBatchConfirmRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsBatchConfirm;
+    let mut payload = json_payload.into_inner();
+
+    let max_batch_size = state.conf.payment_batch_confirm.max_batch_size;
+    if payload.payments.len() > max_batch_size {
+        return api::log_and_return_error_response(report!(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("A batch can contain at most {max_batch_size} payments"),
+            }
+        ));
+    }
+
+    for payment in payload.payments.iter_mut() {
+        if let Err(err) = helpers::populate_ip_into_browser_info(&req, payment) {
+            return api::log_and_return_error_response(err);
+        }
+        payment.confirm = Some(true);
+    }
+
+    let header_payload = match payment_types::HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(err) => {
+            return api::log_and_return_error_response(err);
+        }
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            batch_authorize_verify_select::<Oss>(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                header_payload.clone(),
+                req,
+            )
+        },
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
     .await
 }
+
 /// Payments - Capture
 ///
 /// To capture the funds for an uncaptured payment
@@ -561,7 +645,7 @@ pub async fn payments_capture(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -839,7 +923,7 @@ pub async fn payments_cancel(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -885,7 +969,7 @@ pub async fn payments_list(
         payload,
         |state, auth, req| payments::list_payments(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::PaymentRead),
             &auth::JWTAuth(Permission::PaymentRead),
             req.headers(),
         ),
@@ -909,7 +993,7 @@ pub async fn payments_list_by_filter(
         payload,
         |state, auth, req| payments::apply_filters_on_payments(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::PaymentRead),
             &auth::JWTAuth(Permission::PaymentRead),
             req.headers(),
         ),
@@ -933,7 +1017,7 @@ pub async fn get_filters_for_payments(
         payload,
         |state, auth, req| payments::get_filters_for_payments(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::PaymentRead),
             &auth::JWTAuth(Permission::PaymentRead),
             req.headers(),
         ),
@@ -966,7 +1050,24 @@ pub async fn payments_approve(
         state,
         &http_req,
         payload.clone(),
-        |state, auth, req| {
+        |state,
+         (auth, auth_type): (auth::AuthenticationData, auth::AuthenticationType),
+         req| async move {
+            // The review is recorded before the decision is carried out, so that it is kept
+            // when the connector call fails
+            let reviewed_by = auth_type
+                .get_actor_id()
+                .map(ToString::to_string)
+                .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Request was authenticated without an actor")?;
+            payments::helpers::record_frm_manual_review(
+                &*state.store,
+                &auth.merchant_account,
+                &req.payment_id,
+                reviewed_by,
+            )
+            .await?;
+
             payments::payments_core::<
                 api_types::Capture,
                 payment_types::PaymentsResponse,
@@ -988,12 +1089,15 @@ pub async fn payments_approve(
                 None,
                 payment_types::HeaderPayload::default(),
             )
+            .await
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => {
+                &auth::WithAuthenticationType(auth::ApiKeyAuth(Permission::PaymentWrite))
+            }
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
-                &auth::JWTAuth(Permission::PaymentWrite),
+                &auth::WithAuthenticationType(auth::ApiKeyAuth(Permission::PaymentWrite)),
+                &auth::WithAuthenticationType(auth::JWTAuth(Permission::PaymentWrite)),
                 http_req.headers(),
             ),
         },
@@ -1026,7 +1130,24 @@ pub async fn payments_reject(
         state,
         &http_req,
         payload.clone(),
-        |state, auth, req| {
+        |state,
+         (auth, auth_type): (auth::AuthenticationData, auth::AuthenticationType),
+         req| async move {
+            // The review is recorded before the decision is carried out, so that it is kept
+            // when the connector call fails
+            let reviewed_by = auth_type
+                .get_actor_id()
+                .map(ToString::to_string)
+                .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Request was authenticated without an actor")?;
+            payments::helpers::record_frm_manual_review(
+                &*state.store,
+                &auth.merchant_account,
+                &req.payment_id,
+                reviewed_by,
+            )
+            .await?;
+
             payments::payments_core::<
                 api_types::Void,
                 payment_types::PaymentsResponse,
@@ -1049,12 +1170,15 @@ pub async fn payments_reject(
                 None,
                 payment_types::HeaderPayload::default(),
             )
+            .await
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => {
+                &auth::WithAuthenticationType(auth::ApiKeyAuth(Permission::PaymentWrite))
+            }
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
-                &auth::JWTAuth(Permission::PaymentWrite),
+                &auth::WithAuthenticationType(auth::ApiKeyAuth(Permission::PaymentWrite)),
+                &auth::WithAuthenticationType(auth::JWTAuth(Permission::PaymentWrite)),
                 http_req.headers(),
             ),
         },
@@ -1069,7 +1193,7 @@ async fn authorize_verify_select<Op, Ctx>(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     header_payload: HeaderPayload,
-    req: api_models::payments::PaymentsRequest,
+    mut req: api_models::payments::PaymentsRequest,
     auth_flow: api::AuthFlow,
 ) -> app::core::errors::RouterResponse<api_models::payments::PaymentsResponse>
 where
@@ -1093,6 +1217,15 @@ where
     // the operation are flow agnostic, and the flow is only required in the post_update_tracker
     // Thus the flow can be generated just before calling the connector instead of explicitly passing it here.
 
+    // A zero amount payment set up for future usage verifies the payment method through the
+    // setup mandate flow of the connector instead of authorizing the payment
+    if req.payment_type.is_none()
+        && req.amount == Some(api_models::payments::Amount::Zero)
+        && req.setup_future_usage.is_some()
+    {
+        req.payment_type = Some(api_models::enums::PaymentType::SetupMandate);
+    }
+
     let eligible_connectors = req.connector.clone();
     match req.payment_type.unwrap_or_default() {
         api_models::enums::PaymentType::Normal
@@ -1142,6 +1275,227 @@ where
     }
 }
 
+/// Confirms the payment, unless it is a retry of a confirm which already succeeded within the
+/// grace window, in which case the payment is returned as it is without confirming it again
+async fn confirm_or_replay_succeeded<Ctx>(
+    state: app::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    header_payload: HeaderPayload,
+    req: payment_types::PaymentsRequest,
+    auth_flow: api::AuthFlow,
+) -> app::core::errors::RouterResponse<payment_types::PaymentsResponse>
+where
+    Ctx: PaymentMethodRetrieve,
+{
+    if payments::helpers::is_succeeded_confirm_replay(&state, &merchant_account, &req).await? {
+        logger::info!("Returning the succeeded payment to a confirm within the grace window");
+
+        let retrieve_request = payment_types::PaymentsRetrieveRequest {
+            resource_id: req.payment_id.unwrap_or_default(),
+            client_secret: req.client_secret,
+            ..Default::default()
+        };
+        return payments::payments_core::<
+            api_types::PSync,
+            payment_types::PaymentsResponse,
+            _,
+            _,
+            _,
+            Ctx,
+        >(
+            state,
+            merchant_account,
+            key_store,
+            payments::PaymentStatus,
+            retrieve_request,
+            auth_flow,
+            payments::CallConnectorAction::Avoid,
+            None,
+            header_payload,
+        )
+        .await;
+    }
+
+    authorize_verify_select::<_, Ctx>(
+        payments::PaymentConfirm,
+        state,
+        merchant_account,
+        key_store,
+        header_payload,
+        req,
+        auth_flow,
+    )
+    .await
+}
+
+async fn batch_authorize_verify_select<Ctx>(
+    state: app::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    header_payload: HeaderPayload,
+    req: payment_types::PaymentsBatchConfirmRequest,
+) -> app::core::errors::RouterResponse<payment_types::PaymentsBatchConfirmResponse>
+where
+    Ctx: PaymentMethodRetrieve,
+{
+    let max_concurrency = state.conf.payment_batch_confirm.max_concurrency;
+
+    let data = futures::stream::iter(req.payments)
+        .map(|payment| {
+            batch_confirm_entry::<Ctx>(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                header_payload.clone(),
+                payment,
+            )
+        })
+        .buffered(max_concurrency)
+        .collect::<Vec<_>>()
+        .await;
+
+    Ok(api::ApplicationResponse::Json(
+        payment_types::PaymentsBatchConfirmResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+async fn batch_confirm_entry<Ctx>(
+    state: app::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    header_payload: HeaderPayload,
+    req: payment_types::PaymentsRequest,
+) -> payment_types::PaymentsBatchConfirmResult
+where
+    Ctx: PaymentMethodRetrieve,
+{
+    let payment_id = req
+        .payment_id
+        .as_ref()
+        .and_then(|payment_id| payment_id.get_payment_intent_id().ok());
+
+    let result = async {
+        if payment_id.is_none() {
+            return Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "payment_id"
+            }));
+        }
+
+        if let Some(api_enums::CaptureMethod::Scheduled) = req.capture_method {
+            return Err(report!(errors::ApiErrorResponse::NotImplemented {
+                message: errors::api_error_response::NotImplementedMessage::Default,
+            }));
+        }
+
+        let merchant_id = merchant_account.merchant_id.clone();
+        let locking_action = req.get_locking_input(Flow::PaymentsConfirm);
+        locking_action
+            .clone()
+            .perform_locking_action(&state, merchant_id.clone())
+            .await?;
+
+        let response = authorize_verify_select::<_, Ctx>(
+            payments::PaymentConfirm,
+            state.clone(),
+            merchant_account,
+            key_store,
+            header_payload,
+            req,
+            api::AuthFlow::Merchant,
+        )
+        .await;
+
+        locking_action.free_lock_action(&state, merchant_id).await?;
+
+        match response? {
+            api::ApplicationResponse::Json(response)
+            | api::ApplicationResponse::JsonWithHeaders((response, _)) => Ok(response),
+            _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Unexpected response type from payments confirm"),
+        }
+    }
+    .await;
+
+    match result {
+        Ok(response) => payment_types::PaymentsBatchConfirmResult {
+            payment_id,
+            response: Some(response),
+            error_code: None,
+            error_message: None,
+        },
+        Err(error) => {
+            logger::error!(batch_confirm_error=?error);
+            let error = error.current_context();
+            payment_types::PaymentsBatchConfirmResult {
+                payment_id,
+                response: None,
+                error_code: Some(error.error_code()),
+                error_message: Some(error.error_message()),
+            }
+        }
+    }
+}
+
+/// Payments - Confirm Preflight
+///
+/// Resolves the connector the payment would be confirmed through with a payment method, and returns the fields required to confirm it
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/confirm/preflight",
+    request_body=PaymentsConfirmPreflightRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Fields required to confirm the payment", body = PaymentsConfirmPreflightResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Get the fields required to confirm a Payment",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsConfirmPreflight, payment_id))]
+pub async fn payments_confirm_preflight(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsConfirmPreflightRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsConfirmPreflight;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    payload.payment_id = payment_id;
+
+    let (auth_type, _auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            payments::payments_confirm_preflight(state, auth.merchant_account, auth.key_store, req)
+        },
+        &*auth_type,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payments - Incremental Authorization
 ///
 /// Authorized amount for a payment can be incremented if it is in status: requires_capture
@@ -1200,7 +1554,7 @@ pub async fn payments_incremental_authorization(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
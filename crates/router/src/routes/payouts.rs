@@ -7,7 +7,7 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::api_locking,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 #[cfg(feature = "payouts")]
 use crate::{core::payouts::*, types::api::payouts as payout_types};
@@ -39,7 +39,7 @@ pub async fn payouts_create(
         &req,
         json_payload.into_inner(),
         |state, auth, req| payouts_create_core(state, auth.merchant_account, auth.key_store, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -78,7 +78,7 @@ pub async fn payouts_retrieve(
         &req,
         payout_retrieve_request,
         |state, auth, req| payouts_retrieve_core(state, auth.merchant_account, auth.key_store, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -117,7 +117,7 @@ pub async fn payouts_update(
         &req,
         payout_update_payload,
         |state, auth, req| payouts_update_core(state, auth.merchant_account, auth.key_store, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -156,7 +156,7 @@ pub async fn payouts_cancel(
         &req,
         payload,
         |state, auth, req| payouts_cancel_core(state, auth.merchant_account, auth.key_store, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -195,7 +195,7 @@ pub async fn payouts_fulfill(
         &req,
         payload,
         |state, auth, req| payouts_fulfill_core(state, auth.merchant_account, auth.key_store, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
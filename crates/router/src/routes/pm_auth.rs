@@ -2,7 +2,11 @@ use actix_web::{web, HttpRequest, Responder};
 use api_models as api_types;
 use router_env::{instrument, tracing, types::Flow};
 
-use crate::{core::api_locking, routes::AppState, services::api as oss_api};
+use crate::{
+    core::api_locking,
+    routes::AppState,
+    services::{api as oss_api, authorization::permissions::Permission},
+};
 
 #[instrument(skip_all, fields(flow = ?Flow::PmAuthLinkTokenCreate))]
 pub async fn link_token_create(
@@ -15,6 +19,7 @@ pub async fn link_token_create(
     let (auth, _) = match crate::services::authentication::check_client_secret_and_get_auth(
         req.headers(),
         &payload,
+        Permission::PaymentWrite,
     ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return oss_api::log_and_return_error_response(e),
@@ -49,6 +54,7 @@ pub async fn exchange_token(
     let (auth, _) = match crate::services::authentication::check_client_secret_and_get_auth(
         req.headers(),
         &payload,
+        Permission::PaymentWrite,
     ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return oss_api::log_and_return_error_response(e),
@@ -38,7 +38,7 @@ pub async fn refunds_create(
         json_payload.into_inner(),
         |state, auth, req| refund_create_core(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundWrite),
             &auth::JWTAuth(Permission::RefundWrite),
             req.headers(),
         ),
@@ -93,7 +93,7 @@ pub async fn refunds_retrieve(
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -137,7 +137,7 @@ pub async fn refunds_retrieve_with_body(
                 refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -177,7 +177,7 @@ pub async fn refunds_update(
         &req,
         refund_update_req,
         |state, auth, req| refund_update_core(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -211,7 +211,7 @@ pub async fn refunds_list(
         payload.into_inner(),
         |state, auth, req| refund_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -248,7 +248,7 @@ pub async fn refunds_filter_list(
         payload.into_inner(),
         |state, auth, req| refund_filter_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -35,7 +35,7 @@ pub async fn routing_create_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
@@ -70,7 +70,7 @@ pub async fn routing_link_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
@@ -100,7 +100,7 @@ pub async fn routing_retrieve_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingRead),
             &auth::JWTAuth(Permission::RoutingRead),
             req.headers(),
         ),
@@ -135,7 +135,7 @@ pub async fn routing_retrieve_dictionary(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingRead),
                 &auth::JWTAuth(Permission::RoutingRead),
                 req.headers(),
             ),
@@ -159,7 +159,7 @@ pub async fn routing_retrieve_dictionary(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingRead),
                 &auth::JWTAuth(Permission::RoutingRead),
                 req.headers(),
             ),
@@ -193,7 +193,7 @@ pub async fn routing_unlink_config(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingWrite),
                 &auth::JWTAuth(Permission::RoutingWrite),
                 req.headers(),
             ),
@@ -217,7 +217,7 @@ pub async fn routing_unlink_config(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingWrite),
                 &auth::JWTAuth(Permission::RoutingWrite),
                 req.headers(),
             ),
@@ -246,7 +246,7 @@ pub async fn routing_update_default_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
@@ -273,7 +273,7 @@ pub async fn routing_retrieve_default_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingRead),
             &auth::JWTAuth(Permission::RoutingRead),
             req.headers(),
         ),
@@ -307,7 +307,7 @@ pub async fn upsert_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerWrite),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerWrite),
             req.headers(),
         ),
@@ -338,7 +338,7 @@ pub async fn delete_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerWrite),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerWrite),
             req.headers(),
         ),
@@ -369,7 +369,7 @@ pub async fn retrieve_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerRead),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerRead),
             req.headers(),
         ),
@@ -403,7 +403,7 @@ pub async fn upsert_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerRead),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerRead),
             req.headers(),
         ),
@@ -435,7 +435,7 @@ pub async fn delete_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerWrite),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerWrite),
             req.headers(),
         ),
@@ -463,7 +463,7 @@ pub async fn retrieve_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerRead),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerRead),
             req.headers(),
         ),
@@ -495,7 +495,7 @@ pub async fn routing_retrieve_linked_config(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingRead),
                 &auth::JWTAuth(Permission::RoutingRead),
                 req.headers(),
             ),
@@ -519,7 +519,7 @@ pub async fn routing_retrieve_linked_config(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingRead),
                 &auth::JWTAuth(Permission::RoutingRead),
                 req.headers(),
             ),
@@ -547,13 +547,13 @@ pub async fn routing_retrieve_default_config_for_profiles(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingRead),
             &auth::JWTAuth(Permission::RoutingRead),
             req.headers(),
         ),
         #[cfg(feature = "release")]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingRead),
             &auth::JWTAuth(Permission::RoutingRead),
             req.headers(),
         ),
@@ -589,7 +589,7 @@ pub async fn routing_update_default_config_for_profile(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
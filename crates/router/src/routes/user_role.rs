@@ -22,8 +22,8 @@ pub async fn get_authorization_info(
         state.clone(),
         &http_req,
         (),
-        |state, _: (), _| user_role_core::get_authorization_info(state),
-        &auth::JWTAuth(Permission::UsersRead),
+        |state, user: UserFromToken, _| user_role_core::get_authorization_info(state, user),
+        &auth::DashboardNoPermissionAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -36,8 +36,8 @@ pub async fn list_roles(state: web::Data<AppState>, req: HttpRequest) -> HttpRes
         state.clone(),
         &req,
         (),
-        |state, _: (), _| user_role_core::list_roles(state),
-        &auth::JWTAuth(Permission::UsersRead),
+        |state, user: UserFromToken, _| user_role_core::list_roles(state, user),
+        &auth::DashboardNoPermissionAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -57,13 +57,167 @@ pub async fn get_role(
         state.clone(),
         &req,
         request_payload,
-        |state, _: (), req| user_role_core::get_role(state, req),
+        user_role_core::get_role,
         &auth::JWTAuth(Permission::UsersRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
 
+pub async fn update_role_preferences(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::UpdateRolePreferencesRequest>,
+) -> HttpResponse {
+    let flow = Flow::UpdateRolePreferences;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        user_role_core::update_role_preferences,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_role_preferences(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::DeleteRolePreferencesRequest>,
+) -> HttpResponse {
+    let flow = Flow::DeleteRolePreferences;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        user_role_core::delete_role_preferences,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn update_user_preferences(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::UpdateUserPreferencesRequest>,
+) -> HttpResponse {
+    let flow = Flow::UpdateUserPreferences;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        user_role_core::update_user_preferences,
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_user_preferences(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::DeleteUserPreferences;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user: UserFromToken, _| user_role_core::delete_user_preferences(state, user),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn update_role_permission_overrides(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::UpdateRolePermissionOverridesRequest>,
+) -> HttpResponse {
+    let flow = Flow::UpdateRolePermissionOverrides;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        user_role_core::update_role_permission_overrides,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_role_permission_overrides(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::DeleteRolePermissionOverridesRequest>,
+) -> HttpResponse {
+    let flow = Flow::DeleteRolePermissionOverrides;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        user_role_core::delete_role_permission_overrides,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn clone_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::CloneRoleRequest>,
+) -> HttpResponse {
+    let flow = Flow::CloneRole;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        user_role_core::clone_role,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn export_roles(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ExportRoles;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user: UserFromToken, _| user_role_core::export_roles(state, user),
+        &auth::JWTAuth(Permission::UsersRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn import_roles(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::ImportRolesRequest>,
+) -> HttpResponse {
+    let flow = Flow::ImportRoles;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        user_role_core::import_roles,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn get_role_from_token(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
     let flow = Flow::GetRoleFromToken;
     Box::pin(api::server_wrap(
@@ -78,6 +232,24 @@ pub async fn get_role_from_token(state: web::Data<AppState>, req: HttpRequest) -
     .await
 }
 
+pub async fn get_user_access_map(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<user_role_api::GetUserAccessMapRequest>,
+) -> HttpResponse {
+    let flow = Flow::GetUserAccessMap;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        query.into_inner(),
+        |state, user: UserFromToken, req| user_role_core::get_user_access_map(state, user, req),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn update_user_role(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -97,6 +269,156 @@ pub async fn update_user_role(
     .await
 }
 
+pub async fn assign_or_update_user_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::AssignOrUpdateUserRoleRequest>,
+) -> HttpResponse {
+    let flow = Flow::AssignOrUpdateUserRole;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        user_role_core::assign_or_update_user_role,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn reset_user_role_to_default(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::ResetUserRoleRequest>,
+) -> HttpResponse {
+    let flow = Flow::ResetUserRoleToDefault;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        user_role_core::reset_user_role_to_default,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_user_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::DeleteUserRoleRequest>,
+) -> HttpResponse {
+    let flow = Flow::DeleteUserRole;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        user_role_core::delete_user_role,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn suspend_user_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::SuspendUserRoleRequest>,
+) -> HttpResponse {
+    let flow = Flow::SuspendUserRole;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        user_role_core::suspend_user_role,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn resume_user_role(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::ResumeUserRoleRequest>,
+) -> HttpResponse {
+    let flow = Flow::ResumeUserRole;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        user_role_core::resume_user_role,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn approve_user_role_change(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_role_api::ApproveUserRoleChangeRequest>,
+) -> HttpResponse {
+    let flow = Flow::ApproveUserRoleChange;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        user_role_core::approve_user_role_change,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_user_role_change_requests(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<user_role_api::ListUserRoleChangeRequestsRequest>,
+) -> HttpResponse {
+    let flow = Flow::ListUserRoleChangeRequests;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        query.into_inner(),
+        user_role_core::list_user_role_change_requests,
+        &auth::JWTAuth(Permission::UsersRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_users_with_permission(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<user_role_api::ListUsersWithPermissionRequest>,
+) -> HttpResponse {
+    let flow = Flow::ListUsersWithPermission;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        query.into_inner(),
+        user_role_core::list_users_with_permission,
+        &auth::JWTAuth(Permission::UsersRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn accept_invitation(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -33,7 +33,7 @@ pub async fn apple_pay_merchant_registration(
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantAccountWrite),
             &auth::JWTAuth(Permission::MerchantAccountWrite),
             req.headers(),
         ),
@@ -65,7 +65,7 @@ pub async fn retrieve_apple_pay_verified_domains(
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantAccountRead),
             &auth::JWTAuth(Permission::MerchantAccountRead),
             req.headers(),
         ),
@@ -509,10 +509,11 @@ pub async fn call_connector_api(
     request: Request,
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     let current_time = Instant::now();
+    let option_timeout_secs = request.timeout_in_secs;
 
     let response = state
         .api_client
-        .send_request(state, request, None, true)
+        .send_request(state, request, option_timeout_secs, true)
         .await;
 
     let elapsed_time = current_time.elapsed();
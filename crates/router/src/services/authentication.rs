@@ -91,6 +91,20 @@ impl AuthenticationType {
             Self::AdminApiKey | Self::UserJwt { .. } | Self::NoAuth => None,
         }
     }
+
+    /// Identifier of the API key or of the user who made the request, when it is known
+    pub fn get_actor_id(&self) -> Option<&str> {
+        match self {
+            Self::ApiKey { key_id, .. } => Some(key_id.as_ref()),
+            Self::MerchantJwt { user_id, .. } => user_id.as_deref(),
+            Self::UserJwt { user_id } => Some(user_id.as_ref()),
+            Self::AdminApiKey
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -121,6 +135,9 @@ pub struct AuthToken {
     pub role_id: String,
     pub exp: u64,
     pub org_id: String,
+    /// Tokens issued before this field was introduced are treated as issued at the epoch
+    #[serde(default)]
+    pub iat: i64,
 }
 
 #[cfg(feature = "olap")]
@@ -131,15 +148,21 @@ impl AuthToken {
         role_id: String,
         settings: &settings::Settings,
         org_id: String,
+        role_expires_at: Option<time::PrimitiveDateTime>,
     ) -> UserResult<String> {
         let exp_duration = std::time::Duration::from_secs(consts::JWT_TOKEN_TIME_IN_SECS);
         let exp = jwt::generate_exp(exp_duration)?.as_secs();
+        // The token must not outlive the role it was issued for
+        let exp = role_expires_at
+            .and_then(|expires_at| u64::try_from(expires_at.assume_utc().unix_timestamp()).ok())
+            .map_or(exp, |role_exp| exp.min(role_exp));
         let token_payload = Self {
             user_id,
             merchant_id,
             role_id,
             exp,
             org_id,
+            iat: date_time::now_unix_timestamp(),
         };
         jwt::generate_jwt(&token_payload, settings).await
     }
@@ -181,8 +204,10 @@ where
     ) -> RouterResult<(T, AuthenticationType)>;
 }
 
+/// Authenticates the API key of a merchant. API keys restricted to a role must be granted the
+/// permission by the role.
 #[derive(Debug)]
-pub struct ApiKeyAuth;
+pub struct ApiKeyAuth(pub Permission);
 
 pub struct NoAuth;
 
@@ -253,6 +278,27 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if let Some(role_id) = &stored_api_key.role_id {
+            let permissions = authorization::get_permissions_for_merchant(
+                &*state.store(),
+                role_id,
+                &stored_api_key.merchant_id,
+            )
+            .await
+            .map_err(|error| {
+                // A role which no longer exists grants no permission
+                match error.current_context() {
+                    errors::ApiErrorResponse::InvalidJwtToken => {
+                        error.change_context(errors::ApiErrorResponse::AccessForbidden {
+                            resource: self.0.to_string(),
+                        })
+                    }
+                    _ => error,
+                }
+            })?;
+            authorization::check_authorization(&self.0, &permissions)?;
+        }
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -496,8 +542,20 @@ where
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
 
-        let permissions = authorization::get_permissions(&payload.role_id)?;
-        authorization::check_authorization(&self.0, permissions)?;
+        let permissions = authorization::get_permissions_for_merchant(
+            &*state.store(),
+            &payload.role_id,
+            &payload.merchant_id,
+        )
+        .await?;
+        authorization::check_authorization(&self.0, &permissions)?;
+        authorization::check_user_access(
+            &*state.store(),
+            &payload.user_id,
+            &payload.merchant_id,
+            payload.iat,
+        )
+        .await?;
 
         Ok((
             (),
@@ -522,8 +580,20 @@ where
     ) -> RouterResult<(UserFromToken, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
 
-        let permissions = authorization::get_permissions(&payload.role_id)?;
-        authorization::check_authorization(&self.0, permissions)?;
+        let permissions = authorization::get_permissions_for_merchant(
+            &*state.store(),
+            &payload.role_id,
+            &payload.merchant_id,
+        )
+        .await?;
+        authorization::check_authorization(&self.0, &permissions)?;
+        authorization::check_user_access(
+            &*state.store(),
+            &payload.user_id,
+            &payload.merchant_id,
+            payload.iat,
+        )
+        .await?;
 
         Ok((
             UserFromToken {
@@ -557,8 +627,20 @@ where
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
 
-        let permissions = authorization::get_permissions(&payload.role_id)?;
-        authorization::check_authorization(&self.required_permission, permissions)?;
+        let permissions = authorization::get_permissions_for_merchant(
+            &*state.store(),
+            &payload.role_id,
+            &payload.merchant_id,
+        )
+        .await?;
+        authorization::check_authorization(&self.required_permission, &permissions)?;
+        authorization::check_user_access(
+            &*state.store(),
+            &payload.user_id,
+            &payload.merchant_id,
+            payload.iat,
+        )
+        .await?;
 
         // Check if token has access to MerchantId that has been requested through query param
         if payload.merchant_id != self.merchant_id {
@@ -587,8 +669,11 @@ where
 
 #[derive(serde::Deserialize)]
 struct JwtAuthPayloadFetchMerchantAccount {
+    user_id: String,
     merchant_id: String,
     role_id: String,
+    #[serde(default)]
+    iat: i64,
 }
 
 #[async_trait]
@@ -605,8 +690,20 @@ where
             parse_jwt_payload::<A, JwtAuthPayloadFetchMerchantAccount>(request_headers, state)
                 .await?;
 
-        let permissions = authorization::get_permissions(&payload.role_id)?;
-        authorization::check_authorization(&self.0, permissions)?;
+        let permissions = authorization::get_permissions_for_merchant(
+            &*state.store(),
+            &payload.role_id,
+            &payload.merchant_id,
+        )
+        .await?;
+        authorization::check_authorization(&self.0, &permissions)?;
+        authorization::check_user_access(
+            &*state.store(),
+            &payload.user_id,
+            &payload.merchant_id,
+            payload.iat,
+        )
+        .await?;
 
         let key_store = state
             .store()
@@ -632,12 +729,36 @@ where
             auth.clone(),
             AuthenticationType::MerchantJwt {
                 merchant_id: auth.merchant_account.merchant_id.clone(),
-                user_id: None,
+                user_id: Some(payload.user_id),
             },
         ))
     }
 }
 
+/// Authenticates the request with the wrapped authentication, and also hands over to the handler
+/// how the request was authenticated, for the flows which record who acted
+pub struct WithAuthenticationType<T>(pub T);
+
+#[async_trait]
+impl<A, T, U> AuthenticateAndFetch<(U, AuthenticationType), A> for WithAuthenticationType<T>
+where
+    A: AppStateInfo + Sync,
+    T: AuthenticateAndFetch<U, A> + Sync,
+    U: Send,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<((U, AuthenticationType), AuthenticationType)> {
+        let (auth, auth_type) = self
+            .0
+            .authenticate_and_fetch(request_headers, state)
+            .await?;
+        Ok(((auth, auth_type.clone()), auth_type))
+    }
+}
+
 pub struct DashboardNoPermissionAuth;
 
 #[cfg(feature = "olap")]
@@ -652,6 +773,8 @@ where
         state: &A,
     ) -> RouterResult<(UserFromToken, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_token_not_invalidated(&*state.store(), &payload.user_id, payload.iat)
+            .await?;
 
         Ok((
             UserFromToken {
@@ -679,7 +802,9 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<((), AuthenticationType)> {
-        parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_token_not_invalidated(&*state.store(), &payload.user_id, payload.iat)
+            .await?;
 
         Ok(((), AuthenticationType::NoAuth))
     }
@@ -707,6 +832,12 @@ impl ClientSecretFetch for api_models::cards_info::CardsInfoRequest {
     }
 }
 
+impl ClientSecretFetch for api_models::payments::PaymentsConfirmPreflightRequest {
+    fn get_client_secret(&self) -> Option<&String> {
+        self.client_secret.as_ref()
+    }
+}
+
 impl ClientSecretFetch for api_models::payments::PaymentsRetrieveRequest {
     fn get_client_secret(&self) -> Option<&String> {
         self.client_secret.as_ref()
@@ -733,6 +864,7 @@ impl ClientSecretFetch for api_models::pm_auth::ExchangeTokenCreateRequest {
 
 pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     headers: &HeaderMap,
+    permission: Permission,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<AuthenticationData, A>>,
     api::AuthFlow,
@@ -742,12 +874,13 @@ pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     if api_key.starts_with("pk_") {
         return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((Box::new(ApiKeyAuth(permission)), api::AuthFlow::Merchant))
 }
 
 pub fn check_client_secret_and_get_auth<T>(
     headers: &HeaderMap,
     payload: &impl ClientSecretFetch,
+    permission: Permission,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<AuthenticationData, T>>,
     api::AuthFlow,
@@ -775,18 +908,19 @@ where
         }
         .into());
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((Box::new(ApiKeyAuth(permission)), api::AuthFlow::Merchant))
 }
 
 pub async fn is_ephemeral_auth<A: AppStateInfo + Sync>(
     headers: &HeaderMap,
     db: &dyn StorageInterface,
     customer_id: &str,
+    permission: Permission,
 ) -> RouterResult<Box<dyn AuthenticateAndFetch<AuthenticationData, A>>> {
     let api_key = get_api_key(headers)?;
 
     if !api_key.starts_with("epk") {
-        return Ok(Box::new(ApiKeyAuth));
+        return Ok(Box::new(ApiKeyAuth(permission)));
     }
 
     let ephemeral_key = db
@@ -1,16 +1,467 @@
-use crate::core::errors::{ApiErrorResponse, RouterResult};
+use std::{collections::HashMap, sync::Arc};
+
+use diesel_models::enums::UserStatus;
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::errors::{ApiErrorResponse, RouterResult},
+    db::StorageInterface,
+    utils::StringExt,
+};
 
 pub mod info;
 pub mod permissions;
 pub mod predefined_permissions;
 
-pub fn get_permissions(role: &str) -> RouterResult<&Vec<permissions::Permission>> {
-    predefined_permissions::PREDEFINED_PERMISSIONS
+/// Permissions granted to and revoked from a predefined role for a single merchant
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RolePermissionOverride {
+    #[serde(default)]
+    pub granted: Vec<permissions::Permission>,
+    #[serde(default)]
+    pub revoked: Vec<permissions::Permission>,
+}
+
+/// Permission overrides of a merchant, keyed by the role id
+pub type PermissionOverrides = HashMap<String, RolePermissionOverride>;
+
+/// Role created by a merchant, whose permissions are not tied to a predefined role
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CustomRole {
+    pub role_name: String,
+    pub permissions: Vec<permissions::Permission>,
+}
+
+/// Custom roles of a merchant, keyed by the role id
+pub type CustomRoles = HashMap<String, CustomRole>;
+
+/// Role ids referenced by the application, each of them must be defined as a predefined role
+const REFERENCED_ROLE_IDS: [&str; 9] = [
+    consts::user_role::ROLE_ID_INTERNAL_ADMIN,
+    consts::user_role::ROLE_ID_INTERNAL_VIEW_ONLY_USER,
+    consts::user_role::ROLE_ID_ORGANIZATION_ADMIN,
+    consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+    consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY,
+    consts::user_role::ROLE_ID_MERCHANT_IAM_ADMIN,
+    consts::user_role::ROLE_ID_MERCHANT_DEVELOPER,
+    consts::user_role::ROLE_ID_MERCHANT_OPERATOR,
+    consts::user_role::ROLE_ID_MERCHANT_CUSTOMER_SUPPORT,
+];
+
+/// Permissions which are not described in any permission module, as API keys and users are
+/// managed on pages of their own
+const PERMISSIONS_WITHOUT_MODULE: [permissions::Permission; 4] = [
+    permissions::Permission::ApiKeyRead,
+    permissions::Permission::ApiKeyWrite,
+    permissions::Permission::UsersRead,
+    permissions::Permission::UsersWrite,
+];
+
+/// Finds the inconsistencies between the predefined roles in use and the permission modules of
+/// [`info`], which would otherwise make roles or permissions silently missing from the responses.
+/// An empty list means that the permission map is consistent.
+pub fn get_permission_map_drift() -> Vec<String> {
+    let predefined_roles = predefined_permissions::get_predefined_permissions();
+    let mut drift = Vec::new();
+
+    for role_id in REFERENCED_ROLE_IDS {
+        if !predefined_roles.contains_key(role_id) {
+            drift.push(format!("Role {role_id} is referenced but not defined"));
+        }
+    }
+
+    let mut described_permissions = Vec::new();
+    for module_info in info::get_authorization_info() {
+        for permission_info in module_info.permissions {
+            if described_permissions.contains(&permission_info.enum_name) {
+                drift.push(format!(
+                    "Permission {} is described in more than one module",
+                    permission_info.enum_name
+                ));
+            } else {
+                described_permissions.push(permission_info.enum_name);
+            }
+        }
+    }
+
+    for (role_id, role_info) in predefined_roles.iter() {
+        if role_info.get_permissions().is_empty() {
+            drift.push(format!("Role {role_id} has no permissions"));
+        }
+        if role_info.is_invitable() && role_info.get_name().is_none() {
+            drift.push(format!("Role {role_id} is invitable but has no name"));
+        }
+        for permission in role_info.get_permissions() {
+            if !described_permissions.contains(permission)
+                && !PERMISSIONS_WITHOUT_MODULE.contains(permission)
+            {
+                drift.push(format!(
+                    "Permission {permission} of role {role_id} is not described in any module"
+                ));
+            }
+        }
+    }
+
+    for permission in described_permissions {
+        let is_granted = predefined_roles
+            .values()
+            .any(|role_info| role_info.get_permissions().contains(&permission));
+        if !is_granted {
+            drift.push(format!(
+                "Permission {permission} is described but not granted by any role"
+            ));
+        }
+    }
+
+    // The roles are iterated in an arbitrary order, sorting keeps the reports comparable
+    drift.sort();
+    drift
+}
+
+pub fn get_permissions(role: &str) -> RouterResult<Vec<permissions::Permission>> {
+    predefined_permissions::get_predefined_permissions()
         .get(role)
-        .map(|role_info| role_info.get_permissions())
+        .map(|role_info| role_info.get_permissions().clone())
         .ok_or(ApiErrorResponse::InvalidJwtToken.into())
 }
 
+pub fn get_permission_overrides_key(merchant_id: &str) -> String {
+    format!(
+        "{}_{merchant_id}",
+        consts::user_role::PERMISSION_OVERRIDES_CONFIG_PREFIX
+    )
+}
+
+/// Returns the permission overrides of the merchant. The lookup is made on every request, it is
+/// served from the config cache, which also remembers the merchants without overrides, so that no
+/// config is created for them on the authorization path.
+pub async fn get_permission_overrides(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<PermissionOverrides> {
+    db.find_config_by_key_optional(&get_permission_overrides_key(merchant_id))
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the permission overrides of the merchant")?
+        .map(|config| {
+            config
+                .config
+                .parse_struct("PermissionOverrides")
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the permission overrides of the merchant")
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+pub fn get_custom_roles_key(merchant_id: &str) -> String {
+    format!(
+        "{}_{merchant_id}",
+        consts::user_role::CUSTOM_ROLES_CONFIG_PREFIX
+    )
+}
+
+/// Returns the custom roles of the merchant, the merchants without custom roles are cached in the
+/// same way as for the permission overrides.
+pub async fn get_custom_roles(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<CustomRoles> {
+    db.find_config_by_key_optional(&get_custom_roles_key(merchant_id))
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the custom roles of the merchant")?
+        .map(|config| {
+            config
+                .config
+                .parse_struct("CustomRoles")
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the custom roles of the merchant")
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Reloads the predefined permissions from the configs, so that the permission definitions can be
+/// changed without a restart. The compiled permissions are used for the roles which are not
+/// redefined in the configs. The definitions are validated before being swapped in, and the
+/// permissions in use are left untouched when they are invalid.
+pub async fn refresh_predefined_permissions(db: &dyn StorageInterface) -> RouterResult<()> {
+    let role_permissions = match db
+        .find_config_by_key_from_db(consts::user_role::PREDEFINED_ROLE_PERMISSIONS_CONFIG_KEY)
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct::<HashMap<String, Vec<permissions::Permission>>>(
+                "PredefinedRolePermissions",
+            )
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the predefined role permissions")?,
+        Err(error) if error.current_context().is_db_not_found() => HashMap::new(),
+        Err(error) => {
+            return Err(error.change_context(ApiErrorResponse::InternalServerError))
+                .attach_printable("Failed to fetch the predefined role permissions")
+        }
+    };
+
+    let role_infos = predefined_permissions::build_predefined_permissions(role_permissions)?;
+    predefined_permissions::PREDEFINED_PERMISSIONS.store(Arc::new(role_infos));
+
+    Ok(())
+}
+
+pub fn get_token_epoch_key(user_id: &str) -> String {
+    format!("{}_{user_id}", consts::user_role::TOKEN_EPOCH_PREFIX)
+}
+
+pub fn get_user_role_access_key(user_id: &str, merchant_id: &str) -> String {
+    format!(
+        "{}_{user_id}_{merchant_id}",
+        consts::user_role::USER_ROLE_ACCESS_CACHE_PREFIX
+    )
+}
+
+/// Access of a user to a merchant as given by their user role, cached along with the token epoch
+/// of the user it was read under. The sessions of the user are invalidated on every change to
+/// their role which revokes access, which moves the token epoch, so that a cached access read
+/// before the change is no longer used, even if it is written to the cache after the change.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UserRoleAccess {
+    pub token_epoch: Option<i64>,
+    pub is_suspended: bool,
+    /// Unix timestamp at which the user role expires
+    pub expires_at: Option<i64>,
+}
+
+impl UserRoleAccess {
+    fn check_access(&self, merchant_id: &str) -> RouterResult<()> {
+        let is_expired = self.expires_at.map_or(false, |expires_at| {
+            expires_at <= common_utils::date_time::now_unix_timestamp()
+        });
+        if self.is_suspended || is_expired {
+            return Err(ApiErrorResponse::AccessForbidden {
+                resource: merchant_id.to_string(),
+            }
+            .into())
+            .attach_printable("The access of the user to the merchant is suspended or expired");
+        }
+        Ok(())
+    }
+}
+
+/// Checks the token epoch and the access of the user to the merchant in a single round trip to
+/// redis, falling back to the user role in the database when the access is not cached.
+///
+/// Tokens which cannot be checked because redis is unavailable are rejected rather than let
+/// through. A token is valid for days, and a user whose access was revoked would otherwise keep it
+/// for as long as redis is unavailable.
+pub async fn check_user_access(
+    db: &dyn StorageInterface,
+    user_id: &str,
+    merchant_id: &str,
+    issued_at: i64,
+) -> RouterResult<()> {
+    let redis_conn = db
+        .get_redis_conn()
+        .map_err(|error| {
+            logger::error!(?error, "Rejecting the token as redis is unavailable");
+            error
+        })
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let access_key = get_user_role_access_key(user_id, merchant_id);
+    let mut values = redis_conn
+        .get_multiple_keys::<_, String>(vec![get_token_epoch_key(user_id), access_key.clone()])
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Rejecting the token as redis is unavailable");
+            error
+        })
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the token epoch and the access of the user")?
+        .into_iter();
+
+    let token_epoch = values
+        .next()
+        .flatten()
+        .map(|token_epoch| token_epoch.parse::<i64>())
+        .transpose()
+        .into_report()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the token epoch of the user")?;
+    check_token_epoch(token_epoch, issued_at)?;
+
+    let cached_access = values.next().flatten().and_then(|cached_access| {
+        cached_access
+            .parse_struct::<UserRoleAccess>("UserRoleAccess")
+            .map_err(|error| logger::warn!(?error, "Ignoring the cached access of the user"))
+            .ok()
+    });
+    let access = match cached_access.filter(|access| access.token_epoch == token_epoch) {
+        Some(access) => access,
+        None => {
+            let access = get_user_role_access(db, user_id, merchant_id, token_epoch).await?;
+            // The access is read from the database again on the next request if it is not cached
+            let _ = redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &access_key,
+                    &access,
+                    consts::user_role::USER_ROLE_ACCESS_CACHE_TTL_IN_SECS,
+                )
+                .await
+                .map_err(|error| logger::warn!(?error, "Failed to cache the access of the user"));
+            access
+        }
+    };
+
+    access.check_access(merchant_id)
+}
+
+/// Rejects the tokens issued to the user before their sessions were invalidated, so that changes
+/// to the role of the user take effect before the tokens expire. Tokens are rejected when redis is
+/// unavailable, as in `check_user_access`.
+pub async fn check_token_not_invalidated(
+    db: &dyn StorageInterface,
+    user_id: &str,
+    issued_at: i64,
+) -> RouterResult<()> {
+    let token_epoch = db
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_key::<Option<i64>>(&get_token_epoch_key(user_id))
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Rejecting the token as redis is unavailable");
+            error
+        })
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the token epoch of the user")?;
+
+    check_token_epoch(token_epoch, issued_at)
+}
+
+fn check_token_epoch(token_epoch: Option<i64>, issued_at: i64) -> RouterResult<()> {
+    match token_epoch {
+        Some(token_epoch) if issued_at < token_epoch => {
+            Err(ApiErrorResponse::InvalidJwtToken.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reads the access of the user to the merchant from their user role. A role which expired is
+/// read as well, so that it stops granting access before the expiry workflow removes it.
+async fn get_user_role_access(
+    db: &dyn StorageInterface,
+    user_id: &str,
+    merchant_id: &str,
+    token_epoch: Option<i64>,
+) -> RouterResult<UserRoleAccess> {
+    db.find_user_role_by_user_id_merchant_id_including_expired(user_id, merchant_id)
+        .await
+        .map(|user_role| UserRoleAccess {
+            token_epoch,
+            is_suspended: user_role.status == UserStatus::Suspended,
+            expires_at: user_role
+                .expires_at
+                .map(|expires_at| expires_at.assume_utc().unix_timestamp()),
+        })
+        .or_else(|error| {
+            if error.current_context().is_db_not_found() {
+                Ok(UserRoleAccess {
+                    token_epoch,
+                    is_suspended: false,
+                    expires_at: None,
+                })
+            } else {
+                Err(error)
+            }
+        })
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the user role of the user")
+}
+
+/// Rejects the users whose access to the merchant is suspended or whose role expired, reading the
+/// user role from the database without going through the cache
+pub async fn check_user_not_suspended(
+    db: &dyn StorageInterface,
+    user_id: &str,
+    merchant_id: &str,
+) -> RouterResult<()> {
+    get_user_role_access(db, user_id, merchant_id, None)
+        .await?
+        .check_access(merchant_id)
+}
+
+/// Applies the override of a role on top of its predefined permissions
+pub fn apply_permission_override(
+    predefined_permissions: &[permissions::Permission],
+    role_override: Option<&RolePermissionOverride>,
+) -> Vec<permissions::Permission> {
+    let role_override = match role_override {
+        Some(role_override) => role_override,
+        None => return predefined_permissions.to_vec(),
+    };
+
+    predefined_permissions
+        .iter()
+        .chain(role_override.granted.iter())
+        .fold(Vec::new(), |mut permissions, permission| {
+            if !role_override.revoked.contains(permission) && !permissions.contains(permission) {
+                permissions.push(*permission);
+            }
+            permissions
+        })
+}
+
+/// Resolves the effective permissions of the role from the permission overrides and the custom
+/// roles of the merchant, for callers resolving the permissions of several roles at once
+pub fn resolve_permissions_for_merchant(
+    role: &str,
+    permission_overrides: &PermissionOverrides,
+    custom_roles: &CustomRoles,
+) -> Option<Vec<permissions::Permission>> {
+    match predefined_permissions::get_predefined_permissions().get(role) {
+        Some(role_info) => Some(apply_permission_override(
+            role_info.get_permissions(),
+            permission_overrides.get(role),
+        )),
+        None => custom_roles
+            .get(role)
+            .map(|custom_role| custom_role.permissions.clone()),
+    }
+}
+
+/// Returns the effective permissions of the role in the merchant, with the permission overrides
+/// of the merchant applied on top of the predefined permissions of the role. Roles which are not
+/// predefined are resolved from the custom roles of the merchant.
+pub async fn get_permissions_for_merchant(
+    db: &dyn StorageInterface,
+    role: &str,
+    merchant_id: &str,
+) -> RouterResult<Vec<permissions::Permission>> {
+    match predefined_permissions::get_predefined_permissions().get(role) {
+        Some(role_info) => {
+            let permission_overrides = get_permission_overrides(db, merchant_id).await?;
+
+            Ok(apply_permission_override(
+                role_info.get_permissions(),
+                permission_overrides.get(role),
+            ))
+        }
+        None => get_custom_roles(db, merchant_id)
+            .await?
+            .remove(role)
+            .map(|custom_role| custom_role.permissions)
+            .ok_or(ApiErrorResponse::InvalidJwtToken.into()),
+    }
+}
+
 pub fn check_authorization(
     required_permission: &permissions::Permission,
     permissions: &[permissions::Permission],
@@ -25,3 +476,148 @@ pub fn check_authorization(
             .into(),
         )
 }
+
+#[cfg(test)]
+mod tests {
+    use common_utils::date_time;
+    use diesel_models::user_role::UserRoleNew;
+
+    use super::*;
+    use crate::db::{user_role::UserRoleInterface, MockDb};
+
+    fn new_user_role(user_id: &str, status: UserStatus) -> UserRoleNew {
+        UserRoleNew {
+            user_id: user_id.to_string(),
+            merchant_id: "merchant1".to_string(),
+            role_id: consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY.to_string(),
+            org_id: "org1".to_string(),
+            status,
+            created_by: "admin".to_string(),
+            last_modified_by: "admin".to_string(),
+            created_at: date_time::now(),
+            last_modified: date_time::now(),
+            expires_at: None,
+            title: None,
+            department: None,
+        }
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_check_user_not_suspended_reads_the_user_role_status() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        mockdb
+            .insert_user_role(new_user_role("active_user", UserStatus::Active))
+            .await
+            .unwrap();
+        mockdb
+            .insert_user_role(new_user_role("suspended_user", UserStatus::Suspended))
+            .await
+            .unwrap();
+
+        assert!(
+            check_user_not_suspended(&mockdb, "active_user", "merchant1")
+                .await
+                .is_ok()
+        );
+
+        // The suspension is read from the user role alone, no other record of it is needed
+        let error = check_user_not_suspended(&mockdb, "suspended_user", "merchant1")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ApiErrorResponse::AccessForbidden { .. }
+        ));
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_expired_user_role_does_not_grant_access() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let mut expired_user_role = new_user_role("expired_user", UserStatus::Active);
+        expired_user_role.expires_at = Some(date_time::now() - time::Duration::minutes(1));
+        mockdb.insert_user_role(expired_user_role).await.unwrap();
+
+        // The expiry workflow has not removed the role yet
+        assert!(mockdb
+            .find_user_role_by_user_id_merchant_id("expired_user", "merchant1")
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+        assert!(mockdb
+            .find_user_role_by_user_id_merchant_id_including_expired("expired_user", "merchant1")
+            .await
+            .is_ok());
+
+        let error = check_user_not_suspended(&mockdb, "expired_user", "merchant1")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ApiErrorResponse::AccessForbidden { .. }
+        ));
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_cached_access_is_not_used_after_sessions_are_invalidated() {
+        use storage_impl::redis::kv_store::RedisConnInterface;
+
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        // The cache outlives the mock store, so the user is unique to the test run
+        let user_id = common_utils::generate_id(consts::ID_LENGTH, "user");
+
+        mockdb
+            .insert_user_role(new_user_role(&user_id, UserStatus::Active))
+            .await
+            .unwrap();
+
+        let issued_at = date_time::now_unix_timestamp();
+        check_user_access(&mockdb, &user_id, "merchant1", issued_at)
+            .await
+            .unwrap();
+
+        // The cached access is used until the sessions of the user are invalidated
+        mockdb
+            .update_user_role_by_user_id_merchant_id(
+                &user_id,
+                "merchant1",
+                diesel_models::user_role::UserRoleUpdate::UpdateStatus {
+                    status: UserStatus::Suspended,
+                    modified_by: "admin".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        check_user_access(&mockdb, &user_id, "merchant1", issued_at)
+            .await
+            .unwrap();
+
+        mockdb
+            .get_redis_conn()
+            .unwrap()
+            .set_key_with_expiry(&get_token_epoch_key(&user_id), issued_at, 60)
+            .await
+            .unwrap();
+        let error = check_user_access(&mockdb, &user_id, "merchant1", issued_at)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ApiErrorResponse::AccessForbidden { .. }
+        ));
+    }
+}
@@ -10,6 +10,7 @@ pub fn get_authorization_info() -> Vec<ModuleInfo> {
 
 pub struct PermissionInfo {
     pub enum_name: Permission,
+    pub label: &'static str,
     pub description: &'static str,
 }
 
@@ -18,6 +19,7 @@ impl PermissionInfo {
         permissions
             .iter()
             .map(|&per| Self {
+                label: Permission::get_permission_label(&per),
                 description: Permission::get_permission_description(&per),
                 enum_name: per,
             })
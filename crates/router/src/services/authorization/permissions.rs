@@ -1,6 +1,6 @@
 use strum::Display;
 
-#[derive(PartialEq, Display, Clone, Debug, Copy)]
+#[derive(PartialEq, Display, Clone, Debug, Copy, serde::Serialize, serde::Deserialize)]
 pub enum Permission {
     PaymentRead,
     PaymentWrite,
@@ -73,4 +73,38 @@ impl Permission {
             Self::MerchantAccountCreate => "Create merchant account",
         }
     }
+
+    pub fn get_permission_label(&self) -> &'static str {
+        match self {
+            Self::PaymentRead => "View Payments",
+            Self::PaymentWrite => "Manage Payments",
+            Self::RefundRead => "View Refunds",
+            Self::RefundWrite => "Manage Refunds",
+            Self::ApiKeyRead => "View API Keys",
+            Self::ApiKeyWrite => "Manage API Keys",
+            Self::MerchantAccountRead => "View Merchant Account",
+            Self::MerchantAccountWrite => "Manage Merchant Account",
+            Self::MerchantConnectorAccountRead => "View Connectors",
+            Self::MerchantConnectorAccountWrite => "Manage Connectors",
+            Self::ForexRead => "View Forex Rates",
+            Self::RoutingRead => "View Routing",
+            Self::RoutingWrite => "Manage Routing",
+            Self::DisputeRead => "View Disputes",
+            Self::DisputeWrite => "Manage Disputes",
+            Self::MandateRead => "View Mandates",
+            Self::MandateWrite => "Manage Mandates",
+            Self::CustomerRead => "View Customers",
+            Self::CustomerWrite => "Manage Customers",
+            Self::FileRead => "View Files",
+            Self::FileWrite => "Manage Files",
+            Self::Analytics => "View Analytics",
+            Self::ThreeDsDecisionManagerWrite => "Manage 3DS Decision Rules",
+            Self::ThreeDsDecisionManagerRead => "View 3DS Decision Rules",
+            Self::SurchargeDecisionManagerWrite => "Manage Surcharge Decision Rules",
+            Self::SurchargeDecisionManagerRead => "View Surcharge Decision Rules",
+            Self::UsersRead => "View Users",
+            Self::UsersWrite => "Manage Users",
+            Self::MerchantAccountCreate => "Create Merchant Account",
+        }
+    }
 }
@@ -1,9 +1,14 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
+use arc_swap::ArcSwap;
+use error_stack::report;
 use once_cell::sync::Lazy;
 
 use super::permissions::Permission;
-use crate::consts;
+use crate::{
+    consts,
+    core::errors::{ApiErrorResponse, RouterResult},
+};
 
 pub struct RoleInfo {
     permissions: Vec<Permission>,
@@ -25,7 +30,57 @@ impl RoleInfo {
     }
 }
 
-pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy::new(|| {
+pub type PredefinedPermissions = HashMap<&'static str, RoleInfo>;
+
+/// Predefined roles in use. They start out as the compiled predefined roles, and are swapped
+/// with the definitions loaded from the configs on a refresh, without blocking the readers.
+pub static PREDEFINED_PERMISSIONS: Lazy<ArcSwap<PredefinedPermissions>> =
+    Lazy::new(|| ArcSwap::from_pointee(get_default_predefined_permissions()));
+
+pub fn get_predefined_permissions() -> Arc<PredefinedPermissions> {
+    PREDEFINED_PERMISSIONS.load_full()
+}
+
+/// Builds the predefined roles with the permissions loaded from the configs in place of the
+/// compiled permissions. Only the permissions of the compiled roles can be redefined, the roles
+/// which are not redefined keep their compiled permissions. The admin roles must keep the
+/// permissions to manage users, so that a faulty definition cannot lock the merchants out.
+pub fn build_predefined_permissions(
+    role_permissions: HashMap<String, Vec<Permission>>,
+) -> RouterResult<PredefinedPermissions> {
+    let mut roles = get_default_predefined_permissions();
+
+    for (role_id, permissions) in role_permissions {
+        let role_info = roles.get_mut(role_id.as_str()).ok_or_else(|| {
+            report!(ApiErrorResponse::InternalServerError)
+                .attach_printable(format!("Role {role_id} is not a predefined role"))
+        })?;
+
+        if permissions.is_empty() {
+            return Err(report!(ApiErrorResponse::InternalServerError)
+                .attach_printable(format!("No permissions are defined for role {role_id}")));
+        }
+
+        let is_admin_role = role_id == consts::user_role::ROLE_ID_MERCHANT_ADMIN
+            || role_id == consts::user_role::ROLE_ID_ORGANIZATION_ADMIN
+            || role_id == consts::user_role::ROLE_ID_INTERNAL_ADMIN;
+        let can_manage_users = permissions.contains(&Permission::UsersRead)
+            && permissions.contains(&Permission::UsersWrite);
+        if is_admin_role && !can_manage_users {
+            return Err(
+                report!(ApiErrorResponse::InternalServerError).attach_printable(format!(
+                    "Admin role {role_id} must keep the permissions to manage users"
+                )),
+            );
+        }
+
+        role_info.permissions = permissions;
+    }
+
+    Ok(roles)
+}
+
+fn get_default_predefined_permissions() -> PredefinedPermissions {
     let mut roles = HashMap::new();
     roles.insert(
         consts::user_role::ROLE_ID_INTERNAL_ADMIN,
@@ -294,16 +349,74 @@ pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy:
         },
     );
     roles
-});
+}
 
 pub fn get_role_name_from_id(role_id: &str) -> Option<&'static str> {
-    PREDEFINED_PERMISSIONS
+    get_predefined_permissions()
         .get(role_id)
         .and_then(|role_info| role_info.name)
 }
 
 pub fn is_role_invitable(role_id: &str) -> bool {
-    PREDEFINED_PERMISSIONS
+    get_predefined_permissions()
         .get(role_id)
         .map_or(false, |role_info| role_info.is_invitable)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unwrap_used)]
+    #[test]
+    fn test_build_predefined_permissions_redefines_only_the_given_roles() {
+        let roles = build_predefined_permissions(HashMap::from([(
+            consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY.to_string(),
+            vec![Permission::PaymentRead],
+        )]))
+        .unwrap();
+        let default_roles = get_default_predefined_permissions();
+
+        assert_eq!(
+            roles
+                .get(consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY)
+                .unwrap()
+                .get_permissions(),
+            &vec![Permission::PaymentRead]
+        );
+        assert_eq!(
+            roles
+                .get(consts::user_role::ROLE_ID_MERCHANT_ADMIN)
+                .unwrap()
+                .get_permissions(),
+            default_roles
+                .get(consts::user_role::ROLE_ID_MERCHANT_ADMIN)
+                .unwrap()
+                .get_permissions()
+        );
+    }
+
+    #[test]
+    fn test_build_predefined_permissions_rejects_invalid_definitions() {
+        // Unknown roles cannot be defined
+        assert!(build_predefined_permissions(HashMap::from([(
+            "unknown_role".to_string(),
+            vec![Permission::PaymentRead],
+        )]))
+        .is_err());
+
+        // A role cannot be left without permissions
+        assert!(build_predefined_permissions(HashMap::from([(
+            consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY.to_string(),
+            vec![],
+        )]))
+        .is_err());
+
+        // Admin roles must keep the permissions to manage users
+        assert!(build_predefined_permissions(HashMap::from([(
+            consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
+            vec![Permission::PaymentRead, Permission::UsersRead],
+        )]))
+        .is_err());
+    }
+}
@@ -39,6 +39,7 @@ pub struct KafkaPaymentAttempt<'a> {
     // TODO: These types should implement copy ideally
     pub payment_experience: Option<&'a storage_enums::PaymentExperience>,
     pub payment_method_type: Option<&'a storage_enums::PaymentMethodType>,
+    pub authentication_escalation_reason: Option<&'a String>,
 }
 
 impl<'a> KafkaPaymentAttempt<'a> {
@@ -74,6 +75,7 @@ impl<'a> KafkaPaymentAttempt<'a> {
             connector_metadata: attempt.connector_metadata.as_ref().map(|v| v.to_string()),
             payment_experience: attempt.payment_experience.as_ref(),
             payment_method_type: attempt.payment_method_type.as_ref(),
+            authentication_escalation_reason: attempt.authentication_escalation_reason.as_ref(),
         }
     }
 }
@@ -24,7 +24,7 @@ pub use api_models::{
 use common_enums::MandateStatus;
 pub use common_utils::request::{RequestBody, RequestContent};
 use common_utils::{pii, pii::Email};
-use data_models::mandates::MandateData;
+use data_models::mandates::{CustomerAcceptance, MandateData};
 use error_stack::{IntoReport, ResultExt};
 use masking::Secret;
 use serde::Serialize;
@@ -405,7 +405,15 @@ pub struct PaymentsAuthorizeData {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub mandate_id: Option<api_models::payments::MandateIds>,
     pub off_session: Option<bool>,
+    /// Network transaction id of the customer initiated transaction, to be forwarded for merchant initiated transactions
+    pub network_transaction_id: Option<String>,
     pub setup_mandate_details: Option<MandateData>,
+    /// Installment plan selected by the customer, validated against the plans offered by the connector
+    pub installment_plan: Option<api_models::payments::InstallmentPlan>,
+    /// Idempotency key of the authorization, derived from the attempt, for connectors which deduplicate requests
+    pub connector_idempotency_key: Option<String>,
+    /// Acceptance of the customer for setting up a mandate, for the payments which set up a mandate without mandate data
+    pub customer_acceptance: Option<CustomerAcceptance>,
     pub browser_info: Option<BrowserInformation>,
     pub order_details: Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
     pub order_category: Option<String>,
@@ -418,6 +426,8 @@ pub struct PaymentsAuthorizeData {
     pub customer_id: Option<String>,
     pub request_incremental_authorization: bool,
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// Version of 3DS through which the customer has to be authenticated
+    pub preferred_3ds_version: Option<api_models::enums::ThreeDsVersion>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1397,7 +1407,11 @@ impl From<&SetupMandateRouterData> for PaymentsAuthorizeData {
             mandate_id: data.request.mandate_id.clone(),
             setup_future_usage: data.request.setup_future_usage,
             off_session: data.request.off_session,
+            network_transaction_id: None,
             setup_mandate_details: data.request.setup_mandate_details.clone(),
+            installment_plan: None,
+            connector_idempotency_key: None,
+            customer_acceptance: None,
             router_return_url: data.request.router_return_url.clone(),
             email: data.request.email.clone(),
             customer_name: data.request.customer_name.clone(),
@@ -1418,6 +1432,7 @@ impl From<&SetupMandateRouterData> for PaymentsAuthorizeData {
             surcharge_details: None,
             request_incremental_authorization: data.request.request_incremental_authorization,
             metadata: None,
+            preferred_3ds_version: None,
         }
     }
 }
@@ -5,7 +5,8 @@ pub use api_models::payments::{
     PayLaterData, PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints,
     PaymentListFilters, PaymentListResponse, PaymentListResponseV2, PaymentMethodData,
     PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials,
-    PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest,
+    PaymentsApproveRequest, PaymentsBatchConfirmRequest, PaymentsBatchConfirmResponse,
+    PaymentsBatchConfirmResult, PaymentsCancelRequest, PaymentsCaptureRequest,
     PaymentsIncrementalAuthorizationRequest, PaymentsRedirectRequest, PaymentsRedirectionResponse,
     PaymentsRejectRequest, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
     PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
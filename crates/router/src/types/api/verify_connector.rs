@@ -50,6 +50,11 @@ impl VerifyConnectorData {
             related_transaction_id: None,
             statement_descriptor_suffix: None,
             request_incremental_authorization: false,
+            network_transaction_id: None,
+            installment_plan: None,
+            connector_idempotency_key: None,
+            customer_acceptance: None,
+            preferred_3ds_version: None,
         }
     }
 
@@ -280,4 +280,17 @@ impl MerchantAccount {
             });
         metadata.and_then(|a| a.compatible_connector)
     }
+
+    pub fn get_return_url_allowlist(&self) -> api_models::admin::ReturnUrlAllowlist {
+        let metadata: Option<api_models::admin::MerchantAccountMetadata> =
+            self.metadata.as_ref().and_then(|meta| {
+                meta.clone()
+                    .parse_value("MerchantAccountMetadata")
+                    .map_err(|err| logger::error!("Failed to deserialize {:?}", err))
+                    .ok()
+            });
+        metadata
+            .and_then(|a| a.return_url_allowlist)
+            .unwrap_or_default()
+    }
 }
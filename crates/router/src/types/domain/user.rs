@@ -563,6 +563,9 @@ impl NewUser {
                     .get_new_merchant()
                     .get_new_organization()
                     .get_organization_id(),
+                expires_at: None,
+                title: None,
+                department: None,
             })
             .await
             .change_context(UserErrors::InternalServerError)
@@ -797,12 +800,15 @@ impl From<info::PermissionInfo> for user_role_api::PermissionInfo {
     fn from(value: info::PermissionInfo) -> Self {
         Self {
             enum_name: value.enum_name.into(),
+            label: value.label,
             description: value.description,
         }
     }
 }
 
-pub struct UserAndRoleJoined(pub storage_user::User, pub UserRole);
+/// User and their role in a merchant, along with the name of the role when it is a custom role of
+/// the merchant
+pub struct UserAndRoleJoined(pub storage_user::User, pub UserRole, pub Option<String>);
 
 impl TryFrom<UserAndRoleJoined> for user_api::UserDetails {
     type Error = ();
@@ -810,12 +816,14 @@ impl TryFrom<UserAndRoleJoined> for user_api::UserDetails {
         let status = match user_and_role.1.status {
             UserStatus::Active => user_role_api::UserStatus::Active,
             UserStatus::InvitationSent => user_role_api::UserStatus::InvitationSent,
+            UserStatus::Suspended => user_role_api::UserStatus::Suspended,
         };
 
         let role_id = user_and_role.1.role_id;
         let role_name = predefined_permissions::get_role_name_from_id(role_id.as_str())
-            .ok_or(())?
-            .to_string();
+            .map(ToString::to_string)
+            .or(user_and_role.2)
+            .ok_or(())?;
 
         Ok(Self {
             user_id: user_and_role.0.user_id,
@@ -824,6 +832,8 @@ impl TryFrom<UserAndRoleJoined> for user_api::UserDetails {
             role_id,
             status,
             role_name,
+            title: user_and_role.1.title,
+            department: user_and_role.1.department,
             last_modified_at: user_and_role.0.last_modified_at,
         })
     }
@@ -13,6 +13,7 @@ use masking::{ExposeInterface, PeekInterface};
 
 use super::domain;
 use crate::{
+    consts,
     core::errors,
     services::authentication::get_header_value_by_key,
     types::{api as api_types, api::routing as routing_types, storage},
@@ -464,7 +465,8 @@ impl ForeignTryFrom<api_models::payments::PaymentMethodData> for api_enums::Paym
     ) -> Result<Self, Self::Error> {
         match payment_method_data {
             api_models::payments::PaymentMethodData::Card(..)
-            | api_models::payments::PaymentMethodData::CardToken(..) => Ok(Self::Card),
+            | api_models::payments::PaymentMethodData::CardToken(..)
+            | api_models::payments::PaymentMethodData::NetworkToken(..) => Ok(Self::Card),
             api_models::payments::PaymentMethodData::Wallet(..) => Ok(Self::Wallet),
             api_models::payments::PaymentMethodData::PayLater(..) => Ok(Self::PayLater),
             api_models::payments::PaymentMethodData::BankRedirect(..) => Ok(Self::BankRedirect),
@@ -615,6 +617,7 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            role_id: api_key.role_id,
         }
     }
 }
@@ -629,6 +632,7 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            role_id: api_key.role_id,
         }
     }
 }
@@ -909,9 +913,27 @@ impl ForeignTryFrom<&HeaderMap> for api_models::payments::HeaderPayload {
             .map(|value| value == Some("true"))
             .unwrap_or(false);
 
+        let x_correlation_id = get_header_value_by_key(consts::X_CORRELATION_ID.into(), headers)?
+            .map(|correlation_id| {
+                if correlation_id.is_empty() || correlation_id.len() > consts::MAX_ID_LENGTH {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "{} header must be between 1 and {} characters long",
+                            consts::X_CORRELATION_ID,
+                            consts::MAX_ID_LENGTH
+                        ),
+                    })
+                    .into_report()
+                } else {
+                    Ok(correlation_id.to_owned())
+                }
+            })
+            .transpose()?;
+
         Ok(Self {
             payment_confirm_source,
             x_hs_latency: Some(x_hs_latency),
+            x_correlation_id,
         })
     }
 }
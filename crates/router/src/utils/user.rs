@@ -60,12 +60,17 @@ pub async fn generate_jwt_auth_token(
     user: &UserFromStorage,
     user_role: &UserRole,
 ) -> UserResult<Secret<String>> {
+    if user_role.is_expired() {
+        return Err(UserErrors::UserRoleExpired.into());
+    }
+
     let token = AuthToken::new_token(
         user.get_user_id().to_string(),
         user_role.merchant_id.clone(),
         user_role.role_id.clone(),
         &state.conf,
         user_role.org_id.clone(),
+        user_role.expires_at,
     )
     .await?;
     Ok(Secret::new(token))
@@ -84,6 +89,7 @@ pub async fn generate_jwt_auth_token_with_custom_role_attributes(
         role_id,
         &state.conf,
         org_id,
+        None,
     )
     .await?;
     Ok(Secret::new(token))
@@ -217,6 +217,7 @@ pub async fn generate_sample_data(
             incremental_authorization_allowed: Default::default(),
             authorization_count: Default::default(),
             fingerprint_id: None,
+            merchant_order_reference_id: None,
             session_expiry: Some(session_expiry),
         };
         let payment_attempt = PaymentAttemptBatchNew {
@@ -1,12 +1,24 @@
 use api_models::user_role as user_role_api;
-use diesel_models::enums::UserStatus;
-use error_stack::ResultExt;
+use base64::Engine;
+use diesel_models::{
+    enums::{EventType, ProcessTrackerStatus},
+    user_role::{RolePreferences, UserRole, UserRoleExpiryTrackingData, UserRoleListCursor},
+    ProcessTrackerNew,
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+use time::PrimitiveDateTime;
+use tracing_futures::Instrument;
 
 use crate::{
     consts,
-    core::errors::{UserErrors, UserResult},
+    core::{
+        errors::{CustomResult, HealthCheckAuthzError, UserErrors, UserResult},
+        webhooks,
+    },
     routes::AppState,
     services::authorization::{
+        self, info,
         permissions::Permission,
         predefined_permissions::{self, RoleInfo},
     },
@@ -17,20 +29,132 @@ pub fn is_internal_role(role_id: &str) -> bool {
         || role_id == consts::user_role::ROLE_ID_INTERNAL_VIEW_ONLY_USER
 }
 
-pub async fn get_merchant_ids_for_user(state: &AppState, user_id: &str) -> UserResult<Vec<String>> {
-    Ok(state
-        .store
-        .list_user_roles_by_user_id(user_id)
-        .await
-        .change_context(UserErrors::InternalServerError)?
-        .into_iter()
-        .filter_map(|ele| {
-            if ele.status == UserStatus::Active {
-                return Some(ele.merchant_id);
-            }
-            None
+pub fn is_admin_role(role_id: &str) -> bool {
+    consts::user_role::ADMIN_ROLE_IDS.contains(&role_id)
+}
+
+/// Checks that the user can approve a role change requested by another user. Role changes are
+/// approved by the admins of the merchant, whichever roles are configured as sensitive, and never
+/// by the user who requested the change.
+pub fn validate_role_change_approver(
+    approver_user_id: &str,
+    approver_role_id: &str,
+    requested_by: &str,
+) -> UserResult<()> {
+    if approver_user_id == requested_by {
+        return Err(UserErrors::RoleChangeSelfApproval.into());
+    }
+
+    if !is_admin_role(approver_role_id) {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Role change requests can only be approved by an admin");
+    }
+    Ok(())
+}
+
+/// Returns the page size of a user role listing, the default page size is used when none is
+/// requested and page sizes above the maximum are rejected
+pub fn get_user_role_list_limit(limit: Option<u32>) -> UserResult<i64> {
+    let limit = limit.unwrap_or(consts::user_role::USER_ROLE_LIST_DEFAULT_LIMIT);
+    if !(1..=consts::user_role::USER_ROLE_LIST_MAX_LIMIT).contains(&limit) {
+        return Err(UserErrors::InvalidPageSize.into()).attach_printable(format!(
+            "Invalid page size {limit} for the user role listing"
+        ));
+    }
+    Ok(i64::from(limit))
+}
+
+/// Encodes the position of the user role in the user role listings as an opaque cursor
+pub fn encode_user_role_list_cursor(cursor: &UserRoleListCursor) -> String {
+    consts::BASE64_ENGINE_URL_SAFE.encode(format!(
+        "{}_{}",
+        cursor.created_at.assume_utc().unix_timestamp_nanos(),
+        cursor.id
+    ))
+}
+
+pub fn decode_user_role_list_cursor(cursor: &str) -> UserResult<UserRoleListCursor> {
+    let decoded_cursor = consts::BASE64_ENGINE_URL_SAFE
+        .decode(cursor)
+        .into_report()
+        .change_context(UserErrors::InvalidListCursor)
+        .attach_printable("Failed to decode the list cursor")?;
+    let decoded_cursor = String::from_utf8(decoded_cursor)
+        .into_report()
+        .change_context(UserErrors::InvalidListCursor)?;
+    let (created_at, id) = decoded_cursor
+        .split_once('_')
+        .ok_or(UserErrors::InvalidListCursor)
+        .into_report()?;
+
+    let created_at = created_at
+        .parse::<i128>()
+        .into_report()
+        .change_context(UserErrors::InvalidListCursor)
+        .and_then(|timestamp| {
+            time::OffsetDateTime::from_unix_timestamp_nanos(timestamp)
+                .into_report()
+                .change_context(UserErrors::InvalidListCursor)
         })
-        .collect())
+        .attach_printable("Invalid timestamp in the list cursor")?;
+    let id = id
+        .parse::<i32>()
+        .into_report()
+        .change_context(UserErrors::InvalidListCursor)
+        .attach_printable("Invalid id in the list cursor")?;
+
+    Ok(UserRoleListCursor {
+        created_at: PrimitiveDateTime::new(created_at.date(), created_at.time()),
+        id,
+    })
+}
+
+/// Trims the entries fetched for a page of a user role listing to the page size. One entry more
+/// than the page size is fetched, its presence tells that a next page exists, in which case the
+/// cursor of the last entry of the page is returned along with the page.
+pub fn paginate_user_role_list<T>(
+    mut entries: Vec<T>,
+    limit: i64,
+    get_user_role: impl Fn(&T) -> &UserRole,
+) -> (Vec<T>, Option<String>) {
+    let page_size = usize::try_from(limit).unwrap_or(usize::MAX);
+    if entries.len() <= page_size {
+        return (entries, None);
+    }
+    entries.truncate(page_size);
+    let next_cursor = entries
+        .last()
+        .map(|entry| encode_user_role_list_cursor(&get_user_role(entry).get_list_cursor()));
+    (entries, next_cursor)
+}
+
+/// Returns the default role configured for the merchant, falling back to the system default role
+pub async fn get_default_role_id_for_merchant(
+    state: &AppState,
+    merchant_id: &str,
+) -> UserResult<String> {
+    let key = format!(
+        "{}_{merchant_id}",
+        consts::user_role::DEFAULT_ROLE_CONFIG_PREFIX
+    );
+    match state.store.find_config_by_key(&key).await {
+        Ok(config) => Ok(config.config),
+        Err(e) if e.current_context().is_db_not_found() => {
+            Ok(consts::user_role::ROLE_ID_SYSTEM_DEFAULT.to_string())
+        }
+        Err(e) => Err(e.change_context(UserErrors::InternalServerError))
+            .attach_printable("Failed to fetch the default role of the merchant"),
+    }
+}
+
+/// Sensitive roles are only applied once a second admin approves the change
+pub fn is_sensitive_role(state: &AppState, role_id: &str) -> bool {
+    state
+        .conf
+        .role_change_approval
+        .sensitive_roles
+        .iter()
+        .any(|sensitive_role_id| sensitive_role_id == role_id)
 }
 
 pub fn validate_role_id(role_id: &str) -> UserResult<()> {
@@ -40,6 +164,486 @@ pub fn validate_role_id(role_id: &str) -> UserResult<()> {
     Err(UserErrors::InvalidRoleId.into())
 }
 
+/// Validates that the role can be assigned in the merchant, either as an invitable predefined role
+/// or as a custom role of the merchant
+pub async fn validate_role_id_for_merchant(
+    state: &AppState,
+    role_id: &str,
+    merchant_id: &str,
+) -> UserResult<()> {
+    if predefined_permissions::is_role_invitable(role_id) {
+        return Ok(());
+    }
+
+    let custom_roles = authorization::get_custom_roles(&*state.store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    if custom_roles.contains_key(role_id) {
+        return Ok(());
+    }
+    Err(UserErrors::InvalidRoleId.into())
+}
+
+/// Validates the name of a custom role and returns it trimmed
+pub fn validate_role_name(role_name: &str) -> UserResult<String> {
+    let role_name = role_name.trim();
+    if role_name.is_empty() || role_name.len() > consts::user_role::MAX_ROLE_NAME_LENGTH {
+        return Err(UserErrors::InvalidRoleName.into());
+    }
+    Ok(role_name.to_string())
+}
+
+/// Validates the permission override of a role. Admin roles cannot be overridden, and a role can
+/// only be granted permissions which the admin of the merchant holds, so that an override never
+/// grants a permission the merchant itself lacks.
+pub fn validate_permission_override(
+    role_id: &str,
+    granted: &[Permission],
+    revoked: &[Permission],
+) -> UserResult<()> {
+    validate_role_id(role_id)?;
+
+    if is_admin_role(role_id) {
+        return Err(UserErrors::InvalidPermissionOverride.into())
+            .attach_printable("Permissions of admin roles cannot be overridden");
+    }
+
+    if granted.is_empty() && revoked.is_empty() {
+        return Err(UserErrors::InvalidPermissionOverride.into())
+            .attach_printable("Neither granted nor revoked permissions are provided");
+    }
+
+    if granted
+        .iter()
+        .any(|permission| revoked.contains(permission))
+    {
+        return Err(UserErrors::InvalidPermissionOverride.into())
+            .attach_printable("A permission cannot be both granted and revoked");
+    }
+
+    let merchant_permissions = predefined_permissions::get_predefined_permissions()
+        .get(consts::user_role::ROLE_ID_MERCHANT_ADMIN)
+        .map(|role_info| role_info.get_permissions().clone())
+        .unwrap_or_default();
+    if let Some(permission) = granted
+        .iter()
+        .find(|permission| !merchant_permissions.contains(permission))
+    {
+        return Err(UserErrors::InvalidPermissionOverride.into()).attach_printable(format!(
+            "Permission {permission} is not available to the merchant"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the role definitions of an import, and returns the permission overrides and custom
+/// roles they describe. Predefined roles without granted or revoked permissions are references to
+/// the predefined permissions, and do not result in an override.
+pub fn validate_role_definitions(
+    role_definitions: user_role_api::RoleDefinitions,
+) -> UserResult<(
+    authorization::PermissionOverrides,
+    authorization::CustomRoles,
+)> {
+    let mut permission_overrides = authorization::PermissionOverrides::new();
+    for predefined_role in role_definitions.predefined_roles {
+        let role_override = authorization::RolePermissionOverride {
+            granted: predefined_role
+                .granted
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            revoked: predefined_role
+                .revoked
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        };
+        if role_override.granted.is_empty() && role_override.revoked.is_empty() {
+            validate_role_id(predefined_role.role_id.as_str())?;
+            continue;
+        }
+        validate_permission_override(
+            predefined_role.role_id.as_str(),
+            &role_override.granted,
+            &role_override.revoked,
+        )?;
+        if permission_overrides
+            .insert(predefined_role.role_id, role_override)
+            .is_some()
+        {
+            return Err(UserErrors::InvalidPermissionOverride.into())
+                .attach_printable("Permission overrides are defined more than once for a role");
+        }
+    }
+
+    let merchant_permissions = predefined_permissions::get_predefined_permissions()
+        .get(consts::user_role::ROLE_ID_MERCHANT_ADMIN)
+        .map(|role_info| role_info.get_permissions().clone())
+        .unwrap_or_default();
+    let mut custom_roles = authorization::CustomRoles::new();
+    for custom_role in role_definitions.custom_roles {
+        if !custom_role
+            .role_id
+            .starts_with(consts::user_role::CUSTOM_ROLE_ID_PREFIX)
+        {
+            return Err(UserErrors::InvalidRoleId.into()).attach_printable(format!(
+                "Custom role id {} is not a custom role id",
+                custom_role.role_id
+            ));
+        }
+
+        let role_name = validate_role_name(custom_role.role_name.as_str())?;
+        let is_name_taken = predefined_permissions::get_predefined_permissions()
+            .values()
+            .filter_map(|role_info| role_info.get_name())
+            .chain(
+                custom_roles
+                    .values()
+                    .map(|existing_role| existing_role.role_name.as_str()),
+            )
+            .any(|existing_name| existing_name.eq_ignore_ascii_case(role_name.as_str()));
+        if is_name_taken {
+            return Err(UserErrors::RoleNameAlreadyExists.into());
+        }
+
+        let permissions = custom_role
+            .permissions
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<Permission>>();
+        if let Some(permission) = permissions
+            .iter()
+            .find(|permission| !merchant_permissions.contains(permission))
+        {
+            return Err(UserErrors::InvalidPermissionOverride.into()).attach_printable(format!(
+                "Permission {permission} is not available to the merchant"
+            ));
+        }
+
+        if custom_roles
+            .insert(
+                custom_role.role_id,
+                authorization::CustomRole {
+                    role_name,
+                    permissions,
+                },
+            )
+            .is_some()
+        {
+            return Err(UserErrors::InvalidRoleId.into())
+                .attach_printable("A custom role is defined more than once");
+        }
+    }
+
+    Ok((permission_overrides, custom_roles))
+}
+
+pub fn validate_user_role_attribute(attribute: Option<&str>) -> UserResult<()> {
+    match attribute {
+        Some(attribute)
+            if attribute.trim().is_empty()
+                || attribute.len() > consts::user_role::MAX_USER_ROLE_ATTRIBUTE_LENGTH =>
+        {
+            Err(UserErrors::InvalidUserRoleAttribute.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn validate_role_preferences(
+    default_landing_page: Option<&str>,
+    visible_widgets: Option<&[String]>,
+) -> UserResult<()> {
+    if default_landing_page.is_none() && visible_widgets.is_none() {
+        return Err(UserErrors::InvalidRolePreferences.into())
+            .attach_printable("No preferences are set");
+    }
+
+    if let Some(default_landing_page) = default_landing_page {
+        if default_landing_page.trim().is_empty()
+            || default_landing_page.len() > consts::user_role::MAX_LANDING_PAGE_LENGTH
+        {
+            return Err(UserErrors::InvalidRolePreferences.into())
+                .attach_printable("Invalid length of the default landing page");
+        }
+    }
+
+    if let Some(visible_widgets) = visible_widgets {
+        if visible_widgets.len() > consts::user_role::MAX_VISIBLE_WIDGETS
+            || visible_widgets.iter().any(|widget| {
+                widget.trim().is_empty() || widget.len() > consts::user_role::MAX_WIDGET_NAME_LENGTH
+            })
+        {
+            return Err(UserErrors::InvalidRolePreferences.into())
+                .attach_printable("Invalid visible widgets");
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves the preferences of the role for the user out of the preferences stored for the
+/// merchant, each preference set by the user takes precedence over the default of the role
+pub fn resolve_role_preferences(
+    preferences: &[RolePreferences],
+    role_id: &str,
+    user_id: &str,
+) -> Option<user_role_api::RolePreferencesResponse> {
+    let role_preferences = preferences
+        .iter()
+        .filter(|preferences| preferences.role_id == role_id);
+    let mut role_default = None;
+    let mut user_override = None;
+    for preferences in role_preferences {
+        match preferences.user_id.as_deref() {
+            None => role_default = Some(preferences),
+            Some(preferences_user_id) if preferences_user_id == user_id => {
+                user_override = Some(preferences)
+            }
+            Some(_) => {}
+        }
+    }
+
+    if role_default.is_none() && user_override.is_none() {
+        return None;
+    }
+
+    let default_landing_page = user_override
+        .and_then(|preferences| preferences.default_landing_page.clone())
+        .or_else(|| role_default.and_then(|preferences| preferences.default_landing_page.clone()));
+    let visible_widgets = user_override
+        .and_then(|preferences| preferences.visible_widgets.clone())
+        .or_else(|| role_default.and_then(|preferences| preferences.visible_widgets.clone()))
+        .map(|visible_widgets| visible_widgets.into_iter().flatten().collect());
+
+    Some(user_role_api::RolePreferencesResponse {
+        default_landing_page,
+        visible_widgets,
+    })
+}
+
+pub fn validate_role_expiry(expires_at: Option<PrimitiveDateTime>) -> UserResult<()> {
+    match expires_at {
+        Some(expires_at) if expires_at <= common_utils::date_time::now() => {
+            Err(UserErrors::InvalidRoleOperation.into())
+                .attach_printable("Role expiry time is in the past")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Invalidates the tokens issued to the user so far, the user has to log in again to be issued a
+/// token carrying their current role. The epoch outlives every token issued before it.
+pub async fn invalidate_user_tokens(state: &AppState, user_id: &str) -> UserResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .set_key_with_expiry(
+            &authorization::get_token_epoch_key(user_id),
+            common_utils::date_time::now_unix_timestamp(),
+            i64::try_from(consts::JWT_TOKEN_TIME_IN_SECS)
+                .into_report()
+                .change_context(UserErrors::InternalServerError)?,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to set the token epoch of the user")
+}
+
+/// Removes the cached access of the user to the merchant, for the changes to the user role which
+/// grant access back and so do not invalidate the sessions of the user
+pub async fn clear_user_role_access_cache(
+    state: &AppState,
+    user_id: &str,
+    merchant_id: &str,
+) -> UserResult<()> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .delete_key(&authorization::get_user_role_access_key(
+            user_id,
+            merchant_id,
+        ))
+        .await
+        .map(|_| ())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to clear the cached access of the user")
+}
+
+/// Counts the user role change performed by the user in the current window, and fails once the
+/// user exceeds the number of role changes allowed in a window
+pub async fn check_role_change_rate_limit(state: &AppState, actor_user_id: &str) -> UserResult<()> {
+    let rate_limit = &state.conf.role_change_rate_limit;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let key = format!(
+        "{}_{actor_user_id}",
+        consts::user_role::ROLE_CHANGE_RATE_LIMIT_PREFIX
+    );
+    let request_count = redis_conn
+        .increment_key(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to increment role change count")?;
+
+    // The window starts with the first role change
+    if request_count == 1 {
+        redis_conn
+            .set_expiry(&key, rate_limit.window_in_secs)
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to set role change rate limit window")?;
+    }
+
+    if request_count > rate_limit.max_requests_per_window {
+        let retry_after_in_secs = redis_conn
+            .get_ttl(&key)
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to get role change rate limit window")?;
+
+        // Recover from a window which was left without an expiry
+        if retry_after_in_secs < 0 {
+            redis_conn
+                .set_expiry(&key, rate_limit.window_in_secs)
+                .await
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to set role change rate limit window")?;
+        }
+
+        return Err(UserErrors::TooManyRequests {
+            retry_after_in_secs: if retry_after_in_secs < 0 {
+                rate_limit.window_in_secs
+            } else {
+                retry_after_in_secs
+            },
+        }
+        .into())
+        .attach_printable_lazy(|| format!("Role change rate limit exceeded by {actor_user_id}"));
+    }
+
+    Ok(())
+}
+
+/// Schedules a task to remove the user role once it has expired
+/// Removes the expired role of the user in the merchant which the expiry workflow has not removed
+/// yet, so that it does not block a new role from being inserted
+pub async fn remove_lingering_expired_user_role(
+    state: &AppState,
+    user_id: &str,
+    merchant_id: &str,
+) -> UserResult<()> {
+    let is_deleted = state
+        .store
+        .delete_expired_user_role_by_user_id_merchant_id(user_id, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to remove the expired user role")?;
+    logger::info!(is_deleted, "Removed lingering expired user role");
+    Ok(())
+}
+
+pub async fn add_user_role_expiry_task(state: &AppState, user_role: &UserRole) -> UserResult<()> {
+    let expires_at = match user_role.expires_at {
+        Some(expires_at) => expires_at,
+        None => return Ok(()),
+    };
+
+    let runner = consts::user_role::USER_ROLE_EXPIRY_RUNNER;
+    let current_time = common_utils::date_time::now();
+    let tracking_data = serde_json::to_value(UserRoleExpiryTrackingData {
+        user_id: user_role.user_id.clone(),
+        merchant_id: user_role.merchant_id.clone(),
+    })
+    .into_report()
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to serialize user role expiry tracking data")?;
+
+    let process_tracker_entry = ProcessTrackerNew {
+        id: format!(
+            "{runner}_{}_{}_{}",
+            user_role.user_id,
+            user_role.merchant_id,
+            expires_at.assume_utc().unix_timestamp()
+        ),
+        name: Some(String::from(runner)),
+        tag: vec![String::from(consts::user_role::USER_ROLE_EXPIRY_TAG)],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(expires_at),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // A task for the same expiry time has already been scheduled
+        Err(e) if e.current_context().is_db_unique_violation() => Ok(()),
+        Err(e) => Err(e.change_context(UserErrors::InternalServerError))
+            .attach_printable("Failed to schedule user role expiry task"),
+    }
+}
+
+/// Sends the webhook for a change of the role of a user in the background, the role change does
+/// not wait for the delivery of the webhook
+pub fn trigger_user_role_webhook(
+    state: &AppState,
+    event_type: EventType,
+    content: user_role_api::UserRoleWebhookContent,
+) {
+    let state = state.clone();
+    tokio::spawn(
+        async move {
+            if let Err(error) =
+                webhooks::create_event_and_trigger_user_role_webhook(state, event_type, content)
+                    .await
+            {
+                logger::error!(?error, "Failed to trigger user role webhook");
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// A user can manage another user only if their permissions are a strict superset of the
+/// permissions of the other user. The permissions are the effective permissions of the roles in
+/// the merchant, as resolved by [`authorization::resolve_permissions_for_merchant`].
+pub fn can_manage_user(
+    actor_permissions: &[Permission],
+    target_permissions: &[Permission],
+) -> bool {
+    target_permissions
+        .iter()
+        .all(|permission| actor_permissions.contains(permission))
+        && actor_permissions
+            .iter()
+            .any(|permission| !target_permissions.contains(permission))
+}
+
+/// A user can assign a role to another user only if they hold every permission of the role
+pub fn can_assign_role(actor_permissions: &[Permission], role_permissions: &[Permission]) -> bool {
+    role_permissions
+        .iter()
+        .all(|permission| actor_permissions.contains(permission))
+}
+
 pub fn get_role_name_and_permission_response(
     role_info: &RoleInfo,
 ) -> Option<(Vec<user_role_api::Permission>, &'static str)> {
@@ -55,6 +659,47 @@ pub fn get_role_name_and_permission_response(
     })
 }
 
+pub fn get_permission_info_response(
+    permissions: &[Permission],
+) -> Vec<user_role_api::PermissionInfo> {
+    info::PermissionInfo::new(permissions)
+        .into_iter()
+        .map(Into::into)
+        .collect()
+}
+
+/// Checks that the predefined permissions are loaded, that the name and permissions of a
+/// predefined role can be resolved and that the permission map has not drifted
+pub fn health_check_authz() -> CustomResult<(), HealthCheckAuthzError> {
+    let predefined_roles = predefined_permissions::get_predefined_permissions();
+    if predefined_roles.is_empty() {
+        return Err(HealthCheckAuthzError::PredefinedPermissionsNotLoaded).into_report();
+    }
+
+    predefined_roles
+        .get(consts::user_role::ROLE_ID_MERCHANT_ADMIN)
+        .and_then(get_role_name_and_permission_response)
+        .filter(|(permissions, _)| !permissions.is_empty())
+        .map(|_| ())
+        .ok_or(HealthCheckAuthzError::RoleResolutionFailed)
+        .into_report()
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to resolve role {}",
+                consts::user_role::ROLE_ID_MERCHANT_ADMIN
+            )
+        })?;
+
+    let permission_map_drift = authorization::get_permission_map_drift();
+    if !permission_map_drift.is_empty() {
+        return Err(HealthCheckAuthzError::PermissionMapDrift)
+            .into_report()
+            .attach_printable(permission_map_drift.join(", "));
+    }
+
+    Ok(())
+}
+
 impl From<Permission> for user_role_api::Permission {
     fn from(value: Permission) -> Self {
         match value {
@@ -90,3 +735,308 @@ impl From<Permission> for user_role_api::Permission {
         }
     }
 }
+
+impl From<user_role_api::Permission> for Permission {
+    fn from(value: user_role_api::Permission) -> Self {
+        match value {
+            user_role_api::Permission::PaymentRead => Self::PaymentRead,
+            user_role_api::Permission::PaymentWrite => Self::PaymentWrite,
+            user_role_api::Permission::RefundRead => Self::RefundRead,
+            user_role_api::Permission::RefundWrite => Self::RefundWrite,
+            user_role_api::Permission::ApiKeyRead => Self::ApiKeyRead,
+            user_role_api::Permission::ApiKeyWrite => Self::ApiKeyWrite,
+            user_role_api::Permission::MerchantAccountRead => Self::MerchantAccountRead,
+            user_role_api::Permission::MerchantAccountWrite => Self::MerchantAccountWrite,
+            user_role_api::Permission::MerchantConnectorAccountRead => {
+                Self::MerchantConnectorAccountRead
+            }
+            user_role_api::Permission::MerchantConnectorAccountWrite => {
+                Self::MerchantConnectorAccountWrite
+            }
+            user_role_api::Permission::ForexRead => Self::ForexRead,
+            user_role_api::Permission::RoutingRead => Self::RoutingRead,
+            user_role_api::Permission::RoutingWrite => Self::RoutingWrite,
+            user_role_api::Permission::DisputeRead => Self::DisputeRead,
+            user_role_api::Permission::DisputeWrite => Self::DisputeWrite,
+            user_role_api::Permission::MandateRead => Self::MandateRead,
+            user_role_api::Permission::MandateWrite => Self::MandateWrite,
+            user_role_api::Permission::CustomerRead => Self::CustomerRead,
+            user_role_api::Permission::CustomerWrite => Self::CustomerWrite,
+            user_role_api::Permission::FileRead => Self::FileRead,
+            user_role_api::Permission::FileWrite => Self::FileWrite,
+            user_role_api::Permission::Analytics => Self::Analytics,
+            user_role_api::Permission::ThreeDsDecisionManagerWrite => {
+                Self::ThreeDsDecisionManagerWrite
+            }
+            user_role_api::Permission::ThreeDsDecisionManagerRead => {
+                Self::ThreeDsDecisionManagerRead
+            }
+            user_role_api::Permission::SurchargeDecisionManagerWrite => {
+                Self::SurchargeDecisionManagerWrite
+            }
+            user_role_api::Permission::SurchargeDecisionManagerRead => {
+                Self::SurchargeDecisionManagerRead
+            }
+            user_role_api::Permission::UsersRead => Self::UsersRead,
+            user_role_api::Permission::UsersWrite => Self::UsersWrite,
+            user_role_api::Permission::MerchantAccountCreate => Self::MerchantAccountCreate,
+        }
+    }
+}
+
+impl From<RolePreferences> for user_role_api::RolePreferencesResponse {
+    fn from(value: RolePreferences) -> Self {
+        Self {
+            default_landing_page: value.default_landing_page,
+            visible_widgets: value
+                .visible_widgets
+                .map(|visible_widgets| visible_widgets.into_iter().flatten().collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn can_manage_user_in_merchant(
+        actor_role_id: &str,
+        target_role_id: &str,
+        permission_overrides: &authorization::PermissionOverrides,
+        custom_roles: &authorization::CustomRoles,
+    ) -> bool {
+        match (
+            authorization::resolve_permissions_for_merchant(
+                actor_role_id,
+                permission_overrides,
+                custom_roles,
+            ),
+            authorization::resolve_permissions_for_merchant(
+                target_role_id,
+                permission_overrides,
+                custom_roles,
+            ),
+        ) {
+            (Some(actor_permissions), Some(target_permissions)) => {
+                can_manage_user(&actor_permissions, &target_permissions)
+            }
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_can_manage_user_applies_permission_overrides() {
+        let no_overrides = authorization::PermissionOverrides::new();
+        let custom_roles = authorization::CustomRoles::new();
+        assert!(can_manage_user_in_merchant(
+            consts::user_role::ROLE_ID_MERCHANT_IAM_ADMIN,
+            consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY,
+            &no_overrides,
+            &custom_roles,
+        ));
+
+        // Granting the view only role the permission which set the IAM admin apart makes the two
+        // roles equal, so that the IAM admin cannot manage the view only users of the merchant
+        let permission_overrides = authorization::PermissionOverrides::from([(
+            consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY.to_string(),
+            authorization::RolePermissionOverride {
+                granted: vec![Permission::UsersWrite],
+                revoked: vec![],
+            },
+        )]);
+        assert!(!can_manage_user_in_merchant(
+            consts::user_role::ROLE_ID_MERCHANT_IAM_ADMIN,
+            consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY,
+            &permission_overrides,
+            &custom_roles,
+        ));
+    }
+
+    #[test]
+    fn test_can_manage_user_resolves_custom_roles() {
+        let permission_overrides = authorization::PermissionOverrides::new();
+        let custom_roles = authorization::CustomRoles::from([(
+            "custom_role_support".to_string(),
+            authorization::CustomRole {
+                role_name: "support".to_string(),
+                permissions: vec![Permission::PaymentRead, Permission::UsersRead],
+            },
+        )]);
+
+        // A user on a custom role can be managed by, and can manage, the users whose roles are
+        // resolved in the merchant, as their requests are authorized
+        assert!(can_manage_user_in_merchant(
+            consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+            "custom_role_support",
+            &permission_overrides,
+            &custom_roles,
+        ));
+        assert!(!can_manage_user_in_merchant(
+            "custom_role_support",
+            consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+            &permission_overrides,
+            &custom_roles,
+        ));
+        assert!(!can_manage_user_in_merchant(
+            consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+            "custom_role_unknown",
+            &permission_overrides,
+            &custom_roles,
+        ));
+    }
+
+    #[test]
+    fn test_can_assign_role_rejects_escalation() {
+        let permission_overrides = authorization::PermissionOverrides::new();
+        let custom_roles = authorization::CustomRoles::new();
+        let resolve_permissions = |role_id: &str| {
+            authorization::resolve_permissions_for_merchant(
+                role_id,
+                &permission_overrides,
+                &custom_roles,
+            )
+            .unwrap_or_default()
+        };
+
+        let iam_admin_permissions =
+            resolve_permissions(consts::user_role::ROLE_ID_MERCHANT_IAM_ADMIN);
+        // The IAM admin manages the view only users, but cannot make them admins or developers
+        assert!(can_assign_role(
+            &iam_admin_permissions,
+            &resolve_permissions(consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY),
+        ));
+        assert!(!can_assign_role(
+            &iam_admin_permissions,
+            &resolve_permissions(consts::user_role::ROLE_ID_MERCHANT_ADMIN),
+        ));
+        assert!(!can_assign_role(
+            &iam_admin_permissions,
+            &resolve_permissions(consts::user_role::ROLE_ID_MERCHANT_DEVELOPER),
+        ));
+        assert!(can_assign_role(
+            &resolve_permissions(consts::user_role::ROLE_ID_MERCHANT_ADMIN),
+            &resolve_permissions(consts::user_role::ROLE_ID_MERCHANT_DEVELOPER),
+        ));
+    }
+
+    #[test]
+    fn test_role_change_approver_must_be_another_admin() {
+        assert!(validate_role_change_approver(
+            "approver",
+            consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+            "requester",
+        )
+        .is_ok());
+        assert!(validate_role_change_approver(
+            "approver",
+            consts::user_role::ROLE_ID_ORGANIZATION_ADMIN,
+            "requester",
+        )
+        .is_ok());
+
+        // A role which manages users, and could be configured as sensitive, is not an admin
+        let error = validate_role_change_approver(
+            "approver",
+            consts::user_role::ROLE_ID_MERCHANT_IAM_ADMIN,
+            "requester",
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::InvalidRoleOperation
+        ));
+
+        let error = validate_role_change_approver(
+            "requester",
+            consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+            "requester",
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::RoleChangeSelfApproval
+        ));
+    }
+
+    #[test]
+    fn test_can_manage_user_requires_strict_superset() {
+        assert!(can_manage_user(
+            &[Permission::UsersRead, Permission::UsersWrite],
+            &[Permission::UsersRead],
+        ));
+        assert!(!can_manage_user(
+            &[Permission::UsersRead, Permission::UsersWrite],
+            &[Permission::UsersRead, Permission::UsersWrite],
+        ));
+        assert!(!can_manage_user(
+            &[Permission::UsersRead, Permission::UsersWrite],
+            &[Permission::PaymentRead],
+        ));
+    }
+
+    fn new_user_role(id: i32, created_at: PrimitiveDateTime) -> UserRole {
+        UserRole {
+            id,
+            user_id: format!("user{id}"),
+            merchant_id: "merchant1".to_string(),
+            role_id: consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY.to_string(),
+            org_id: "org1".to_string(),
+            status: diesel_models::enums::UserStatus::Active,
+            created_by: "admin".to_string(),
+            last_modified_by: "admin".to_string(),
+            created_at,
+            last_modified: created_at,
+            expires_at: None,
+            title: None,
+            department: None,
+        }
+    }
+
+    #[test]
+    fn test_user_role_list_cursor_round_trip() {
+        let cursor = new_user_role(42, common_utils::date_time::now()).get_list_cursor();
+
+        let decoded_cursor =
+            decode_user_role_list_cursor(&encode_user_role_list_cursor(&cursor)).unwrap();
+        assert_eq!(decoded_cursor, cursor);
+
+        assert!(decode_user_role_list_cursor("not a cursor").is_err());
+        assert!(decode_user_role_list_cursor(
+            &consts::BASE64_ENGINE_URL_SAFE.encode("1700000000000000000_not_an_id")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_user_role_list_limit() {
+        assert_eq!(
+            get_user_role_list_limit(None).unwrap(),
+            i64::from(consts::user_role::USER_ROLE_LIST_DEFAULT_LIMIT)
+        );
+        assert_eq!(get_user_role_list_limit(Some(1)).unwrap(), 1);
+        assert!(get_user_role_list_limit(Some(0)).is_err());
+        assert!(
+            get_user_role_list_limit(Some(consts::user_role::USER_ROLE_LIST_MAX_LIMIT + 1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_paginate_user_role_list_returns_cursor_only_when_next_page_exists() {
+        let now = common_utils::date_time::now();
+        let user_roles = (1..=3).map(|id| new_user_role(id, now)).collect::<Vec<_>>();
+
+        let (page, next_cursor) = paginate_user_role_list(user_roles.clone(), 3, |ur| ur);
+        assert_eq!(page.len(), 3);
+        assert!(next_cursor.is_none());
+
+        // One entry more than the page size is fetched when a next page exists
+        let (page, next_cursor) = paginate_user_role_list(user_roles, 2, |ur| ur);
+        assert_eq!(page.len(), 2);
+        let next_cursor = decode_user_role_list_cursor(&next_cursor.unwrap()).unwrap();
+        assert_eq!(next_cursor.id, 2);
+        assert!(next_cursor.precedes(&new_user_role(3, now)));
+        assert!(!next_cursor.precedes(&new_user_role(2, now)));
+    }
+}
@@ -1,3 +1,5 @@
 pub mod payment_sync;
 pub mod refund_router;
 pub mod tokenized_data;
+pub mod user_role_expiry;
+pub mod user_role_webhook;
@@ -139,6 +139,7 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                             unified_code: None,
                             unified_message: None,
                             connector_transaction_id: None,
+                            error_classification: None,
                         };
 
                     payment_data.payment_attempt = db
@@ -0,0 +1,104 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::webhooks,
+    errors, logger,
+    routes::AppState,
+    types::storage::{self, UserRoleExpiryTrackingData},
+    utils,
+};
+
+pub struct UserRoleExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for UserRoleExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let tracking_data: UserRoleExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("UserRoleExpiryTrackingData")?;
+
+        let user_role = state
+            .store
+            .find_user_role_by_user_id_merchant_id_including_expired(
+                tracking_data.user_id.as_str(),
+                tracking_data.merchant_id.as_str(),
+            )
+            .await
+            .ok();
+
+        // The tokens of the user are invalidated before the role is removed, so that a failure is
+        // retried while the role is still there to be found. A role which was re-assigned without
+        // an expiry in the meantime is left alone.
+        if let Some(user_role) = user_role
+            .as_ref()
+            .filter(|user_role| user_role.is_expired())
+        {
+            utils::user_role::invalidate_user_tokens(state, &user_role.user_id)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to invalidate the tokens of the user");
+                    sch_errors::ProcessTrackerError::FlowExecutionError {
+                        flow: "UserRoleExpiryWorkflow",
+                    }
+                })?;
+        }
+
+        // Only removes the user role if it is still expired, the role could have been
+        // re-assigned with a different expiry after this task was scheduled
+        let is_deleted = state
+            .store
+            .delete_expired_user_role_by_user_id_merchant_id(
+                tracking_data.user_id.as_str(),
+                tracking_data.merchant_id.as_str(),
+            )
+            .await?;
+        logger::info!(is_deleted, "Processed user role expiry");
+
+        if let Some(user_role) = user_role.filter(|_| is_deleted) {
+            let webhook_result = webhooks::create_event_and_trigger_user_role_webhook(
+                state.clone(),
+                storage::enums::EventType::UserRoleDeleted,
+                api_models::user_role::UserRoleWebhookContent {
+                    merchant_id: user_role.merchant_id,
+                    user_id: user_role.user_id,
+                    old_role: Some(user_role.role_id),
+                    new_role: None,
+                    actor: None,
+                },
+            )
+            .await;
+            if let Err(error) = webhook_result {
+                logger::error!(?error, "Failed to trigger user role webhook");
+            }
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(
+                state.get_db().as_scheduler(),
+                format!("COMPLETED_BY_PT_{id}"),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
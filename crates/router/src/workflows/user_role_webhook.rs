@@ -0,0 +1,104 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, utils as scheduler_utils, SchedulerAppState,
+};
+
+use crate::{
+    core::webhooks::{self, UserRoleWebhookTrackingData},
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct UserRoleWebhookRetryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for UserRoleWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let tracking_data: UserRoleWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("UserRoleWebhookTrackingData")?;
+
+        let merchant_details = webhooks::get_merchant_account_and_default_business_profile(
+            state,
+            &tracking_data.content.merchant_id,
+        )
+        .await?;
+        let (merchant_account, business_profile) = match merchant_details {
+            Some(merchant_details) => merchant_details,
+            None => {
+                process
+                    .finish_with_status(
+                        state.get_db().as_scheduler(),
+                        String::from("BUSINESS_PROFILE_NOT_FOUND"),
+                    )
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let delivery_result = webhooks::trigger_user_role_webhook_to_merchant(
+            state.clone(),
+            &merchant_account,
+            business_profile,
+            &tracking_data,
+        )
+        .await;
+
+        match delivery_result {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(
+                        state.get_db().as_scheduler(),
+                        format!("COMPLETED_BY_PT_{id}"),
+                    )
+                    .await?;
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to deliver user role webhook");
+                let schedule_time =
+                    scheduler_utils::get_time_from_delta(scheduler_utils::get_schedule_time(
+                        process_data::ConnectorPTMapping::default(),
+                        &tracking_data.content.merchant_id,
+                        process.retry_count + 1,
+                    ));
+
+                match schedule_time {
+                    Some(schedule_time) => {
+                        process
+                            .retry(state.get_db().as_scheduler(), schedule_time)
+                            .await?
+                    }
+                    None => {
+                        process
+                            .finish_with_status(
+                                state.get_db().as_scheduler(),
+                                String::from("RETRIES_EXCEEDED"),
+                            )
+                            .await?
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
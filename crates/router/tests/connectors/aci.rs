@@ -71,7 +71,12 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: false,
+            network_transaction_id: None,
+            installment_plan: None,
+            connector_idempotency_key: None,
+            customer_acceptance: None,
             metadata: None,
+            preferred_3ds_version: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
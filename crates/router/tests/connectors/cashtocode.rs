@@ -69,7 +69,12 @@ impl CashtocodeTest {
             customer_id: Some("John Doe".to_owned()),
             surcharge_details: None,
             request_incremental_authorization: false,
+            network_transaction_id: None,
+            installment_plan: None,
+            connector_idempotency_key: None,
+            customer_acceptance: None,
             metadata: None,
+            preferred_3ds_version: None,
         })
     }
 
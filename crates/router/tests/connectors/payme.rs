@@ -77,6 +77,7 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
             product_name: "iphone 13".to_string(),
             quantity: 1,
             amount: 1000,
+            tax_amount: None,
             product_img_link: None,
             requires_shipping: None,
             product_id: None,
@@ -377,6 +378,7 @@ async fn should_fail_payment_for_incorrect_cvc() {
                     product_name: "iphone 13".to_string(),
                     quantity: 1,
                     amount: 100,
+                    tax_amount: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -415,6 +417,7 @@ async fn should_fail_payment_for_invalid_exp_month() {
                     product_name: "iphone 13".to_string(),
                     quantity: 1,
                     amount: 100,
+                    tax_amount: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -453,6 +456,7 @@ async fn should_fail_payment_for_incorrect_expiry_year() {
                     product_name: "iphone 13".to_string(),
                     quantity: 1,
                     amount: 100,
+                    tax_amount: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
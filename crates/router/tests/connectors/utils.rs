@@ -912,7 +912,12 @@ impl Default for PaymentAuthorizeType {
             customer_id: None,
             surcharge_details: None,
             request_incremental_authorization: false,
+            network_transaction_id: None,
+            installment_plan: None,
+            connector_idempotency_key: None,
+            customer_acceptance: None,
             metadata: None,
+            preferred_3ds_version: None,
         };
         Self(data)
     }
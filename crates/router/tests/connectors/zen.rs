@@ -314,6 +314,7 @@ async fn should_fail_payment_for_incorrect_card_number() {
                     product_name: "test".to_string(),
                     quantity: 1,
                     amount: 1000,
+                    tax_amount: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -355,6 +356,7 @@ async fn should_fail_payment_for_incorrect_cvc() {
                     product_name: "test".to_string(),
                     quantity: 1,
                     amount: 1000,
+                    tax_amount: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -396,6 +398,7 @@ async fn should_fail_payment_for_invalid_exp_month() {
                     product_name: "test".to_string(),
                     quantity: 1,
                     amount: 1000,
+                    tax_amount: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -437,6 +440,7 @@ async fn should_fail_payment_for_incorrect_expiry_year() {
                     product_name: "test".to_string(),
                     quantity: 1,
                     amount: 1000,
+                    tax_amount: None,
                     product_img_link: None,
                     requires_shipping: None,
                     product_id: None,
@@ -122,6 +122,10 @@ pub enum Flow {
     PaymentsUpdate,
     /// Payments confirm flow.
     PaymentsConfirm,
+    /// Payments batch confirm flow.
+    PaymentsBatchConfirm,
+    /// Payments confirm preflight flow.
+    PaymentsConfirmPreflight,
     /// Payments capture flow.
     PaymentsCapture,
     /// Payments cancel flow.
@@ -235,6 +239,10 @@ pub enum Flow {
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Simulate a failure of a component in the upcoming deep health checks
+    SimulateHealthCheckFailure,
+    /// List the entries the drainer failed to drain
+    DrainerDeadLetterList,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
@@ -299,8 +307,44 @@ pub enum Flow {
     GetRole,
     /// Get role from token
     GetRoleFromToken,
+    /// Get the roles and permissions of a user across merchants
+    GetUserAccessMap,
     /// Update user role
     UpdateUserRole,
+    /// Assign or update user role
+    AssignOrUpdateUserRole,
+    /// Reset user role to the default role of the merchant
+    ResetUserRoleToDefault,
+    /// Delete the role of a user in a merchant
+    DeleteUserRole,
+    /// Suspend the access of a user to a merchant while preserving their role
+    SuspendUserRole,
+    /// Resume the suspended access of a user to a merchant
+    ResumeUserRole,
+    /// Approve a pending change of the role of a user
+    ApproveUserRoleChange,
+    /// List the pending changes of the roles of the users of a merchant
+    ListUserRoleChangeRequests,
+    /// List the users of a merchant whose role grants a permission
+    ListUsersWithPermission,
+    /// Update the default UI preferences of a role
+    UpdateRolePreferences,
+    /// Delete the default UI preferences of a role
+    DeleteRolePreferences,
+    /// Update the UI preferences of the user which override the defaults of their role
+    UpdateUserPreferences,
+    /// Delete the UI preferences of the user which override the defaults of their role
+    DeleteUserPreferences,
+    /// Update the permissions granted to and revoked from a role for the merchant
+    UpdateRolePermissionOverrides,
+    /// Delete the permission overrides of a role for the merchant
+    DeleteRolePermissionOverrides,
+    /// Create a custom role with the permissions of an existing role
+    CloneRole,
+    /// Export the role definitions of the merchant
+    ExportRoles,
+    /// Import the role definitions of the merchant from an export
+    ImportRoles,
     /// Create merchant account for user in a org
     UserMerchantAccountCreate,
     /// Generate Sample Data
@@ -394,6 +394,12 @@ pub enum HealthCheckDBError {
     UnknownError,
     #[error("Error in database transaction")]
     TransactionError,
+    #[error("Error while reading the applied schema version from the database")]
+    SchemaVersionReadError,
+    #[error("Error while counting the stuck payments in the database")]
+    StuckPaymentsCountError,
+    #[error("Error while reading the time of the database")]
+    ClockReadError,
 }
 
 impl From<diesel::result::Error> for HealthCheckDBError {
@@ -422,10 +428,50 @@ pub enum HealthCheckRedisError {
     GetFailed,
     #[error("Failed to delete key value in Redis")]
     DeleteFailed,
+    #[error("Failed to append an entry to the Redis stream")]
+    StreamAppendFailed,
+    #[error("Failed to read the entry appended to the Redis stream")]
+    StreamReadFailed,
+    #[error("Failed to delete the Redis stream")]
+    StreamDeleteFailed,
+    #[error("Failed to subscribe to the Redis health check channel")]
+    SubscribeFailed,
+    #[error("Failed to publish to the Redis health check channel")]
+    PublishFailed,
+    #[error("Published message was not received by the Redis subscriber in time")]
+    MessageNotReceived,
+    #[error("Failed to read the dead letter stream of the drainer from Redis")]
+    DeadLetterReadFailed,
+    #[error("Failed to read the replication info from Redis")]
+    ReplicationInfoReadFailed,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum HealthCheckLockerError {
     #[error("Failed to establish Locker connection")]
     FailedToCallLocker,
+    #[error("Locker did not respond within the timeout")]
+    LockerTimedOut,
+    #[error("Locker responded with an error status")]
+    LockerUnhealthy,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HealthCheckEndToEndError {
+    #[error("Failed to find the merchant used by the end to end probe")]
+    MerchantNotFound,
+    #[error("Failed to authorize the end to end probe payment")]
+    AuthorizationFailed,
+    #[error("Failed to void the end to end probe payment")]
+    VoidFailed,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum HealthCheckAuthzError {
+    #[error("Predefined permissions are not loaded")]
+    PredefinedPermissionsNotLoaded,
+    #[error("Failed to resolve the permissions of a predefined role")]
+    RoleResolutionFailed,
+    #[error("The predefined roles are inconsistent with the permission modules")]
+    PermissionMapDrift,
 }
@@ -43,6 +43,8 @@ pub struct MockDb {
     pub organizations: Arc<Mutex<Vec<store::organization::Organization>>>,
     pub users: Arc<Mutex<Vec<store::user::User>>>,
     pub user_roles: Arc<Mutex<Vec<store::user_role::UserRole>>>,
+    pub user_role_change_requests: Arc<Mutex<Vec<store::user_role::UserRoleChangeRequest>>>,
+    pub role_preferences: Arc<Mutex<Vec<store::user_role::RolePreferences>>>,
     pub authorizations: Arc<Mutex<Vec<store::authorization::Authorization>>>,
     pub dashboard_metadata: Arc<Mutex<Vec<store::user::dashboard_metadata::DashboardMetadata>>>,
 }
@@ -80,6 +82,8 @@ impl MockDb {
             organizations: Default::default(),
             users: Default::default(),
             user_roles: Default::default(),
+            user_role_change_requests: Default::default(),
+            role_preferences: Default::default(),
             authorizations: Default::default(),
             dashboard_metadata: Default::default(),
         })
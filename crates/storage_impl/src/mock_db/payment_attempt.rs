@@ -147,6 +147,17 @@ impl PaymentAttemptInterface for MockDb {
             merchant_connector_id: payment_attempt.merchant_connector_id,
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            network_transaction_id: payment_attempt.network_transaction_id,
+            connector_fallback_index: payment_attempt.connector_fallback_index,
+            routed_to_unhealthy_connector: payment_attempt.routed_to_unhealthy_connector,
+            installment_plan: payment_attempt.installment_plan,
+            connector_idempotency_key: payment_attempt.connector_idempotency_key,
+            frm_score: payment_attempt.frm_score,
+            frm_score_threshold: payment_attempt.frm_score_threshold,
+            customer_acceptance: payment_attempt.customer_acceptance,
+            correlation_id: payment_attempt.correlation_id,
+            error_classification: payment_attempt.error_classification,
+            authentication_escalation_reason: payment_attempt.authentication_escalation_reason,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
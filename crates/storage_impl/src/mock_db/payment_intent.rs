@@ -110,6 +110,7 @@ impl PaymentIntentInterface for MockDb {
             incremental_authorization_allowed: new.incremental_authorization_allowed,
             authorization_count: new.authorization_count,
             fingerprint_id: new.fingerprint_id,
+            merchant_order_reference_id: new.merchant_order_reference_id,
             session_expiry: new.session_expiry,
         };
         payment_intents.push(payment_intent.clone());
@@ -156,6 +157,24 @@ impl PaymentIntentInterface for MockDb {
             .unwrap())
     }
 
+    async fn find_optional_payment_intent_by_merchant_order_reference_id_merchant_id(
+        &self,
+        merchant_order_reference_id: &str,
+        merchant_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+
+        Ok(payment_intents
+            .iter()
+            .find(|payment_intent| {
+                payment_intent.merchant_order_reference_id.as_deref()
+                    == Some(merchant_order_reference_id)
+                    && payment_intent.merchant_id == merchant_id
+            })
+            .cloned())
+    }
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
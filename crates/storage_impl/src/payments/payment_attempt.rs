@@ -390,6 +390,18 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
                     unified_code: payment_attempt.unified_code.clone(),
                     unified_message: payment_attempt.unified_message.clone(),
+                    network_transaction_id: payment_attempt.network_transaction_id.clone(),
+                    connector_fallback_index: payment_attempt.connector_fallback_index,
+                    routed_to_unhealthy_connector: payment_attempt.routed_to_unhealthy_connector,
+                    installment_plan: payment_attempt.installment_plan.clone(),
+                    connector_idempotency_key: payment_attempt.connector_idempotency_key.clone(),
+                    frm_score: payment_attempt.frm_score,
+                    frm_score_threshold: payment_attempt.frm_score_threshold,
+                    customer_acceptance: payment_attempt.customer_acceptance.clone(),
+                    correlation_id: payment_attempt.correlation_id,
+                    error_classification: payment_attempt.error_classification,
+                    authentication_escalation_reason: payment_attempt
+                        .authentication_escalation_reason,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1079,6 +1091,17 @@ impl DataModelExt for PaymentAttempt {
             merchant_connector_id: self.merchant_connector_id,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            network_transaction_id: self.network_transaction_id,
+            connector_fallback_index: self.connector_fallback_index,
+            routed_to_unhealthy_connector: self.routed_to_unhealthy_connector,
+            installment_plan: self.installment_plan,
+            connector_idempotency_key: self.connector_idempotency_key,
+            frm_score: self.frm_score,
+            frm_score_threshold: self.frm_score_threshold,
+            customer_acceptance: self.customer_acceptance,
+            correlation_id: self.correlation_id,
+            error_classification: self.error_classification,
+            authentication_escalation_reason: self.authentication_escalation_reason,
         }
     }
 
@@ -1134,6 +1157,17 @@ impl DataModelExt for PaymentAttempt {
             merchant_connector_id: storage_model.merchant_connector_id,
             unified_code: storage_model.unified_code,
             unified_message: storage_model.unified_message,
+            network_transaction_id: storage_model.network_transaction_id,
+            connector_fallback_index: storage_model.connector_fallback_index,
+            routed_to_unhealthy_connector: storage_model.routed_to_unhealthy_connector,
+            installment_plan: storage_model.installment_plan,
+            connector_idempotency_key: storage_model.connector_idempotency_key,
+            frm_score: storage_model.frm_score,
+            frm_score_threshold: storage_model.frm_score_threshold,
+            customer_acceptance: storage_model.customer_acceptance,
+            correlation_id: storage_model.correlation_id,
+            error_classification: storage_model.error_classification,
+            authentication_escalation_reason: storage_model.authentication_escalation_reason,
         }
     }
 }
@@ -1189,6 +1223,17 @@ impl DataModelExt for PaymentAttemptNew {
             merchant_connector_id: self.merchant_connector_id,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            network_transaction_id: self.network_transaction_id,
+            connector_fallback_index: self.connector_fallback_index,
+            routed_to_unhealthy_connector: self.routed_to_unhealthy_connector,
+            installment_plan: self.installment_plan,
+            connector_idempotency_key: self.connector_idempotency_key,
+            frm_score: self.frm_score,
+            frm_score_threshold: self.frm_score_threshold,
+            customer_acceptance: self.customer_acceptance,
+            correlation_id: self.correlation_id,
+            error_classification: self.error_classification,
+            authentication_escalation_reason: self.authentication_escalation_reason,
         }
     }
 
@@ -1242,6 +1287,17 @@ impl DataModelExt for PaymentAttemptNew {
             merchant_connector_id: storage_model.merchant_connector_id,
             unified_code: storage_model.unified_code,
             unified_message: storage_model.unified_message,
+            network_transaction_id: storage_model.network_transaction_id,
+            connector_fallback_index: storage_model.connector_fallback_index,
+            routed_to_unhealthy_connector: storage_model.routed_to_unhealthy_connector,
+            installment_plan: storage_model.installment_plan,
+            connector_idempotency_key: storage_model.connector_idempotency_key,
+            frm_score: storage_model.frm_score,
+            frm_score_threshold: storage_model.frm_score_threshold,
+            customer_acceptance: storage_model.customer_acceptance,
+            correlation_id: storage_model.correlation_id,
+            error_classification: storage_model.error_classification,
+            authentication_escalation_reason: storage_model.authentication_escalation_reason,
         }
     }
 }
@@ -1331,6 +1387,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                network_transaction_id,
+                connector_fallback_index,
+                routed_to_unhealthy_connector,
+                installment_plan,
+                connector_idempotency_key,
+                frm_score,
+                frm_score_threshold,
+                customer_acceptance,
+                correlation_id,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
                 currency,
@@ -1352,6 +1417,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                network_transaction_id,
+                connector_fallback_index,
+                routed_to_unhealthy_connector,
+                installment_plan,
+                connector_idempotency_key,
+                frm_score,
+                frm_score_threshold,
+                customer_acceptance,
+                correlation_id,
             },
             Self::VoidUpdate {
                 status,
@@ -1436,6 +1510,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 connector_transaction_id,
+                error_classification,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -1447,6 +1522,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 connector_transaction_id,
+                error_classification,
             },
             Self::CaptureUpdate {
                 multiple_capture_count,
@@ -1599,6 +1675,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                network_transaction_id,
+                connector_fallback_index,
+                routed_to_unhealthy_connector,
+                installment_plan,
+                connector_idempotency_key,
+                frm_score,
+                frm_score_threshold,
+                customer_acceptance,
+                correlation_id,
             } => Self::ConfirmUpdate {
                 amount,
                 currency,
@@ -1620,6 +1705,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                network_transaction_id,
+                connector_fallback_index,
+                routed_to_unhealthy_connector,
+                installment_plan,
+                connector_idempotency_key,
+                frm_score,
+                frm_score_threshold,
+                customer_acceptance,
+                correlation_id,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -1704,6 +1798,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 connector_transaction_id,
+                error_classification,
             } => Self::ErrorUpdate {
                 connector,
                 status,
@@ -1715,6 +1810,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 connector_transaction_id,
+                error_classification,
             },
             DieselPaymentAttemptUpdate::CaptureUpdate {
                 amount_to_capture,
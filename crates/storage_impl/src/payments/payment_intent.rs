@@ -102,6 +102,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     incremental_authorization_allowed: new.incremental_authorization_allowed,
                     authorization_count: new.authorization_count,
                     fingerprint_id: new.fingerprint_id.clone(),
+                    merchant_order_reference_id: new.merchant_order_reference_id.clone(),
                     session_expiry: new.session_expiry,
                 };
                 let redis_entry = kv::TypedSql {
@@ -230,6 +231,25 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
         .map(PaymentIntent::from_storage_model)
     }
 
+    /// The merchant order reference is not part of the redis key, so the lookup always goes to
+    /// the database. The payment intents which are only in redis are covered by the reservation
+    /// of the reference made in redis when the payment is created or updated
+    #[instrument(skip_all)]
+    async fn find_optional_payment_intent_by_merchant_order_reference_id_merchant_id(
+        &self,
+        merchant_order_reference_id: &str,
+        merchant_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<PaymentIntent>, StorageError> {
+        self.router_store
+            .find_optional_payment_intent_by_merchant_order_reference_id_merchant_id(
+                merchant_order_reference_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
@@ -366,6 +386,29 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn find_optional_payment_intent_by_merchant_order_reference_id_merchant_id(
+        &self,
+        merchant_order_reference_id: &str,
+        merchant_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Option<PaymentIntent>, StorageError> {
+        // The lookup guards the uniqueness of the reference, so it is not made on a replica which
+        // might lag behind the payment intents just written
+        let conn = pg_connection_write(self).await?;
+        DieselPaymentIntent::find_optional_by_merchant_order_reference_id_merchant_id(
+            &conn,
+            merchant_order_reference_id,
+            merchant_id,
+        )
+        .await
+        .map(|payment_intent| payment_intent.map(PaymentIntent::from_storage_model))
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
@@ -771,6 +814,7 @@ impl DataModelExt for PaymentIntentNew {
             incremental_authorization_allowed: self.incremental_authorization_allowed,
             authorization_count: self.authorization_count,
             fingerprint_id: self.fingerprint_id,
+            merchant_order_reference_id: self.merchant_order_reference_id,
             session_expiry: self.session_expiry,
         }
     }
@@ -816,6 +860,7 @@ impl DataModelExt for PaymentIntentNew {
             incremental_authorization_allowed: storage_model.incremental_authorization_allowed,
             authorization_count: storage_model.authorization_count,
             fingerprint_id: storage_model.fingerprint_id,
+            merchant_order_reference_id: storage_model.merchant_order_reference_id,
             session_expiry: storage_model.session_expiry,
         }
     }
@@ -866,6 +911,7 @@ impl DataModelExt for PaymentIntent {
             incremental_authorization_allowed: self.incremental_authorization_allowed,
             authorization_count: self.authorization_count,
             fingerprint_id: self.fingerprint_id,
+            merchant_order_reference_id: self.merchant_order_reference_id,
             session_expiry: self.session_expiry,
         }
     }
@@ -912,6 +958,7 @@ impl DataModelExt for PaymentIntent {
             incremental_authorization_allowed: storage_model.incremental_authorization_allowed,
             authorization_count: storage_model.authorization_count,
             fingerprint_id: storage_model.fingerprint_id,
+            merchant_order_reference_id: storage_model.merchant_order_reference_id,
             session_expiry: storage_model.session_expiry,
         }
     }
@@ -996,6 +1043,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 payment_confirm_source,
                 updated_by,
                 fingerprint_id,
+                merchant_order_reference_id,
                 session_expiry,
             } => DieselPaymentIntentUpdate::Update {
                 amount,
@@ -1016,6 +1064,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 payment_confirm_source,
                 updated_by,
                 fingerprint_id,
+                merchant_order_reference_id,
                 session_expiry,
             },
             Self::PaymentAttemptAndAttemptCountUpdate {
@@ -1039,9 +1088,11 @@ impl DataModelExt for PaymentIntentUpdate {
                 updated_by,
             },
             Self::ApproveUpdate {
+                status,
                 merchant_decision,
                 updated_by,
             } => DieselPaymentIntentUpdate::ApproveUpdate {
+                status,
                 merchant_decision,
                 updated_by,
             },
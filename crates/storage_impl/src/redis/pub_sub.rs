@@ -2,7 +2,7 @@ use error_stack::{IntoReport, ResultExt};
 use redis_interface::{errors as redis_errors, PubsubInterface, RedisValue};
 use router_env::logger;
 
-use crate::redis::cache::{CacheKind, ACCOUNTS_CACHE, CONFIG_CACHE};
+use crate::redis::cache::{CacheKind, ACCOUNTS_CACHE, CONFIG_CACHE, PUB_SUB_CHANNEL};
 
 #[async_trait::async_trait]
 pub trait PubSubInterface {
@@ -49,6 +49,11 @@ impl PubSubInterface for redis_interface::RedisConnectionPool {
         logger::debug!("Started on message");
         let mut rx = self.subscriber.on_message();
         while let Ok(message) = rx.recv().await {
+            // The subscriber is shared with the probes of the health check
+            if &*message.channel != PUB_SUB_CHANNEL {
+                continue;
+            }
+
             logger::debug!("Invalidating {message:?}");
             let key: CacheKind<'_> = match RedisValue::new(message.value)
                 .try_into()
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE user_roles ADD COLUMN expires_at TIMESTAMP;
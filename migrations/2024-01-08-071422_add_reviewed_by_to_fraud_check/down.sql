@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE fraud_check DROP COLUMN IF EXISTS reviewed_by;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE fraud_check ADD COLUMN IF NOT EXISTS reviewed_by VARCHAR(255);
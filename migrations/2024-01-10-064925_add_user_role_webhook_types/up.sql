@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'user_roles';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'user_role_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'user_role_updated';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'user_role_deleted';
@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS user_role_change_requests (
+	id SERIAL PRIMARY KEY,
+	request_id VARCHAR(64) NOT NULL,
+	user_id VARCHAR(64) NOT NULL,
+	merchant_id VARCHAR(64) NOT NULL,
+	role_id VARCHAR(64) NOT NULL,
+	role_expires_at TIMESTAMP,
+	requested_by VARCHAR(64) NOT NULL,
+	created_at TIMESTAMP NOT NULL DEFAULT now(),
+	expires_at TIMESTAMP NOT NULL,
+	CONSTRAINT role_change_request_id_unique UNIQUE (request_id),
+	CONSTRAINT role_change_request_user_merchant_unique UNIQUE (user_id, merchant_id)
+);
+
+CREATE INDEX IF NOT EXISTS role_change_requests_mid_index ON user_role_change_requests (merchant_id);
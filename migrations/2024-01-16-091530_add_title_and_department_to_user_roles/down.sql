@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE user_roles DROP COLUMN IF EXISTS title;
+ALTER TABLE user_roles DROP COLUMN IF EXISTS department;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE user_roles ADD COLUMN IF NOT EXISTS title VARCHAR(64);
+ALTER TABLE user_roles ADD COLUMN IF NOT EXISTS department VARCHAR(64);
@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS role_preferences (
+	id SERIAL PRIMARY KEY,
+	merchant_id VARCHAR(64) NOT NULL,
+	role_id VARCHAR(64) NOT NULL,
+	user_id VARCHAR(64),
+	default_landing_page VARCHAR(255),
+	visible_widgets TEXT[],
+	created_by VARCHAR(64) NOT NULL,
+	created_at TIMESTAMP NOT NULL DEFAULT now(),
+	last_modified_by VARCHAR(64) NOT NULL,
+	last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS role_preferences_role_default_unique_index ON role_preferences (merchant_id, role_id)
+WHERE user_id IS NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS role_preferences_user_override_unique_index ON role_preferences (merchant_id, role_id, user_id)
+WHERE user_id IS NOT NULL;
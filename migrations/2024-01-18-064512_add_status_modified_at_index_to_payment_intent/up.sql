@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_intent_status_modified_at_index ON payment_intent (status, modified_at);
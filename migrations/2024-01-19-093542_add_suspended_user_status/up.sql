@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "UserStatus" ADD VALUE IF NOT EXISTS 'suspended';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'user_role_suspended';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'user_role_resumed';
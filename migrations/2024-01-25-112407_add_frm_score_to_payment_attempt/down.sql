@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS frm_score;
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS frm_score_threshold;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS frm_score INTEGER;
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS frm_score_threshold INTEGER;
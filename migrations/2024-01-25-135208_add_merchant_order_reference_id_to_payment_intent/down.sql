@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_merchant_order_reference_id_index;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS merchant_order_reference_id;
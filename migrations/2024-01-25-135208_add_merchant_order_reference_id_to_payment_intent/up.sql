@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS merchant_order_reference_id VARCHAR(255) DEFAULT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS payment_intent_merchant_id_merchant_order_reference_id_index ON payment_intent (merchant_id, merchant_order_reference_id);